@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The method parameters that are parsed as JSON before being sent to the server.
+//!
+//! All other parameters are sent as strings, this mirrors `vRPCConvertParams` in Bitcoin Core's
+//! `src/rpc/client.cpp` so that e.g., a label of `123` is not sent as a number.
+
+use serde_json::Value;
+
+/// The method, the position and the name of each parameter that is passed as JSON.
+const CONVERT_PARAMS: &[(&str, usize, &str)] = &[
+    ("setmocktime", 0, "timestamp"),
+    ("mockscheduler", 0, "delta_time"),
+    ("utxoupdatepsbt", 1, "descriptors"),
+    ("generatetoaddress", 0, "nblocks"),
+    ("generatetoaddress", 2, "maxtries"),
+    ("generatetodescriptor", 0, "num_blocks"),
+    ("generatetodescriptor", 2, "maxtries"),
+    ("generateblock", 1, "transactions"),
+    ("generateblock", 2, "submit"),
+    ("getnetworkhashps", 0, "nblocks"),
+    ("getnetworkhashps", 1, "height"),
+    ("sendtoaddress", 1, "amount"),
+    ("sendtoaddress", 4, "subtractfeefromamount"),
+    ("sendtoaddress", 5, "replaceable"),
+    ("sendtoaddress", 6, "conf_target"),
+    ("sendtoaddress", 8, "avoid_reuse"),
+    ("sendtoaddress", 9, "fee_rate"),
+    ("sendtoaddress", 10, "verbose"),
+    ("settxfee", 0, "amount"),
+    ("sethdseed", 0, "newkeypool"),
+    ("getreceivedbyaddress", 1, "minconf"),
+    ("getreceivedbyaddress", 2, "include_immature_coinbase"),
+    ("getreceivedbylabel", 1, "minconf"),
+    ("getreceivedbylabel", 2, "include_immature_coinbase"),
+    ("listreceivedbyaddress", 0, "minconf"),
+    ("listreceivedbyaddress", 1, "include_empty"),
+    ("listreceivedbyaddress", 2, "include_watchonly"),
+    ("listreceivedbyaddress", 4, "include_immature_coinbase"),
+    ("listreceivedbylabel", 0, "minconf"),
+    ("listreceivedbylabel", 1, "include_empty"),
+    ("listreceivedbylabel", 2, "include_watchonly"),
+    ("listreceivedbylabel", 3, "include_immature_coinbase"),
+    ("getbalance", 1, "minconf"),
+    ("getbalance", 2, "include_watchonly"),
+    ("getbalance", 3, "avoid_reuse"),
+    ("getblockfrompeer", 1, "peer_id"),
+    ("getblockhash", 0, "height"),
+    ("waitforblockheight", 0, "height"),
+    ("waitforblockheight", 1, "timeout"),
+    ("waitforblock", 1, "timeout"),
+    ("waitfornewblock", 0, "timeout"),
+    ("listtransactions", 1, "count"),
+    ("listtransactions", 2, "skip"),
+    ("listtransactions", 3, "include_watchonly"),
+    ("walletpassphrase", 1, "timeout"),
+    ("getblocktemplate", 0, "template_request"),
+    ("listsinceblock", 1, "target_confirmations"),
+    ("listsinceblock", 2, "include_watchonly"),
+    ("listsinceblock", 3, "include_removed"),
+    ("listsinceblock", 4, "include_change"),
+    ("sendmany", 1, "amounts"),
+    ("sendmany", 2, "minconf"),
+    ("sendmany", 4, "subtractfeefrom"),
+    ("sendmany", 5, "replaceable"),
+    ("sendmany", 6, "conf_target"),
+    ("sendmany", 8, "fee_rate"),
+    ("sendmany", 9, "verbose"),
+    ("deriveaddresses", 1, "range"),
+    ("scanblocks", 1, "scanobjects"),
+    ("scanblocks", 2, "start_height"),
+    ("scanblocks", 3, "stop_height"),
+    ("scanblocks", 5, "options"),
+    ("scantxoutset", 1, "scanobjects"),
+    ("addmultisigaddress", 0, "nrequired"),
+    ("addmultisigaddress", 1, "keys"),
+    ("createmultisig", 0, "nrequired"),
+    ("createmultisig", 1, "keys"),
+    ("listunspent", 0, "minconf"),
+    ("listunspent", 1, "maxconf"),
+    ("listunspent", 2, "addresses"),
+    ("listunspent", 3, "include_unsafe"),
+    ("listunspent", 4, "query_options"),
+    ("getblock", 1, "verbosity"),
+    ("getblock", 1, "verbose"),
+    ("getblockheader", 1, "verbose"),
+    ("getchaintxstats", 0, "nblocks"),
+    ("gettransaction", 1, "include_watchonly"),
+    ("gettransaction", 2, "verbose"),
+    ("getrawtransaction", 1, "verbosity"),
+    ("getrawtransaction", 1, "verbose"),
+    ("createrawtransaction", 0, "inputs"),
+    ("createrawtransaction", 1, "outputs"),
+    ("createrawtransaction", 2, "locktime"),
+    ("createrawtransaction", 3, "replaceable"),
+    ("decoderawtransaction", 1, "iswitness"),
+    ("signrawtransactionwithkey", 1, "privkeys"),
+    ("signrawtransactionwithkey", 2, "prevtxs"),
+    ("signrawtransactionwithwallet", 1, "prevtxs"),
+    ("sendrawtransaction", 1, "maxfeerate"),
+    ("sendrawtransaction", 2, "maxburnamount"),
+    ("testmempoolaccept", 0, "rawtxs"),
+    ("testmempoolaccept", 1, "maxfeerate"),
+    ("submitpackage", 0, "package"),
+    ("combinerawtransaction", 0, "txs"),
+    ("fundrawtransaction", 1, "options"),
+    ("fundrawtransaction", 2, "iswitness"),
+    ("walletcreatefundedpsbt", 0, "inputs"),
+    ("walletcreatefundedpsbt", 1, "outputs"),
+    ("walletcreatefundedpsbt", 2, "locktime"),
+    ("walletcreatefundedpsbt", 3, "options"),
+    ("walletcreatefundedpsbt", 4, "bip32derivs"),
+    ("walletprocesspsbt", 1, "sign"),
+    ("walletprocesspsbt", 3, "bip32derivs"),
+    ("walletprocesspsbt", 4, "finalize"),
+    ("descriptorprocesspsbt", 1, "descriptors"),
+    ("descriptorprocesspsbt", 3, "bip32derivs"),
+    ("descriptorprocesspsbt", 4, "finalize"),
+    ("createpsbt", 0, "inputs"),
+    ("createpsbt", 1, "outputs"),
+    ("createpsbt", 2, "locktime"),
+    ("createpsbt", 3, "replaceable"),
+    ("combinepsbt", 0, "txs"),
+    ("joinpsbts", 0, "txs"),
+    ("finalizepsbt", 1, "extract"),
+    ("converttopsbt", 1, "permitsigdata"),
+    ("converttopsbt", 2, "iswitness"),
+    ("gettxout", 1, "n"),
+    ("gettxout", 2, "include_mempool"),
+    ("gettxoutproof", 0, "txids"),
+    ("gettxoutsetinfo", 1, "hash_or_height"),
+    ("gettxoutsetinfo", 2, "use_index"),
+    ("lockunspent", 0, "unlock"),
+    ("lockunspent", 1, "transactions"),
+    ("lockunspent", 2, "persistent"),
+    ("send", 0, "outputs"),
+    ("send", 1, "conf_target"),
+    ("send", 3, "fee_rate"),
+    ("send", 4, "options"),
+    ("sendall", 0, "recipients"),
+    ("sendall", 1, "conf_target"),
+    ("sendall", 3, "fee_rate"),
+    ("sendall", 4, "options"),
+    ("simulaterawtransaction", 0, "rawtxs"),
+    ("simulaterawtransaction", 1, "options"),
+    ("importprivkey", 2, "rescan"),
+    ("importaddress", 2, "rescan"),
+    ("importaddress", 3, "p2sh"),
+    ("importpubkey", 2, "rescan"),
+    ("importmulti", 0, "requests"),
+    ("importmulti", 1, "options"),
+    ("importdescriptors", 0, "requests"),
+    ("listdescriptors", 0, "private"),
+    ("createwalletdescriptor", 1, "options"),
+    ("verifychain", 0, "checklevel"),
+    ("verifychain", 1, "nblocks"),
+    ("getblockstats", 0, "hash_or_height"),
+    ("getblockstats", 1, "stats"),
+    ("pruneblockchain", 0, "height"),
+    ("keypoolrefill", 0, "newsize"),
+    ("getrawmempool", 0, "verbose"),
+    ("getrawmempool", 1, "mempool_sequence"),
+    ("estimatesmartfee", 0, "conf_target"),
+    ("estimaterawfee", 0, "conf_target"),
+    ("estimaterawfee", 1, "threshold"),
+    ("prioritisetransaction", 1, "dummy"),
+    ("prioritisetransaction", 2, "fee_delta"),
+    ("setban", 2, "bantime"),
+    ("setban", 3, "absolute"),
+    ("setnetworkactive", 0, "state"),
+    ("setwalletflag", 1, "value"),
+    ("getmempoolancestors", 1, "verbose"),
+    ("getmempooldescendants", 1, "verbose"),
+    ("gettxspendingprevout", 0, "outputs"),
+    ("bumpfee", 1, "options"),
+    ("psbtbumpfee", 1, "options"),
+    ("logging", 0, "include"),
+    ("logging", 1, "exclude"),
+    ("disconnectnode", 1, "nodeid"),
+    ("upgradewallet", 0, "version"),
+    ("rescanblockchain", 0, "start_height"),
+    ("rescanblockchain", 1, "stop_height"),
+    ("createwallet", 1, "disable_private_keys"),
+    ("createwallet", 2, "blank"),
+    ("createwallet", 4, "avoid_reuse"),
+    ("createwallet", 5, "descriptors"),
+    ("createwallet", 6, "load_on_startup"),
+    ("createwallet", 7, "external_signer"),
+    ("restorewallet", 2, "load_on_startup"),
+    ("loadwallet", 1, "load_on_startup"),
+    ("unloadwallet", 1, "load_on_startup"),
+    ("getnodeaddresses", 0, "count"),
+    ("addpeeraddress", 1, "port"),
+    ("addpeeraddress", 2, "tried"),
+    ("sendmsgtopeer", 0, "peer_id"),
+    ("stop", 0, "wait"),
+    ("addnode", 2, "v2transport"),
+    ("addconnection", 2, "v2transport"),
+];
+
+/// Returns the positional parameter `s` at `index` of `method`.
+pub fn positional(method: &str, index: usize, s: &str) -> Value {
+    let convert = CONVERT_PARAMS.iter().any(|&(m, i, _)| m == method && i == index);
+    param(convert, s)
+}
+
+/// Returns the named parameter `s` called `name` of `method`.
+pub fn named(method: &str, name: &str, s: &str) -> Value {
+    let convert = CONVERT_PARAMS.iter().any(|&(m, _, n)| m == method && n == name);
+    param(convert, s)
+}
+
+/// Parses `s` as JSON if `convert` is set, falls back to a string if `s` is not valid JSON (e.g.,
+/// a block hash passed as `hash_or_height`).
+fn param(convert: bool, s: &str) -> Value {
+    if convert {
+        if let Ok(json) = serde_json::from_str(s) {
+            return json;
+        }
+    }
+    Value::String(s.to_owned())
+}
@@ -0,0 +1,412 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! `bitcoind-rpc` - a `bitcoin-cli` replacement built on the typed JSON-RPC clients.
+//!
+//! Calls a JSON-RPC method and prints the returned JSON. The server version is detected
+//! automatically and used to pick the version specific type for the method, this allows printing
+//! the [`json::model`] conversion (`--model`) or reporting fields that the version specific type
+//! does not capture (`--check`), making the tool useful for debugging schema drift.
+//!
+//! [`json::model`]: bitcoind_json_rpc_client::json::model
+
+mod convert;
+mod types;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process;
+
+use bitcoind_json_rpc_client::bitcoin::Network;
+use bitcoind_json_rpc_client::client_sync::v17::Client;
+use bitcoind_json_rpc_client::client_sync::{self, Auth, ClientConfig};
+use serde_json::{Map, Value};
+
+const USAGE: &str = "\
+Usage: bitcoind-rpc [options] <method> [params...]
+
+Calls <method> on a bitcoind JSON-RPC server. Params are passed as strings, except for the ones that
+bitcoin-cli parses as JSON. With -named, params are given as <name>=<value> pairs.
+
+Options:
+  -help, -h               Print this help message
+  -named                  Pass named instead of positional params
+  -datadir=<dir>          Data directory (used to find bitcoin.conf and the cookie file)
+  -conf=<file>            Configuration file (default: <datadir>/bitcoin.conf)
+  -chain=<chain>          Use the chain <chain>: main, test, testnet4, signet or regtest
+  -testnet, -testnet4, -signet, -regtest
+                          Shorthand for -chain=<chain>
+  -rpcconnect=<ip>        Send commands to node running on <ip> (default: 127.0.0.1)
+  -rpcport=<port>         Connect to JSON-RPC on <port> (default: depends on chain)
+  -rpcuser=<user>         Username for JSON-RPC connections
+  -rpcpassword=<pw>       Password for JSON-RPC connections
+  -rpccookiefile=<file>   Cookie file (default: <datadir>/<chain>/.cookie)
+  -rpcwallet=<wallet>     Send the request to the wallet endpoint of <wallet>
+
+Output (in addition to the default raw JSON):
+  --model                 Print the result converted to the version non-specific model type
+  --check                 Report any fields that the version specific type does not capture
+";
+
+/// Exit code used when the result could not be parsed, converted or was not fully captured.
+const EXIT_MISMATCH: i32 = 2;
+
+fn main() {
+    let code = match run() {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            1
+        }
+    };
+    process::exit(code);
+}
+
+fn run() -> Result<i32, String> {
+    let args = Args::parse(std::env::args().skip(1))?;
+    if args.help {
+        print!("{}", USAGE);
+        return Ok(0);
+    }
+    let method = args.method.as_deref().ok_or_else(|| format!("missing method\n\n{}", USAGE))?;
+
+    let (url, auth) = args.connection()?;
+    let client = match auth {
+        Auth::None => Client::new(&url),
+        auth => Client::new_with_auth(&url, auth).map_err(|e| e.to_string())?,
+    };
+
+    let version = server_version(&client)?;
+    let json = match args.params {
+        Params::Positional(ref v) => client.call::<Value>(method, v),
+        Params::Named(ref m) => client.call_named::<Value>(method, m),
+    }
+    .map_err(|e| e.to_string())?;
+
+    if !args.model && !args.check {
+        print_json(&json);
+        return Ok(0);
+    }
+
+    let key = types::type_key(method, &args.params);
+    let parsed = match types::parse(version, &key, json.clone()) {
+        Some(Ok(parsed)) => parsed,
+        Some(Err(e)) => {
+            print_json(&json);
+            eprintln!("failed to parse result of `{}` using the v{} type: {}", key, version, e);
+            return Ok(EXIT_MISMATCH);
+        }
+        None => return Err(format!("no v{} type for method `{}`", version, key)),
+    };
+
+    let mut code = 0;
+    if args.model {
+        match parsed.model {
+            Some(Ok(ref model)) => print_json(model),
+            Some(Err(ref e)) => {
+                print_json(&json);
+                eprintln!("into_model failed for `{}`: {}", key, e);
+                code = EXIT_MISMATCH;
+            }
+            None => eprintln!("the v{} type for `{}` has no model", version, key),
+        }
+    } else {
+        print_json(&json);
+    }
+
+    if args.check {
+        let mut uncaptured = vec![];
+        find_uncaptured(&json, &parsed.typed, "", &mut uncaptured);
+        for path in &uncaptured {
+            eprintln!("uncaptured field: {}", path);
+        }
+        if !uncaptured.is_empty() {
+            code = EXIT_MISMATCH;
+        }
+    }
+    Ok(code)
+}
+
+/// Returns the major version of the server e.g., 17 for `v0.17.1` and 28 for `v28.0`.
+fn server_version(client: &Client) -> Result<usize, String> {
+    let info = client.call::<Value>("getnetworkinfo", &[]).map_err(|e| e.to_string())?;
+    let version = info
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or("`getnetworkinfo` did not return a version")?;
+    Ok(version as usize / 10_000)
+}
+
+/// Prints `json` the same way `bitcoin-cli` does i.e., strings without quotes and null as nothing.
+fn print_json(json: &Value) {
+    match *json {
+        Value::Null => {}
+        Value::String(ref s) => println!("{}", s),
+        ref v => println!("{}", serde_json::to_string_pretty(v).expect("valid JSON")),
+    }
+}
+
+/// Pushes the path of every field that is in `raw` but missing from `typed` onto `uncaptured`.
+fn find_uncaptured(raw: &Value, typed: &Value, path: &str, uncaptured: &mut Vec<String>) {
+    match (raw, typed) {
+        (Value::Object(raw), Value::Object(typed)) =>
+            for (key, value) in raw {
+                let path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                match typed.get(key) {
+                    Some(typed) => find_uncaptured(value, typed, &path, uncaptured),
+                    None => uncaptured.push(path),
+                }
+            },
+        (Value::Array(raw), Value::Array(typed)) =>
+            for (i, (raw, typed)) in raw.iter().zip(typed.iter()).enumerate() {
+                find_uncaptured(raw, typed, &format!("{}[{}]", path, i), uncaptured);
+            },
+        _ => {}
+    }
+}
+
+/// The parameters passed to the JSON-RPC method.
+pub enum Params {
+    /// Parameters passed by position.
+    Positional(Vec<Value>),
+    /// Parameters passed by name (`-named`).
+    Named(Map<String, Value>),
+}
+
+impl Params {
+    /// Returns the parameter at `index` if positional, or called `name` if named.
+    pub fn get(&self, index: usize, name: &str) -> Option<&Value> {
+        match *self {
+            Params::Positional(ref v) => v.get(index),
+            Params::Named(ref m) => m.get(name),
+        }
+    }
+}
+
+/// The parsed command line.
+struct Args {
+    help: bool,
+    model: bool,
+    check: bool,
+    /// The `-option=value` options, without the leading dash.
+    options: BTreeMap<String, String>,
+    method: Option<String>,
+    params: Params,
+}
+
+impl Args {
+    /// Parses the command line arguments (excluding the program name).
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut help = false;
+        let mut model = false;
+        let mut check = false;
+        let mut named = false;
+        let mut options = BTreeMap::new();
+        let mut method = None;
+        let mut params = vec![];
+
+        for arg in args {
+            if method.is_some() {
+                params.push(arg);
+                continue;
+            }
+            let option = match arg.strip_prefix("--").or_else(|| arg.strip_prefix('-')) {
+                Some(option) => option,
+                None => {
+                    method = Some(arg);
+                    continue;
+                }
+            };
+            let (name, value) = match option.split_once('=') {
+                Some((name, value)) => (name, value),
+                None => (option, ""),
+            };
+            match name {
+                "h" | "help" | "?" => help = true,
+                "model" => model = true,
+                "check" => check = true,
+                "named" => named = true,
+                "testnet" => {
+                    options.insert("chain".to_owned(), "test".to_owned());
+                }
+                "testnet4" | "signet" | "regtest" => {
+                    options.insert("chain".to_owned(), name.to_owned());
+                }
+                "datadir" | "conf" | "chain" | "rpcconnect" | "rpcport" | "rpcuser"
+                | "rpcpassword" | "rpccookiefile" | "rpcwallet" => {
+                    options.insert(name.to_owned(), value.to_owned());
+                }
+                _ => return Err(format!("unknown option: {}", arg)),
+            }
+        }
+
+        let params = match method {
+            Some(ref method) => Self::parse_params(method, named, params)?,
+            None => Params::Positional(vec![]),
+        };
+
+        Ok(Args { help, model, check, options, method, params })
+    }
+
+    /// Parses the params of `method`, see [`convert`] for which ones are parsed as JSON.
+    fn parse_params(method: &str, named: bool, params: Vec<String>) -> Result<Params, String> {
+        let params = if named {
+            let mut map = Map::new();
+            for param in params {
+                let (name, value) = param.split_once('=').ok_or_else(|| {
+                    format!("named param is not of the form <name>=<value>: {}", param)
+                })?;
+                map.insert(name.to_owned(), convert::named(method, name, value));
+            }
+            Params::Named(map)
+        } else {
+            let params = params.iter().enumerate();
+            Params::Positional(params.map(|(i, s)| convert::positional(method, i, s)).collect())
+        };
+        Ok(params)
+    }
+
+    /// Returns the URL and authentication to use, from the command line and `bitcoin.conf`.
+    fn connection(&self) -> Result<(String, Auth), String> {
+        let datadir = match self.options.get("datadir") {
+            Some(dir) => PathBuf::from(dir),
+            None => default_datadir().ok_or("failed to find the default data directory")?,
+        };
+        let network = match self.options.get("chain") {
+            Some(chain) => Some(
+                Network::from_core_arg(chain).map_err(|_| format!("unknown chain: {}", chain))?,
+            ),
+            None => None,
+        };
+        let conf = match self.options.get("conf") {
+            Some(path) => Some(PathBuf::from(path)),
+            None => Some(datadir.join("bitcoin.conf")).filter(|path| path.exists()),
+        };
+
+        let config = match (conf, network) {
+            (Some(conf), Some(network)) =>
+                ClientConfig::from_bitcoin_conf_with_network(conf, network),
+            (Some(conf), None) => ClientConfig::from_bitcoin_conf(conf),
+            (None, network) =>
+                ClientConfig::from_datadir(&datadir, network.unwrap_or(Network::Bitcoin)),
+        }
+        .map_err(|e| e.to_string())?;
+
+        // Command line options take precedence over `bitcoin.conf`, as in `bitcoin-cli`.
+        let mut config = config;
+        if let Some(host) = self.options.get("rpcconnect") {
+            config.host = host.clone();
+        }
+        if let Some(port) = self.options.get("rpcport") {
+            config.port = port.parse().map_err(|_| format!("invalid port: {}", port))?;
+        }
+        match (self.options.get("rpcuser"), self.options.get("rpcpassword")) {
+            (Some(user), Some(pass)) =>
+                config.auth = Auth::UserPass(user.clone(), pass.as_str().into()),
+            (Some(_), None) => return Err("-rpcuser requires -rpcpassword".to_owned()),
+            (None, Some(_)) => return Err("-rpcpassword requires -rpcuser".to_owned()),
+            (None, None) =>
+                if let Some(cookie) = self.options.get("rpccookiefile") {
+                    let dir = client_sync::network_datadir(&datadir, config.network);
+                    config.auth = Auth::CookieFile(dir.join(cookie));
+                },
+        }
+        if let Auth::CookieFile(ref cookie) = config.auth {
+            if !cookie.exists() {
+                return Err(format!(
+                    "no credentials, set -rpcuser and -rpcpassword or check the cookie file {}",
+                    cookie.display()
+                ));
+            }
+        }
+
+        let (mut url, auth) = config.into_url_and_auth();
+        if let Some(wallet) = self.options.get("rpcwallet") {
+            url.push_str("/wallet/");
+            url.push_str(&encode_uri(wallet));
+        }
+        Ok((url, auth))
+    }
+}
+
+/// Percent-encodes all but the unreserved characters of `s`, as `bitcoin-cli` does for wallet names.
+fn encode_uri(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' =>
+                encoded.push(b as char),
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+/// Returns the default `bitcoind` data directory for this platform.
+fn default_datadir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(|dir| PathBuf::from(dir).join("Bitcoin"))
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME")
+            .map(|dir| PathBuf::from(dir).join("Library/Application Support/Bitcoin"))
+    } else {
+        std::env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".bitcoin"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connection_encodes_wallet_name() {
+        let args = ["-regtest", "-rpcuser=user", "-rpcpassword=pass", "-rpcwallet=my wallet/#?%"];
+        let mut args = Args::parse(args.iter().map(|s| s.to_string())).unwrap();
+        args.options.insert("datadir".to_owned(), "/nonexistent".to_owned());
+
+        let (url, _) = args.connection().unwrap();
+        assert_eq!(url, "http://127.0.0.1:18443/wallet/my%20wallet%2F%23%3F%25");
+    }
+
+    #[test]
+    fn connection_requires_user_and_password() {
+        for (arg, want) in [
+            ("-rpcuser=user", "-rpcuser requires -rpcpassword"),
+            ("-rpcpassword=pass", "-rpcpassword requires -rpcuser"),
+        ] {
+            let args = ["-regtest", "-datadir=/nonexistent", arg];
+            let args = Args::parse(args.iter().map(|s| s.to_string())).unwrap();
+            assert_eq!(args.connection().unwrap_err(), want);
+        }
+    }
+
+    fn params(args: &[&str]) -> Vec<Value> {
+        match Args::parse(args.iter().map(|s| s.to_string())).unwrap().params {
+            Params::Positional(v) => v,
+            Params::Named(_) => panic!("expected positional params"),
+        }
+    }
+
+    #[test]
+    fn params_are_strings_unless_converted() {
+        assert_eq!(params(&["setlabel", "addr", "123"]), vec![Value::from("addr"), "123".into()]);
+        assert_eq!(params(&["loadwallet", "true"]), vec![Value::from("true")]);
+        assert_eq!(params(&["loadwallet", "w", "true"]), vec![Value::from("w"), true.into()]);
+        assert_eq!(params(&["getblockstats", "10"]), vec![Value::from(10)]);
+        let hash = "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206";
+        assert_eq!(params(&["getblockstats", hash]), vec![Value::from(hash)]);
+    }
+
+    #[test]
+    fn named_params_are_strings_unless_converted() {
+        let args = ["-named", "createwallet", "wallet_name=123", "descriptors=true"];
+        let args = Args::parse(args.iter().map(|s| s.to_string())).unwrap();
+        assert_eq!(args.params.get(0, "wallet_name"), Some(&Value::from("123")));
+        assert_eq!(args.params.get(5, "descriptors"), Some(&Value::from(true)));
+    }
+
+    #[test]
+    fn encode_uri_keeps_unreserved_characters() {
+        assert_eq!(encode_uri("Az09-._~"), "Az09-._~");
+        assert_eq!(encode_uri("w\u{e9}"), "w%C3%A9");
+    }
+}
@@ -0,0 +1,1424 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Maps JSON-RPC methods to the version specific types used to parse their results.
+//!
+//! Some methods return differently shaped data depending on their arguments (e.g. `getblock`
+//! verbosity), for these the lookup key is the method name followed by the shape selecting
+//! argument e.g., `getblock 0`. See [`type_key`].
+
+use std::error::Error;
+
+use bitcoind_json_rpc_client::json::model;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Params;
+
+/// The result of parsing a JSON-RPC response using a version specific type.
+pub struct Parsed {
+    /// The version specific type serialized back into JSON.
+    pub typed: Value,
+    /// The result of calling `into_model`, `None` if the type does not have an `into_model` function.
+    pub model: Option<Result<Value, String>>,
+}
+
+/// Returns the key used to look up the type that `method` returns when called with `params`.
+pub fn type_key(method: &str, params: &Params) -> String {
+    match method {
+        "getblock" => {
+            let verbosity = params.get(1, "verbosity").and_then(Value::as_u64).unwrap_or(1);
+            format!("{} {}", method, verbosity)
+        }
+        "getblockheader" => {
+            let verbose = params.get(1, "verbose").and_then(Value::as_bool).unwrap_or(true);
+            format!("{} {}", method, verbose)
+        }
+        "getrawtransaction" => {
+            // Before v25 the argument is the boolean `verbose`, after it is the numeric
+            // `verbosity` (which still accepts a boolean).
+            let verbosity = params
+                .get(1, "verbosity")
+                .or_else(|| params.get(1, "verbose"))
+                .and_then(|v| v.as_u64().or_else(|| v.as_bool().map(u64::from)))
+                .unwrap_or(0);
+            format!("{} {}", method, verbosity)
+        }
+        "getmemoryinfo" => {
+            let mode = params.get(0, "mode").and_then(Value::as_str).unwrap_or("stats");
+            format!("{} {}", method, mode)
+        }
+        "getmempoolancestors" | "getmempooldescendants" => {
+            let verbose = params.get(1, "verbose").and_then(Value::as_bool).unwrap_or(false);
+            format!("{} {}", method, verbose)
+        }
+        "scanblocks" | "scantxoutset" => {
+            let action = params.get(0, "action").and_then(Value::as_str).unwrap_or_default();
+            format!("{} {}", method, action)
+        }
+        "getrawmempool" => {
+            let verbose = params.get(0, "verbose").and_then(Value::as_bool).unwrap_or(false);
+            let sequence =
+                params.get(1, "mempool_sequence").and_then(Value::as_bool).unwrap_or(false);
+            if sequence && !verbose {
+                format!("{} sequence", method)
+            } else {
+                format!("{} {}", method, verbose)
+            }
+        }
+        _ => method.to_owned(),
+    }
+}
+
+/// Parses `json` using the type returned by the method identified by `key`.
+///
+/// Returns `None` if we do not support `version` or do not have a type for `key`.
+pub fn parse(version: usize, key: &str, json: Value) -> Option<Result<Parsed, String>> {
+    match version {
+        17 => v17::parse(key, json),
+        18 => v18::parse(key, json),
+        19 => v19::parse(key, json),
+        20 => v20::parse(key, json),
+        21 => v21::parse(key, json),
+        22 => v22::parse(key, json),
+        23 => v23::parse(key, json),
+        24 => v24::parse(key, json),
+        25 => v25::parse(key, json),
+        26 => v26::parse(key, json),
+        27 => v27::parse(key, json),
+        28 => v28::parse(key, json),
+        _ => None,
+    }
+}
+
+/// Parses `json` as a `T` and converts it to a model type using `into_model`.
+fn parse_with_model<T, R>(json: Value, into_model: impl FnOnce(T) -> R) -> Result<Parsed, String>
+where
+    T: DeserializeOwned + Serialize,
+    R: IntoJson,
+{
+    let t = serde_json::from_value::<T>(json).map_err(|e| e.to_string())?;
+    let typed = serde_json::to_value(&t).map_err(|e| e.to_string())?;
+    Ok(Parsed { typed, model: Some(into_model(t).into_json()) })
+}
+
+/// Parses `json` as a `T`, for types that do not have an `into_model` function.
+fn parse_without_model<T>(json: Value) -> Result<Parsed, String>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let t = serde_json::from_value::<T>(json).map_err(|e| e.to_string())?;
+    let typed = serde_json::to_value(&t).map_err(|e| e.to_string())?;
+    Ok(Parsed { typed, model: None })
+}
+
+/// Unifies the fallible and infallible `into_model` functions.
+trait IntoJson {
+    /// Serializes the model type, or the conversion error including all its sources.
+    fn into_json(self) -> Result<Value, String>;
+}
+
+impl<T: Serialize, E: Error> IntoJson for Result<T, E> {
+    fn into_json(self) -> Result<Value, String> {
+        match self {
+            Ok(model) => serde_json::to_value(model).map_err(|e| e.to_string()),
+            Err(e) => {
+                let mut s = e.to_string();
+                let mut source = e.source();
+                while let Some(e) = source {
+                    s.push_str(": ");
+                    s.push_str(&e.to_string());
+                    source = e.source();
+                }
+                Err(s)
+            }
+        }
+    }
+}
+
+/// Implements `IntoJson` for model types returned by infallible `into_model` functions.
+macro_rules! impl_into_json_infallible {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl IntoJson for model::$ty {
+                fn into_json(self) -> Result<Value, String> {
+                    serde_json::to_value(self).map_err(|e| e.to_string())
+                }
+            }
+        )*
+    };
+}
+impl_into_json_infallible!(
+    CreateWallet,
+    CreateWalletDescriptor,
+    GetAddrManInfo,
+    GetBlockCount,
+    GetConnectionCount,
+    GetDescriptorInfo,
+    GetDifficulty,
+    GetNetworkHashPs,
+    GetNodeAddresses,
+    GetPeerInfo,
+    GetRpcInfo,
+    ImportDescriptors,
+    ImportMulti,
+    ListLabels,
+    ListWalletDir,
+    ListWallets,
+    LoadWallet,
+    Logging,
+    PrioritiseTransaction,
+    ScanBlocksAbort,
+    ScanTxOutSetAbort,
+    SignMessage,
+    SignMessageWithPrivKey,
+    SubmitBlock,
+    UnloadWallet,
+    Uptime,
+    VerifyMessage,
+);
+
+/// Defines a module with a `parse` function for the types of a specific version.
+macro_rules! define_version_types {
+    (
+        $version:ident {
+            $($key:literal => $ty:ident,)*
+        } without_model {
+            $($key_without_model:literal => $ty_without_model:ident,)*
+        }
+    ) => {
+        mod $version {
+            use bitcoind_json_rpc_client::json::$version::*;
+            use serde_json::Value;
+
+            use super::Parsed;
+
+            /// Parses `json` using the type identified by `key`.
+            pub fn parse(key: &str, json: Value) -> Option<Result<Parsed, String>> {
+                let parsed = match key {
+                    $($key => super::parse_with_model(json, |t: $ty| t.into_model()),)*
+                    $($key_without_model => super::parse_without_model::<$ty_without_model>(json),)*
+                    _ => return None,
+                };
+                Some(parsed)
+            }
+        }
+    };
+}
+
+define_version_types!(v17 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockhash" => GetBlockHash,
+    "getblockheader false" => GetBlockHeader,
+    "getblockheader true" => GetBlockHeaderVerbose,
+    "getblockstats" => GetBlockStats,
+    "getchaintips" => GetChainTips,
+    "getchaintxstats" => GetChainTxStats,
+    "getdifficulty" => GetDifficulty,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generate" => Generate,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "createmultisig" => CreateMultisig,
+    "estimatesmartfee" => EstimateSmartFee,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importmulti" => ImportMulti,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v18 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockhash" => GetBlockHash,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importmulti" => ImportMulti,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v19 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importmulti" => ImportMulti,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v20 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importmulti" => ImportMulti,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v21 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v22 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listdescriptors" => ListDescriptors,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "unloadwallet" => UnloadWallet,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v23 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblock 3" => GetBlockVerbosityThree,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getdeploymentinfo" => GetDeploymentInfo,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listdescriptors" => ListDescriptors,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "unloadwallet" => UnloadWallet,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v24 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblock 3" => GetBlockVerbosityThree,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getdeploymentinfo" => GetDeploymentInfo,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listdescriptors" => ListDescriptors,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendall" => SendAll,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "unloadwallet" => UnloadWallet,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v25 {
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblock 3" => GetBlockVerbosityThree,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getdeploymentinfo" => GetDeploymentInfo,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "scanblocks abort" => ScanBlocksAbort,
+    "scanblocks start" => ScanBlocksStart,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "getrawtransaction 2" => GetRawTransactionVerbosityTwo,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listdescriptors" => ListDescriptors,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendall" => SendAll,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "unloadwallet" => UnloadWallet,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v26 {
+    "dumptxoutset" => DumpTxOutSet,
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblock 3" => GetBlockVerbosityThree,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getchainstates" => GetChainStates,
+    "getdeploymentinfo" => GetDeploymentInfo,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "loadtxoutset" => LoadTxOutSet,
+    "scanblocks abort" => ScanBlocksAbort,
+    "scanblocks start" => ScanBlocksStart,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "getprioritisedtransactions" => GetPrioritisedTransactions,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getaddrmaninfo" => GetAddrManInfo,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "descriptorprocesspsbt" => DescriptorProcessPsbt,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "getrawtransaction 2" => GetRawTransactionVerbosityTwo,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "submitpackage" => SubmitPackage,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listdescriptors" => ListDescriptors,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendall" => SendAll,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "unloadwallet" => UnloadWallet,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v27 {
+    "dumptxoutset" => DumpTxOutSet,
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblock 3" => GetBlockVerbosityThree,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getchainstates" => GetChainStates,
+    "getdeploymentinfo" => GetDeploymentInfo,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "loadtxoutset" => LoadTxOutSet,
+    "scanblocks abort" => ScanBlocksAbort,
+    "scanblocks start" => ScanBlocksStart,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "getprioritisedtransactions" => GetPrioritisedTransactions,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getaddrmaninfo" => GetAddrManInfo,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "descriptorprocesspsbt" => DescriptorProcessPsbt,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "getrawtransaction 2" => GetRawTransactionVerbosityTwo,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "submitpackage" => SubmitPackage,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listdescriptors" => ListDescriptors,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendall" => SendAll,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "unloadwallet" => UnloadWallet,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
+
+define_version_types!(v28 {
+    "dumptxoutset" => DumpTxOutSet,
+    "getbestblockhash" => GetBestBlockHash,
+    "getblock 0" => GetBlockVerbosityZero,
+    "getblock 1" => GetBlockVerbosityOne,
+    "getblock 2" => GetBlockVerbosityTwo,
+    "getblock 3" => GetBlockVerbosityThree,
+    "getblockchaininfo" => GetBlockchainInfo,
+    "getblockcount" => GetBlockCount,
+    "getblockfilter" => GetBlockFilter,
+    "getblockhash" => GetBlockHash,
+    "getchainstates" => GetChainStates,
+    "getdeploymentinfo" => GetDeploymentInfo,
+    "getmempoolancestors false" => GetMempoolAncestors,
+    "getmempoolancestors true" => GetMempoolAncestorsVerbose,
+    "getmempooldescendants false" => GetMempoolDescendants,
+    "getmempooldescendants true" => GetMempoolDescendantsVerbose,
+    "getmempoolentry" => GetMempoolEntry,
+    "getmempoolinfo" => GetMempoolInfo,
+    "getrawmempool false" => GetRawMempool,
+    "getrawmempool true" => GetRawMempoolVerbose,
+    "getrawmempool sequence" => GetRawMempoolSequence,
+    "gettxout" => GetTxOut,
+    "gettxoutsetinfo" => GetTxOutSetInfo,
+    "loadtxoutset" => LoadTxOutSet,
+    "scanblocks abort" => ScanBlocksAbort,
+    "scanblocks start" => ScanBlocksStart,
+    "scantxoutset abort" => ScanTxOutSetAbort,
+    "scantxoutset start" => ScanTxOutSetStart,
+    "waitforblock" => WaitForBlock,
+    "waitforblockheight" => WaitForBlockHeight,
+    "waitfornewblock" => WaitForNewBlock,
+    "getrpcinfo" => GetRpcInfo,
+    "logging" => Logging,
+    "uptime" => Uptime,
+    "generatetoaddress" => GenerateToAddress,
+    "getblocktemplate" => GetBlockTemplate,
+    "getmininginfo" => GetMiningInfo,
+    "getnetworkhashps" => GetNetworkHashPs,
+    "getprioritisedtransactions" => GetPrioritisedTransactions,
+    "prioritisetransaction" => PrioritiseTransaction,
+    "submitblock" => SubmitBlock,
+    "getaddrmaninfo" => GetAddrManInfo,
+    "getconnectioncount" => GetConnectionCount,
+    "getnetworkinfo" => GetNetworkInfo,
+    "getnodeaddresses" => GetNodeAddresses,
+    "getpeerinfo" => GetPeerInfo,
+    "listbanned" => ListBanned,
+    "analyzepsbt" => AnalyzePsbt,
+    "combinepsbt" => CombinePsbt,
+    "combinerawtransaction" => CombineRawTransaction,
+    "converttopsbt" => ConvertToPsbt,
+    "createpsbt" => CreatePsbt,
+    "createrawtransaction" => CreateRawTransaction,
+    "decodepsbt" => DecodePsbt,
+    "decoderawtransaction" => DecodeRawTransaction,
+    "decodescript" => DecodeScript,
+    "descriptorprocesspsbt" => DescriptorProcessPsbt,
+    "finalizepsbt" => FinalizePsbt,
+    "fundrawtransaction" => FundRawTransaction,
+    "getrawtransaction 0" => GetRawTransaction,
+    "getrawtransaction 1" => GetRawTransactionVerbose,
+    "getrawtransaction 2" => GetRawTransactionVerbosityTwo,
+    "joinpsbts" => JoinPsbts,
+    "sendrawtransaction" => SendRawTransaction,
+    "signrawtransactionwithkey" => SignRawTransaction,
+    "submitpackage" => SubmitPackage,
+    "testmempoolaccept" => TestMempoolAccept,
+    "utxoupdatepsbt" => UtxoUpdatePsbt,
+    "createmultisig" => CreateMultisig,
+    "deriveaddresses" => DeriveAddresses,
+    "estimatesmartfee" => EstimateSmartFee,
+    "getdescriptorinfo" => GetDescriptorInfo,
+    "getindexinfo" => GetIndexInfo,
+    "signmessagewithprivkey" => SignMessageWithPrivKey,
+    "validateaddress" => ValidateAddress,
+    "verifymessage" => VerifyMessage,
+    "bumpfee" => BumpFee,
+    "createwallet" => CreateWallet,
+    "getaddressesbylabel" => GetAddressesByLabel,
+    "getaddressinfo" => GetAddressInfo,
+    "getbalance" => GetBalance,
+    "getbalances" => GetBalances,
+    "getnewaddress" => GetNewAddress,
+    "gettransaction" => GetTransaction,
+    "getwalletinfo" => GetWalletInfo,
+    "listdescriptors" => ListDescriptors,
+    "listlabels" => ListLabels,
+    "listsinceblock" => ListSinceBlock,
+    "listtransactions" => ListTransactions,
+    "listunspent" => ListUnspent,
+    "listwalletdir" => ListWalletDir,
+    "listwallets" => ListWallets,
+    "loadwallet" => LoadWallet,
+    "importdescriptors" => ImportDescriptors,
+    "importmulti" => ImportMulti,
+    "createwalletdescriptor" => CreateWalletDescriptor,
+    "gethdkeys" => GetHdKeys,
+    "psbtbumpfee" => PsbtBumpFee,
+    "send" => Send,
+    "sendall" => SendAll,
+    "sendmany" => SendMany,
+    "sendtoaddress" => SendToAddress,
+    "signmessage" => SignMessage,
+    "signrawtransactionwithwallet" => SignRawTransaction,
+    "unloadwallet" => UnloadWallet,
+    "walletcreatefundedpsbt" => WalletCreateFundedPsbt,
+    "walletprocesspsbt" => WalletProcessPsbt,
+} without_model {
+    "getmemoryinfo mallocinfo" => GetMemoryInfoMallocInfo,
+    "getmemoryinfo stats" => GetMemoryInfoStats,
+    "getaddednodeinfo" => GetAddedNodeInfo,
+    "getnettotals" => GetNetTotals,
+});
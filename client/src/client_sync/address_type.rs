@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The address types supported by Bitcoin Core v23 and later.
+//!
+//! Defined here rather than in `v23` so that the option structs in `v17` can use it, every version
+//! module re-exports it as `ChangeType`.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Argument to the `Client::get_new_address_with_type` function.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AddressType {
+    Legacy,
+    P2shSegwit,
+    Bech32,
+    Bech32m,
+}
+
+impl fmt::Display for AddressType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use AddressType::*;
+
+        let s = match *self {
+            Legacy => "legacy",
+            P2shSegwit => "p2sh-segwit",
+            Bech32 => "bech32",
+            Bech32m => "bech32m",
+        };
+        fmt::Display::fmt(s, f)
+    }
+}
@@ -0,0 +1,462 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Support for building a client configuration from a `bitcoind` data directory and `bitcoin.conf`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bitcoin::Network;
+
+use crate::client_sync::{Auth, Error, Result};
+
+/// The options that only apply to mainnet when they are in the top section of `bitcoin.conf`.
+///
+/// This is the subset of Core's network only options that we care about.
+const NETWORK_ONLY_OPTIONS: [&str; 1] = ["rpcport"];
+
+/// The default `bitcoind` JSON-RPC port for `network`.
+pub fn default_rpc_port(network: Network) -> u16 {
+    // Match on the Core argument name so we handle networks added in later `bitcoin` versions.
+    match network.to_core_arg() {
+        "main" => 8332,
+        "test" => 18332,
+        "testnet4" => 48332,
+        "signet" => 38332,
+        _ => 18443,
+    }
+}
+
+/// The directory within `datadir` that `bitcoind` uses for `network` e.g., `<datadir>/regtest`.
+pub fn network_datadir<P: AsRef<Path>>(datadir: P, network: Network) -> PathBuf {
+    let datadir = datadir.as_ref();
+    match network.to_core_arg() {
+        "main" => datadir.to_path_buf(),
+        "test" => datadir.join("testnet3"),
+        dir => datadir.join(dir),
+    }
+}
+
+/// The configuration required to connect to a `bitcoind` JSON-RPC server.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ClientConfig {
+    /// The network the server is running on.
+    pub network: Network,
+    /// The host to connect to (`rpcconnect`).
+    pub host: String,
+    /// The port to connect to (`rpcport`).
+    pub port: u16,
+    /// The authentication to use.
+    pub auth: Auth,
+}
+
+impl ClientConfig {
+    /// Builds the configuration for a server running on `network` and using `datadir`.
+    ///
+    /// Reads `<datadir>/bitcoin.conf` if it exists, otherwise connects to localhost on the default
+    /// port for `network` using the default cookie file.
+    pub fn from_datadir<P: AsRef<Path>>(datadir: P, network: Network) -> Result<Self> {
+        let datadir = datadir.as_ref();
+        let conf = datadir.join("bitcoin.conf");
+        let conf = if conf.exists() {
+            BitcoinConf::read(&conf, datadir, Some(network))?.0
+        } else {
+            BitcoinConf::default()
+        };
+        conf.client_config(datadir, network)
+    }
+
+    /// Builds the configuration by reading the `bitcoin.conf` file at `path`.
+    ///
+    /// The network is the one selected in the file (`chain=` or `regtest=1` etc.), defaulting to
+    /// mainnet. The data directory is the one set in the file (`datadir=`), defaulting to the
+    /// directory that contains the file.
+    pub fn from_bitcoin_conf<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let (conf, datadir, network) = BitcoinConf::read_with_datadir(path.as_ref(), None)?;
+        conf.client_config(&datadir, network)
+    }
+
+    /// Builds the configuration for `network` by reading the `bitcoin.conf` file at `path`.
+    ///
+    /// Same as [`ClientConfig::from_bitcoin_conf`] but ignores the network selected in the file.
+    pub fn from_bitcoin_conf_with_network<P: AsRef<Path>>(
+        path: P,
+        network: Network,
+    ) -> Result<Self> {
+        let (conf, datadir, network) =
+            BitcoinConf::read_with_datadir(path.as_ref(), Some(network))?;
+        conf.client_config(&datadir, network)
+    }
+
+    /// Returns the URL of the JSON-RPC server e.g., `http://127.0.0.1:18443`.
+    pub fn url(&self) -> String { format!("http://{}:{}", self.host, self.port) }
+
+    /// Returns the URL of the JSON-RPC server and the authentication to use.
+    pub fn into_url_and_auth(self) -> (String, Auth) { (self.url(), self.auth) }
+}
+
+impl Auth {
+    /// Returns the authentication for a server running on `network` and using `datadir`.
+    ///
+    /// Uses `rpcuser`/`rpcpassword` or `rpccookiefile` from `<datadir>/bitcoin.conf` if set,
+    /// otherwise the default cookie file for `network`.
+    pub fn from_datadir<P: AsRef<Path>>(datadir: P, network: Network) -> Result<Self> {
+        Ok(ClientConfig::from_datadir(datadir, network)?.auth)
+    }
+}
+
+/// The options parsed from a `bitcoin.conf` file, by section.
+///
+/// The top section has the empty string as its name. Every value of an option is kept, in the
+/// order read, since some options can be set multiple times (e.g., `rpcauth` or `includeconf`).
+#[derive(Debug, Default)]
+struct BitcoinConf(BTreeMap<String, BTreeMap<String, Vec<String>>>);
+
+impl BitcoinConf {
+    /// Reads the file at `path`, the data directory it uses and the network it is read for.
+    ///
+    /// See [`BitcoinConf::read`] for how `network` is used.
+    fn read_with_datadir(
+        path: &Path,
+        network: Option<Network>,
+    ) -> Result<(Self, PathBuf, Network)> {
+        // Read once without includes to find `datadir`, includes are relative to it.
+        let top = Self::parse(&read(path)?)?;
+        let datadir = match top.get("", "datadir") {
+            Some(dir) => PathBuf::from(dir),
+            None => path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        };
+        let (conf, network) = Self::read(path, &datadir, network)?;
+        Ok((conf, datadir, network))
+    }
+
+    /// Reads the file at `path` and any files it includes with `includeconf`.
+    ///
+    /// As in Core, only `includeconf` in the top section and in the section for `network` is
+    /// followed, included files are relative to `datadir` and can not include further files. If
+    /// `network` is `None` the network selected in the file (or a top section include) is used.
+    fn read(path: &Path, datadir: &Path, network: Option<Network>) -> Result<(Self, Network)> {
+        let mut conf = Self::parse(&read(path)?)?;
+        conf.include(datadir, "")?;
+        let network = match network {
+            Some(network) => network,
+            None => conf.network()?,
+        };
+        conf.include(datadir, network.to_core_arg())?;
+        Ok((conf, network))
+    }
+
+    /// Merges in every file included with `includeconf` in `section`, in order.
+    fn include(&mut self, datadir: &Path, section: &str) -> Result<()> {
+        let paths: Vec<PathBuf> = self
+            .get_all(section, "includeconf")
+            .iter()
+            .map(|include| datadir.join(include))
+            .collect();
+        for path in paths {
+            let mut included = Self::parse(&read(&path)?)?;
+            for options in included.0.values_mut() {
+                options.remove("includeconf");
+            }
+            self.merge(included);
+        }
+        Ok(())
+    }
+
+    /// Parses the contents of a `bitcoin.conf` file.
+    fn parse(s: &str) -> Result<Self> {
+        let mut conf = BitcoinConf::default();
+        let mut section = String::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let (line, used_hash) = match line.split_once('#') {
+                Some((line, _)) => (line.trim(), true),
+                None => (line.trim(), false),
+            };
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name.strip_suffix(']').ok_or_else(|| {
+                    Error::InvalidBitcoinConf(format!("line {}: invalid section: {}", i + 1, line))
+                })?;
+                section = name.trim().to_owned();
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                Error::InvalidBitcoinConf(format!(
+                    "line {}: expected <key>=<value>: {}",
+                    i + 1,
+                    line
+                ))
+            })?;
+            let (key, value) = (key.trim(), value.trim());
+
+            // As in Core, a `#` starts a comment but is ambiguous in a password so is rejected.
+            if used_hash && key.contains("rpcpassword") {
+                return Err(Error::InvalidBitcoinConf(format!(
+                    "line {}: using # in rpcpassword can be ambiguous and should be avoided",
+                    i + 1
+                )));
+            }
+
+            // Options in the top section may be prefixed with a section name e.g., `regtest.rpcport`.
+            let (section, key) = match key.split_once('.') {
+                Some((prefix, key)) if section.is_empty() => (prefix, key),
+                _ => (section.as_str(), key),
+            };
+            conf.insert(section, key, value);
+        }
+        Ok(conf)
+    }
+
+    /// Inserts an option, values of an option that is set multiple times are kept in order.
+    fn insert(&mut self, section: &str, key: &str, value: &str) {
+        self.0
+            .entry(section.to_owned())
+            .or_default()
+            .entry(key.to_owned())
+            .or_default()
+            .push(value.to_owned());
+    }
+
+    /// Merges the options in `other` into `self`, values already in `self` come first.
+    fn merge(&mut self, other: BitcoinConf) {
+        for (section, options) in other.0 {
+            for (key, values) in options {
+                for value in values {
+                    self.insert(&section, &key, &value);
+                }
+            }
+        }
+    }
+
+    /// Returns the value of the option `key` in `section`.
+    ///
+    /// If the option is set multiple times the first value is used (as in Core).
+    fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.get_all(section, key).first().map(String::as_str)
+    }
+
+    /// Returns all the values of the option `key` in `section`.
+    fn get_all(&self, section: &str, key: &str) -> &[String] {
+        self.0.get(section).and_then(|options| options.get(key)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the value of the option `key` that applies to `network`.
+    ///
+    /// Options in the network section take precedence over options in the top section. Network
+    /// only options in the top section only apply to mainnet.
+    fn get_for_network(&self, network: Network, key: &str) -> Option<&str> {
+        let section = network.to_core_arg();
+        if let Some(value) = self.get(section, key) {
+            return Some(value);
+        }
+        if NETWORK_ONLY_OPTIONS.contains(&key) && section != "main" {
+            return None;
+        }
+        self.get("", key)
+    }
+
+    /// Returns the network selected in the top section, defaulting to mainnet.
+    fn network(&self) -> Result<Network> {
+        if let Some(chain) = self.get("", "chain") {
+            return Network::from_core_arg(chain)
+                .map_err(|_| Error::InvalidBitcoinConf(format!("unknown chain: {}", chain)));
+        }
+        for arg in ["test", "testnet4", "signet", "regtest"] {
+            let flag = if arg == "test" { "testnet" } else { arg };
+            if self.get("", flag) == Some("1") {
+                // Can only fail for networks not supported by the `bitcoin` dependency.
+                return Network::from_core_arg(arg)
+                    .map_err(|_| Error::InvalidBitcoinConf(format!("unknown chain: {}", arg)));
+            }
+        }
+        Ok(Network::Bitcoin)
+    }
+
+    /// Builds the client configuration for `network`.
+    fn client_config(&self, datadir: &Path, network: Network) -> Result<ClientConfig> {
+        let mut host =
+            self.get_for_network(network, "rpcconnect").unwrap_or("127.0.0.1").to_owned();
+        let mut port = default_rpc_port(network);
+
+        // As in Core, `rpcconnect` may include a port which is overridden by `rpcport`.
+        if let Some((h, p)) = host.rsplit_once(':') {
+            if !h.contains(':') || h.ends_with(']') {
+                port = parse_port(p)?;
+                host = h.to_owned();
+            }
+        }
+        if let Some(p) = self.get_for_network(network, "rpcport") {
+            port = parse_port(p)?;
+        }
+
+        let user = self.get_for_network(network, "rpcuser");
+        let pass = self.get_for_network(network, "rpcpassword");
+        let auth = match (user, pass) {
+            (Some(user), Some(pass)) => Auth::UserPass(user.to_owned(), pass.into()),
+            _ => {
+                let cookie = self.get_for_network(network, "rpccookiefile").unwrap_or(".cookie");
+                Auth::CookieFile(network_datadir(datadir, network).join(cookie))
+            }
+        };
+
+        Ok(ClientConfig { network, host, port, auth })
+    }
+}
+
+/// Reads the file at `path` to a string.
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| Error::InvalidBitcoinConf(format!("failed to read {}: {}", path.display(), e)))
+}
+
+/// Parses a port number.
+fn parse_port(s: &str) -> Result<u16> {
+    s.parse::<u16>().map_err(|_| Error::InvalidBitcoinConf(format!("invalid port: {}", s)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sections_and_network_only_options() {
+        let s = "\
+# A comment.
+rpcuser=alice
+rpcpassword=secret
+rpcport=1234 # Trailing comment.
+regtest=1
+
+[regtest]
+rpcconnect=10.0.0.1
+
+[test]
+rpcport=5678
+";
+        let conf = BitcoinConf::parse(s).unwrap();
+        assert_eq!(conf.network().unwrap(), Network::Regtest);
+
+        let config = conf.client_config(Path::new("/data"), Network::Regtest).unwrap();
+        assert_eq!(config.url(), "http://10.0.0.1:18443");
+        assert_eq!(config.auth, Auth::UserPass("alice".to_owned(), "secret".into()));
+        assert!(!format!("{:?}", config).contains("secret"));
+
+        let config = conf.client_config(Path::new("/data"), Network::Testnet).unwrap();
+        assert_eq!(config.url(), "http://127.0.0.1:5678");
+
+        let config = conf.client_config(Path::new("/data"), Network::Bitcoin).unwrap();
+        assert_eq!(config.url(), "http://127.0.0.1:1234");
+    }
+
+    #[test]
+    fn cookie_file_defaults_to_network_datadir() {
+        let conf = BitcoinConf::parse("chain=signet\n[regtest]\nrpccookiefile=my.cookie").unwrap();
+        assert_eq!(conf.network().unwrap(), Network::Signet);
+
+        let config = conf.client_config(Path::new("/data"), Network::Signet).unwrap();
+        assert_eq!(config.auth, Auth::CookieFile(PathBuf::from("/data/signet/.cookie")));
+        assert_eq!(config.port, 38332);
+
+        let config = conf.client_config(Path::new("/data"), Network::Regtest).unwrap();
+        assert_eq!(config.auth, Auth::CookieFile(PathBuf::from("/data/regtest/my.cookie")));
+
+        let config = conf.client_config(Path::new("/data"), Network::Bitcoin).unwrap();
+        assert_eq!(config.auth, Auth::CookieFile(PathBuf::from("/data/.cookie")));
+    }
+
+    #[test]
+    fn section_prefixed_options_and_rpcconnect_port() {
+        let conf = BitcoinConf::parse("regtest.rpcconnect=example.com:4321").unwrap();
+        let config = conf.client_config(Path::new("/data"), Network::Regtest).unwrap();
+        assert_eq!(config.url(), "http://example.com:4321");
+
+        let conf = BitcoinConf::parse("rpcconnect=example.com:4321\nrpcport=1111").unwrap();
+        let config = conf.client_config(Path::new("/data"), Network::Bitcoin).unwrap();
+        assert_eq!(config.url(), "http://example.com:1111");
+    }
+
+    #[test]
+    fn includeconf() {
+        let dir = std::env::temp_dir().join(format!("bitcoin-conf-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("bitcoin.conf"), "includeconf=extra.conf\nrpcuser=alice\n").unwrap();
+        fs::write(dir.join("extra.conf"), "rpcuser=bob\nrpcpassword=secret\nregtest=1\n").unwrap();
+
+        let config = ClientConfig::from_bitcoin_conf(dir.join("bitcoin.conf")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(config.network, Network::Regtest);
+        assert_eq!(config.auth, Auth::UserPass("alice".to_owned(), "secret".into()));
+    }
+
+    #[test]
+    fn includeconf_multiple_files() {
+        let dir = std::env::temp_dir().join(format!("bitcoin-conf-multi-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let conf = "includeconf=a.conf\nincludeconf=b.conf\nrpcallowip=10.0.0.1\n";
+        fs::write(dir.join("bitcoin.conf"), conf).unwrap();
+        fs::write(dir.join("a.conf"), "rpcuser=alice\nrpcallowip=10.0.0.2\n").unwrap();
+        fs::write(dir.join("b.conf"), "rpcpassword=secret\nrpcallowip=10.0.0.3\n").unwrap();
+
+        let (conf, network) =
+            BitcoinConf::read(&dir.join("bitcoin.conf"), &dir, Some(Network::Regtest)).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(conf.get_all("", "rpcallowip"), ["10.0.0.1", "10.0.0.2", "10.0.0.3"]);
+        let config = conf.client_config(&dir, network).unwrap();
+        assert_eq!(config.auth, Auth::UserPass("alice".to_owned(), "secret".into()));
+    }
+
+    #[test]
+    fn includeconf_only_for_selected_network() {
+        let dir = std::env::temp_dir().join(format!("bitcoin-conf-net-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let conf = "\
+regtest=1
+[main]
+includeconf=main.conf
+[regtest]
+includeconf=regtest.conf
+";
+        fs::write(dir.join("bitcoin.conf"), conf).unwrap();
+        fs::write(dir.join("main.conf"), "rpcuser=main\nrpcpassword=main\n").unwrap();
+        fs::write(dir.join("regtest.conf"), "rpcconnect=10.0.0.1\n").unwrap();
+
+        let regtest = ClientConfig::from_bitcoin_conf(dir.join("bitcoin.conf")).unwrap();
+        let main = ClientConfig::from_bitcoin_conf_with_network(
+            dir.join("bitcoin.conf"),
+            Network::Bitcoin,
+        )
+        .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(regtest.network, Network::Regtest);
+        assert_eq!(regtest.url(), "http://10.0.0.1:18443");
+        assert_eq!(regtest.auth, Auth::CookieFile(dir.join("regtest/.cookie")));
+        assert_eq!(main.url(), "http://127.0.0.1:8332");
+        assert_eq!(main.auth, Auth::UserPass("main".to_owned(), "main".into()));
+    }
+
+    #[test]
+    fn hash_in_rpcpassword() {
+        assert!(BitcoinConf::parse("rpcpassword=ab#cd").is_err());
+        assert!(BitcoinConf::parse("regtest.rpcpassword=ab # Comment.").is_err());
+
+        let conf = "rpcuser=alice\nrpcpassword=abcd\n# rpcpassword=ab#cd";
+        let conf = BitcoinConf::parse(conf).unwrap();
+        let config = conf.client_config(Path::new("/data"), Network::Bitcoin).unwrap();
+        assert_eq!(config.auth, Auth::UserPass("alice".to_owned(), "abcd".into()));
+    }
+
+    #[test]
+    fn invalid_conf() {
+        assert!(BitcoinConf::parse("[regtest").is_err());
+        assert!(BitcoinConf::parse("rpcuser").is_err());
+        assert!(BitcoinConf::parse("chain=foo").unwrap().network().is_err());
+        let conf = BitcoinConf::parse("rpcport=foo").unwrap();
+        assert!(conf.client_config(Path::new("/data"), Network::Bitcoin).is_err());
+    }
+}
@@ -1,8 +1,14 @@
 // SPDX-License-Identifier: CC0-1.0
 
+use std::time::Duration;
 use std::{error, fmt, io};
 
-use bitcoin::{hex, secp256k1};
+use bitcoin::bip158::FilterHeader;
+use bitcoin::psbt::Psbt;
+use bitcoin::{hex, secp256k1, BlockHash, Txid};
+use json::model::RejectReason;
+use json::v17::{FinalizePsbtError, WalletCreateFundedPsbtError};
+use json::v19::GetBlockFilterError;
 
 /// The error type for errors produced in this library.
 #[derive(Debug)]
@@ -16,6 +22,8 @@ pub enum Error {
     Io(io::Error),
     InvalidAmount(bitcoin::amount::ParseAmountError),
     InvalidCookieFile,
+    /// Failed to read or parse a `bitcoin.conf` file.
+    InvalidBitcoinConf(String),
     /// The JSON result had an unexpected structure.
     UnexpectedStructure,
     /// The daemon returned an error string.
@@ -24,6 +32,14 @@ pub enum Error {
     ServerVersion(UnexpectedServerVersionError),
     /// Missing user/password
     MissingUserPassword,
+    /// A blocking wait helper gave up before its condition was met.
+    Timeout(WaitTimeoutError),
+    /// A step of a PSBT workflow failed.
+    PsbtWorkflow(PsbtWorkflowError),
+    /// A block filter header does not commit to its filter and the previous filter header.
+    FilterHeaderMismatch(FilterHeaderMismatchError),
+    /// An option was set that the server version does not support, nothing was sent.
+    UnsupportedOption(UnsupportedOptionError),
 }
 
 impl From<jsonrpc::error::Error> for Error {
@@ -58,6 +74,15 @@ impl From<bitcoin::amount::ParseAmountError> for Error {
     fn from(e: bitcoin::amount::ParseAmountError) -> Error { Error::InvalidAmount(e) }
 }
 
+impl From<GetBlockFilterError> for Error {
+    fn from(e: GetBlockFilterError) -> Error {
+        match e {
+            GetBlockFilterError::Filter(e) => Error::HexToBytes(e),
+            GetBlockFilterError::Header(e) => Error::HexToArray(e),
+        }
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Error::*;
@@ -72,10 +97,15 @@ impl fmt::Display for Error {
             Io(ref e) => write!(f, "I/O error: {}", e),
             InvalidAmount(ref e) => write!(f, "invalid amount: {}", e),
             InvalidCookieFile => write!(f, "invalid cookie file"),
+            InvalidBitcoinConf(ref s) => write!(f, "invalid bitcoin.conf: {}", s),
             UnexpectedStructure => write!(f, "the JSON result had an unexpected structure"),
             Returned(ref s) => write!(f, "the daemon returned an error string: {}", s),
             ServerVersion(ref e) => write!(f, "server version: {}", e),
             MissingUserPassword => write!(f, "missing user and/or password"),
+            Timeout(ref e) => write!(f, "timeout: {}", e),
+            PsbtWorkflow(ref e) => write!(f, "PSBT workflow: {}", e),
+            FilterHeaderMismatch(ref e) => write!(f, "filter header mismatch: {}", e),
+            UnsupportedOption(ref e) => write!(f, "unsupported option: {}", e),
         }
     }
 }
@@ -94,7 +124,15 @@ impl error::Error for Error {
             Io(ref e) => Some(e),
             InvalidAmount(ref e) => Some(e),
             ServerVersion(ref e) => Some(e),
-            InvalidCookieFile | UnexpectedStructure | Returned(_) | MissingUserPassword => None,
+            Timeout(ref e) => Some(e),
+            PsbtWorkflow(ref e) => Some(e),
+            FilterHeaderMismatch(ref e) => Some(e),
+            UnsupportedOption(ref e) => Some(e),
+            InvalidCookieFile
+            | InvalidBitcoinConf(_)
+            | UnexpectedStructure
+            | Returned(_)
+            | MissingUserPassword => None,
         }
     }
 }
@@ -124,3 +162,180 @@ impl error::Error for UnexpectedServerVersionError {}
 impl From<UnexpectedServerVersionError> for Error {
     fn from(e: UnexpectedServerVersionError) -> Self { Self::ServerVersion(e) }
 }
+
+/// Error returned when a block filter header does not match the one we computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterHeaderMismatchError {
+    /// The block the filter is for.
+    pub block_hash: BlockHash,
+    /// The filter header computed from the filter and the previous filter header.
+    pub expected: FilterHeader,
+    /// The filter header returned by the server.
+    pub got: FilterHeader,
+}
+
+impl fmt::Display for FilterHeaderMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "block {}: expected {} got {}", self.block_hash, self.expected, self.got)
+    }
+}
+
+impl error::Error for FilterHeaderMismatchError {}
+
+impl From<FilterHeaderMismatchError> for Error {
+    fn from(e: FilterHeaderMismatchError) -> Self { Self::FilterHeaderMismatch(e) }
+}
+
+/// Error returned when an option is set that the server version does not support.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedOptionError {
+    /// The RPC method the option was set for.
+    pub method: &'static str,
+    /// The option that was set.
+    pub option: &'static str,
+    /// The major version of Bitcoin Core that introduced the option.
+    pub since: u32,
+    /// The major version of Bitcoin Core the client is for.
+    pub version: u32,
+}
+
+impl fmt::Display for UnsupportedOptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` option `{}` requires version {} or later, client is for version {}",
+            self.method, self.option, self.since, self.version
+        )
+    }
+}
+
+impl error::Error for UnsupportedOptionError {}
+
+impl From<UnsupportedOptionError> for Error {
+    fn from(e: UnsupportedOptionError) -> Self { Self::UnsupportedOption(e) }
+}
+
+/// Error returned when a blocking wait helper times out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitTimeoutError {
+    /// The condition that was being waited for.
+    pub condition: WaitCondition,
+    /// How long we waited for before giving up.
+    pub timeout: Duration,
+}
+
+impl fmt::Display for WaitTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "gave up after {:?} waiting for {}", self.timeout, self.condition)
+    }
+}
+
+impl error::Error for WaitTimeoutError {}
+
+impl From<WaitTimeoutError> for Error {
+    fn from(e: WaitTimeoutError) -> Self { Self::Timeout(e) }
+}
+
+/// The condition a blocking wait helper was waiting for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitCondition {
+    /// A block to be connected on top of `tip`.
+    NewBlock {
+        /// The chain tip when we started waiting.
+        tip: BlockHash,
+    },
+    /// A specific block to become the chain tip.
+    Block(BlockHash),
+    /// The chain to reach (at least) a specific height.
+    Height(u64),
+    /// A transaction to enter the mempool.
+    Mempool(Txid),
+    /// A wallet transaction to reach a number of confirmations.
+    Confirmations {
+        /// The transaction being waited for.
+        txid: Txid,
+        /// The number of confirmations being waited for.
+        confirmations: u32,
+    },
+}
+
+impl fmt::Display for WaitCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use WaitCondition::*;
+
+        match *self {
+            NewBlock { ref tip } => write!(f, "a new block on top of {}", tip),
+            Block(ref hash) => write!(f, "block {}", hash),
+            Height(height) => write!(f, "block height {}", height),
+            Mempool(ref txid) => write!(f, "transaction {} to enter the mempool", txid),
+            Confirmations { ref txid, confirmations } =>
+                write!(f, "transaction {} to reach {} confirmations", txid, confirmations),
+        }
+    }
+}
+
+/// Error returned when a step of a PSBT workflow fails.
+#[derive(Debug)]
+pub enum PsbtWorkflowError {
+    /// Conversion of the `walletcreatefundedpsbt` result to the model type failed.
+    Create(WalletCreateFundedPsbtError),
+    /// Conversion of the `walletprocesspsbt` result to the model type failed.
+    Process(Box<dyn error::Error + Send + Sync>),
+    /// An external signer failed.
+    Signer(Box<dyn error::Error + Send + Sync>),
+    /// Conversion of the `finalizepsbt` result to the model type failed.
+    Finalize(FinalizePsbtError),
+    /// The PSBT could not be finalized because it is not fully signed.
+    Incomplete(Box<Psbt>),
+    /// Conversion of the `testmempoolaccept` result to the model type failed.
+    TestAccept(Box<dyn error::Error + Send + Sync>),
+    /// The transaction would not be accepted to the mempool.
+    Rejected {
+        /// The rejected transaction.
+        txid: Txid,
+        /// The reason given by the server, if any.
+        reason: Option<RejectReason>,
+        /// The reason string given by the server, including any details.
+        message: Option<String>,
+    },
+}
+
+impl fmt::Display for PsbtWorkflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtWorkflowError::*;
+
+        match *self {
+            Create(ref e) => write!(f, "invalid `walletcreatefundedpsbt` result: {}", e),
+            Process(ref e) => write!(f, "invalid `walletprocesspsbt` result: {}", e),
+            Signer(ref e) => write!(f, "signer failed: {}", e),
+            Finalize(ref e) => write!(f, "invalid `finalizepsbt` result: {}", e),
+            Incomplete(_) => write!(f, "failed to finalize the PSBT, it is not fully signed"),
+            TestAccept(ref e) => write!(f, "invalid `testmempoolaccept` result: {}", e),
+            Rejected { ref txid, message: Some(ref message), .. } =>
+                write!(f, "transaction {} rejected by the mempool: {}", txid, message),
+            Rejected { ref txid, reason: Some(ref reason), message: None } =>
+                write!(f, "transaction {} rejected by the mempool: {}", txid, reason),
+            Rejected { ref txid, reason: None, message: None } =>
+                write!(f, "transaction {} rejected by the mempool", txid),
+        }
+    }
+}
+
+impl error::Error for PsbtWorkflowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtWorkflowError::*;
+
+        match *self {
+            Create(ref e) => Some(e),
+            Process(ref e) => Some(&**e),
+            Signer(ref e) => Some(&**e),
+            Finalize(ref e) => Some(e),
+            TestAccept(ref e) => Some(&**e),
+            Incomplete(_) | Rejected { .. } => None,
+        }
+    }
+}
+
+impl From<PsbtWorkflowError> for Error {
+    fn from(e: PsbtWorkflowError) -> Self { Self::PsbtWorkflow(e) }
+}
@@ -2,7 +2,13 @@
 
 //! JSON-RPC clients for testing against specific versions of Bitcoin Core.
 
+mod address_type;
+mod config;
 mod error;
+mod psbt_workflow;
+mod redact;
+#[cfg(feature = "tracing")]
+mod trace;
 pub mod v17;
 pub mod v18;
 pub mod v19;
@@ -19,8 +25,28 @@ pub mod v28;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{cmp, thread};
 
-pub use crate::client_sync::error::Error;
+pub use jsonrpc::Secret;
+
+pub use crate::client_sync::config::{default_rpc_port, network_datadir, ClientConfig};
+pub use crate::client_sync::error::{
+    Error, FilterHeaderMismatchError, PsbtWorkflowError, UnsupportedOptionError, WaitCondition,
+    WaitTimeoutError,
+};
+pub use crate::client_sync::psbt_workflow::{PsbtSigner, SignerError};
+
+/// The longest a single wait RPC call is allowed to block server side.
+///
+/// Kept well below the transport timeout so that long waits do not fail with an HTTP error.
+const WAIT_RPC_SLICE: Duration = Duration::from_secs(5);
+
+/// How long to sleep between polls when we can not block server side.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// JSON-RPC error code returned by the server for an unknown method.
+const RPC_METHOD_NOT_FOUND: i32 = -32601;
 
 /// Crate-specific Result type.
 ///
@@ -28,16 +54,18 @@ pub use crate::client_sync::error::Error;
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// The different authentication methods for the client.
+///
+/// The password is a [`Secret`] so it is redacted from the `Debug` output.
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Auth {
     None,
-    UserPass(String, String),
+    UserPass(String, Secret),
     CookieFile(PathBuf),
 }
 
 impl Auth {
     /// Convert into the arguments that jsonrpc::Client needs.
-    pub fn get_user_pass(self) -> Result<(Option<String>, Option<String>)> {
+    pub fn get_user_pass(self) -> Result<(Option<String>, Option<Secret>)> {
         match self {
             Auth::None => Ok((None, None)),
             Auth::UserPass(u, p) => Ok((Some(u), Some(p))),
@@ -46,6 +74,8 @@ impl Auth {
                     .lines()
                     .next()
                     .ok_or(Error::InvalidCookieFile)??;
+                let line = Secret::from(line);
+                let line = line.expose_secret();
                 let colon = line.find(':').ok_or(Error::InvalidCookieFile)?;
                 Ok((Some(line[..colon].into()), Some(line[colon + 1..].into())))
             }
@@ -59,33 +89,36 @@ macro_rules! define_jsonrpc_minreq_client {
     ($version:literal) => {
         use std::fmt;
 
-        use $crate::client_sync::{log_response, Auth, Result};
         use $crate::client_sync::error::Error;
+        use $crate::client_sync::{send_request, Auth, Result};
 
         /// Client implements a JSON-RPC client for the Bitcoin Core daemon or compatible APIs.
         pub struct Client {
             inner: jsonrpc::client::Client,
+            /// The wallet endpoint, if the URL is of the form `<url>/wallet/<name>`.
+            wallet: Option<String>,
         }
 
         impl fmt::Debug for Client {
             fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
-                write!(
-                    f,
-                    "bitcoind-json-rpc::client_sync::{}::Client({:?})", $version, self.inner
-                )
+                write!(f, "bitcoind-json-rpc::client_sync::{}::Client({:?})", $version, self.inner)
             }
         }
 
         impl Client {
+            /// The major version of Bitcoin Core this client is for e.g., `17` for `v0.17.1`.
+            #[allow(dead_code)] // Only used by methods that take version dependent options.
+            const VERSION: u32 = $crate::client_sync::major_version($version);
+
             /// Creates a client to a bitcoind JSON-RPC server without authentication.
             pub fn new(url: &str) -> Self {
                 let transport = jsonrpc::http::minreq_http::Builder::new()
                     .url(url)
-                    .expect("jsonrpc v0.18, this function does not error")
+                    .expect("jsonrpc v0.19, this function does not error")
                     .build();
                 let inner = jsonrpc::client::Client::with_transport(transport);
 
-                Self { inner }
+                Self { inner, wallet: $crate::client_sync::wallet_from_url(url) }
             }
 
             /// Creates a client to a bitcoind JSON-RPC server with authentication.
@@ -97,12 +130,15 @@ macro_rules! define_jsonrpc_minreq_client {
 
                 let transport = jsonrpc::http::minreq_http::Builder::new()
                     .url(url)
-                    .expect("jsonrpc v0.18, this function does not error")
-                    .basic_auth(user.unwrap(), pass)
+                    .expect("jsonrpc v0.19, this function does not error")
+                    .basic_auth(
+                        user.as_deref().unwrap(),
+                        pass.as_ref().map($crate::client_sync::Secret::expose_secret),
+                    )
                     .build();
                 let inner = jsonrpc::client::Client::with_transport(transport);
 
-                Ok(Self { inner })
+                Ok(Self { inner, wallet: $crate::client_sync::wallet_from_url(url) })
             }
 
             /// Call an RPC `method` with given `args` list.
@@ -113,16 +149,27 @@ macro_rules! define_jsonrpc_minreq_client {
             ) -> Result<T> {
                 let raw = serde_json::value::to_raw_value(args)?;
                 let req = self.inner.build_request(&method, Some(&*raw));
-                if log::log_enabled!(log::Level::Debug) {
-                    log::debug!(target: "bitcoind-json-rpc", "request: {} {}", method, serde_json::Value::from(args));
-                }
+                let params = $crate::client_sync::redact::Params::Positional(args);
+
+                let resp = send_request(&self.inner, req, params, self.wallet.as_deref());
+                Ok(resp?.result()?)
+            }
+
+            /// Call an RPC `method` with given named `args`.
+            pub fn call_named<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &serde_json::Map<String, serde_json::Value>,
+            ) -> Result<T> {
+                let raw = serde_json::value::to_raw_value(args)?;
+                let req = self.inner.build_request(&method, Some(&*raw));
+                let params = $crate::client_sync::redact::Params::Named(args);
 
-                let resp = self.inner.send_request(req).map_err(Error::from);
-                log_response(method, &resp);
+                let resp = send_request(&self.inner, req, params, self.wallet.as_deref());
                 Ok(resp?.result()?)
             }
         }
-    }
+    };
 }
 
 /// Implements the `check_expected_server_version()` on `Client`.
@@ -152,6 +199,177 @@ macro_rules! impl_client_check_expected_server_version {
     };
 }
 
+/// Implements blocking wait helpers on `Client`.
+///
+/// Requires `Client` to be in scope and to implement `getblockcount`, `getblockhash`,
+/// `getrawmempool`, `gettransaction`, `waitforblock`, `waitforblockheight` and `waitfornewblock`.
+///
+/// The helpers use the (hidden) `waitfor*` RPC methods where possible, blocking server side for at
+/// most a few seconds per call so as to not trip the transport timeout. If the server does not
+/// support a `waitfor*` method we fall back to polling. All helpers return
+/// [`Error::Timeout`](crate::client_sync::Error::Timeout) if the condition is not met in time.
+#[macro_export]
+macro_rules! impl_client_wait_helpers {
+    () => {
+        impl Client {
+            /// Blocks until a new block is connected to the chain tip, or `timeout` elapses.
+            pub fn wait_for_new_block_with_timeout(
+                &self,
+                timeout: std::time::Duration,
+            ) -> Result<$crate::json::model::WaitForNewBlock> {
+                let (tip, _) = self.chain_tip()?;
+                self.wait_for_tip_change(tip, timeout)
+            }
+
+            /// Blocks until the block with `hash` is the chain tip, or `timeout` elapses.
+            pub fn wait_for_block_with_timeout(
+                &self,
+                hash: BlockHash,
+                timeout: std::time::Duration,
+            ) -> Result<$crate::json::model::WaitForBlock> {
+                let condition = $crate::client_sync::WaitCondition::Block(hash);
+                let mut use_rpc = true;
+
+                $crate::client_sync::wait_until(condition, timeout, |slice| {
+                    if use_rpc {
+                        match self.wait_for_block(hash, $crate::client_sync::wait_millis(slice)) {
+                            Ok(json) => {
+                                let model = json.into_model()?;
+                                return Ok(if model.hash == hash { Some(model) } else { None });
+                            }
+                            Err(e) if $crate::client_sync::is_method_not_found(&e) =>
+                                use_rpc = false,
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    let (tip, height) = self.chain_tip()?;
+                    Ok(if tip == hash {
+                        Some($crate::json::model::WaitForBlock { hash: tip, height })
+                    } else {
+                        None
+                    })
+                })
+            }
+
+            /// Blocks until the chain reaches (at least) `height`, or `timeout` elapses.
+            pub fn wait_for_block_height_with_timeout(
+                &self,
+                height: u64,
+                timeout: std::time::Duration,
+            ) -> Result<$crate::json::model::WaitForBlockHeight> {
+                let condition = $crate::client_sync::WaitCondition::Height(height);
+                let mut use_rpc = true;
+
+                $crate::client_sync::wait_until(condition, timeout, |slice| {
+                    if use_rpc {
+                        let millis = $crate::client_sync::wait_millis(slice);
+                        match self.wait_for_block_height(height, millis) {
+                            Ok(json) => {
+                                let model = json.into_model()?;
+                                return Ok(if model.height >= height { Some(model) } else { None });
+                            }
+                            Err(e) if $crate::client_sync::is_method_not_found(&e) =>
+                                use_rpc = false,
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    let (tip, tip_height) = self.chain_tip()?;
+                    Ok(if tip_height >= height {
+                        Some($crate::json::model::WaitForBlockHeight {
+                            hash: tip,
+                            height: tip_height,
+                        })
+                    } else {
+                        None
+                    })
+                })
+            }
+
+            /// Blocks until `txid` is in the mempool, or `timeout` elapses.
+            ///
+            /// There is no wait RPC for the mempool so this polls `getrawmempool`.
+            pub fn wait_for_mempool_tx_with_timeout(
+                &self,
+                txid: Txid,
+                timeout: std::time::Duration,
+            ) -> Result<()> {
+                let condition = $crate::client_sync::WaitCondition::Mempool(txid);
+
+                $crate::client_sync::wait_until(condition, timeout, |_| {
+                    let mempool = self.get_raw_mempool()?.into_model()?;
+                    Ok(if mempool.0.contains(&txid) { Some(()) } else { None })
+                })
+            }
+
+            /// Blocks until wallet transaction `txid` has at least `confirmations`, or `timeout`
+            /// elapses.
+            ///
+            /// Checks the wallet after every new block instead of polling.
+            pub fn wait_for_confirmations_with_timeout(
+                &self,
+                txid: Txid,
+                confirmations: u32,
+                timeout: std::time::Duration,
+            ) -> Result<GetTransaction> {
+                let condition =
+                    $crate::client_sync::WaitCondition::Confirmations { txid, confirmations };
+                let start = std::time::Instant::now();
+
+                $crate::client_sync::wait_until(condition, timeout, |_| {
+                    // Get the tip first so we can not miss a block connected in between.
+                    let (tip, _) = self.chain_tip()?;
+                    let tx = self.get_transaction(txid)?;
+                    if tx.confirmations >= confirmations {
+                        return Ok(Some(tx));
+                    }
+                    let remaining = timeout.saturating_sub(start.elapsed());
+                    match self.wait_for_tip_change(tip, remaining) {
+                        Ok(_) | Err($crate::client_sync::Error::Timeout(_)) => Ok(None),
+                        Err(e) => Err(e),
+                    }
+                })
+            }
+
+            /// Blocks until the chain tip is no longer `tip`, or `timeout` elapses.
+            fn wait_for_tip_change(
+                &self,
+                tip: BlockHash,
+                timeout: std::time::Duration,
+            ) -> Result<$crate::json::model::WaitForNewBlock> {
+                let condition = $crate::client_sync::WaitCondition::NewBlock { tip };
+                let mut use_rpc = true;
+
+                $crate::client_sync::wait_until(condition, timeout, |slice| {
+                    if use_rpc {
+                        match self.wait_for_new_block($crate::client_sync::wait_millis(slice)) {
+                            Ok(json) => {
+                                let model = json.into_model()?;
+                                return Ok(if model.hash != tip { Some(model) } else { None });
+                            }
+                            Err(e) if $crate::client_sync::is_method_not_found(&e) =>
+                                use_rpc = false,
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    let (hash, height) = self.chain_tip()?;
+                    Ok(if hash != tip {
+                        Some($crate::json::model::WaitForNewBlock { hash, height })
+                    } else {
+                        None
+                    })
+                })
+            }
+
+            /// Returns the hash and height of the current chain tip.
+            fn chain_tip(&self) -> Result<(BlockHash, u64)> {
+                let height = self.get_block_count()?.0;
+                let hash = self.get_block_hash(height)?.block_hash()?;
+                Ok((hash, height))
+            }
+        }
+    };
+}
+
 /// Shorthand for converting a variable into a `serde_json::Value`.
 fn into_json<T>(val: T) -> Result<serde_json::Value>
 where
@@ -172,6 +390,22 @@ where
     }
 }
 
+/// Converts `outputs` into the `[{"address":amount},...]` form taken by the funding methods.
+#[allow(dead_code)] // TODO: Remove this if unused still when we are done.
+fn outputs_into_json(
+    outputs: &[(bitcoin::Address<bitcoin::address::NetworkChecked>, bitcoin::Amount)],
+) -> Result<serde_json::Value> {
+    let outputs = outputs
+        .iter()
+        .map(|(address, amount)| {
+            let mut output = serde_json::Map::new();
+            output.insert(address.to_string(), into_json(amount.to_btc())?);
+            Ok(serde_json::Value::Object(output))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(outputs.into())
+}
+
 /// Shorthand for `serde_json::Value::Null`.
 #[allow(dead_code)] // TODO: Remove this if unused still when we are done.
 fn null() -> serde_json::Value { serde_json::Value::Null }
@@ -244,6 +478,240 @@ fn opt_result<T: for<'a> serde::de::Deserialize<'a>>(
     }
 }
 
+/// Parses the major version out of a client version string e.g., `"v17"`.
+const fn major_version(version: &str) -> u32 {
+    let bytes = version.as_bytes();
+    let mut major = 0;
+    let mut i = 1; // Skip the leading 'v'.
+    while i < bytes.len() {
+        major = major * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    major
+}
+
+/// Returns an error if any of the set `options` is not supported by Bitcoin Core `version`.
+///
+/// Each option is given as its name, whether it is set, and the version that introduced it.
+fn check_options(
+    method: &'static str,
+    version: u32,
+    options: &[(&'static str, bool, u32)],
+) -> Result<()> {
+    for &(option, is_set, since) in options {
+        if is_set && version < since {
+            return Err(UnsupportedOptionError { method, option, since, version }.into());
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error if any of the logging `categories` is not supported by Bitcoin Core `version`.
+fn check_log_categories(version: u32, categories: &[json::model::LogCategory]) -> Result<()> {
+    let options = categories
+        .iter()
+        .map(|&category| {
+            let (name, since) = log_category_since(category);
+            (name, true, since)
+        })
+        .collect::<Vec<_>>();
+    check_options("logging", version, &options)
+}
+
+/// Returns the name of the logging `category` and the major version of Bitcoin Core that added it.
+fn log_category_since(category: json::model::LogCategory) -> (&'static str, u32) {
+    use json::model::LogCategory::*;
+
+    match category {
+        All => ("all", 17),
+        Net => ("net", 17),
+        Tor => ("tor", 17),
+        Mempool => ("mempool", 17),
+        Http => ("http", 17),
+        Bench => ("bench", 17),
+        Zmq => ("zmq", 17),
+        Db => ("db", 17),
+        WalletDb => ("walletdb", 20),
+        Rpc => ("rpc", 17),
+        EstimateFee => ("estimatefee", 17),
+        AddrMan => ("addrman", 17),
+        SelectCoins => ("selectcoins", 17),
+        Reindex => ("reindex", 17),
+        CmpctBlock => ("cmpctblock", 17),
+        Rand => ("rand", 17),
+        Prune => ("prune", 17),
+        Proxy => ("proxy", 17),
+        MempoolRej => ("mempoolrej", 17),
+        Libevent => ("libevent", 17),
+        CoinDb => ("coindb", 17),
+        Qt => ("qt", 17),
+        LevelDb => ("leveldb", 17),
+        Validation => ("validation", 20),
+        I2p => ("i2p", 22),
+        Ipc => ("ipc", 22),
+        Lock => ("lock", 23),
+        Util => ("util", 23),
+        BlockStorage => ("blockstorage", 23),
+        TxReconciliation => ("txreconciliation", 25),
+        Scan => ("scan", 25),
+        TxPackages => ("txpackages", 26),
+    }
+}
+
+/// Serde serialization of a list of outpoints as `[{"txid":"hex","vout":n},...]`.
+fn serialize_outpoints<S: serde::Serializer>(
+    outpoints: &[bitcoin::OutPoint],
+    s: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    s.collect_seq(outpoints.iter().map(
+        |outpoint| serde_json::json!({ "txid": outpoint.txid.to_string(), "vout": outpoint.vout }),
+    ))
+}
+
+/// Serde serialization of input weights as `[{"txid":"hex","vout":n,"weight":n},...]`.
+fn serialize_input_weights<S: serde::Serializer>(
+    weights: &[(bitcoin::OutPoint, bitcoin::Weight)],
+    s: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    s.collect_seq(weights.iter().map(|(outpoint, weight)| {
+        serde_json::json!({
+            "txid": outpoint.txid.to_string(),
+            "vout": outpoint.vout,
+            "weight": weight.to_wu(),
+        })
+    }))
+}
+
+/// Serde (de)serialization of an optional [`bitcoin::FeeRate`] in BTC/kvB.
+///
+/// Fee rate arguments are in BTC/kvB for Core versions before `v0.21`.
+mod fee_rate_btc_per_kvb {
+    use bitcoin::{Amount, FeeRate};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        rate: &Option<FeeRate>,
+        s: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        // There are 4,000 weight units in a kilo virtual byte.
+        rate.map(|rate| Amount::from_sat(rate.to_sat_per_kwu() * 4).to_btc()).serialize(s)
+    }
+
+    pub fn deserialize<'d, D: Deserializer<'d>>(
+        d: D,
+    ) -> std::result::Result<Option<FeeRate>, D::Error> {
+        Option::<f64>::deserialize(d)?
+            .map(|btc| {
+                let amount = Amount::from_btc(btc).map_err(serde::de::Error::custom)?;
+                Ok(FeeRate::from_sat_per_kwu(amount.to_sat() / 4))
+            })
+            .transpose()
+    }
+}
+
+/// Serde (de)serialization of an optional [`bitcoin::FeeRate`] in sat/vB.
+///
+/// Fee rate arguments are in sat/vB from Core `v0.21` onwards.
+mod fee_rate_sat_per_vb {
+    use bitcoin::FeeRate;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        rate: &Option<FeeRate>,
+        s: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        // 1 sat/vB is 250 sat/kwu, Core accepts fractional sat/vB.
+        rate.map(|rate| rate.to_sat_per_kwu() as f64 / 250.0).serialize(s)
+    }
+
+    pub fn deserialize<'d, D: Deserializer<'d>>(
+        d: D,
+    ) -> std::result::Result<Option<FeeRate>, D::Error> {
+        Ok(Option::<f64>::deserialize(d)?
+            .map(|sat_per_vb| FeeRate::from_sat_per_kwu((sat_per_vb * 250.0).round() as u64)))
+    }
+}
+
+/// Calls `poll` until it returns `Some` or `timeout` elapses.
+///
+/// `poll` is passed the longest it may block for. If it returns quickly without blocking we sleep
+/// for a short interval before calling it again so as to not hammer the server.
+fn wait_until<T>(
+    condition: WaitCondition,
+    timeout: Duration,
+    mut poll: impl FnMut(Duration) -> Result<Option<T>>,
+) -> Result<T> {
+    let start = Instant::now();
+    loop {
+        let remaining = timeout.saturating_sub(start.elapsed());
+        let slice = cmp::max(cmp::min(remaining, WAIT_RPC_SLICE), Duration::from_millis(1));
+
+        let polled = Instant::now();
+        if let Some(t) = poll(slice)? {
+            return Ok(t);
+        }
+
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            return Err(WaitTimeoutError { condition, timeout }.into());
+        }
+        if polled.elapsed() < WAIT_POLL_INTERVAL {
+            thread::sleep(cmp::min(WAIT_POLL_INTERVAL, remaining));
+        }
+    }
+}
+
+/// Converts a wait slice into the millisecond timeout argument of the `waitfor*` RPC methods.
+///
+/// Never returns zero because Core treats a timeout of zero as "wait forever".
+fn wait_millis(slice: Duration) -> u64 { cmp::max(slice.as_millis() as u64, 1) }
+
+/// Returns true if `e` is the error returned by the server for an unknown RPC method.
+fn is_method_not_found(e: &Error) -> bool {
+    match *e {
+        Error::JsonRpc(jsonrpc::error::Error::Rpc(ref e)) => e.code == RPC_METHOD_NOT_FOUND,
+        _ => false,
+    }
+}
+
+/// Returns the wallet name from a URL of the form `<url>/wallet/<name>`.
+fn wallet_from_url(url: &str) -> Option<String> {
+    url.find("/wallet/").map(|i| url[i + "/wallet/".len()..].to_owned())
+}
+
+/// Sends `req`, logging the call and, with the `tracing` feature, recording it in a span.
+fn send_request(
+    client: &jsonrpc::client::Client,
+    req: jsonrpc::Request,
+    params: redact::Params,
+    wallet: Option<&str>,
+) -> Result<jsonrpc::Response> {
+    let method = req.method;
+    if log::log_enabled!(log::Level::Debug) {
+        let params = params.redacted(method);
+        match wallet {
+            Some(wallet) =>
+                log::debug!(target: "bitcoind-json-rpc", "request: {} {} (wallet: {})", method, params, wallet),
+            None => log::debug!(target: "bitcoind-json-rpc", "request: {} {}", method, params),
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    let span = trace::request_span(&req, params, wallet);
+    #[cfg(feature = "tracing")]
+    let _enter = span.enter();
+    #[cfg(feature = "tracing")]
+    let start = Instant::now();
+
+    let resp = client.send_request(req).map_err(Error::from);
+    log_response(method, &resp);
+
+    #[cfg(feature = "tracing")]
+    trace::record_response(&span, start.elapsed(), &resp);
+
+    resp
+}
+
 /// Helper to log an RPC response.
 fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
     use log::Level::{Debug, Trace, Warn};
@@ -259,6 +727,8 @@ fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
                     if log::log_enabled!(Debug) {
                         log::debug!(target: "bitcoind-json-rpc", "response error for {}: {:?}", method, e);
                     }
+                } else if log::log_enabled!(Trace) && redact::is_secret_result(method) {
+                    log::trace!(target: "bitcoind-json-rpc", "response for {}: <redacted>", method);
                 } else if log::log_enabled!(Trace) {
                     let def =
                         serde_json::value::to_raw_value(&serde_json::value::Value::Null).unwrap();
@@ -268,3 +738,206 @@ fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::{Address, Amount, FeeRate, Network};
+    use serde_json::json;
+
+    #[test]
+    fn bump_fee_options_fee_rate_units() {
+        let fee_rate = FeeRate::from_sat_per_vb(25);
+
+        let options = super::v20::BumpFeeOptions { fee_rate, ..Default::default() };
+        let value = serde_json::to_value(&options).unwrap();
+        assert_eq!(value, json!({ "fee_rate": 0.00025 }));
+        assert_eq!(serde_json::from_value::<super::v20::BumpFeeOptions>(value).unwrap(), options);
+
+        let options = super::v21::BumpFeeOptions { fee_rate, ..Default::default() };
+        let value = serde_json::to_value(&options).unwrap();
+        assert_eq!(value, json!({ "fee_rate": 25.0 }));
+        assert_eq!(serde_json::from_value::<super::v21::BumpFeeOptions>(value).unwrap(), options);
+    }
+
+    #[test]
+    fn bump_fee_options_outputs() {
+        let address = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"
+            .parse::<Address<_>>()
+            .unwrap()
+            .require_network(Network::Regtest)
+            .unwrap();
+        let options = super::v26::BumpFeeOptions {
+            outputs: vec![(address.clone(), Amount::from_sat(50_000))],
+            original_change_index: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({ "outputs": [{ address.to_string(): 0.0005 }], "original_change_index": 1 })
+        );
+    }
+
+    #[test]
+    fn debug_redacts_password() {
+        let auth = super::Auth::UserPass("alice".to_owned(), "hunter2".into());
+        let debug = format!("{:?}", auth);
+        assert!(debug.contains("alice"));
+        assert!(!debug.contains("hunter2"));
+
+        let client = super::v17::Client::new_with_auth("http://localhost:1", auth).unwrap();
+        let debug = format!("{:?}", client);
+        assert!(debug.contains("localhost"));
+        assert!(!debug.contains("hunter2"));
+        // The basic authentication header i.e., base64 of "alice:hunter2".
+        assert!(!debug.contains("YWxpY2U6aHVudGVyMg"));
+    }
+
+    #[test]
+    fn input_weights_are_checked_per_version() {
+        use bitcoin::hashes::Hash;
+        use bitcoin::{OutPoint, Txid, Weight};
+
+        let input_weights = vec![(OutPoint::new(Txid::all_zeros(), 0), Weight::from_wu(400))];
+
+        let options = super::v17::FundRawTransactionOptions {
+            input_weights: input_weights.clone(),
+            ..Default::default()
+        };
+        assert!(options.check(23).is_err());
+        assert!(options.check(24).is_ok());
+
+        let options = super::v17::WalletCreateFundedPsbtOptions {
+            input_weights: input_weights.clone(),
+            ..Default::default()
+        };
+        assert!(options.check(23).is_err());
+        assert!(options.check(24).is_ok());
+
+        let options = super::v21::SendOptions { input_weights, ..Default::default() };
+        match options.check(23) {
+            Err(super::Error::UnsupportedOption(e)) => {
+                assert_eq!(e.method, "send");
+                assert_eq!(e.option, "input_weights");
+                assert_eq!(e.since, 24);
+            }
+            res => panic!("expected unsupported option error, got {:?}", res),
+        }
+        assert!(options.check(24).is_ok());
+    }
+
+    #[test]
+    fn log_categories_are_checked_per_version() {
+        use json::model::LogCategory;
+
+        assert!(super::check_log_categories(17, &[LogCategory::Net, LogCategory::All]).is_ok());
+        match super::check_log_categories(22, &[LogCategory::Net, LogCategory::BlockStorage]) {
+            Err(super::Error::UnsupportedOption(e)) => {
+                assert_eq!(e.option, "blockstorage");
+                assert_eq!(e.since, 23);
+            }
+            res => panic!("expected unsupported option error, got {:?}", res),
+        }
+        assert!(super::check_log_categories(23, &[LogCategory::BlockStorage]).is_ok());
+    }
+
+    #[test]
+    fn major_version_from_client_version() {
+        assert_eq!(super::major_version("v17"), 17);
+        assert_eq!(super::major_version("v28"), 28);
+    }
+
+    #[test]
+    fn unsupported_options_are_rejected_before_calling_server() {
+        // Nothing listens here, any error other than an unsupported option means we called out.
+        let client = super::v17::Client::new("http://localhost:1");
+        let tx = bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![],
+            output: vec![],
+        };
+        let options =
+            super::v17::FundRawTransactionOptions { add_inputs: Some(true), ..Default::default() };
+        match client.fund_raw_transaction_with_options(&tx, &options) {
+            Err(super::Error::UnsupportedOption(e)) => assert_eq!(e.since, 22),
+            res => panic!("expected unsupported option error, got {:?}", res),
+        }
+
+        let options = super::v17::FundRawTransactionOptions {
+            lock_unspents: Some(true),
+            ..Default::default()
+        };
+        assert!(options.check(17).is_ok());
+        assert!(options.check(28).is_ok());
+    }
+
+    #[test]
+    fn send_many_options_args() {
+        let address = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"
+            .parse::<Address<_>>()
+            .unwrap()
+            .require_network(Network::Regtest)
+            .unwrap();
+        let amounts = [(address.clone(), Amount::from_sat(50_000))];
+
+        let options = super::v17::SendManyOptions::default();
+        assert_eq!(
+            options.args(&amounts, false).unwrap(),
+            vec![json!(""), json!({ address.to_string(): 0.0005 })]
+        );
+
+        let options = super::v17::SendManyOptions {
+            subtract_fee_from: vec![address.clone()],
+            ..Default::default()
+        };
+        assert_eq!(
+            options.args(&amounts, true).unwrap(),
+            vec![
+                json!(""),
+                json!({ address.to_string(): 0.0005 }),
+                json!(null),
+                json!(null),
+                json!([address.to_string()]),
+                json!(null),
+                json!(null),
+                json!(null),
+                json!(null),
+                json!(true),
+            ]
+        );
+    }
+
+    #[test]
+    fn sighash_type_from_bitcoin() {
+        use bitcoin::{EcdsaSighashType, TapSighashType};
+
+        use super::v17::SighashType;
+
+        let ty = SighashType::from(EcdsaSighashType::AllPlusAnyoneCanPay);
+        assert_eq!(serde_json::to_value(ty).unwrap(), json!("ALL|ANYONECANPAY"));
+        assert!(ty.check("signrawtransactionwithwallet", 17).is_ok());
+
+        let ty = SighashType::from(TapSighashType::Default);
+        assert_eq!(serde_json::to_value(ty).unwrap(), json!("DEFAULT"));
+        assert!(ty.check("signrawtransactionwithwallet", 21).is_err());
+        assert!(ty.check("signrawtransactionwithwallet", 22).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "miniscript")]
+    fn import_descriptors_request_from_descriptor() {
+        use std::str::FromStr as _;
+
+        use crate::json::miniscript::{Descriptor, DescriptorPublicKey};
+
+        let desc = "wpkh([5159c173/84'/1'/0']tpubDD3WNAnESEbJ7RrrzgsTbLaViceBrnHr9pni1pfweCVuC1HrS6ffrs3BKvDDJZr6ANcUugKBYWzkwELD4wBSjN7V3wkyDZq98425NzaruAy/0/*)#qszlqt7n";
+        let descriptor = Descriptor::<DescriptorPublicKey>::from_str(desc).unwrap();
+
+        let request = super::v21::ImportDescriptorsRequest::from_descriptor(
+            &descriptor,
+            super::v17::Timestamp::Now,
+        );
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value, json!({ "desc": desc, "timestamp": "now" }));
+    }
+}
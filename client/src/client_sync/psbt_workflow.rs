@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! End-to-end PSBT workflow i.e., create, fund, sign, finalize and broadcast a transaction.
+//!
+//! Each version specific client gets a `PsbtWorkflow` type using [`impl_client_psbt_workflow`].
+//! A workflow runs these steps, each of which can also be called on its own:
+//!
+//! 1. `walletcreatefundedpsbt` - create a PSBT paying the outputs, funded by the wallet.
+//! 2. `walletprocesspsbt` - update the PSBT with wallet information and sign it.
+//! 3. External signing - run each [`PsbtSigner`] e.g., a hardware wallet.
+//! 4. `finalizepsbt` - finalize the PSBT and extract the transaction.
+//! 5. `testmempoolaccept` - check that the transaction would be accepted.
+//! 6. `sendrawtransaction` - broadcast the transaction.
+//!
+//! [`impl_client_psbt_workflow`]: crate::impl_client_psbt_workflow
+
+use std::error;
+
+use bitcoin::Psbt;
+
+/// The error type returned by a [`PsbtSigner`].
+pub type SignerError = Box<dyn error::Error + Send + Sync>;
+
+/// An external signer, called by a PSBT workflow after the wallet has processed the PSBT.
+pub trait PsbtSigner {
+    /// Adds the signatures this signer can produce to `psbt`.
+    fn sign(&mut self, psbt: &mut Psbt) -> Result<(), SignerError>;
+}
+
+impl<F> PsbtSigner for F
+where
+    F: FnMut(&mut Psbt) -> Result<(), SignerError>,
+{
+    fn sign(&mut self, psbt: &mut Psbt) -> Result<(), SignerError> { self(psbt) }
+}
+
+/// Defines a `PsbtWorkflow` for the `Client` in scope.
+///
+/// Requires `Client` to implement `walletcreatefundedpsbt`, `walletprocesspsbt`, `finalizepsbt`,
+/// `testmempoolaccept` and `sendrawtransaction`.
+#[macro_export]
+macro_rules! impl_client_psbt_workflow {
+    () => {
+        /// Builds, funds, signs, finalizes and broadcasts a transaction using PSBTs.
+        ///
+        /// Create one with [`Client::psbt_workflow`], the client must be connected to a wallet.
+        pub struct PsbtWorkflow<'a> {
+            client: &'a Client,
+            wallet_sign: bool,
+            signers: Vec<Box<dyn $crate::client_sync::PsbtSigner + 'a>>,
+        }
+
+        impl<'a> PsbtWorkflow<'a> {
+            /// Creates a workflow where the wallet signs and there are no external signers.
+            pub fn new(client: &'a Client) -> Self {
+                PsbtWorkflow { client, wallet_sign: true, signers: vec![] }
+            }
+
+            /// Sets whether the wallet signs the PSBT when processing it (default: `true`).
+            ///
+            /// Disable this to have only the external signers sign e.g., for a watch-only wallet.
+            pub fn wallet_sign(mut self, sign: bool) -> Self {
+                self.wallet_sign = sign;
+                self
+            }
+
+            /// Adds an external signer, signers are called in the order they are added.
+            pub fn signer(mut self, signer: impl $crate::client_sync::PsbtSigner + 'a) -> Self {
+                self.signers.push(Box::new(signer));
+                self
+            }
+
+            /// Runs all the steps, returning the txid of the broadcast transaction.
+            pub fn send(&mut self, outputs: &[(Address<NetworkChecked>, Amount)]) -> Result<Txid> {
+                let funded = self.create_funded(outputs)?;
+                let psbt = self.process(funded.psbt)?;
+                let psbt = self.sign(psbt)?;
+                let tx = self.finalize(psbt)?;
+                self.test_accept(&tx)?;
+                self.broadcast(&tx)
+            }
+
+            /// Creates a PSBT paying `outputs`, with inputs and change added by the wallet.
+            pub fn create_funded(
+                &self,
+                outputs: &[(Address<NetworkChecked>, Amount)],
+            ) -> Result<$crate::json::model::WalletCreateFundedPsbt> {
+                let json = self.client.wallet_create_funded_psbt(outputs)?;
+                let model =
+                    json.into_model().map_err($crate::client_sync::PsbtWorkflowError::Create)?;
+                Ok(model)
+            }
+
+            /// Updates `psbt` with information from the wallet and, if enabled, signs it.
+            pub fn process(&self, psbt: bitcoin::Psbt) -> Result<bitcoin::Psbt> {
+                use $crate::client_sync::PsbtWorkflowError as E;
+
+                let json = self.client.wallet_process_psbt(&psbt, self.wallet_sign)?;
+                let model = json.into_model().map_err(|e| E::Process(Box::new(e)))?;
+                Ok(model.psbt)
+            }
+
+            /// Runs the external signers over `psbt`.
+            pub fn sign(&mut self, mut psbt: bitcoin::Psbt) -> Result<bitcoin::Psbt> {
+                for signer in self.signers.iter_mut() {
+                    signer
+                        .sign(&mut psbt)
+                        .map_err($crate::client_sync::PsbtWorkflowError::Signer)?;
+                }
+                Ok(psbt)
+            }
+
+            /// Finalizes `psbt` and extracts the signed transaction.
+            pub fn finalize(&self, psbt: bitcoin::Psbt) -> Result<bitcoin::Transaction> {
+                use $crate::client_sync::PsbtWorkflowError as E;
+
+                let json = self.client.finalize_psbt(&psbt)?;
+                let model = json.into_model().map_err(E::Finalize)?;
+                match (model.complete, model.tx) {
+                    (true, Some(tx)) => Ok(tx),
+                    _ => Err(E::Incomplete(Box::new(model.psbt.unwrap_or(psbt))).into()),
+                }
+            }
+
+            /// Checks that `tx` would be accepted to the mempool.
+            pub fn test_accept(&self, tx: &bitcoin::Transaction) -> Result<()> {
+                use $crate::client_sync::PsbtWorkflowError as E;
+
+                let json = self.client.test_mempool_accept(core::slice::from_ref(tx))?;
+                let model = json.into_model().map_err(|e| E::TestAccept(Box::new(e)))?;
+                match model.results.into_iter().next() {
+                    Some(result) if result.allowed => Ok(()),
+                    Some(result) => Err(E::Rejected {
+                        txid: result.txid,
+                        reason: result.reject_reason,
+                        message: result.reject_message,
+                    }
+                    .into()),
+                    None => Err($crate::client_sync::Error::UnexpectedStructure),
+                }
+            }
+
+            /// Broadcasts `tx`, returning its txid.
+            pub fn broadcast(&self, tx: &bitcoin::Transaction) -> Result<Txid> {
+                Ok(self.client.send_raw_transaction(tx)?.txid()?)
+            }
+        }
+
+        impl Client {
+            /// Returns a [`PsbtWorkflow`] that uses this client's wallet.
+            pub fn psbt_workflow(&self) -> PsbtWorkflow<'_> { PsbtWorkflow::new(self) }
+        }
+    };
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Redaction of secrets (passphrases, private keys, seeds) before RPC calls are logged or traced.
+
+use serde_json::{Map, Value};
+
+/// Replaces a secret value in logged output.
+const REDACTED: &str = "<redacted>";
+
+/// Parameter summaries longer than this many characters are truncated.
+const MAX_SUMMARY_LEN: usize = 256;
+
+/// The secret-bearing parameters of each method, as `(position, name)` pairs.
+///
+/// Descriptors and import requests are redacted in full because they may contain private keys.
+const SECRET_PARAMS: &[(&str, &[(usize, &str)])] = &[
+    ("createwallet", &[(3, "passphrase")]),
+    ("deriveaddresses", &[(0, "descriptor")]),
+    ("descriptorprocesspsbt", &[(1, "descriptors")]),
+    ("encryptwallet", &[(0, "passphrase")]),
+    ("getdescriptorinfo", &[(0, "descriptor")]),
+    ("importdescriptors", &[(0, "requests")]),
+    ("importmulti", &[(0, "requests")]),
+    ("importprivkey", &[(0, "privkey")]),
+    ("migratewallet", &[(1, "passphrase")]),
+    ("scanblocks", &[(1, "scanobjects")]),
+    ("scantxoutset", &[(1, "scanobjects")]),
+    ("sethdseed", &[(1, "seed")]),
+    ("signmessagewithprivkey", &[(0, "privkey")]),
+    ("signrawtransaction", &[(2, "privkeys")]),
+    ("signrawtransactionwithkey", &[(1, "privkeys")]),
+    ("walletpassphrase", &[(0, "passphrase")]),
+    ("walletpassphrasechange", &[(0, "oldpassphrase"), (1, "newpassphrase")]),
+];
+
+/// Methods whose result may contain private keys.
+const SECRET_RESULTS: &[&str] = &["dumpprivkey", "gethdkeys", "listdescriptors"];
+
+/// The parameters of an RPC call.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Params<'a> {
+    /// Parameters passed by position.
+    Positional(&'a [Value]),
+    /// Parameters passed by name.
+    Named(&'a Map<String, Value>),
+}
+
+impl Params<'_> {
+    /// Returns the parameters of a call to `method`, as JSON, with any secrets replaced.
+    pub(crate) fn redacted(self, method: &str) -> Value {
+        let secrets = secret_params(method);
+        match self {
+            Params::Positional(args) => args
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    if secrets.iter().any(|&(pos, _)| pos == i) {
+                        REDACTED.into()
+                    } else {
+                        arg.clone()
+                    }
+                })
+                .collect(),
+            Params::Named(args) => args
+                .iter()
+                .map(|(name, arg)| {
+                    let arg = if secrets.iter().any(|&(_, n)| n == name) {
+                        REDACTED.into()
+                    } else {
+                        arg.clone()
+                    };
+                    (name.clone(), arg)
+                })
+                .collect::<Map<_, _>>()
+                .into(),
+        }
+    }
+
+    /// Returns a short summary of the parameters of a call to `method`, with any secrets replaced.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    pub(crate) fn summary(self, method: &str) -> String {
+        let mut summary = self.redacted(method).to_string();
+        if let Some((i, _)) = summary.char_indices().nth(MAX_SUMMARY_LEN) {
+            summary.truncate(i);
+            summary.push_str("...");
+        }
+        summary
+    }
+}
+
+/// Returns `true` if the result of `method` must not be logged.
+pub(crate) fn is_secret_result(method: &str) -> bool { SECRET_RESULTS.contains(&method) }
+
+/// Returns the secret-bearing parameters of `method`.
+fn secret_params(method: &str) -> &'static [(usize, &'static str)] {
+    SECRET_PARAMS.iter().find(|&&(m, _)| m == method).map(|&(_, params)| params).unwrap_or(&[])
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn redacts_positional_params() {
+        let args = [json!("hunter2"), json!(60)];
+        let got = Params::Positional(&args).redacted("walletpassphrase");
+        assert_eq!(got, json!([REDACTED, 60]));
+    }
+
+    #[test]
+    fn redacts_named_params() {
+        let args = json!({"oldpassphrase": "hunter2", "newpassphrase": "hunter3"});
+        let args = args.as_object().unwrap();
+        let got = Params::Named(args).redacted("walletpassphrasechange");
+        assert_eq!(got, json!({"oldpassphrase": REDACTED, "newpassphrase": REDACTED}));
+    }
+
+    #[test]
+    fn redacts_descriptors_with_private_keys() {
+        let xprv = "tprv8ZgxMBicQKsPd7Uf69XL1XwhmjHopUGep8GuEiJDZmbQz6o58LninorQAfcKZWARbtRtfnLcJ\
+                    5MQ2AtHcQJCCRUcMRvmDUjyEmNUWwx8UbK";
+        let desc = format!("wpkh({}/0/*)", xprv);
+
+        let args = [json!(desc), json!([0, 5])];
+        let got = Params::Positional(&args).summary("deriveaddresses");
+        assert!(!got.contains(xprv), "{}", got);
+
+        let args = [json!("start"), json!([{ "desc": desc, "range": 5 }])];
+        for method in ["scantxoutset", "scanblocks"] {
+            let got = Params::Positional(&args).summary(method);
+            assert!(!got.contains(xprv), "{}", got);
+            assert!(got.contains("start"), "{}", got);
+        }
+    }
+
+    #[test]
+    fn keeps_other_params() {
+        let args = [json!("wallet"), json!(false), json!(false)];
+        let got = Params::Positional(&args).redacted("createwallet");
+        assert_eq!(got, json!(args));
+
+        let got = Params::Positional(&args).redacted("getblock");
+        assert_eq!(got, json!(args));
+    }
+
+    #[test]
+    fn summary_is_truncated() {
+        let args = [json!("a".repeat(2 * MAX_SUMMARY_LEN))];
+        let got = Params::Positional(&args).summary("getblock");
+        assert_eq!(got.len(), MAX_SUMMARY_LEN + "...".len());
+    }
+
+    #[test]
+    fn secret_results() {
+        assert!(is_secret_result("dumpprivkey"));
+        assert!(!is_secret_result("getblock"));
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Structured `tracing` spans for RPC calls, enabled by the `tracing` feature.
+//!
+//! Each call gets a `rpc` span (target `bitcoind-json-rpc`, level `DEBUG`) with the fields:
+//!
+//! - `method`: The RPC method name.
+//! - `id`: The JSON-RPC request id.
+//! - `params`: A summary of the parameters, with any secrets redacted.
+//! - `wallet`: The wallet endpoint, if the client is connected to one.
+//! - `duration_ms`: How long the call took.
+//! - `response_size`: The size in bytes of the JSON result.
+//! - `error_code`: The JSON-RPC error code, if the server returned an error.
+//! - `error`: The transport error, if the call did not get a response.
+
+use std::time::Duration;
+
+use tracing::field::Empty;
+use tracing::Span;
+
+use crate::client_sync::redact::Params;
+use crate::client_sync::Result;
+
+/// Returns the span for the call `req`.
+pub(crate) fn request_span(req: &jsonrpc::Request, params: Params, wallet: Option<&str>) -> Span {
+    let span = tracing::debug_span!(
+        target: "bitcoind-json-rpc",
+        "rpc",
+        method = req.method,
+        id = %req.id,
+        params = Empty,
+        wallet = Empty,
+        duration_ms = Empty,
+        response_size = Empty,
+        error_code = Empty,
+        error = Empty,
+    );
+    if !span.is_disabled() {
+        span.record("params", params.summary(req.method).as_str());
+        if let Some(wallet) = wallet {
+            span.record("wallet", wallet);
+        }
+    }
+    span
+}
+
+/// Records the outcome of a call on its `span`.
+pub(crate) fn record_response(span: &Span, duration: Duration, resp: &Result<jsonrpc::Response>) {
+    span.record("duration_ms", duration.as_millis() as u64);
+    match *resp {
+        Ok(ref resp) => {
+            if let Some(ref result) = resp.result {
+                span.record("response_size", result.get().len() as u64);
+            }
+            if let Some(ref e) = resp.error {
+                span.record("error_code", e.code);
+            }
+        }
+        Err(ref e) => {
+            span.record("error", tracing::field::display(e));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::subscriber::{self, Interest};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::client_sync::{redact, send_request};
+
+    type Fields = Arc<Mutex<BTreeMap<String, String>>>;
+
+    /// A subscriber that captures the fields recorded on the `rpc` span.
+    struct Capture(Fields);
+
+    impl Visit for Capture {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.lock().unwrap().insert(field.name().to_owned(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.lock().unwrap().insert(field.name().to_owned(), format!("{:?}", value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+            Interest::sometimes()
+        }
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "bitcoind-json-rpc"
+        }
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut Capture(self.0.clone()));
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut Capture(self.0.clone()))
+        }
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// A transport that returns `response` to every request.
+    struct Mock(serde_json::Value);
+
+    impl jsonrpc::Transport for Mock {
+        fn send_request(&self, req: jsonrpc::Request) -> Result<jsonrpc::Response, jsonrpc::Error> {
+            let mut response = self.0.clone();
+            response["id"] = req.id;
+            Ok(serde_json::from_value(response).unwrap())
+        }
+        fn send_batch(
+            &self,
+            _: &[jsonrpc::Request],
+        ) -> Result<Vec<jsonrpc::Response>, jsonrpc::Error> {
+            unimplemented!()
+        }
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str("mock") }
+    }
+
+    /// Calls `method` with `args` against a server that returns `response`, returns the span fields.
+    fn call(
+        method: &str,
+        args: &[serde_json::Value],
+        wallet: Option<&str>,
+        response: serde_json::Value,
+    ) -> BTreeMap<String, String> {
+        let fields = Fields::default();
+        let client = jsonrpc::client::Client::with_transport(Mock(response));
+        subscriber::with_default(Capture(fields.clone()), || {
+            let raw = serde_json::value::to_raw_value(args).unwrap();
+            let req = client.build_request(method, Some(&*raw));
+            let _ = send_request(&client, req, redact::Params::Positional(args), wallet);
+        });
+        let fields = fields.lock().unwrap().clone();
+        fields
+    }
+
+    #[test]
+    fn span_records_call() {
+        let response = json!({ "result": "00ff", "error": null });
+        let fields = call("getblockhash", &[json!(1)], None, response);
+
+        assert_eq!(fields["method"], "getblockhash");
+        assert_eq!(fields["id"], "1");
+        assert_eq!(fields["params"], "[1]");
+        assert_eq!(fields["response_size"], "6");
+        assert!(fields.contains_key("duration_ms"));
+        assert!(!fields.contains_key("wallet"));
+        assert!(!fields.contains_key("error_code"));
+    }
+
+    #[test]
+    fn span_records_wallet_and_error_code_and_redacts_secrets() {
+        let response = json!({
+            "result": null,
+            "error": { "code": -14, "message": "The wallet passphrase entered was incorrect." },
+        });
+        let args = [json!("hunter2"), json!(60)];
+        let fields = call("walletpassphrase", &args, Some("alice"), response);
+
+        assert_eq!(fields["method"], "walletpassphrase");
+        assert_eq!(fields["params"], r#"["<redacted>",60]"#);
+        assert_eq!(fields["wallet"], "alice");
+        assert_eq!(fields["error_code"], "-14");
+        assert!(fields.contains_key("duration_ms"));
+        assert!(fields.values().all(|value| !value.contains("hunter2")));
+    }
+}
@@ -48,6 +48,11 @@ macro_rules! impl_client_v17__getblock {
             pub fn get_block_verbosity_one(&self, hash: BlockHash) -> Result<GetBlockVerbosityOne> {
                 self.call("getblock", &[into_json(hash)?, 1.into()])
             }
+
+            /// Gets a block including the decoded transactions and, from v0.21, their fees.
+            pub fn get_block_verbosity_two(&self, hash: BlockHash) -> Result<GetBlockVerbosityTwo> {
+                self.call("getblock", &[into_json(hash)?, 2.into()])
+            }
         }
     };
 }
@@ -166,6 +171,72 @@ macro_rules! impl_client_v17__getmempoolancestors {
             pub fn get_mempool_ancestors(&self, txid: Txid) -> Result<GetMempoolAncestors> {
                 self.call("getmempoolancestors", &[into_json(txid)?])
             }
+
+            pub fn get_mempool_ancestors_verbose(
+                &self,
+                txid: Txid,
+            ) -> Result<GetMempoolAncestorsVerbose> {
+                self.call("getmempoolancestors", &[into_json(txid)?, into_json(true)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getmempooldescendants`
+#[macro_export]
+macro_rules! impl_client_v17__getmempooldescendants {
+    () => {
+        impl Client {
+            pub fn get_mempool_descendants(&self, txid: Txid) -> Result<GetMempoolDescendants> {
+                self.call("getmempooldescendants", &[into_json(txid)?])
+            }
+
+            pub fn get_mempool_descendants_verbose(
+                &self,
+                txid: Txid,
+            ) -> Result<GetMempoolDescendantsVerbose> {
+                self.call("getmempooldescendants", &[into_json(txid)?, into_json(true)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getmempoolentry`
+#[macro_export]
+macro_rules! impl_client_v17__getmempoolentry {
+    () => {
+        impl Client {
+            pub fn get_mempool_entry(&self, txid: Txid) -> Result<GetMempoolEntry> {
+                self.call("getmempoolentry", &[into_json(txid)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getmempoolinfo`
+#[macro_export]
+macro_rules! impl_client_v17__getmempoolinfo {
+    () => {
+        impl Client {
+            pub fn get_mempool_info(&self) -> Result<GetMempoolInfo> {
+                self.call("getmempoolinfo", &[])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getrawmempool`
+#[macro_export]
+macro_rules! impl_client_v17__getrawmempool {
+    () => {
+        impl Client {
+            pub fn get_raw_mempool(&self) -> Result<GetRawMempool> {
+                self.call("getrawmempool", &[])
+            }
+
+            pub fn get_raw_mempool_verbose(&self) -> Result<GetRawMempoolVerbose> {
+                self.call("getrawmempool", &[into_json(true)?])
+            }
         }
     };
 }
@@ -181,3 +252,82 @@ macro_rules! impl_client_v17__gettxout {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `gettxoutsetinfo`
+#[macro_export]
+macro_rules! impl_client_v17__gettxoutsetinfo {
+    () => {
+        impl Client {
+            pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `scantxoutset`
+#[macro_export]
+macro_rules! impl_client_v17__scantxoutset {
+    () => {
+        impl Client {
+            pub fn scan_tx_out_set_start(
+                &self,
+                scan_objects: &[ScanObject],
+            ) -> Result<ScanTxOutSetStart> {
+                self.call("scantxoutset", &[into_json("start")?, into_json(scan_objects)?])
+            }
+
+            /// Returns `None` if there is no scan in progress.
+            pub fn scan_tx_out_set_status(&self) -> Result<Option<ScanTxOutSetStatus>> {
+                self.call("scantxoutset", &[into_json("status")?])
+            }
+
+            pub fn scan_tx_out_set_abort(&self) -> Result<ScanTxOutSetAbort> {
+                self.call("scantxoutset", &[into_json("abort")?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `waitforblock`
+#[macro_export]
+macro_rules! impl_client_v17__waitforblock {
+    () => {
+        impl Client {
+            /// Waits for block `hash` to be the chain tip, a `timeout_ms` of zero waits forever.
+            pub fn wait_for_block(&self, hash: BlockHash, timeout_ms: u64) -> Result<WaitForBlock> {
+                self.call("waitforblock", &[into_json(hash)?, into_json(timeout_ms)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `waitforblockheight`
+#[macro_export]
+macro_rules! impl_client_v17__waitforblockheight {
+    () => {
+        impl Client {
+            /// Waits for the chain to reach `height`, a `timeout_ms` of zero waits forever.
+            pub fn wait_for_block_height(
+                &self,
+                height: u64,
+                timeout_ms: u64,
+            ) -> Result<WaitForBlockHeight> {
+                self.call("waitforblockheight", &[into_json(height)?, into_json(timeout_ms)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `waitfornewblock`
+#[macro_export]
+macro_rules! impl_client_v17__waitfornewblock {
+    () => {
+        impl Client {
+            /// Waits for a new block, a `timeout_ms` of zero waits forever.
+            pub fn wait_for_new_block(&self, timeout_ms: u64) -> Result<WaitForNewBlock> {
+                self.call("waitfornewblock", &[into_json(timeout_ms)?])
+            }
+        }
+    };
+}
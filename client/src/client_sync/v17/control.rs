@@ -9,7 +9,7 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
-/// Implements bitcoind JSON-RPC API method `getmemory`
+/// Implements bitcoind JSON-RPC API method `getmemoryinfo`
 #[macro_export]
 macro_rules! impl_client_v17__getmemoryinfo {
     () => {
@@ -17,6 +17,11 @@ macro_rules! impl_client_v17__getmemoryinfo {
             pub fn get_memory_info(&self) -> Result<GetMemoryInfoStats> {
                 self.call("getmemoryinfo", &[])
             }
+
+            /// Calls `getmemoryinfo` with mode "mallocinfo" (requires Core compiled with glibc).
+            pub fn get_memory_info_malloc_info(&self) -> Result<GetMemoryInfoMallocInfo> {
+                self.call("getmemoryinfo", &["mallocinfo".into()])
+            }
         }
     };
 }
@@ -27,6 +32,20 @@ macro_rules! impl_client_v17__logging {
     () => {
         impl Client {
             pub fn logging(&self) -> Result<Logging> { self.call("logging", &[]) }
+
+            /// Enables debug logging for the `include` categories and disables it for the
+            /// `exclude` categories, exclusion takes precedence.
+            ///
+            /// Passing a category that the server version does not support is an error.
+            pub fn set_logging(
+                &self,
+                include: &[$crate::json::model::LogCategory],
+                exclude: &[$crate::json::model::LogCategory],
+            ) -> Result<Logging> {
+                $crate::client_sync::check_log_categories(Self::VERSION, include)?;
+                $crate::client_sync::check_log_categories(Self::VERSION, exclude)?;
+                self.call("logging", &[into_json(include)?, into_json(exclude)?])
+            }
         }
     };
 }
@@ -46,7 +65,7 @@ macro_rules! impl_client_v17__stop {
 macro_rules! impl_client_v17__uptime {
     () => {
         impl Client {
-            pub fn uptime(&self) -> Result<u32> { self.call("uptime", &[]) }
+            pub fn uptime(&self) -> Result<Uptime> { self.call("uptime", &[]) }
         }
     };
 }
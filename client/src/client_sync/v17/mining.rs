@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getblocktemplate`
+#[macro_export]
+macro_rules! impl_client_v17__getblocktemplate {
+    () => {
+        impl Client {
+            pub fn get_block_template(
+                &self,
+                request: &TemplateRequest,
+            ) -> Result<GetBlockTemplate> {
+                self.call("getblocktemplate", &[into_json(request)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getmininginfo`
+#[macro_export]
+macro_rules! impl_client_v17__getmininginfo {
+    () => {
+        impl Client {
+            pub fn get_mining_info(&self) -> Result<GetMiningInfo> {
+                self.call("getmininginfo", &[])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getnetworkhashps`
+#[macro_export]
+macro_rules! impl_client_v17__getnetworkhashps {
+    () => {
+        impl Client {
+            pub fn get_network_hash_ps(&self) -> Result<GetNetworkHashPs> {
+                self.call("getnetworkhashps", &[])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `prioritisetransaction`
+#[macro_export]
+macro_rules! impl_client_v17__prioritisetransaction {
+    () => {
+        impl Client {
+            /// Adds `fee_delta` to the fee of `txid` when selecting transactions for a block.
+            pub fn prioritise_transaction(
+                &self,
+                txid: &Txid,
+                fee_delta: bitcoin::SignedAmount,
+            ) -> Result<PrioritiseTransaction> {
+                // The `dummy` argument must be zero.
+                let args = [into_json(txid)?, 0.into(), fee_delta.to_sat().into()];
+                self.call("prioritisetransaction", &args)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `submitblock`
+#[macro_export]
+macro_rules! impl_client_v17__submitblock {
+    () => {
+        impl Client {
+            pub fn submit_block(&self, block: &Block) -> Result<SubmitBlock> {
+                let hex = bitcoin::consensus::encode::serialize_hex(block);
+                self.call("submitblock", &[hex.into()])
+            }
+        }
+    };
+}
@@ -7,14 +7,23 @@
 pub mod blockchain;
 pub mod control;
 pub mod generating;
+pub mod mining;
 pub mod network;
 pub mod raw_transactions;
+pub mod util;
 pub mod wallet;
 
 use bitcoin::address::{Address, NetworkChecked};
-use bitcoin::{Amount, Block, BlockHash, Txid};
+use bitcoin::{
+    Amount, Block, BlockHash, EcdsaSighashType, FeeRate, OutPoint, PrivateKey, PublicKey,
+    ScriptBuf, TapSighashType, Txid, Weight,
+};
 use serde::{Deserialize, Serialize};
 
+/// The output type of the change, an option to the funding methods.
+///
+/// `Bech32m` requires v23, it is the same type as `AddressType` from `v23` onwards.
+pub use crate::client_sync::address_type::AddressType as ChangeType;
 use crate::client_sync::{handle_defaults, into_json};
 use crate::json::v17::*;
 
@@ -34,6 +43,16 @@ crate::impl_client_v17__getchaintips!();
 crate::impl_client_v17__getchaintxstats!();
 crate::impl_client_v17__getdifficulty!();
 crate::impl_client_v17__getmempoolancestors!();
+crate::impl_client_v17__getmempooldescendants!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
+crate::impl_client_v17__getrawmempool!();
+crate::impl_client_v17__gettxoutsetinfo!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v17__waitforblock!();
+crate::impl_client_v17__waitforblockheight!();
+crate::impl_client_v17__waitfornewblock!();
+crate::impl_client_wait_helpers!();
 
 // == Control ==
 crate::impl_client_v17__getmemoryinfo!();
@@ -45,23 +64,76 @@ crate::impl_client_v17__uptime!();
 crate::impl_client_v17__generatetoaddress!();
 crate::impl_client_v17__generate!();
 
+// == Mining ==
+crate::impl_client_v17__getblocktemplate!();
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getnetworkhashps!();
+crate::impl_client_v17__prioritisetransaction!();
+crate::impl_client_v17__submitblock!();
+
 // == Network ==
+crate::impl_client_v17__addnode!();
+crate::impl_client_v17__clearbanned!();
+crate::impl_client_v17__disconnectnode!();
 crate::impl_client_v17__getaddednodeinfo!();
+crate::impl_client_v17__getconnectioncount!();
 crate::impl_client_v17__getnettotals!();
 crate::impl_client_v17__getnetworkinfo!();
 crate::impl_client_v17__getpeerinfo!();
+crate::impl_client_v17__listbanned!();
+crate::impl_client_v17__ping!();
+crate::impl_client_v17__setban!();
+crate::impl_client_v17__setnetworkactive!();
 
 // == Rawtransactions ==
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__combinerawtransaction!();
+crate::impl_client_v17__converttopsbt!();
+crate::impl_client_v17__createpsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__decodescript!();
+crate::impl_client_v17__finalizepsbt!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
 crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v17__signrawtransactionwithkey!();
+crate::impl_client_v17__testmempoolaccept!();
+
+// == Util ==
+crate::impl_client_v17__createmultisig!();
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v17__signmessagewithprivkey!();
+crate::impl_client_v17__validateaddress!();
+crate::impl_client_v17__verifymessage!();
 
 // == Wallet ==
 crate::impl_client_v17__createwallet!();
 crate::impl_client_v17__unloadwallet!();
 crate::impl_client_v17__loadwallet!();
+crate::impl_client_v17__abandontransaction!();
+crate::impl_client_v17__bumpfee!();
 crate::impl_client_v17__getnewaddress!();
 crate::impl_client_v17__getbalance!();
+crate::impl_client_v17__sendmany!();
 crate::impl_client_v17__sendtoaddress!();
+crate::impl_client_v17__signmessage!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v17__getaddressinfo!();
 crate::impl_client_v17__gettransaction!();
+crate::impl_client_v17__getwalletinfo!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v17__listlabels!();
+crate::impl_client_v17__listsinceblock!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__setlabel!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_psbt_workflow!();
 
 /// Argument to the `Client::get_new_address_with_type` function.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -84,3 +156,581 @@ impl fmt::Display for AddressType {
         fmt::Display::fmt(s, f)
     }
 }
+
+/// Argument to the `Client::add_node` function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddNodeCommand {
+    /// Add the node to the added node list and try to connect.
+    Add,
+    /// Remove the node from the added node list.
+    Remove,
+    /// Try a single connection to the node.
+    OneTry,
+}
+
+/// Argument to the `Client::set_ban` function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SetBanCommand {
+    /// Add the subnet to the banned list.
+    Add,
+    /// Remove the subnet from the banned list.
+    Remove,
+}
+
+/// Argument to the `Client::bump_fee_with_options` function.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BumpFeeOptions {
+    /// Confirmation target (in blocks).
+    #[serde(rename = "confTarget", skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u32>,
+    /// Total fee (NOT feerate) to pay.
+    #[serde(
+        rename = "totalFee",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "bitcoin::amount::serde::as_sat::opt"
+    )]
+    pub total_fee: Option<Amount>,
+    /// Whether the new transaction should still be marked BIP-125 replaceable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// The fee estimate mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+/// The fee estimate mode, an argument in [`BumpFeeOptions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EstimateMode {
+    /// Use the default mode.
+    Unset,
+    /// Use a potentially lower fee, estimated over a shorter time horizon.
+    Economical,
+    /// Use a potentially higher fee, estimated over a longer time horizon.
+    Conservative,
+}
+
+/// Argument to the `Client::fund_raw_transaction_with_options` function.
+///
+/// Setting an option that the server version does not support is an error.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FundRawTransactionOptions {
+    /// Whether to add inputs from the wallet (since v22).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_inputs: Option<bool>,
+    /// Whether to include inputs that are not safe to spend (since v22).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_unsafe: Option<bool>,
+    /// The address to receive the change.
+    #[serde(rename = "changeAddress", skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address<NetworkChecked>>,
+    /// The index of the change output.
+    #[serde(rename = "changePosition", skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// The output type to use for the change, `Bech32m` requires v23.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<ChangeType>,
+    /// Whether to lock the selected UTXOs.
+    #[serde(rename = "lockUnspents", skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// The fee rate to pay, sent to Core in BTC/kvB.
+    #[serde(
+        rename = "feeRate",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::client_sync::fee_rate_btc_per_kvb::serialize"
+    )]
+    pub fee_rate: Option<FeeRate>,
+    /// The zero-based output indices to deduct the fee from.
+    #[serde(rename = "subtractFeeFromOutputs", skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether to signal BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Keys and scripts needed for producing a final transaction with external inputs (since v23).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solving_data: Option<SolvingData>,
+    /// The maximum weight of the external inputs (since v24).
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "crate::client_sync::serialize_input_weights"
+    )]
+    pub input_weights: Vec<(OutPoint, Weight)>,
+}
+
+impl FundRawTransactionOptions {
+    /// Returns an error if an option is set that Bitcoin Core `version` does not support.
+    pub(crate) fn check(&self, version: u32) -> Result<()> {
+        crate::client_sync::check_options(
+            "fundrawtransaction",
+            version,
+            &[
+                ("add_inputs", self.add_inputs.is_some(), 22),
+                ("include_unsafe", self.include_unsafe.is_some(), 22),
+                ("change_type", is_bech32m(&self.change_type), 23),
+                ("solving_data", self.solving_data.is_some(), 23),
+                ("input_weights", !self.input_weights.is_empty(), 24),
+            ],
+        )
+    }
+}
+
+/// Argument to the `Client::wallet_create_funded_psbt_with_options` function.
+///
+/// Setting an option that the server version does not support is an error.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WalletCreateFundedPsbtOptions {
+    /// The inputs to spend, sent as a separate argument.
+    #[serde(skip)]
+    pub inputs: Vec<OutPoint>,
+    /// Whether to add inputs from the wallet if `inputs` is not enough (since v21).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_inputs: Option<bool>,
+    /// Whether to include inputs that are not safe to spend (since v22).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_unsafe: Option<bool>,
+    /// The address to receive the change.
+    #[serde(rename = "changeAddress", skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address<NetworkChecked>>,
+    /// The index of the change output.
+    #[serde(rename = "changePosition", skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// The output type to use for the change, `Bech32m` requires v23.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<ChangeType>,
+    /// Whether to lock the selected UTXOs.
+    #[serde(rename = "lockUnspents", skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// The fee rate to pay, sent to Core in BTC/kvB.
+    #[serde(
+        rename = "feeRate",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::client_sync::fee_rate_btc_per_kvb::serialize"
+    )]
+    pub fee_rate: Option<FeeRate>,
+    /// The zero-based output indices to deduct the fee from.
+    #[serde(rename = "subtractFeeFromOutputs", skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether to signal BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Keys and scripts needed for producing a final transaction with external inputs (since v23).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solving_data: Option<SolvingData>,
+    /// The maximum weight of the external inputs (since v24).
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "crate::client_sync::serialize_input_weights"
+    )]
+    pub input_weights: Vec<(OutPoint, Weight)>,
+}
+
+impl WalletCreateFundedPsbtOptions {
+    /// Returns an error if an option is set that Bitcoin Core `version` does not support.
+    pub(crate) fn check(&self, version: u32) -> Result<()> {
+        crate::client_sync::check_options(
+            "walletcreatefundedpsbt",
+            version,
+            &[
+                ("add_inputs", self.add_inputs.is_some(), 21),
+                ("include_unsafe", self.include_unsafe.is_some(), 22),
+                ("change_type", is_bech32m(&self.change_type), 23),
+                ("solving_data", self.solving_data.is_some(), 23),
+                ("input_weights", !self.input_weights.is_empty(), 24),
+            ],
+        )
+    }
+}
+
+/// Argument to the `Client::send_many_with_options` and `Client::send_many_verbose` functions.
+///
+/// Setting an option that the server version does not support is an error.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SendManyOptions {
+    /// The addresses to deduct the fee from, equally split between them.
+    pub subtract_fee_from: Vec<Address<NetworkChecked>>,
+    /// Whether to signal BIP-125 replaceability.
+    pub replaceable: Option<bool>,
+    /// The fee rate to pay (since v21).
+    pub fee_rate: Option<FeeRate>,
+}
+
+impl SendManyOptions {
+    /// Returns an error if an option is set that Bitcoin Core `version` does not support.
+    pub(crate) fn check(&self, version: u32) -> Result<()> {
+        crate::client_sync::check_options(
+            "sendmany",
+            version,
+            &[("fee_rate", self.fee_rate.is_some(), 21)],
+        )
+    }
+
+    /// Returns the positional `sendmany` arguments to pay `amounts` with these options.
+    pub(crate) fn args(
+        &self,
+        amounts: &[(Address<NetworkChecked>, Amount)],
+        verbose: bool,
+    ) -> Result<Vec<serde_json::Value>> {
+        use crate::client_sync::{null, opt_into_json};
+
+        let mut recipients = serde_json::Map::new();
+        for (address, amount) in amounts {
+            recipients.insert(address.to_string(), into_json(amount.to_btc())?);
+        }
+        let subtract_fee_from = if self.subtract_fee_from.is_empty() {
+            null()
+        } else {
+            self.subtract_fee_from.iter().map(|a| a.to_string()).collect::<Vec<_>>().into()
+        };
+        let fee_rate = crate::client_sync::fee_rate_sat_per_vb::serialize(
+            &self.fee_rate,
+            serde_json::value::Serializer,
+        )?;
+        let verbose = if verbose { true.into() } else { null() };
+
+        // Core treats null as not set, we only need to trim the trailing ones.
+        let mut args = vec![
+            "".into(),
+            recipients.into(),
+            null(),
+            null(),
+            subtract_fee_from,
+            opt_into_json(self.replaceable)?,
+            null(),
+            null(),
+            fee_rate,
+            verbose,
+        ];
+        while args.last() == Some(&null()) {
+            args.pop();
+        }
+        Ok(args)
+    }
+}
+
+/// Keys and scripts needed for producing a final transaction with external inputs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SolvingData {
+    /// Public keys involved in the transaction.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pubkeys: Vec<PublicKey>,
+    /// Scripts involved in the transaction.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub scripts: Vec<ScriptBuf>,
+    /// Descriptors that provide solving data for the transaction.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub descriptors: Vec<String>,
+}
+
+/// Returns true if `change_type` is bech32m, which requires v23.
+pub(crate) fn is_bech32m(change_type: &Option<ChangeType>) -> bool {
+    matches!(change_type, Some(ChangeType::Bech32m))
+}
+
+/// A previous output spent by a transaction being signed, an argument to the
+/// `signrawtransactionwithkey` and `signrawtransactionwithwallet` methods.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PreviousTx {
+    /// The transaction id of the previous output.
+    pub txid: Txid,
+    /// The output index of the previous output.
+    pub vout: u32,
+    /// The script pubkey of the previous output.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: ScriptBuf,
+    /// The redeem script, required for P2SH.
+    #[serde(rename = "redeemScript", skip_serializing_if = "Option::is_none")]
+    pub redeem_script: Option<ScriptBuf>,
+    /// The witness script, required for P2WSH or P2SH-P2WSH.
+    #[serde(rename = "witnessScript", skip_serializing_if = "Option::is_none")]
+    pub witness_script: Option<ScriptBuf>,
+    /// The amount spent, required for segwit outputs.
+    #[serde(skip_serializing_if = "Option::is_none", with = "bitcoin::amount::serde::as_btc::opt")]
+    pub amount: Option<Amount>,
+}
+
+/// The signature hash type to sign with.
+///
+/// Can be created from both an `EcdsaSighashType` and a `TapSighashType`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum SighashType {
+    /// Only valid for taproot inputs, requires v22 or later.
+    #[serde(rename = "DEFAULT")]
+    Default,
+    /// Sign all inputs and outputs.
+    #[serde(rename = "ALL")]
+    All,
+    /// Sign all inputs and no outputs.
+    #[serde(rename = "NONE")]
+    None,
+    /// Sign all inputs and the output with the same index.
+    #[serde(rename = "SINGLE")]
+    Single,
+    /// Sign this input and all outputs.
+    #[serde(rename = "ALL|ANYONECANPAY")]
+    AllPlusAnyoneCanPay,
+    /// Sign this input and no outputs.
+    #[serde(rename = "NONE|ANYONECANPAY")]
+    NonePlusAnyoneCanPay,
+    /// Sign this input and the output with the same index.
+    #[serde(rename = "SINGLE|ANYONECANPAY")]
+    SinglePlusAnyoneCanPay,
+}
+
+impl SighashType {
+    /// Returns an error if this sighash type is not supported by Bitcoin Core `version`.
+    pub(crate) fn check(self, method: &'static str, version: u32) -> Result<()> {
+        crate::client_sync::check_options(
+            method,
+            version,
+            &[("sighashtype", self == SighashType::Default, 22)],
+        )
+    }
+}
+
+impl From<EcdsaSighashType> for SighashType {
+    fn from(ty: EcdsaSighashType) -> Self {
+        match ty {
+            EcdsaSighashType::All => Self::All,
+            EcdsaSighashType::None => Self::None,
+            EcdsaSighashType::Single => Self::Single,
+            EcdsaSighashType::AllPlusAnyoneCanPay => Self::AllPlusAnyoneCanPay,
+            EcdsaSighashType::NonePlusAnyoneCanPay => Self::NonePlusAnyoneCanPay,
+            EcdsaSighashType::SinglePlusAnyoneCanPay => Self::SinglePlusAnyoneCanPay,
+        }
+    }
+}
+
+impl From<TapSighashType> for SighashType {
+    fn from(ty: TapSighashType) -> Self {
+        match ty {
+            TapSighashType::Default => Self::Default,
+            TapSighashType::All => Self::All,
+            TapSighashType::None => Self::None,
+            TapSighashType::Single => Self::Single,
+            TapSighashType::AllPlusAnyoneCanPay => Self::AllPlusAnyoneCanPay,
+            TapSighashType::NonePlusAnyoneCanPay => Self::NonePlusAnyoneCanPay,
+            TapSighashType::SinglePlusAnyoneCanPay => Self::SinglePlusAnyoneCanPay,
+        }
+    }
+}
+
+/// Returns the positional `prevtxs` and `sighashtype` arguments of the `signrawtransaction*`
+/// methods, `null` if not set.
+pub(crate) fn sign_raw_transaction_args(
+    method: &'static str,
+    version: u32,
+    prevtxs: &[PreviousTx],
+    sighash_type: Option<SighashType>,
+) -> Result<[serde_json::Value; 2]> {
+    use crate::client_sync::{null, opt_into_json};
+
+    if let Some(ty) = sighash_type {
+        ty.check(method, version)?;
+    }
+    let prevtxs = if prevtxs.is_empty() { null() } else { into_json(prevtxs)? };
+    Ok([prevtxs, opt_into_json(sighash_type)?])
+}
+
+/// Argument to the `Client::wallet_process_psbt_with_options` function.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WalletProcessPsbtOptions {
+    /// Also sign the transaction when updating (default: true).
+    pub sign: Option<bool>,
+    /// The signature hash type to sign with if not specified by the PSBT.
+    pub sighash_type: Option<SighashType>,
+    /// Include BIP-32 derivation paths for public keys if known.
+    pub bip32derivs: Option<bool>,
+    /// Also finalize inputs if possible, requires v23 or later.
+    pub finalize: Option<bool>,
+}
+
+impl WalletProcessPsbtOptions {
+    /// Returns an error if an option is set that Bitcoin Core `version` does not support.
+    pub(crate) fn check(&self, version: u32) -> Result<()> {
+        if let Some(ty) = self.sighash_type {
+            ty.check("walletprocesspsbt", version)?;
+        }
+        crate::client_sync::check_options(
+            "walletprocesspsbt",
+            version,
+            &[("finalize", self.finalize.is_some(), 23)],
+        )
+    }
+
+    /// Returns the positional `walletprocesspsbt` arguments to process `psbt` with these options.
+    pub(crate) fn args(&self, psbt: &bitcoin::Psbt) -> Result<Vec<serde_json::Value>> {
+        use crate::client_sync::{null, opt_into_json};
+
+        // Core treats null as not set, we only need to trim the trailing ones.
+        let mut args = vec![
+            psbt.to_string().into(),
+            opt_into_json(self.sign)?,
+            opt_into_json(self.sighash_type)?,
+            opt_into_json(self.bip32derivs)?,
+            opt_into_json(self.finalize)?,
+        ];
+        while args.last() == Some(&null()) {
+            args.pop();
+        }
+        Ok(args)
+    }
+}
+
+/// The earliest time an imported key, script or descriptor may have been used.
+///
+/// Decides where the wallet rescans the chain from after an import.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Timestamp {
+    /// The current time, skips the rescan (default).
+    #[default]
+    Now,
+    /// UNIX epoch time, `0` rescans the entire chain.
+    Time(u64),
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        match *self {
+            Timestamp::Now => s.serialize_str("now"),
+            Timestamp::Time(time) => s.serialize_u64(time),
+        }
+    }
+}
+
+/// A single request, an argument to the `Client::import_multi` function.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ImportMultiRequest {
+    /// Descriptor to import, requires v18 or later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub desc: Option<String>,
+    /// The script or address to import, if `desc` is not used.
+    #[serde(rename = "scriptPubKey", skip_serializing_if = "Option::is_none")]
+    pub script_pubkey: Option<ImportMultiScriptPubkey>,
+    /// Creation time of the key.
+    pub timestamp: Timestamp,
+    /// The redeem script, for P2SH or P2SH-P2WSH outputs.
+    #[serde(rename = "redeemscript", skip_serializing_if = "Option::is_none")]
+    pub redeem_script: Option<ScriptBuf>,
+    /// The witness script, for P2WSH or P2SH-P2WSH outputs, requires v18 or later.
+    #[serde(rename = "witnessscript", skip_serializing_if = "Option::is_none")]
+    pub witness_script: Option<ScriptBuf>,
+    /// Public keys to import.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pubkeys: Vec<PublicKey>,
+    /// Private keys to import.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<PrivateKey>,
+    /// The range of indices to import for a ranged descriptor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(u32, u32)>,
+    /// Whether the matching outputs should be treated as change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    /// Whether the matching outputs should be considered watch-only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watchonly: Option<bool>,
+    /// Label to assign to the address, only allowed with `internal` false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Whether imported public keys should be added to the keypool, requires v18 or later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keypool: Option<bool>,
+}
+
+impl ImportMultiRequest {
+    /// Returns an error if an option is set that Bitcoin Core `version` does not support.
+    pub(crate) fn check(&self, version: u32) -> Result<()> {
+        crate::client_sync::check_options(
+            "importmulti",
+            version,
+            &[
+                ("desc", self.desc.is_some(), 18),
+                ("witnessscript", self.witness_script.is_some(), 18),
+                ("range", self.range.is_some(), 18),
+                ("keypool", self.keypool.is_some(), 18),
+            ],
+        )
+    }
+}
+
+/// The output script to import, part of [`ImportMultiRequest`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum ImportMultiScriptPubkey {
+    /// An output script.
+    Script(ScriptBuf),
+    /// The output script of an address.
+    Address {
+        /// The address.
+        address: Address<NetworkChecked>,
+    },
+}
+
+/// Argument to the `Client::list_unspent_with_query_options` function.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListUnspentQueryOptions {
+    /// Minimum value of each UTXO.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "bitcoin::amount::serde::as_btc::opt"
+    )]
+    pub minimum_amount: Option<Amount>,
+    /// Maximum value of each UTXO.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "bitcoin::amount::serde::as_btc::opt"
+    )]
+    pub maximum_amount: Option<Amount>,
+    /// Maximum number of UTXOs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_count: Option<u32>,
+    /// Minimum sum value of all UTXOs.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "bitcoin::amount::serde::as_btc::opt"
+    )]
+    pub minimum_sum_amount: Option<Amount>,
+}
+
+/// Argument to the `Client::get_block_template` function.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TemplateRequest {
+    /// The client side supported softfork deployments.
+    pub rules: Vec<TemplateRules>,
+}
+
+/// Client side supported softfork deployment, an entry in [`TemplateRequest::rules`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateRules {
+    /// SegWit v0 supported.
+    Segwit,
+    /// Signet supported.
+    Signet,
+    /// CSV supported.
+    Csv,
+    /// Taproot supported.
+    Taproot,
+}
+
+/// A scan object, an entry in the argument to the `Client::scan_tx_out_set_start` function.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanObject {
+    /// An output descriptor.
+    pub desc: String,
+    /// The range of child indexes to explore if `desc` is ranged (Core defaults to 0-1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(u32, u32)>,
+}
+
+impl ScanObject {
+    /// Creates a scan object for the output descriptor `desc`, using Core's default range.
+    pub fn new(desc: &str) -> Self { Self { desc: desc.to_owned(), range: None } }
+}
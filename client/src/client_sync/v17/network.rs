@@ -9,6 +9,41 @@
 //!
 //! See, or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements bitcoind JSON-RPC API method `addnode`
+#[macro_export]
+macro_rules! impl_client_v17__addnode {
+    () => {
+        impl Client {
+            pub fn add_node(&self, node: &str, command: AddNodeCommand) -> Result<()> {
+                self.call("addnode", &[node.into(), into_json(command)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `clearbanned`
+#[macro_export]
+macro_rules! impl_client_v17__clearbanned {
+    () => {
+        impl Client {
+            pub fn clear_banned(&self) -> Result<()> { self.call("clearbanned", &[]) }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `disconnectnode`
+#[macro_export]
+macro_rules! impl_client_v17__disconnectnode {
+    () => {
+        impl Client {
+            /// Disconnects from the node with `address` ("host:port").
+            pub fn disconnect_node(&self, address: &str) -> Result<()> {
+                self.call("disconnectnode", &[address.into()])
+            }
+        }
+    };
+}
+
 /// Implements bitcoind JSON-RPC API method `getaddednodeinfo`
 #[macro_export]
 macro_rules! impl_client_v17__getaddednodeinfo {
@@ -21,6 +56,18 @@ macro_rules! impl_client_v17__getaddednodeinfo {
     };
 }
 
+/// Implements bitcoind JSON-RPC API method `getconnectioncount`
+#[macro_export]
+macro_rules! impl_client_v17__getconnectioncount {
+    () => {
+        impl Client {
+            pub fn get_connection_count(&self) -> Result<GetConnectionCount> {
+                self.call("getconnectioncount", &[])
+            }
+        }
+    };
+}
+
 /// Implements bitcoind JSON-RPC API method `getnettotals`
 #[macro_export]
 macro_rules! impl_client_v17__getnettotals {
@@ -58,3 +105,50 @@ macro_rules! impl_client_v17__getpeerinfo {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `listbanned`
+#[macro_export]
+macro_rules! impl_client_v17__listbanned {
+    () => {
+        impl Client {
+            pub fn list_banned(&self) -> Result<ListBanned> { self.call("listbanned", &[]) }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `ping`
+#[macro_export]
+macro_rules! impl_client_v17__ping {
+    () => {
+        impl Client {
+            pub fn ping(&self) -> Result<()> { self.call("ping", &[]) }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `setban`
+#[macro_export]
+macro_rules! impl_client_v17__setban {
+    () => {
+        impl Client {
+            /// Adds or removes `subnet` ("ip[/netmask]") from the banned list, using the default
+            /// ban time when adding.
+            pub fn set_ban(&self, subnet: &str, command: SetBanCommand) -> Result<()> {
+                self.call("setban", &[subnet.into(), into_json(command)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `setnetworkactive`
+#[macro_export]
+macro_rules! impl_client_v17__setnetworkactive {
+    () => {
+        impl Client {
+            /// Enables or disables all p2p network activity, returns the new state.
+            pub fn set_network_active(&self, state: bool) -> Result<bool> {
+                self.call("setnetworkactive", &[state.into()])
+            }
+        }
+    };
+}
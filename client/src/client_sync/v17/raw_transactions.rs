@@ -9,6 +9,178 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements bitcoind JSON-RPC API method `combinepsbt`
+#[macro_export]
+macro_rules! impl_client_v17__combinepsbt {
+    () => {
+        impl Client {
+            pub fn combine_psbt(&self, psbts: &[bitcoin::Psbt]) -> Result<CombinePsbt> {
+                let psbts = psbts.iter().map(|psbt| psbt.to_string()).collect::<Vec<String>>();
+                self.call("combinepsbt", &[into_json(psbts)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `combinerawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__combinerawtransaction {
+    () => {
+        impl Client {
+            pub fn combine_raw_transaction(
+                &self,
+                txs: &[bitcoin::Transaction],
+            ) -> Result<CombineRawTransaction> {
+                let hexes = txs
+                    .iter()
+                    .map(bitcoin::consensus::encode::serialize_hex)
+                    .collect::<Vec<String>>();
+                self.call("combinerawtransaction", &[into_json(hexes)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `converttopsbt`
+#[macro_export]
+macro_rules! impl_client_v17__converttopsbt {
+    () => {
+        impl Client {
+            pub fn convert_to_psbt(&self, tx: &bitcoin::Transaction) -> Result<ConvertToPsbt> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("converttopsbt", &[hex.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `createpsbt`
+#[macro_export]
+macro_rules! impl_client_v17__createpsbt {
+    () => {
+        impl Client {
+            /// Creates a PSBT spending `inputs` and paying `outputs`.
+            pub fn create_psbt(
+                &self,
+                inputs: &[bitcoin::OutPoint],
+                outputs: &[(Address<NetworkChecked>, Amount)],
+            ) -> Result<CreatePsbt> {
+                let inputs = inputs
+                    .iter()
+                    .map(|outpoint| {
+                        let mut input = serde_json::Map::new();
+                        input.insert("txid".to_owned(), outpoint.txid.to_string().into());
+                        input.insert("vout".to_owned(), outpoint.vout.into());
+                        serde_json::Value::Object(input)
+                    })
+                    .collect::<Vec<_>>();
+                let outputs = outputs
+                    .iter()
+                    .map(|(address, amount)| {
+                        let mut output = serde_json::Map::new();
+                        output.insert(address.to_string(), into_json(amount.to_btc())?);
+                        Ok(serde_json::Value::Object(output))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                self.call("createpsbt", &[inputs.into(), outputs.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `createrawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__createrawtransaction {
+    () => {
+        impl Client {
+            /// Creates an unsigned transaction spending `inputs` and paying `outputs`.
+            pub fn create_raw_transaction(
+                &self,
+                inputs: &[bitcoin::OutPoint],
+                outputs: &[(Address<NetworkChecked>, Amount)],
+            ) -> Result<CreateRawTransaction> {
+                let inputs = inputs
+                    .iter()
+                    .map(|outpoint| {
+                        let mut input = serde_json::Map::new();
+                        input.insert("txid".to_owned(), outpoint.txid.to_string().into());
+                        input.insert("vout".to_owned(), outpoint.vout.into());
+                        serde_json::Value::Object(input)
+                    })
+                    .collect::<Vec<_>>();
+                let outputs = outputs
+                    .iter()
+                    .map(|(address, amount)| {
+                        let mut output = serde_json::Map::new();
+                        output.insert(address.to_string(), into_json(amount.to_btc())?);
+                        Ok(serde_json::Value::Object(output))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                self.call("createrawtransaction", &[inputs.into(), outputs.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `decodepsbt`
+#[macro_export]
+macro_rules! impl_client_v17__decodepsbt {
+    () => {
+        impl Client {
+            pub fn decode_psbt(&self, psbt: &bitcoin::Psbt) -> Result<DecodePsbt> {
+                self.call("decodepsbt", &[psbt.to_string().into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `decoderawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__decoderawtransaction {
+    () => {
+        impl Client {
+            pub fn decode_raw_transaction(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<DecodeRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("decoderawtransaction", &[hex.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `decodescript`
+#[macro_export]
+macro_rules! impl_client_v17__decodescript {
+    () => {
+        impl Client {
+            pub fn decode_script(&self, script: &bitcoin::Script) -> Result<DecodeScript> {
+                self.call("decodescript", &[script.to_hex_string().into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getrawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__getrawtransaction {
+    () => {
+        impl Client {
+            pub fn get_raw_transaction(&self, txid: Txid) -> Result<GetRawTransaction> {
+                self.call("getrawtransaction", &[into_json(txid)?, false.into()])
+            }
+
+            pub fn get_raw_transaction_verbose(
+                &self,
+                txid: Txid,
+            ) -> Result<GetRawTransactionVerbose> {
+                self.call("getrawtransaction", &[into_json(txid)?, true.into()])
+            }
+        }
+    };
+}
+
 /// Implements bitcoind JSON-RPC API method `sendrawtransaction`
 #[macro_export]
 macro_rules! impl_client_v17__sendrawtransaction {
@@ -24,3 +196,101 @@ macro_rules! impl_client_v17__sendrawtransaction {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `finalizepsbt`
+#[macro_export]
+macro_rules! impl_client_v17__finalizepsbt {
+    () => {
+        impl Client {
+            pub fn finalize_psbt(&self, psbt: &bitcoin::Psbt) -> Result<FinalizePsbt> {
+                self.call("finalizepsbt", &[psbt.to_string().into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `testmempoolaccept`
+#[macro_export]
+macro_rules! impl_client_v17__testmempoolaccept {
+    () => {
+        impl Client {
+            /// Tests mempool acceptance of `txs`, before v22 exactly one transaction is allowed.
+            pub fn test_mempool_accept(
+                &self,
+                txs: &[bitcoin::Transaction],
+            ) -> Result<TestMempoolAccept> {
+                let hexes = txs
+                    .iter()
+                    .map(bitcoin::consensus::encode::serialize_hex)
+                    .collect::<Vec<String>>();
+                self.call("testmempoolaccept", &[into_json(hexes)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `fundrawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__fundrawtransaction {
+    () => {
+        impl Client {
+            /// Adds inputs from the wallet, and a change output if needed, to `tx`.
+            pub fn fund_raw_transaction(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<FundRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("fundrawtransaction", &[hex.into()])
+            }
+
+            /// Funds `tx` from the wallet as configured by `options`.
+            ///
+            /// Errors without calling the server if `options` are not supported by it.
+            pub fn fund_raw_transaction_with_options(
+                &self,
+                tx: &bitcoin::Transaction,
+                options: &FundRawTransactionOptions,
+            ) -> Result<FundRawTransaction> {
+                options.check(Self::VERSION)?;
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("fundrawtransaction", &[hex.into(), into_json(options)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `signrawtransactionwithkey`
+#[macro_export]
+macro_rules! impl_client_v17__signrawtransactionwithkey {
+    () => {
+        impl Client {
+            /// Signs the inputs of `tx` that can be signed using only `keys`.
+            ///
+            /// Outputs spent by `tx` that are not in the UTXO set can be passed in `prevtxs`.
+            pub fn sign_raw_transaction_with_key(
+                &self,
+                tx: &bitcoin::Transaction,
+                keys: &[bitcoin::PrivateKey],
+                prevtxs: &[PreviousTx],
+                sighash_type: Option<SighashType>,
+            ) -> Result<SignRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                let keys = keys.iter().map(|key| key.to_wif()).collect::<Vec<_>>();
+                let [prevtxs, sighash_type] = $crate::client_sync::v17::sign_raw_transaction_args(
+                    "signrawtransactionwithkey",
+                    Self::VERSION,
+                    prevtxs,
+                    sighash_type,
+                )?;
+                let mut args = [hex.into(), keys.into(), prevtxs, sighash_type];
+                self.call(
+                    "signrawtransactionwithkey",
+                    handle_defaults(
+                        &mut args,
+                        &[$crate::client_sync::empty_arr(), $crate::client_sync::null()],
+                    ),
+                )
+            }
+        }
+    };
+}
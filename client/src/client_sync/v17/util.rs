@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Requires `Client` to be in scope.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+//!
+//! See, or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `createmultisig`
+#[macro_export]
+macro_rules! impl_client_v17__createmultisig {
+    () => {
+        impl Client {
+            pub fn create_multisig(
+                &self,
+                nrequired: u32,
+                keys: &[bitcoin::PublicKey],
+            ) -> Result<CreateMultisig> {
+                let keys = keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+                self.call("createmultisig", &[nrequired.into(), into_json(keys)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `estimatesmartfee`
+#[macro_export]
+macro_rules! impl_client_v17__estimatesmartfee {
+    () => {
+        impl Client {
+            pub fn estimate_smart_fee(&self, conf_target: u32) -> Result<EstimateSmartFee> {
+                self.call("estimatesmartfee", &[conf_target.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `signmessagewithprivkey`
+#[macro_export]
+macro_rules! impl_client_v17__signmessagewithprivkey {
+    () => {
+        impl Client {
+            pub fn sign_message_with_priv_key(
+                &self,
+                privkey: &bitcoin::PrivateKey,
+                message: &str,
+            ) -> Result<SignMessageWithPrivKey> {
+                self.call("signmessagewithprivkey", &[privkey.to_wif().into(), message.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `validateaddress`
+#[macro_export]
+macro_rules! impl_client_v17__validateaddress {
+    () => {
+        impl Client {
+            /// Validates `address`, which is passed as a string so invalid addresses can be checked.
+            pub fn validate_address(&self, address: &str) -> Result<ValidateAddress> {
+                self.call("validateaddress", &[address.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `verifymessage`
+#[macro_export]
+macro_rules! impl_client_v17__verifymessage {
+    () => {
+        impl Client {
+            pub fn verify_message(
+                &self,
+                address: &Address<NetworkChecked>,
+                signature: &str,
+                message: &str,
+            ) -> Result<VerifyMessage> {
+                self.call(
+                    "verifymessage",
+                    &[address.to_string().into(), signature.into(), message.into()],
+                )
+            }
+        }
+    };
+}
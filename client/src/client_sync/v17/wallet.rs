@@ -121,3 +121,366 @@ macro_rules! impl_client_v17__gettransaction {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `getaddressesbylabel`
+#[macro_export]
+macro_rules! impl_client_v17__getaddressesbylabel {
+    () => {
+        impl Client {
+            pub fn get_addresses_by_label(&self, label: &str) -> Result<GetAddressesByLabel> {
+                self.call("getaddressesbylabel", &[label.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getaddressinfo`
+#[macro_export]
+macro_rules! impl_client_v17__getaddressinfo {
+    () => {
+        impl Client {
+            pub fn get_address_info(
+                &self,
+                address: &Address<NetworkChecked>,
+            ) -> Result<GetAddressInfo> {
+                self.call("getaddressinfo", &[address.to_string().into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getwalletinfo`
+#[macro_export]
+macro_rules! impl_client_v17__getwalletinfo {
+    () => {
+        impl Client {
+            pub fn get_wallet_info(&self) -> Result<GetWalletInfo> {
+                self.call("getwalletinfo", &[])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listlabels`
+#[macro_export]
+macro_rules! impl_client_v17__listlabels {
+    () => {
+        impl Client {
+            pub fn list_labels(&self) -> Result<ListLabels> { self.call("listlabels", &[]) }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listwallets`
+#[macro_export]
+macro_rules! impl_client_v17__listwallets {
+    () => {
+        impl Client {
+            pub fn list_wallets(&self) -> Result<ListWallets> { self.call("listwallets", &[]) }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `setlabel`
+#[macro_export]
+macro_rules! impl_client_v17__setlabel {
+    () => {
+        impl Client {
+            /// Sets the label associated with `address`.
+            pub fn set_label(&self, address: &Address<NetworkChecked>, label: &str) -> Result<()> {
+                self.call("setlabel", &[address.to_string().into(), label.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listsinceblock`
+#[macro_export]
+macro_rules! impl_client_v17__listsinceblock {
+    () => {
+        impl Client {
+            /// Lists wallet transactions in blocks since `blockhash`, or all transactions if `None`.
+            ///
+            /// If `include_removed` is set, transactions removed by a reorg are returned as well.
+            pub fn list_since_block(
+                &self,
+                blockhash: Option<BlockHash>,
+                include_removed: bool,
+            ) -> Result<ListSinceBlock> {
+                let blockhash = blockhash.map(|hash| hash.to_string()).unwrap_or_default();
+                self.call(
+                    "listsinceblock",
+                    &[blockhash.into(), 1.into(), false.into(), include_removed.into()],
+                )
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listtransactions`
+#[macro_export]
+macro_rules! impl_client_v17__listtransactions {
+    () => {
+        impl Client {
+            /// Lists the most recent wallet transactions.
+            ///
+            /// Returns up to `count` (default 10) transactions, skipping the first `skip`
+            /// (default 0), optionally only incoming transactions paying to `label`.
+            pub fn list_transactions(
+                &self,
+                label: Option<&str>,
+                count: Option<u32>,
+                skip: Option<u32>,
+            ) -> Result<ListTransactions> {
+                let mut args = [
+                    into_json(label.unwrap_or("*"))?,
+                    $crate::client_sync::opt_into_json(count)?,
+                    $crate::client_sync::opt_into_json(skip)?,
+                ];
+                let defaults = [into_json(10)?, into_json(0)?];
+                self.call("listtransactions", handle_defaults(&mut args, &defaults))
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listunspent`
+#[macro_export]
+macro_rules! impl_client_v17__listunspent {
+    () => {
+        impl Client {
+            pub fn list_unspent(&self) -> Result<ListUnspent> { self.call("listunspent", &[]) }
+
+            /// Lists the wallet's unspent outputs with at least one confirmation, filtered by
+            /// `query_options`.
+            pub fn list_unspent_with_query_options(
+                &self,
+                query_options: &ListUnspentQueryOptions,
+            ) -> Result<ListUnspent> {
+                self.call(
+                    "listunspent",
+                    &[
+                        1.into(),
+                        9_999_999.into(),
+                        $crate::client_sync::empty_arr(),
+                        true.into(),
+                        into_json(query_options)?,
+                    ],
+                )
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `walletcreatefundedpsbt`
+#[macro_export]
+macro_rules! impl_client_v17__walletcreatefundedpsbt {
+    () => {
+        impl Client {
+            /// Creates a PSBT paying `outputs`, funded by the wallet.
+            pub fn wallet_create_funded_psbt(
+                &self,
+                outputs: &[(Address<NetworkChecked>, Amount)],
+            ) -> Result<WalletCreateFundedPsbt> {
+                let outputs = outputs
+                    .iter()
+                    .map(|(address, amount)| {
+                        let mut output = serde_json::Map::new();
+                        output.insert(address.to_string(), into_json(amount.to_btc())?);
+                        Ok(serde_json::Value::Object(output))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                self.call(
+                    "walletcreatefundedpsbt",
+                    &[$crate::client_sync::empty_arr(), outputs.into()],
+                )
+            }
+
+            /// Creates a PSBT paying `outputs`, funded as configured by `options`.
+            ///
+            /// Errors without calling the server if `options` are not supported by it.
+            pub fn wallet_create_funded_psbt_with_options(
+                &self,
+                outputs: &[(Address<NetworkChecked>, Amount)],
+                options: &WalletCreateFundedPsbtOptions,
+            ) -> Result<WalletCreateFundedPsbt> {
+                options.check(Self::VERSION)?;
+                let inputs = into_json(
+                    options
+                        .inputs
+                        .iter()
+                        .map(|outpoint| {
+                            serde_json::json!({ "txid": outpoint.txid, "vout": outpoint.vout })
+                        })
+                        .collect::<Vec<_>>(),
+                )?;
+                let outputs = $crate::client_sync::outputs_into_json(outputs)?;
+                self.call(
+                    "walletcreatefundedpsbt",
+                    &[inputs, outputs, 0.into(), into_json(options)?],
+                )
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `walletprocesspsbt`
+#[macro_export]
+macro_rules! impl_client_v17__walletprocesspsbt {
+    () => {
+        impl Client {
+            /// Updates `psbt` with input information from the wallet and, if `sign`, signs it.
+            pub fn wallet_process_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+                sign: bool,
+            ) -> Result<WalletProcessPsbt> {
+                self.call("walletprocesspsbt", &[psbt.to_string().into(), sign.into()])
+            }
+
+            /// Updates `psbt` with input information from the wallet as configured by `options`.
+            ///
+            /// Errors without calling the server if `options` are not supported by it.
+            pub fn wallet_process_psbt_with_options(
+                &self,
+                psbt: &bitcoin::Psbt,
+                options: &WalletProcessPsbtOptions,
+            ) -> Result<WalletProcessPsbt> {
+                options.check(Self::VERSION)?;
+                self.call("walletprocesspsbt", &options.args(psbt)?)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `abandontransaction`
+#[macro_export]
+macro_rules! impl_client_v17__abandontransaction {
+    () => {
+        impl Client {
+            /// Marks in-wallet transaction `txid` and all its in-wallet descendants as abandoned.
+            pub fn abandon_transaction(&self, txid: Txid) -> Result<()> {
+                self.call("abandontransaction", &[into_json(txid)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `bumpfee`
+#[macro_export]
+macro_rules! impl_client_v17__bumpfee {
+    () => {
+        impl Client {
+            /// Bumps the fee of opt-in-RBF transaction `txid` using the default options.
+            pub fn bump_fee(&self, txid: Txid) -> Result<BumpFee> {
+                self.call("bumpfee", &[into_json(txid)?])
+            }
+
+            /// Bumps the fee of opt-in-RBF transaction `txid` as configured by `options`.
+            pub fn bump_fee_with_options(
+                &self,
+                txid: Txid,
+                options: &BumpFeeOptions,
+            ) -> Result<BumpFee> {
+                self.call("bumpfee", &[into_json(txid)?, into_json(options)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `sendmany`
+#[macro_export]
+macro_rules! impl_client_v17__sendmany {
+    () => {
+        impl Client {
+            /// Sends `amounts` to multiple addresses in a single transaction.
+            pub fn send_many(
+                &self,
+                amounts: &[(Address<NetworkChecked>, Amount)],
+            ) -> Result<SendMany> {
+                self.call("sendmany", &SendManyOptions::default().args(amounts, false)?)
+            }
+
+            /// Sends `amounts` to multiple addresses in a single transaction configured by `options`.
+            ///
+            /// Errors without calling the server if `options` are not supported by it.
+            pub fn send_many_with_options(
+                &self,
+                amounts: &[(Address<NetworkChecked>, Amount)],
+                options: &SendManyOptions,
+            ) -> Result<SendMany> {
+                options.check(Self::VERSION)?;
+                self.call("sendmany", &options.args(amounts, false)?)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `signmessage`
+#[macro_export]
+macro_rules! impl_client_v17__signmessage {
+    () => {
+        impl Client {
+            /// Signs `message` with the private key of `address`.
+            pub fn sign_message(
+                &self,
+                address: &Address<NetworkChecked>,
+                message: &str,
+            ) -> Result<SignMessage> {
+                self.call("signmessage", &[address.to_string().into(), message.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `signrawtransactionwithwallet`
+#[macro_export]
+macro_rules! impl_client_v17__signrawtransactionwithwallet {
+    () => {
+        impl Client {
+            /// Signs the inputs of `tx` using keys from the wallet.
+            ///
+            /// Outputs spent by `tx` that are not in the UTXO set can be passed in `prevtxs`.
+            pub fn sign_raw_transaction_with_wallet(
+                &self,
+                tx: &bitcoin::Transaction,
+                prevtxs: &[PreviousTx],
+                sighash_type: Option<SighashType>,
+            ) -> Result<SignRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                let [prevtxs, sighash_type] = $crate::client_sync::v17::sign_raw_transaction_args(
+                    "signrawtransactionwithwallet",
+                    Self::VERSION,
+                    prevtxs,
+                    sighash_type,
+                )?;
+                let mut args = [hex.into(), prevtxs, sighash_type];
+                self.call(
+                    "signrawtransactionwithwallet",
+                    handle_defaults(
+                        &mut args,
+                        &[$crate::client_sync::empty_arr(), $crate::client_sync::null()],
+                    ),
+                )
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `importmulti`
+#[macro_export]
+macro_rules! impl_client_v17__importmulti {
+    () => {
+        impl Client {
+            /// Imports addresses, scripts and keys, rescanning from the earliest `timestamp`.
+            ///
+            /// Errors without calling the server if any request is not supported by it.
+            pub fn import_multi(&self, requests: &[ImportMultiRequest]) -> Result<ImportMulti> {
+                for request in requests {
+                    request.check(Self::VERSION)?;
+                }
+                self.call("importmulti", &[into_json(requests)?])
+            }
+        }
+    };
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `submitheader`
+#[macro_export]
+macro_rules! impl_client_v18__submitheader {
+    () => {
+        impl Client {
+            pub fn submit_header(&self, header: &bitcoin::block::Header) -> Result<()> {
+                let hex = bitcoin::consensus::encode::serialize_hex(header);
+                self.call("submitheader", &[hex.into()])
+            }
+        }
+    };
+}
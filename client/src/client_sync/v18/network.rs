@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getnodeaddresses`
+#[macro_export]
+macro_rules! impl_client_v18__getnodeaddresses {
+    () => {
+        impl Client {
+            /// Returns up to `count` known addresses that can be used to find new nodes.
+            pub fn get_node_addresses(&self, count: u32) -> Result<GetNodeAddresses> {
+                self.call("getnodeaddresses", &[count.into()])
+            }
+        }
+    };
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `analyzepsbt`
+#[macro_export]
+macro_rules! impl_client_v18__analyzepsbt {
+    () => {
+        impl Client {
+            pub fn analyze_psbt(&self, psbt: &bitcoin::Psbt) -> Result<AnalyzePsbt> {
+                self.call("analyzepsbt", &[psbt.to_string().into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `joinpsbts`
+#[macro_export]
+macro_rules! impl_client_v18__joinpsbts {
+    () => {
+        impl Client {
+            pub fn join_psbts(&self, psbts: &[bitcoin::Psbt]) -> Result<JoinPsbts> {
+                let psbts = psbts.iter().map(|psbt| psbt.to_string()).collect::<Vec<String>>();
+                self.call("joinpsbts", &[into_json(psbts)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `utxoupdatepsbt`
+#[macro_export]
+macro_rules! impl_client_v18__utxoupdatepsbt {
+    () => {
+        impl Client {
+            pub fn utxo_update_psbt(&self, psbt: &bitcoin::Psbt) -> Result<UtxoUpdatePsbt> {
+                self.call("utxoupdatepsbt", &[psbt.to_string().into()])
+            }
+        }
+    };
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `deriveaddresses`
+#[macro_export]
+macro_rules! impl_client_v18__deriveaddresses {
+    () => {
+        impl Client {
+            /// Derives addresses from `descriptor`, `range` is required for ranged descriptors.
+            pub fn derive_addresses(
+                &self,
+                descriptor: &str,
+                range: Option<(u32, u32)>,
+            ) -> Result<DeriveAddresses> {
+                match range {
+                    Some(range) =>
+                        self.call("deriveaddresses", &[descriptor.into(), into_json(range)?]),
+                    None => self.call("deriveaddresses", &[descriptor.into()]),
+                }
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getdescriptorinfo`
+#[macro_export]
+macro_rules! impl_client_v18__getdescriptorinfo {
+    () => {
+        impl Client {
+            pub fn get_descriptor_info(&self, descriptor: &str) -> Result<GetDescriptorInfo> {
+                self.call("getdescriptorinfo", &[descriptor.into()])
+            }
+        }
+    };
+}
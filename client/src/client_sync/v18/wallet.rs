@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `listwalletdir`
+#[macro_export]
+macro_rules! impl_client_v18__listwalletdir {
+    () => {
+        impl Client {
+            pub fn list_wallet_dir(&self) -> Result<ListWalletDir> {
+                self.call("listwalletdir", &[])
+            }
+        }
+    };
+}
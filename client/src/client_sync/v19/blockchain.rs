@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.19.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getblockfilter`
+///
+/// Also implements a helper that checks the filter header chain over a range of blocks.
+#[macro_export]
+macro_rules! impl_client_v19__getblockfilter {
+    () => {
+        impl Client {
+            pub fn get_block_filter(&self, hash: &BlockHash) -> Result<GetBlockFilter> {
+                self.call("getblockfilter", &[into_json(hash)?])
+            }
+
+            /// Gets the filters for the blocks at `start_height..=stop_height` and checks that each
+            /// filter header commits to its filter and to the previous filter header.
+            ///
+            /// Requires the node to be running with `-blockfilterindex`. Returns the checked
+            /// filters in height order.
+            pub fn verify_block_filter_headers(
+                &self,
+                start_height: u64,
+                stop_height: u64,
+            ) -> Result<Vec<$crate::json::model::GetBlockFilter>> {
+                use bitcoin::hashes::Hash as _;
+
+                // BIP-157: the previous filter header of the genesis block is all zeros.
+                let mut prev = if start_height == 0 {
+                    bitcoin::bip158::FilterHeader::all_zeros()
+                } else {
+                    let hash = self.get_block_hash(start_height - 1)?.block_hash()?;
+                    self.get_block_filter(&hash)?.into_model()?.header
+                };
+
+                let mut filters = vec![];
+                for height in start_height..=stop_height {
+                    let block_hash = self.get_block_hash(height)?.block_hash()?;
+                    let model = self.get_block_filter(&block_hash)?.into_model()?;
+
+                    let expected = model.filter.filter_header(&prev);
+                    if model.header != expected {
+                        return Err($crate::client_sync::FilterHeaderMismatchError {
+                            block_hash,
+                            expected,
+                            got: model.header,
+                        })?;
+                    }
+                    prev = model.header;
+                    filters.push(model);
+                }
+                Ok(filters)
+            }
+        }
+    };
+}
@@ -4,6 +4,7 @@
 //!
 //! We ignore option arguments unless they effect the shape of the returned JSON data.
 
+mod blockchain;
 mod wallet;
 
 use bitcoin::address::{Address, NetworkChecked};
@@ -19,28 +20,116 @@ crate::impl_client_v17__getblockchaininfo!();
 crate::impl_client_v17__getbestblockhash!();
 crate::impl_client_v17__getblock!();
 crate::impl_client_v17__gettxout!();
+crate::impl_client_v17__getblockcount!();
+crate::impl_client_v19__getblockfilter!();
+crate::impl_client_v17__getblockhash!();
+crate::impl_client_v17__getmempoolancestors!();
+crate::impl_client_v17__getmempooldescendants!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
+crate::impl_client_v17__getrawmempool!();
+crate::impl_client_v17__gettxoutsetinfo!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v17__waitforblock!();
+crate::impl_client_v17__waitforblockheight!();
+crate::impl_client_v17__waitfornewblock!();
+crate::impl_client_wait_helpers!();
 
 // == Control ==
+crate::impl_client_v17__getmemoryinfo!();
+crate::impl_client_v18__getrpcinfo!();
+crate::impl_client_v17__logging!();
 crate::impl_client_v17__stop!();
+crate::impl_client_v17__uptime!();
 
 // == Generating ==
 crate::impl_client_v17__generatetoaddress!();
 
+// == Mining ==
+crate::impl_client_v17__getblocktemplate!();
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getnetworkhashps!();
+crate::impl_client_v17__prioritisetransaction!();
+crate::impl_client_v17__submitblock!();
+crate::impl_client_v18__submitheader!();
+
 // == Network ==
+crate::impl_client_v17__addnode!();
+crate::impl_client_v17__clearbanned!();
+crate::impl_client_v17__disconnectnode!();
+crate::impl_client_v17__getaddednodeinfo!();
+crate::impl_client_v17__getconnectioncount!();
+crate::impl_client_v17__getnettotals!();
 crate::impl_client_v17__getnetworkinfo!();
 crate::impl_client_check_expected_server_version!({ [190100] });
+crate::impl_client_v18__getnodeaddresses!();
+crate::impl_client_v17__getpeerinfo!();
+crate::impl_client_v17__listbanned!();
+crate::impl_client_v17__ping!();
+crate::impl_client_v17__setban!();
+crate::impl_client_v17__setnetworkactive!();
 
 // == Rawtransactions ==
+crate::impl_client_v18__analyzepsbt!();
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__combinerawtransaction!();
+crate::impl_client_v17__converttopsbt!();
+crate::impl_client_v17__createpsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__decodescript!();
+crate::impl_client_v17__finalizepsbt!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
+crate::impl_client_v18__joinpsbts!();
 crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v17__signrawtransactionwithkey!();
+crate::impl_client_v17__testmempoolaccept!();
+crate::impl_client_v18__utxoupdatepsbt!();
+
+// == Util ==
+crate::impl_client_v17__createmultisig!();
+crate::impl_client_v18__deriveaddresses!();
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v18__getdescriptorinfo!();
+crate::impl_client_v17__signmessagewithprivkey!();
+crate::impl_client_v17__validateaddress!();
+crate::impl_client_v17__verifymessage!();
 
 // == Wallet ==
 crate::impl_client_v17__createwallet!();
 crate::impl_client_v17__unloadwallet!();
 crate::impl_client_v17__loadwallet!();
+crate::impl_client_v17__abandontransaction!();
+crate::impl_client_v17__bumpfee!();
 crate::impl_client_v17__getnewaddress!();
 crate::impl_client_v17__getbalance!();
 crate::impl_client_v19__getbalances!();
+crate::impl_client_v17__sendmany!();
 crate::impl_client_v17__sendtoaddress!();
+crate::impl_client_v17__signmessage!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v17__getaddressinfo!();
 crate::impl_client_v17__gettransaction!();
+crate::impl_client_v17__getwalletinfo!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v17__listlabels!();
+crate::impl_client_v17__listsinceblock!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v18__listwalletdir!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__setlabel!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_psbt_workflow!();
 
-pub use crate::client_sync::v17::AddressType;
+pub use crate::client_sync::v17::{
+    AddNodeCommand, AddressType, BumpFeeOptions, ChangeType, EstimateMode,
+    FundRawTransactionOptions, ImportMultiRequest, ImportMultiScriptPubkey,
+    ListUnspentQueryOptions, PreviousTx, ScanObject, SendManyOptions, SetBanCommand, SighashType,
+    SolvingData, TemplateRequest, TemplateRules, Timestamp, WalletCreateFundedPsbtOptions,
+    WalletProcessPsbtOptions,
+};
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getrawmempool`
+#[macro_export]
+macro_rules! impl_client_v21__getrawmempool {
+    () => {
+        impl Client {
+            pub fn get_raw_mempool(&self) -> Result<GetRawMempool> {
+                self.call("getrawmempool", &[])
+            }
+
+            pub fn get_raw_mempool_verbose(&self) -> Result<GetRawMempoolVerbose> {
+                self.call("getrawmempool", &[into_json(true)?])
+            }
+
+            pub fn get_raw_mempool_sequence(&self) -> Result<GetRawMempoolSequence> {
+                self.call("getrawmempool", &[into_json(false)?, into_json(true)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettxoutsetinfo`
+#[macro_export]
+macro_rules! impl_client_v21__gettxoutsetinfo {
+    () => {
+        impl Client {
+            pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[])
+            }
+
+            pub fn get_tx_out_set_info_with_hash_type(
+                &self,
+                hash_type: TxOutSetHashType,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[into_json(hash_type)?])
+            }
+        }
+    };
+}
@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A JSON-RPC client for testing against Bitcoin Core `v0.21.2`.
+//!
+//! We ignore option arguments unless they effect the shape of the returned JSON data.
+
+mod blockchain;
+mod util;
+mod wallet;
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::{Amount, Block, BlockHash, FeeRate, OutPoint, Txid, Weight};
+use serde::{Deserialize, Serialize};
+
+use crate::client_sync::{handle_defaults, into_json};
+use crate::json::v21::*;
+
+crate::define_jsonrpc_minreq_client!("v21");
+
+// == Blockchain ==
+crate::impl_client_v17__getblockchaininfo!();
+crate::impl_client_v17__getbestblockhash!();
+crate::impl_client_v17__getblock!();
+crate::impl_client_v17__gettxout!();
+crate::impl_client_v17__getblockcount!();
+crate::impl_client_v19__getblockfilter!();
+crate::impl_client_v17__getblockhash!();
+crate::impl_client_v17__getmempoolancestors!();
+crate::impl_client_v17__getmempooldescendants!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
+crate::impl_client_v21__getrawmempool!();
+crate::impl_client_v21__gettxoutsetinfo!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v17__waitforblock!();
+crate::impl_client_v17__waitforblockheight!();
+crate::impl_client_v17__waitfornewblock!();
+crate::impl_client_wait_helpers!();
+
+// == Control ==
+crate::impl_client_v17__getmemoryinfo!();
+crate::impl_client_v18__getrpcinfo!();
+crate::impl_client_v17__logging!();
+crate::impl_client_v17__stop!();
+crate::impl_client_v17__uptime!();
+
+// == Generating ==
+crate::impl_client_v17__generatetoaddress!();
+
+// == Mining ==
+crate::impl_client_v17__getblocktemplate!();
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getnetworkhashps!();
+crate::impl_client_v17__prioritisetransaction!();
+crate::impl_client_v17__submitblock!();
+crate::impl_client_v18__submitheader!();
+
+// == Network ==
+crate::impl_client_v17__addnode!();
+crate::impl_client_v17__clearbanned!();
+crate::impl_client_v17__disconnectnode!();
+crate::impl_client_v17__getaddednodeinfo!();
+crate::impl_client_v17__getconnectioncount!();
+crate::impl_client_v17__getnettotals!();
+crate::impl_client_v17__getnetworkinfo!();
+crate::impl_client_check_expected_server_version!({ [210200] });
+crate::impl_client_v18__getnodeaddresses!();
+crate::impl_client_v17__getpeerinfo!();
+crate::impl_client_v17__listbanned!();
+crate::impl_client_v17__ping!();
+crate::impl_client_v17__setban!();
+crate::impl_client_v17__setnetworkactive!();
+
+// == Rawtransactions ==
+crate::impl_client_v18__analyzepsbt!();
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__combinerawtransaction!();
+crate::impl_client_v17__converttopsbt!();
+crate::impl_client_v17__createpsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__decodescript!();
+crate::impl_client_v17__finalizepsbt!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
+crate::impl_client_v18__joinpsbts!();
+crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v17__signrawtransactionwithkey!();
+crate::impl_client_v17__testmempoolaccept!();
+crate::impl_client_v18__utxoupdatepsbt!();
+
+// == Util ==
+crate::impl_client_v17__createmultisig!();
+crate::impl_client_v18__deriveaddresses!();
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v18__getdescriptorinfo!();
+crate::impl_client_v21__getindexinfo!();
+crate::impl_client_v17__signmessagewithprivkey!();
+crate::impl_client_v17__validateaddress!();
+crate::impl_client_v17__verifymessage!();
+
+// == Wallet ==
+crate::impl_client_v17__createwallet!();
+crate::impl_client_v17__unloadwallet!();
+crate::impl_client_v17__loadwallet!();
+crate::impl_client_v17__abandontransaction!();
+crate::impl_client_v17__bumpfee!();
+crate::impl_client_v21__psbtbumpfee!();
+crate::impl_client_v17__getnewaddress!();
+crate::impl_client_v17__getbalance!();
+crate::impl_client_v19__getbalances!();
+crate::impl_client_v17__sendmany!();
+crate::impl_client_v21__sendmany_verbose!();
+crate::impl_client_v21__send!();
+crate::impl_client_v17__sendtoaddress!();
+crate::impl_client_v17__signmessage!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v17__getaddressinfo!();
+crate::impl_client_v17__gettransaction!();
+crate::impl_client_v17__getwalletinfo!();
+crate::impl_client_v21__importdescriptors!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v17__listlabels!();
+crate::impl_client_v17__listsinceblock!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v18__listwalletdir!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__setlabel!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_psbt_workflow!();
+
+/// Argument to the `Client::get_tx_out_set_info_with_hash_type` function.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TxOutSetHashType {
+    /// The legacy algorithm.
+    #[serde(rename = "hash_serialized_2")]
+    HashSerialized2,
+    /// The MuHash3072 algorithm.
+    #[serde(rename = "muhash")]
+    Muhash,
+    /// Do not calculate a UTXO set hash.
+    #[serde(rename = "none")]
+    None,
+}
+
+/// Argument to the `Client::bump_fee_with_options` and `Client::psbt_bump_fee_with_options`
+/// functions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BumpFeeOptions {
+    /// Confirmation target (in blocks).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u32>,
+    /// Fee rate (NOT total fee) to pay, sent to Core in sat/vB.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::client_sync::fee_rate_sat_per_vb"
+    )]
+    pub fee_rate: Option<FeeRate>,
+    /// Whether the new transaction should still be marked BIP-125 replaceable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// The fee estimate mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+/// Argument to the `Client::send_with_options` function.
+///
+/// Setting an option that the server version does not support is an error.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SendOptions {
+    /// The inputs to spend.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "crate::client_sync::serialize_outpoints"
+    )]
+    pub inputs: Vec<OutPoint>,
+    /// Whether to add inputs from the wallet if `inputs` is not enough.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_inputs: Option<bool>,
+    /// Whether to include inputs that are not safe to spend (since v22).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_unsafe: Option<bool>,
+    /// Whether to add the transaction to the wallet and broadcast it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_to_wallet: Option<bool>,
+    /// The address to receive the change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address<NetworkChecked>>,
+    /// The index of the change output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// The output type to use for the change, `Bech32m` requires v23.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<ChangeType>,
+    /// Whether to lock the selected UTXOs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// The fee rate to pay, sent to Core in sat/vB.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::client_sync::fee_rate_sat_per_vb::serialize"
+    )]
+    pub fee_rate: Option<FeeRate>,
+    /// The zero-based output indices to deduct the fee from.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether to signal BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Keys and scripts needed for producing a final transaction with external inputs (since v23).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solving_data: Option<SolvingData>,
+    /// The maximum weight of the external inputs (since v24).
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "crate::client_sync::serialize_input_weights"
+    )]
+    pub input_weights: Vec<(OutPoint, Weight)>,
+}
+
+impl SendOptions {
+    /// Returns an error if an option is set that Bitcoin Core `version` does not support.
+    pub(crate) fn check(&self, version: u32) -> Result<()> {
+        crate::client_sync::check_options(
+            "send",
+            version,
+            &[
+                ("include_unsafe", self.include_unsafe.is_some(), 22),
+                ("change_type", crate::client_sync::v17::is_bech32m(&self.change_type), 23),
+                ("solving_data", self.solving_data.is_some(), 23),
+                ("input_weights", !self.input_weights.is_empty(), 24),
+            ],
+        )
+    }
+}
+
+/// A single request, an argument to the `Client::import_descriptors` function.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ImportDescriptorsRequest {
+    /// Descriptor to import, including the checksum.
+    pub desc: String,
+    /// Whether this descriptor should be used to generate new addresses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The range of indices to import for a ranged descriptor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(u32, u32)>,
+    /// The next index to generate addresses from, for a ranged descriptor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_index: Option<u32>,
+    /// Creation time of the descriptor.
+    pub timestamp: Timestamp,
+    /// Whether the matching outputs should be treated as change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    /// Label to assign to the address, only allowed with `internal` false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[cfg(feature = "miniscript")]
+impl ImportDescriptorsRequest {
+    /// Creates a request to import `descriptor`, the checksum is added by `miniscript`.
+    ///
+    /// Public descriptors can only be imported into a wallet with private keys disabled.
+    pub fn from_descriptor(
+        descriptor: &crate::json::miniscript::Descriptor<
+            crate::json::miniscript::DescriptorPublicKey,
+        >,
+        timestamp: Timestamp,
+    ) -> Self {
+        Self { desc: descriptor.to_string(), timestamp, ..Default::default() }
+    }
+}
+
+pub use crate::client_sync::v17::{
+    AddNodeCommand, AddressType, ChangeType, EstimateMode, FundRawTransactionOptions,
+    ImportMultiRequest, ImportMultiScriptPubkey, ListUnspentQueryOptions, PreviousTx, ScanObject,
+    SendManyOptions, SetBanCommand, SighashType, SolvingData, TemplateRequest, TemplateRules,
+    Timestamp, WalletCreateFundedPsbtOptions, WalletProcessPsbtOptions,
+};
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getindexinfo`
+#[macro_export]
+macro_rules! impl_client_v21__getindexinfo {
+    () => {
+        impl Client {
+            pub fn get_index_info(&self) -> Result<GetIndexInfo> { self.call("getindexinfo", &[]) }
+
+            /// Returns an empty map if the index `name` is not running.
+            pub fn get_index_info_for(&self, name: &str) -> Result<GetIndexInfo> {
+                self.call("getindexinfo", &[into_json(name)?])
+            }
+        }
+    };
+}
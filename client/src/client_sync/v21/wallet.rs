@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `psbtbumpfee`
+#[macro_export]
+macro_rules! impl_client_v21__psbtbumpfee {
+    () => {
+        impl Client {
+            /// Creates a PSBT bumping the fee of opt-in-RBF transaction `txid` using the default
+            /// options.
+            pub fn psbt_bump_fee(&self, txid: Txid) -> Result<PsbtBumpFee> {
+                self.call("psbtbumpfee", &[into_json(txid)?])
+            }
+
+            /// Creates a PSBT bumping the fee of opt-in-RBF transaction `txid` as configured by
+            /// `options`.
+            pub fn psbt_bump_fee_with_options(
+                &self,
+                txid: Txid,
+                options: &BumpFeeOptions,
+            ) -> Result<PsbtBumpFee> {
+                self.call("psbtbumpfee", &[into_json(txid)?, into_json(options)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `send`
+#[macro_export]
+macro_rules! impl_client_v21__send {
+    () => {
+        impl Client {
+            /// Sends a transaction paying `outputs`.
+            pub fn send(&self, outputs: &[(Address<NetworkChecked>, Amount)]) -> Result<Send> {
+                self.call("send", &[$crate::client_sync::outputs_into_json(outputs)?])
+            }
+
+            /// Sends a transaction paying `outputs` as configured by `options`.
+            ///
+            /// Errors without calling the server if `options` are not supported by it.
+            pub fn send_with_options(
+                &self,
+                outputs: &[(Address<NetworkChecked>, Amount)],
+                options: &SendOptions,
+            ) -> Result<Send> {
+                use $crate::client_sync::null;
+
+                options.check(Self::VERSION)?;
+                let outputs = $crate::client_sync::outputs_into_json(outputs)?;
+                self.call("send", &[outputs, null(), null(), null(), into_json(options)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `sendmany` with verbose set to `true`
+#[macro_export]
+macro_rules! impl_client_v21__sendmany_verbose {
+    () => {
+        impl Client {
+            /// Sends `amounts` to multiple addresses in a single transaction configured by `options`,
+            /// returning extra information about the transaction.
+            pub fn send_many_verbose(
+                &self,
+                amounts: &[(Address<NetworkChecked>, Amount)],
+                options: &SendManyOptions,
+            ) -> Result<SendManyVerbose> {
+                options.check(Self::VERSION)?;
+                self.call("sendmany", &options.args(amounts, true)?)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `importdescriptors`
+#[macro_export]
+macro_rules! impl_client_v21__importdescriptors {
+    () => {
+        impl Client {
+            /// Imports descriptors into a descriptor wallet.
+            pub fn import_descriptors(
+                &self,
+                requests: &[ImportDescriptorsRequest],
+            ) -> Result<ImportDescriptors> {
+                self.call("importdescriptors", &[into_json(requests)?])
+            }
+        }
+    };
+}
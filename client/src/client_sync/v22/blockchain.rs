@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v22.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `gettxoutsetinfo`
+#[macro_export]
+macro_rules! impl_client_v22__gettxoutsetinfo {
+    () => {
+        impl Client {
+            pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[])
+            }
+
+            pub fn get_tx_out_set_info_with_hash_type(
+                &self,
+                hash_type: TxOutSetHashType,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[into_json(hash_type)?])
+            }
+
+            /// Gets the UTXO set statistics at `hash_or_height`, or at the chain tip if `None`.
+            ///
+            /// Statistics for a block other than the chain tip require `coinstatsindex`. If
+            /// `use_index` is `false` the index is not used, even if enabled, and the statistics
+            /// are calculated for the chain tip.
+            pub fn get_tx_out_set_info_at(
+                &self,
+                hash_type: TxOutSetHashType,
+                hash_or_height: Option<HashOrHeight>,
+                use_index: bool,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call(
+                    "gettxoutsetinfo",
+                    &[into_json(hash_type)?, into_json(hash_or_height)?, into_json(use_index)?],
+                )
+            }
+        }
+    };
+}
@@ -32,3 +32,16 @@ macro_rules! impl_client_v22__loadwallet {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `listdescriptors`
+#[macro_export]
+macro_rules! impl_client_v22__listdescriptors {
+    () => {
+        impl Client {
+            /// Lists the public descriptors imported into a descriptor wallet.
+            pub fn list_descriptors(&self) -> Result<ListDescriptors> {
+                self.call("listdescriptors", &[])
+            }
+        }
+    };
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v23.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getblock` with verbosity set to 3.
+///
+/// Verbosity 3 was added in v23, `impl_client_v17__getblock` implements verbosity 0, 1 and 2.
+#[macro_export]
+macro_rules! impl_client_v23__getblock_verbosity_three {
+    () => {
+        impl Client {
+            /// Gets a block including the decoded transactions, their fees and the outputs spent
+            /// by their inputs.
+            pub fn get_block_verbosity_three(
+                &self,
+                hash: BlockHash,
+            ) -> Result<GetBlockVerbosityThree> {
+                self.call("getblock", &[into_json(hash)?, 3.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getdeploymentinfo`
+#[macro_export]
+macro_rules! impl_client_v23__getdeploymentinfo {
+    () => {
+        impl Client {
+            /// Gets the state of the consensus change deployments at the chain tip.
+            pub fn get_deployment_info(&self) -> Result<GetDeploymentInfo> {
+                self.call("getdeploymentinfo", &[])
+            }
+
+            /// Gets the state of the consensus change deployments at block `hash`.
+            pub fn get_deployment_info_at(&self, hash: &BlockHash) -> Result<GetDeploymentInfo> {
+                self.call("getdeploymentinfo", &[into_json(hash)?])
+            }
+        }
+    };
+}
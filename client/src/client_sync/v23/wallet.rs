@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v23.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `listdescriptors`
+#[macro_export]
+macro_rules! impl_client_v23__listdescriptors {
+    () => {
+        $crate::impl_client_v22__listdescriptors!();
+
+        impl Client {
+            /// Lists the descriptors imported into a descriptor wallet, including private keys.
+            pub fn list_descriptors_private(&self) -> Result<ListDescriptors> {
+                self.call("listdescriptors", &[true.into()])
+            }
+        }
+    };
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v24.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `listsinceblock`
+#[macro_export]
+macro_rules! impl_client_v24__listsinceblock {
+    () => {
+        impl Client {
+            /// Lists wallet transactions in blocks since `blockhash`, or all transactions if `None`.
+            ///
+            /// If `include_removed` is set, transactions removed by a reorg are returned as well.
+            /// If `include_change` is set, entries for change outputs are also returned.
+            pub fn list_since_block(
+                &self,
+                blockhash: Option<BlockHash>,
+                include_removed: bool,
+                include_change: bool,
+            ) -> Result<ListSinceBlock> {
+                let blockhash = blockhash.map(|hash| hash.to_string()).unwrap_or_default();
+                self.call(
+                    "listsinceblock",
+                    &[
+                        blockhash.into(),
+                        1.into(),
+                        false.into(),
+                        include_removed.into(),
+                        include_change.into(),
+                    ],
+                )
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `sendall`
+#[macro_export]
+macro_rules! impl_client_v24__sendall {
+    () => {
+        impl Client {
+            /// Spends all confirmed wallet UTXOs to `recipients`, split evenly between them.
+            pub fn send_all(&self, recipients: &[Address<NetworkChecked>]) -> Result<SendAll> {
+                let recipients = recipients.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+                self.call("sendall", &[recipients.into()])
+            }
+
+            /// Spends wallet UTXOs to `recipients` as configured by `options`.
+            pub fn send_all_with_options(
+                &self,
+                recipients: &[Address<NetworkChecked>],
+                options: &SendAllOptions,
+            ) -> Result<SendAll> {
+                use $crate::client_sync::null;
+
+                let recipients = recipients.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+                self.call(
+                    "sendall",
+                    &[recipients.into(), null(), null(), null(), into_json(options)?],
+                )
+            }
+        }
+    };
+}
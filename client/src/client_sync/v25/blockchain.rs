@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v25.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `scanblocks`
+#[macro_export]
+macro_rules! impl_client_v25__scanblocks {
+    () => {
+        impl Client {
+            /// Scans the blocks from `start_height` (default genesis) to `stop_height` (default
+            /// chain tip) using the block filter index.
+            pub fn scan_blocks_start(
+                &self,
+                scan_objects: &[ScanObject],
+                start_height: Option<u32>,
+                stop_height: Option<u32>,
+            ) -> Result<ScanBlocksStart> {
+                let mut args = [
+                    into_json("start")?,
+                    into_json(scan_objects)?,
+                    $crate::client_sync::opt_into_json(start_height)?,
+                    $crate::client_sync::opt_into_json(stop_height)?,
+                ];
+                let defaults = [into_json(0)?, $crate::client_sync::null()];
+                self.call("scanblocks", handle_defaults(&mut args, &defaults))
+            }
+
+            /// Returns `None` if there is no scan in progress.
+            pub fn scan_blocks_status(&self) -> Result<Option<ScanBlocksStatus>> {
+                self.call("scanblocks", &[into_json("status")?])
+            }
+
+            pub fn scan_blocks_abort(&self) -> Result<ScanBlocksAbort> {
+                self.call("scanblocks", &[into_json("abort")?])
+            }
+        }
+    };
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v25`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getrawtransaction` with verbosity set to 2.
+///
+/// Verbosity 2 was added in v25, `impl_client_v17__getrawtransaction` implements verbosity 0 and 1.
+#[macro_export]
+macro_rules! impl_client_v25__getrawtransaction_verbosity_two {
+    () => {
+        impl Client {
+            /// Gets the transaction including the fee and the outputs spent by its inputs.
+            pub fn get_raw_transaction_verbosity_two(
+                &self,
+                txid: Txid,
+            ) -> Result<GetRawTransactionVerbosityTwo> {
+                self.call("getrawtransaction", &[into_json(txid)?, 2.into()])
+            }
+
+            /// Gets the transaction, from the block with `block_hash`, including the fee and the
+            /// outputs spent by its inputs.
+            ///
+            /// Does not require `-txindex` since the block to look in is given.
+            pub fn get_raw_transaction_verbosity_two_in_block(
+                &self,
+                txid: Txid,
+                block_hash: BlockHash,
+            ) -> Result<GetRawTransactionVerbosityTwo> {
+                self.call(
+                    "getrawtransaction",
+                    &[into_json(txid)?, 2.into(), into_json(block_hash)?],
+                )
+            }
+        }
+    };
+}
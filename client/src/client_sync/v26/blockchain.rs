@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v26.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `dumptxoutset`
+#[macro_export]
+macro_rules! impl_client_v26__dumptxoutset {
+    () => {
+        impl Client {
+            pub fn dump_tx_out_set(&self, path: &str) -> Result<DumpTxOutSet> {
+                self.call("dumptxoutset", &[path.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getchainstates`
+#[macro_export]
+macro_rules! impl_client_v26__getchainstates {
+    () => {
+        impl Client {
+            pub fn get_chain_states(&self) -> Result<GetChainStates> {
+                self.call("getchainstates", &[])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `loadtxoutset`
+#[macro_export]
+macro_rules! impl_client_v26__loadtxoutset {
+    () => {
+        impl Client {
+            pub fn load_tx_out_set(&self, path: &str) -> Result<LoadTxOutSet> {
+                self.call("loadtxoutset", &[path.into()])
+            }
+        }
+    };
+}
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A JSON-RPC client for testing against Bitcoin Core `v26`.
+//!
+//! We ignore option arguments unless they effect the shape of the returned JSON data.
+
+mod blockchain;
+mod mining;
+mod network;
+mod raw_transactions;
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::{Amount, Block, BlockHash, FeeRate, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::client_sync::{handle_defaults, into_json};
+use crate::json::v26::*;
+
+crate::define_jsonrpc_minreq_client!("v26");
+
+// == Blockchain ==
+crate::impl_client_v17__getblockchaininfo!();
+crate::impl_client_v17__getbestblockhash!();
+crate::impl_client_v17__getblock!();
+crate::impl_client_v23__getblock_verbosity_three!();
+crate::impl_client_v17__gettxout!();
+crate::impl_client_v17__getblockcount!();
+crate::impl_client_v19__getblockfilter!();
+crate::impl_client_v17__getblockhash!();
+crate::impl_client_v23__getdeploymentinfo!();
+crate::impl_client_v17__getmempoolancestors!();
+crate::impl_client_v17__getmempooldescendants!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
+crate::impl_client_v21__getrawmempool!();
+crate::impl_client_v26__dumptxoutset!();
+crate::impl_client_v26__getchainstates!();
+crate::impl_client_v22__gettxoutsetinfo!();
+crate::impl_client_v25__scanblocks!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v26__loadtxoutset!();
+crate::impl_client_v17__waitforblock!();
+crate::impl_client_v17__waitforblockheight!();
+crate::impl_client_v17__waitfornewblock!();
+crate::impl_client_wait_helpers!();
+
+// == Control ==
+crate::impl_client_v17__getmemoryinfo!();
+crate::impl_client_v18__getrpcinfo!();
+crate::impl_client_v17__logging!();
+crate::impl_client_v17__stop!();
+crate::impl_client_v17__uptime!();
+
+// == Generating ==
+crate::impl_client_v17__generatetoaddress!();
+
+// == Mining ==
+crate::impl_client_v17__getblocktemplate!();
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getnetworkhashps!();
+crate::impl_client_v26__getprioritisedtransactions!();
+crate::impl_client_v17__prioritisetransaction!();
+crate::impl_client_v17__submitblock!();
+crate::impl_client_v18__submitheader!();
+
+// == Network ==
+crate::impl_client_v17__addnode!();
+crate::impl_client_v17__clearbanned!();
+crate::impl_client_v17__disconnectnode!();
+crate::impl_client_v17__getaddednodeinfo!();
+crate::impl_client_v26__getaddrmaninfo!();
+crate::impl_client_v17__getconnectioncount!();
+crate::impl_client_v17__getnettotals!();
+crate::impl_client_v17__getnetworkinfo!();
+crate::impl_client_check_expected_server_version!({ [260000, 260100, 260200] });
+crate::impl_client_v18__getnodeaddresses!();
+crate::impl_client_v17__getpeerinfo!();
+crate::impl_client_v17__listbanned!();
+crate::impl_client_v17__ping!();
+crate::impl_client_v17__setban!();
+crate::impl_client_v17__setnetworkactive!();
+
+// == Rawtransactions ==
+crate::impl_client_v18__analyzepsbt!();
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__combinerawtransaction!();
+crate::impl_client_v17__converttopsbt!();
+crate::impl_client_v17__createpsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__decodescript!();
+crate::impl_client_v26__descriptorprocesspsbt!();
+crate::impl_client_v17__finalizepsbt!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
+crate::impl_client_v25__getrawtransaction_verbosity_two!();
+crate::impl_client_v18__joinpsbts!();
+crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v17__signrawtransactionwithkey!();
+crate::impl_client_v26__submitpackage!();
+crate::impl_client_v17__testmempoolaccept!();
+crate::impl_client_v18__utxoupdatepsbt!();
+
+// == Util ==
+crate::impl_client_v17__createmultisig!();
+crate::impl_client_v18__deriveaddresses!();
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v18__getdescriptorinfo!();
+crate::impl_client_v21__getindexinfo!();
+crate::impl_client_v17__signmessagewithprivkey!();
+crate::impl_client_v17__validateaddress!();
+crate::impl_client_v17__verifymessage!();
+
+// == Wallet ==
+crate::impl_client_v17__createwallet!();
+crate::impl_client_v22__unloadwallet!();
+crate::impl_client_v22__loadwallet!();
+crate::impl_client_v17__abandontransaction!();
+crate::impl_client_v17__bumpfee!();
+crate::impl_client_v21__psbtbumpfee!();
+crate::impl_client_v17__getbalance!();
+crate::impl_client_v19__getbalances!();
+crate::impl_client_v17__getnewaddress!();
+crate::impl_client_v17__sendmany!();
+crate::impl_client_v21__sendmany_verbose!();
+crate::impl_client_v21__send!();
+crate::impl_client_v24__sendall!();
+crate::impl_client_v17__sendtoaddress!();
+crate::impl_client_v17__signmessage!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v17__getaddressinfo!();
+crate::impl_client_v17__gettransaction!();
+crate::impl_client_v17__getwalletinfo!();
+crate::impl_client_v21__importdescriptors!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v23__listdescriptors!();
+crate::impl_client_v17__listlabels!();
+crate::impl_client_v24__listsinceblock!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v18__listwalletdir!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__setlabel!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_psbt_workflow!();
+
+/// Argument to the `Client::get_tx_out_set_info_with_hash_type` function.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TxOutSetHashType {
+    /// The legacy algorithm.
+    #[serde(rename = "hash_serialized_3")]
+    HashSerialized3,
+    /// The MuHash3072 algorithm.
+    #[serde(rename = "muhash")]
+    Muhash,
+    /// Do not calculate a UTXO set hash.
+    #[serde(rename = "none")]
+    None,
+}
+
+/// Argument to the `Client::bump_fee_with_options` and `Client::psbt_bump_fee_with_options`
+/// functions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct BumpFeeOptions {
+    /// Confirmation target (in blocks).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u32>,
+    /// Fee rate (NOT total fee) to pay, sent to Core in sat/vB.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::client_sync::fee_rate_sat_per_vb"
+    )]
+    pub fee_rate: Option<FeeRate>,
+    /// Whether the new transaction should still be marked BIP-125 replaceable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// The fee estimate mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+    /// New outputs of the replacement transaction, replacing the original outputs.
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "serialize_outputs")]
+    pub outputs: Vec<(Address<NetworkChecked>, Amount)>,
+    /// The index of the change output to recycle from the original transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_change_index: Option<u32>,
+}
+
+/// Serializes `outputs` in the `[{"address":amount},...]` form expected by Core.
+fn serialize_outputs<S: serde::Serializer>(
+    outputs: &[(Address<NetworkChecked>, Amount)],
+    s: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    s.collect_seq(outputs.iter().map(|(address, amount)| {
+        let mut output = std::collections::BTreeMap::new();
+        output.insert(address.to_string(), amount.to_btc());
+        output
+    }))
+}
+
+pub use crate::client_sync::v17::{
+    AddNodeCommand, ChangeType, EstimateMode, FundRawTransactionOptions, ImportMultiRequest,
+    ImportMultiScriptPubkey, ListUnspentQueryOptions, PreviousTx, ScanObject, SendManyOptions,
+    SetBanCommand, SighashType, SolvingData, TemplateRequest, TemplateRules, Timestamp,
+    WalletCreateFundedPsbtOptions, WalletProcessPsbtOptions,
+};
+pub use crate::client_sync::v21::{ImportDescriptorsRequest, SendOptions};
+pub use crate::client_sync::v22::HashOrHeight;
+pub use crate::client_sync::v23::AddressType;
+pub use crate::client_sync::v24::SendAllOptions;
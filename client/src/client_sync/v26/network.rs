@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v26`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getaddrmaninfo`
+#[macro_export]
+macro_rules! impl_client_v26__getaddrmaninfo {
+    () => {
+        impl Client {
+            pub fn get_addr_man_info(&self) -> Result<GetAddrManInfo> {
+                self.call("getaddrmaninfo", &[])
+            }
+        }
+    };
+}
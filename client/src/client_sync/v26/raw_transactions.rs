@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v26`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `descriptorprocesspsbt`
+#[macro_export]
+macro_rules! impl_client_v26__descriptorprocesspsbt {
+    () => {
+        impl Client {
+            /// Updates and signs `psbt` using the output `descriptors`.
+            pub fn descriptor_process_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+                descriptors: &[String],
+            ) -> Result<DescriptorProcessPsbt> {
+                self.call(
+                    "descriptorprocesspsbt",
+                    &[psbt.to_string().into(), into_json(descriptors)?],
+                )
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `submitpackage`
+#[macro_export]
+macro_rules! impl_client_v26__submitpackage {
+    () => {
+        impl Client {
+            /// Submits a package of transactions, a child with its parents in topological order.
+            pub fn submit_package(&self, txs: &[bitcoin::Transaction]) -> Result<SubmitPackage> {
+                let hexes = txs
+                    .iter()
+                    .map(bitcoin::consensus::encode::serialize_hex)
+                    .collect::<Vec<String>>();
+                self.call("submitpackage", &[into_json(hexes)?])
+            }
+        }
+    };
+}
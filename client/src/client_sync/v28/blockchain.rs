@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v28.0`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `dumptxoutset`
+#[macro_export]
+macro_rules! impl_client_v28__dumptxoutset {
+    () => {
+        impl Client {
+            /// Dumps the UTXO set at the current chain tip, v28 requires the snapshot `type`.
+            pub fn dump_tx_out_set(&self, path: &str) -> Result<DumpTxOutSet> {
+                self.call("dumptxoutset", &[path.into(), "latest".into()])
+            }
+        }
+    };
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v28.0`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `createwalletdescriptor`
+#[macro_export]
+macro_rules! impl_client_v28__createwalletdescriptor {
+    () => {
+        impl Client {
+            /// Creates the wallet's descriptors for an address type it does not yet have.
+            pub fn create_wallet_descriptor(
+                &self,
+                ty: AddressType,
+            ) -> Result<CreateWalletDescriptor> {
+                self.call("createwalletdescriptor", &[ty.to_string().into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gethdkeys`
+#[macro_export]
+macro_rules! impl_client_v28__gethdkeys {
+    () => {
+        impl Client {
+            /// Lists the BIP 32 HD keys in the wallet and the descriptors using them.
+            pub fn get_hd_keys(&self) -> Result<GetHdKeys> { self.call("gethdkeys", &[]) }
+
+            /// Lists the BIP 32 HD keys in the wallet, including private keys.
+            pub fn get_hd_keys_private(&self) -> Result<GetHdKeys> {
+                self.call("gethdkeys", &[serde_json::json!({ "private": true })])
+            }
+        }
+    };
+}
@@ -11,3 +11,5 @@ pub extern crate json;
 #[cfg(feature = "client-sync")]
 #[macro_use]
 pub mod client_sync;
+
+pub mod rpcauth;
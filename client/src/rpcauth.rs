@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Generate and verify `rpcauth` credentials.
+//!
+//! Bitcoin Core authenticates JSON-RPC users configured with `-rpcauth=<user>:<salt>$<hmac>` where
+//! `hmac` is the hex encoded HMAC-SHA256 of the password keyed with the salt. This module does the
+//! same as Core's `share/rpcauth/rpcauth.py` script.
+//!
+//! # Examples
+//!
+//! ```
+//! use bitcoind_json_rpc_client::rpcauth::Credentials;
+//!
+//! let creds = Credentials::generate("alice");
+//! let rpcauth = creds.rpcauth();
+//!
+//! assert!(rpcauth.verify("alice", creds.password.expose_secret()));
+//! println!("add this to bitcoin.conf: rpcauth={}", rpcauth);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use bitcoin::hashes::{hmac, sha256, Hash, HashEngine};
+use bitcoin::hex::DisplayHex;
+use bitcoin::secp256k1::rand::{self, RngCore};
+pub use jsonrpc::Secret;
+
+/// Number of random bytes in a generated salt, as in `rpcauth.py`.
+const SALT_LEN: usize = 16;
+
+/// Number of random bytes in a generated password.
+const PASSWORD_LEN: usize = 32;
+
+/// A user name and password pair, the client side of an `rpcauth` entry.
+///
+/// The password is a [`Secret`] so it is redacted from the `Debug` output.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Credentials {
+    /// The user name.
+    pub user: String,
+    /// The password.
+    pub password: Secret,
+}
+
+impl Credentials {
+    /// Creates credentials for `user` with the given `password`.
+    pub fn new(user: &str, password: &str) -> Self {
+        Credentials { user: user.to_owned(), password: password.into() }
+    }
+
+    /// Creates credentials for `user` with a random password.
+    pub fn generate(user: &str) -> Self {
+        Credentials { user: user.to_owned(), password: generate_password().into() }
+    }
+
+    /// Returns an `rpcauth` entry, with a random salt, that authenticates these credentials.
+    pub fn rpcauth(&self) -> RpcAuth { RpcAuth::new(&self.user, self.password.expose_secret()) }
+
+    /// Returns these credentials as a client [`Auth`](crate::client_sync::Auth).
+    #[cfg(feature = "client-sync")]
+    pub fn auth(&self) -> crate::client_sync::Auth {
+        crate::client_sync::Auth::UserPass(self.user.clone(), self.password.clone())
+    }
+}
+
+/// An `rpcauth` entry i.e., `<user>:<salt>$<hmac>`.
+///
+/// Formats as the value of the `-rpcauth` option and parses back from it.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct RpcAuth {
+    user: String,
+    salt: String,
+    hmac: String,
+}
+
+impl RpcAuth {
+    /// Creates an `rpcauth` entry for `user` and `password` using a random salt.
+    pub fn new(user: &str, password: &str) -> Self {
+        let mut salt = [0_u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        Self::with_salt(user, &salt.to_lower_hex_string(), password)
+    }
+
+    /// Creates an `rpcauth` entry for `user` and `password` using the given `salt`.
+    pub fn with_salt(user: &str, salt: &str, password: &str) -> Self {
+        RpcAuth {
+            user: user.to_owned(),
+            salt: salt.to_owned(),
+            hmac: password_hmac(salt, password),
+        }
+    }
+
+    /// Returns the user name.
+    pub fn user(&self) -> &str { &self.user }
+
+    /// Returns the salt.
+    pub fn salt(&self) -> &str { &self.salt }
+
+    /// Returns the hex encoded HMAC-SHA256 of the password.
+    pub fn hmac(&self) -> &str { &self.hmac }
+
+    /// Returns `true` if `user` and `password` are accepted by this entry.
+    ///
+    /// Like Core, compares the HMAC in constant time.
+    pub fn verify(&self, user: &str, password: &str) -> bool {
+        let hmac = password_hmac(&self.salt, password);
+        user == self.user && timing_resistant_eq(hmac.as_bytes(), self.hmac.as_bytes())
+    }
+
+    /// Returns the command line argument for `bitcoind` e.g., `-rpcauth=alice:abcd$1234`.
+    pub fn to_arg(&self) -> String { format!("-rpcauth={}", self) }
+}
+
+impl fmt::Display for RpcAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}${}", self.user, self.salt, self.hmac)
+    }
+}
+
+impl FromStr for RpcAuth {
+    type Err = ParseRpcAuthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("-rpcauth=").or_else(|| s.strip_prefix("rpcauth=")).unwrap_or(s);
+        // Core splits on the first ':' and then on '$', user names can not contain ':'.
+        let (user, rest) = s.split_once(':').ok_or(ParseRpcAuthError::MissingSalt)?;
+        let (salt, hmac) = rest.split_once('$').ok_or(ParseRpcAuthError::MissingHmac)?;
+        if hmac.len() != sha256::Hash::LEN * 2 || !hmac.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseRpcAuthError::InvalidHmac(hmac.to_owned()));
+        }
+        Ok(RpcAuth {
+            user: user.to_owned(),
+            salt: salt.to_owned(),
+            hmac: hmac.to_ascii_lowercase(),
+        })
+    }
+}
+
+/// Error when parsing an `rpcauth` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRpcAuthError {
+    /// There is no `:` separating the user name from the salt.
+    MissingSalt,
+    /// There is no `$` separating the salt from the HMAC.
+    MissingHmac,
+    /// The HMAC is not 64 hex characters.
+    InvalidHmac(String),
+}
+
+impl fmt::Display for ParseRpcAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseRpcAuthError::*;
+
+        match *self {
+            MissingSalt => write!(f, "missing ':' between user and salt"),
+            MissingHmac => write!(f, "missing '$' between salt and hmac"),
+            InvalidHmac(ref hmac) => write!(f, "invalid hmac: {}", hmac),
+        }
+    }
+}
+
+impl std::error::Error for ParseRpcAuthError {}
+
+/// Generates a random, hex encoded, password.
+pub fn generate_password() -> String {
+    let mut password = [0_u8; PASSWORD_LEN];
+    rand::thread_rng().fill_bytes(&mut password);
+    password.to_lower_hex_string()
+}
+
+/// Returns the hex encoded HMAC-SHA256 of `password` keyed with `salt`.
+fn password_hmac(salt: &str, password: &str) -> String {
+    let mut engine = hmac::HmacEngine::<sha256::Hash>::new(salt.as_bytes());
+    engine.input(password.as_bytes());
+    hmac::Hmac::<sha256::Hash>::from_engine(engine).to_string()
+}
+
+/// Compares `a` and `b` without short circuiting on the first difference.
+fn timing_resistant_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0_u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Generated with `share/rpcauth/rpcauth.py bitcoind bitcoind`.
+    const RPCAUTH: &str = "bitcoind:cccd5d7fd36e55c1b8576b8077dc1b83$60b5676a09f8518dcb4574838fb86f37700cd690d99bd2fdc2ea2bf2ab80ead6";
+
+    #[test]
+    fn matches_rpcauth_py() {
+        let rpcauth =
+            RpcAuth::with_salt("bitcoind", "cccd5d7fd36e55c1b8576b8077dc1b83", "bitcoind");
+        assert_eq!(rpcauth.to_string(), RPCAUTH);
+        assert_eq!(rpcauth, RPCAUTH.parse().unwrap());
+    }
+
+    #[test]
+    fn verify() {
+        let rpcauth = RPCAUTH.parse::<RpcAuth>().unwrap();
+        assert!(rpcauth.verify("bitcoind", "bitcoind"));
+        assert!(!rpcauth.verify("bitcoind", "wrong"));
+        assert!(!rpcauth.verify("other", "bitcoind"));
+    }
+
+    #[test]
+    fn generate() {
+        let creds = Credentials::generate("alice");
+        let a = creds.rpcauth();
+        let b = creds.rpcauth();
+        assert_ne!(a.salt(), b.salt());
+        assert!(a.verify("alice", creds.password.expose_secret()));
+        assert!(b.verify("alice", creds.password.expose_secret()));
+        assert_eq!(a.to_arg().parse::<RpcAuth>().unwrap(), a);
+    }
+
+    #[test]
+    fn debug_redacts_password() {
+        let creds = Credentials::new("alice", "hunter2");
+        assert_eq!(
+            format!("{:?}", creds),
+            "Credentials { user: \"alice\", password: Secret(<redacted>) }"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("alice".parse::<RpcAuth>(), Err(ParseRpcAuthError::MissingSalt));
+        assert_eq!("alice:salt".parse::<RpcAuth>(), Err(ParseRpcAuthError::MissingHmac));
+        assert!(matches!(
+            "alice:salt$beef".parse::<RpcAuth>(),
+            Err(ParseRpcAuthError::InvalidHmac(_))
+        ));
+    }
+}
@@ -1,8 +1,16 @@
 //! Provides a macro that implements the tests.
 
 pub mod v17;
+pub mod v18;
 pub mod v19;
+pub mod v20;
+pub mod v21;
 pub mod v22;
+pub mod v23;
+pub mod v24;
+pub mod v25;
+pub mod v26;
+pub mod v28;
 
 /// Requires `RPC_PORT` to be in scope.
 use bitcoind::BitcoinD;
@@ -22,6 +30,18 @@ pub fn bitcoind_with_default_wallet() -> BitcoinD {
     BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
 }
 
+/// Returns a handle to a `bitcoind` instance with "default" wallet loaded and opt-in RBF enabled.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn bitcoind_with_wallet_rbf() -> BitcoinD {
+    init_logger();
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+
+    let mut conf = bitcoind::Conf::default();
+    conf.args.push("-walletrbf=1");
+    BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
+}
+
 /// Returns a handle to a `bitcoind` instance without any wallets.
 #[allow(dead_code)] // Not all tests use this function.
 pub fn bitcoind_with_wallet(wallet: String) -> BitcoinD {
@@ -41,3 +61,93 @@ pub fn bitcoind_no_wallet() -> BitcoinD {
     conf.wallet = None;
     BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
 }
+
+/// Returns a handle to a `bitcoind` instance with a "default" descriptor wallet loaded.
+///
+/// Descriptor wallets are only the default from v23, this works from v21 onwards.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn bitcoind_with_descriptor_wallet() -> BitcoinD {
+    let bitcoind = bitcoind_no_wallet();
+    // An empty passphrase leaves the wallet unencrypted, the last argument is `descriptors`.
+    let _ = bitcoind
+        .client
+        .call::<bitcoind::json::CreateWallet>(
+            "createwallet",
+            &["default".into(), false.into(), false.into(), "".into(), false.into(), true.into()],
+        )
+        .expect("createwallet");
+    bitcoind
+}
+
+/// Returns handles to two connected `bitcoind` instances, each with "default" wallet loaded.
+///
+/// Some methods e.g., `getblocktemplate` require the node to have peers.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn two_node_network() -> (BitcoinD, BitcoinD) {
+    init_logger();
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+
+    let mut conf = bitcoind::Conf::default();
+    conf.p2p = bitcoind::P2P::Yes;
+    let node1 = BitcoinD::with_conf(&exe, &conf).expect("failed to create BitcoinD");
+
+    let mut conf = bitcoind::Conf::default();
+    conf.p2p = node1.p2p_connect(true).expect("node1 has p2p enabled");
+    let node2 = BitcoinD::with_conf(&exe, &conf).expect("failed to create BitcoinD");
+
+    // Wait for the nodes to connect to each other.
+    for _ in 0..100 {
+        let info = node1.client.get_network_info().expect("getnetworkinfo");
+        if info.connections > 0 {
+            return (node1, node2);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    panic!("nodes failed to connect");
+}
+
+/// Creates a parent and child transaction in the mempool of `bitcoind`.
+///
+/// Mines 101 blocks so only a single coinbase output is spendable, this forces the second send to
+/// spend the unconfirmed change of the first one. Returns the `(parent, child)` txids.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn create_mempool_parent_child(bitcoind: &BitcoinD) -> (bitcoin::Txid, bitcoin::Txid) {
+    use bitcoin::Amount;
+
+    let address = bitcoind.client.new_address().expect("failed to create new address");
+    let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+    let parent = bitcoind
+        .client
+        .send_to_address(&address, Amount::from_sat(10_000))
+        .expect("sendtoaddress")
+        .txid()
+        .unwrap();
+    let child = bitcoind
+        .client
+        .send_to_address(&address, Amount::from_sat(10_000))
+        .expect("sendtoaddress")
+        .txid()
+        .unwrap();
+
+    (parent, child)
+}
+
+/// Creates a transaction signalling BIP-125 replaceability in the mempool of `bitcoind`.
+///
+/// Expects `bitcoind` to have been created with `bitcoind_with_wallet_rbf`. Returns the txid.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn create_replaceable_transaction(bitcoind: &BitcoinD) -> bitcoin::Txid {
+    use bitcoin::Amount;
+
+    let address = bitcoind.client.new_address().expect("failed to create new address");
+    let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+    bitcoind
+        .client
+        .send_to_address(&address, Amount::from_int_btc(1))
+        .expect("sendtoaddress")
+        .txid()
+        .unwrap()
+}
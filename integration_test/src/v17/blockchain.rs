@@ -53,17 +53,33 @@ macro_rules! impl_test_v17__getblock_verbosity_1 {
     };
 }
 
-/// Requires `Client` to be in scope and to implement `get_block 2`.
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_block_verbosity_two`
 #[macro_export]
 macro_rules! impl_test_v17__getblock_verbosity_2 {
     () => {
         #[test]
         fn get_block_verbosity_2() {
-            let bitcoind = $crate::bitcoind_no_wallet();
-            let block_hash = best_block_hash();
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, bitcoin::Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let json = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let block_hash = json.into_model().unwrap().0[0];
 
-            let json = client.get_block_verbosity_two(block_hash).expect("getblock 2");
-            json.into_model().unwrap();
+            let json = bitcoind.client.get_block_verbosity_two(block_hash).expect("getblock 2");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.tx.len(), 2);
+            assert!(model.tx[0].transaction.is_coinbase());
+            assert!(model.tx[0].fee.is_none());
+            assert_eq!(model.tx[1].transaction.compute_txid(), txid);
         }
     };
 }
@@ -189,16 +205,144 @@ macro_rules! impl_test_v17__getdifficulty {
     }
 }
 
-/// Requires `Client` to be in scope and to implement `getmempoolancestors`.
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_mempool_ancestors`
+/// - `get_mempool_ancestors_verbose`
 #[macro_export]
 macro_rules! impl_test_v17__getmempoolancestors {
     () => {
         #[test]
         fn get_mempool_ancestors() {
-            // FIXME: We need a valid txid to test this.
-            todo!()
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let (parent, child) = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json = bitcoind.client.get_mempool_ancestors(child).expect("getmempoolancestors");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0, vec![parent]);
         }
-    }
+
+        #[test]
+        fn get_mempool_ancestors_verbose() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let (parent, child) = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json = bitcoind
+                .client
+                .get_mempool_ancestors_verbose(child)
+                .expect("getmempoolancestors verbose");
+            let model = json.into_model().unwrap();
+            let entry = model.0.get(&parent).expect("parent is an ancestor");
+            assert_eq!(entry.descendant_count, 2);
+            assert!(entry.spent_by.contains(&child));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_mempool_descendants`
+/// - `get_mempool_descendants_verbose`
+#[macro_export]
+macro_rules! impl_test_v17__getmempooldescendants {
+    () => {
+        #[test]
+        fn get_mempool_descendants() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let (parent, child) = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json =
+                bitcoind.client.get_mempool_descendants(parent).expect("getmempooldescendants");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0, vec![child]);
+        }
+
+        #[test]
+        fn get_mempool_descendants_verbose() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let (parent, child) = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json = bitcoind
+                .client
+                .get_mempool_descendants_verbose(parent)
+                .expect("getmempooldescendants verbose");
+            let model = json.into_model().unwrap();
+            let entry = model.0.get(&child).expect("child is a descendant");
+            assert_eq!(entry.ancestor_count, 2);
+            assert_eq!(entry.depends, vec![parent]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_mempool_entry`
+#[macro_export]
+macro_rules! impl_test_v17__getmempoolentry {
+    () => {
+        #[test]
+        fn get_mempool_entry() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let (parent, child) = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json = bitcoind.client.get_mempool_entry(child).expect("getmempoolentry");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.depends, vec![parent]);
+            assert_eq!(model.0.ancestor_count, 2);
+            assert!(model.0.fees.base > bitcoin::Amount::ZERO);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_mempool_info`
+#[macro_export]
+macro_rules! impl_test_v17__getmempoolinfo {
+    () => {
+        #[test]
+        fn get_mempool_info() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let _ = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json = bitcoind.client.get_mempool_info().expect("getmempoolinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.size, 2);
+            assert!(model.bytes > 0);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_raw_mempool`
+/// - `get_raw_mempool_verbose`
+#[macro_export]
+macro_rules! impl_test_v17__getrawmempool {
+    () => {
+        #[test]
+        fn get_raw_mempool() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_raw_mempool().expect("getrawmempool");
+            assert!(json.into_model().is_ok());
+        }
+
+        #[test]
+        fn get_raw_mempool_verbose() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let (parent, child) = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json = bitcoind.client.get_raw_mempool_verbose().expect("getrawmempool verbose");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 2);
+            assert_eq!(model.0.get(&child).expect("child in mempool").depends, vec![parent]);
+        }
+    };
 }
 
 /// Requires `Client` to be in scope and to implement `get_tx_out`.
@@ -212,3 +356,188 @@ macro_rules! impl_test_v17__gettxout {
         }
     };
 }
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `get_tx_out_set_info`
+#[macro_export]
+macro_rules! impl_test_v17__gettxoutsetinfo {
+    () => {
+        #[test]
+        fn get_tx_out_set_info() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_tx_out_set_info().expect("gettxoutsetinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.height, 10);
+            assert_eq!(model.tx_outs, 10);
+            assert!(model.hash_serialized.is_some());
+            assert_eq!(model.total_amount, bitcoin::Amount::from_int_btc(500));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `scan_tx_out_set_start`
+/// - `scan_tx_out_set_status`
+/// - `scan_tx_out_set_abort`
+#[macro_export]
+macro_rules! impl_test_v17__scantxoutset {
+    () => {
+        #[test]
+        fn scan_tx_out_set() {
+            use client::client_sync::v17::ScanObject;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(2, &address).expect("generatetoaddress");
+
+            let scan_objects = [ScanObject::new(&format!("addr({})", address))];
+            let json = bitcoind.client.scan_tx_out_set_start(&scan_objects).expect("scantxoutset");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.unspents.len(), 2);
+            assert!(model.unspents.iter().all(|u| u.script_pubkey == address.script_pubkey()));
+            assert_eq!(model.total_amount, bitcoin::Amount::from_int_btc(100));
+
+            // No scan is in progress once `start` has returned.
+            let status = bitcoind.client.scan_tx_out_set_status().expect("scantxoutset status");
+            assert!(status.is_none());
+            let json = bitcoind.client.scan_tx_out_set_abort().expect("scantxoutset abort");
+            assert!(!json.into_model().0);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `waitforblock`.
+#[macro_export]
+macro_rules! impl_test_v17__waitforblock {
+    () => {
+        #[test]
+        fn wait_for_block() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let block_hash = best_block_hash();
+            let json = bitcoind.client.wait_for_block(block_hash, 1).expect("waitforblock");
+            assert!(json.into_model().is_ok());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `waitforblockheight`.
+#[macro_export]
+macro_rules! impl_test_v17__waitforblockheight {
+    () => {
+        #[test]
+        fn wait_for_block_height() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.wait_for_block_height(0, 1).expect("waitforblockheight");
+            assert!(json.into_model().is_ok());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `waitfornewblock`.
+#[macro_export]
+macro_rules! impl_test_v17__waitfornewblock {
+    () => {
+        #[test]
+        fn wait_for_new_block() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.wait_for_new_block(1).expect("waitfornewblock");
+            assert!(json.into_model().is_ok());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - the blocking wait helpers (see `impl_client_wait_helpers`)
+#[macro_export]
+macro_rules! impl_test_wait_helpers {
+    () => {
+        #[test]
+        fn wait_for_block_height_with_timeout() {
+            use std::time::Duration;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(2, &address).expect("generatetoaddress");
+
+            let model = bitcoind
+                .client
+                .wait_for_block_height_with_timeout(2, Duration::from_secs(10))
+                .expect("wait_for_block_height_with_timeout");
+            assert_eq!(model.height, 2);
+
+            let got = bitcoind
+                .client
+                .wait_for_block_height_with_timeout(100, Duration::from_millis(200))
+                .unwrap_err();
+            assert!(matches!(got, client::client_sync::Error::Timeout(_)));
+        }
+
+        #[test]
+        fn wait_for_new_block_with_timeout() {
+            use std::time::Duration;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let got = bitcoind
+                .client
+                .wait_for_new_block_with_timeout(Duration::from_millis(200))
+                .unwrap_err();
+            assert!(matches!(got, client::client_sync::Error::Timeout(_)));
+        }
+
+        #[test]
+        fn wait_for_block_with_timeout() {
+            use std::time::Duration;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let block_hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+            let model = bitcoind
+                .client
+                .wait_for_block_with_timeout(block_hash, Duration::from_secs(10))
+                .expect("wait_for_block_with_timeout");
+            assert_eq!(model.hash, block_hash);
+        }
+
+        #[test]
+        fn wait_for_transaction_with_timeout() {
+            use std::time::Duration;
+
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            bitcoind
+                .client
+                .wait_for_mempool_tx_with_timeout(txid, Duration::from_secs(10))
+                .expect("wait_for_mempool_tx_with_timeout");
+
+            let got = bitcoind
+                .client
+                .wait_for_confirmations_with_timeout(txid, 1, Duration::from_millis(200))
+                .unwrap_err();
+            assert!(matches!(got, client::client_sync::Error::Timeout(_)));
+
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let tx = bitcoind
+                .client
+                .wait_for_confirmations_with_timeout(txid, 1, Duration::from_secs(10))
+                .expect("wait_for_confirmations_with_timeout");
+            assert_eq!(tx.confirmations, 1);
+        }
+    };
+}
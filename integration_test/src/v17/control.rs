@@ -14,6 +14,13 @@ macro_rules! impl_test_v17__getmemoryinfo {
             let bitcoind = $crate::bitcoind_no_wallet();
             // There is no model for `getmemoryinfo`, just check we can make the call.
             let _ = bitcoind.client.get_memory_info().expect("getmemoryinfo");
+
+            // Only supported if Core is built with glibc, otherwise the server returns an error.
+            match bitcoind.client.get_memory_info_malloc_info() {
+                Ok(json) => assert!(json.0.starts_with("<malloc")),
+                Err(client::client_sync::Error::JsonRpc(_)) => {}
+                Err(e) => panic!("getmemoryinfo mallocinfo: {}", e),
+            }
         }
     };
 }
@@ -24,9 +31,18 @@ macro_rules! impl_test_v17__logging {
     () => {
         #[test]
         fn logging() {
+            use client::json::model::LogCategory;
+
             let bitcoind = $crate::bitcoind_no_wallet();
-            // There is no model for `logging`, just check we can make the call.
-            let _ = bitcoind.client.logging().expect("logging");
+            let json = bitcoind.client.logging().expect("logging");
+            let model = json.into_model();
+            assert!(model.0.contains_key(&LogCategory::Net));
+
+            let json = bitcoind.client.set_logging(&[LogCategory::Net], &[]).expect("logging");
+            assert!(json.into_model().is_enabled(LogCategory::Net));
+
+            let json = bitcoind.client.set_logging(&[], &[LogCategory::Net]).expect("logging");
+            assert!(!json.into_model().is_enabled(LogCategory::Net));
         }
     };
 }
@@ -51,8 +67,8 @@ macro_rules! impl_test_v17__uptime {
         #[test]
         fn uptime() {
             let bitcoind = $crate::bitcoind_no_wallet();
-            // There is no json object for `stop`, we just return a int.
-            let _ = bitcoind.client.uptime().expect("uptime");
+            let json = bitcoind.client.uptime().expect("uptime");
+            let _ = json.into_model();
         }
     };
 }
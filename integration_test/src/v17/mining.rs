@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_block_template`
+#[macro_export]
+macro_rules! impl_test_v17__getblocktemplate {
+    () => {
+        #[test]
+        fn get_block_template() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::{TemplateRequest, TemplateRules};
+
+            let (bitcoind, _peer) = $crate::two_node_network();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let request = TemplateRequest { rules: vec![TemplateRules::Segwit] };
+            let json = bitcoind.client.get_block_template(&request).expect("getblocktemplate");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.height, 102);
+            assert!(model.transactions.iter().any(|tx| tx.txid == txid));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_mining_info`.
+#[macro_export]
+macro_rules! impl_test_v17__getmininginfo {
+    () => {
+        #[test]
+        fn get_mining_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_mining_info().expect("getmininginfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.chain, bitcoin::Network::Regtest);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_network_hash_ps`.
+#[macro_export]
+macro_rules! impl_test_v17__getnetworkhashps {
+    () => {
+        #[test]
+        fn get_network_hash_ps() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_network_hash_ps().expect("getnetworkhashps");
+            let _ = json.into_model();
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `prioritise_transaction`
+#[macro_export]
+macro_rules! impl_test_v17__prioritisetransaction {
+    () => {
+        #[test]
+        fn prioritise_transaction() {
+            use bitcoin::{Amount, SignedAmount};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let fee_delta = SignedAmount::from_sat(10_000);
+            let json = bitcoind
+                .client
+                .prioritise_transaction(&txid, fee_delta)
+                .expect("prioritisetransaction");
+            assert!(json.into_model().0);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `best_block_hash`
+/// - `get_block`
+/// - `submit_block`
+#[macro_export]
+macro_rules! impl_test_v17__submitblock {
+    () => {
+        #[test]
+        fn submit_block() {
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+            let block = bitcoind.client.get_block(hash).expect("getblock");
+
+            // Re-submitting a block we already have is a BIP22 "duplicate".
+            let json = bitcoind.client.submit_block(&block).expect("submitblock");
+            assert_eq!(json.into_model(), model::SubmitBlock::Duplicate);
+        }
+    };
+}
@@ -5,6 +5,62 @@
 //! Specifically this is methods found under the `== Network ==` section of the
 //! API docs of `bitcoind v0.17.1`.
 
+/// Requires `Client` to be in scope and to implement `add_node` and `get_added_node_info`.
+#[macro_export]
+macro_rules! impl_test_v17__addnode {
+    () => {
+        #[test]
+        fn add_node() {
+            use client::client_sync::v17::AddNodeCommand;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let node = "127.0.0.1:18444";
+
+            let _: () = bitcoind.client.add_node(node, AddNodeCommand::Add).expect("addnode");
+            let json = bitcoind.client.get_added_node_info().expect("getaddednodeinfo");
+            assert!(json.0.iter().any(|n| n.added_node == node));
+
+            let _: () = bitcoind.client.add_node(node, AddNodeCommand::Remove).expect("addnode");
+            let json = bitcoind.client.get_added_node_info().expect("getaddednodeinfo");
+            assert!(json.0.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `set_ban`, `clear_banned` and
+/// `list_banned`.
+#[macro_export]
+macro_rules! impl_test_v17__clearbanned {
+    () => {
+        #[test]
+        fn clear_banned() {
+            use client::client_sync::v17::SetBanCommand;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+
+            let _: () = bitcoind.client.set_ban("192.0.2.1", SetBanCommand::Add).expect("setban");
+            let _: () = bitcoind.client.clear_banned().expect("clearbanned");
+            let json = bitcoind.client.list_banned().expect("listbanned");
+            assert!(json.0.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `disconnect_node` and `get_peer_info`.
+#[macro_export]
+macro_rules! impl_test_v17__disconnectnode {
+    () => {
+        #[test]
+        fn disconnect_node() {
+            let (node1, _node2) = $crate::two_node_network();
+
+            let peers = node1.client.get_peer_info().expect("getpeerinfo");
+            let address = &peers.0.first().expect("connected peer").address;
+            let _: () = node1.client.disconnect_node(address).expect("disconnectnode");
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement `get_network_info`.
 #[macro_export]
 macro_rules! impl_test_v17__getaddednodeinfo {
@@ -17,6 +73,19 @@ macro_rules! impl_test_v17__getaddednodeinfo {
     };
 }
 
+/// Requires `Client` to be in scope and to implement `get_connection_count`.
+#[macro_export]
+macro_rules! impl_test_v17__getconnectioncount {
+    () => {
+        #[test]
+        fn get_connection_count() {
+            let (node1, _node2) = $crate::two_node_network();
+            let json = node1.client.get_connection_count().expect("getconnectioncount");
+            assert_eq!(json.into_model().0, 1);
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement `get_network_info`.
 #[macro_export]
 macro_rules! impl_test_v17__getnettotals {
@@ -52,8 +121,80 @@ macro_rules! impl_test_v17__getpeerinfo {
     () => {
         #[test]
         fn get_peer_info() {
+            let (node1, _node2) = $crate::two_node_network();
+            let json = node1.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model();
+            assert_eq!(model.0.len(), 1);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `set_ban` and `list_banned`.
+#[macro_export]
+macro_rules! impl_test_v17__listbanned {
+    () => {
+        #[test]
+        fn list_banned() {
+            use client::client_sync::v17::SetBanCommand;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+
+            let _: () = bitcoind.client.set_ban("192.0.2.1", SetBanCommand::Add).expect("setban");
+            let json = bitcoind.client.list_banned().expect("listbanned");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+
+            let banned = &model.0[0];
+            assert!(banned.address.starts_with("192.0.2.1"));
+            assert!(banned.banned_until > banned.ban_created);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `ping`.
+#[macro_export]
+macro_rules! impl_test_v17__ping {
+    () => {
+        #[test]
+        fn ping() {
+            let (node1, _node2) = $crate::two_node_network();
+            let _: () = node1.client.ping().expect("ping");
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `set_ban` and `list_banned`.
+#[macro_export]
+macro_rules! impl_test_v17__setban {
+    () => {
+        #[test]
+        fn set_ban() {
+            use client::client_sync::v17::SetBanCommand;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let subnet = "192.0.2.0/24";
+
+            let _: () = bitcoind.client.set_ban(subnet, SetBanCommand::Add).expect("setban");
+            assert_eq!(bitcoind.client.list_banned().expect("listbanned").0.len(), 1);
+
+            let _: () = bitcoind.client.set_ban(subnet, SetBanCommand::Remove).expect("setban");
+            assert!(bitcoind.client.list_banned().expect("listbanned").0.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `set_network_active`.
+#[macro_export]
+macro_rules! impl_test_v17__setnetworkactive {
+    () => {
+        #[test]
+        fn set_network_active() {
             let bitcoind = $crate::bitcoind_no_wallet();
-            let _ = bitcoind.client.get_peer_info().expect("getpeerinfo");
+
+            let active = bitcoind.client.set_network_active(false).expect("setnetworkactive");
+            assert!(!active);
+            let active = bitcoind.client.set_network_active(true).expect("setnetworkactive");
+            assert!(active);
         }
     };
 }
@@ -5,6 +5,320 @@
 //! Specifically this is methods found under the `== Rawtransactions ==` section of the
 //! API docs of `bitcoind v0.17.1`.
 
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+/// - `combine_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__combinepsbt {
+    () => {
+        #[test]
+        fn combine_psbt() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+
+            // Combining a PSBT with itself is a no-op.
+            let psbts = [psbt.clone(), psbt];
+            let json = bitcoind.client.combine_psbt(&psbts).expect("combinepsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0, psbts[0]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_transaction`
+/// - `create_raw_transaction`
+/// - `combine_raw_transaction`
+#[macro_export]
+macro_rules! impl_test_v17__combinerawtransaction {
+    () => {
+        #[test]
+        fn combine_raw_transaction() {
+            use bitcoin::{Amount, OutPoint};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Core needs to know the coins being spent so create a transaction spending a
+            // wallet output.
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(100_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let tx = bitcoind
+                .client
+                .get_transaction(txid)
+                .expect("gettransaction")
+                .into_model()
+                .unwrap()
+                .tx;
+            let vout = tx
+                .output
+                .iter()
+                .position(|out| out.script_pubkey == address.script_pubkey())
+                .expect("output paying to address") as u32;
+
+            let outputs = [(address, Amount::from_sat(50_000))];
+            let unsigned = bitcoind
+                .client
+                .create_raw_transaction(&[OutPoint { txid, vout }], &outputs)
+                .expect("createrawtransaction")
+                .transaction()
+                .unwrap();
+
+            let txs = [unsigned];
+            let json =
+                bitcoind.client.combine_raw_transaction(&txs).expect("combinerawtransaction");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0, txs[0]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_raw_transaction`
+/// - `convert_to_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__converttopsbt {
+    () => {
+        #[test]
+        fn convert_to_psbt() {
+            use bitcoin::hashes::Hash;
+            use bitcoin::{Amount, OutPoint, Txid};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            let outpoint = OutPoint { txid: Txid::all_zeros(), vout: 1 };
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let tx = bitcoind
+                .client
+                .create_raw_transaction(&[outpoint], &outputs)
+                .expect("createrawtransaction")
+                .transaction()
+                .unwrap();
+
+            let json = bitcoind.client.convert_to_psbt(&tx).expect("converttopsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.unsigned_tx, tx);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `create_psbt`.
+#[macro_export]
+macro_rules! impl_test_v17__createpsbt {
+    () => {
+        #[test]
+        fn create_psbt() {
+            use bitcoin::hashes::Hash;
+            use bitcoin::{Amount, OutPoint, Txid};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            // Core does not check that inputs exist when creating a PSBT.
+            let outpoint = OutPoint { txid: Txid::all_zeros(), vout: 1 };
+            let outputs = [(address.clone(), Amount::from_sat(10_000))];
+
+            let json = bitcoind.client.create_psbt(&[outpoint], &outputs).expect("createpsbt");
+            let psbt = json.psbt().unwrap();
+            assert_eq!(psbt.inputs.len(), 1);
+            assert_eq!(psbt.unsigned_tx.input[0].previous_output, outpoint);
+            assert_eq!(psbt.unsigned_tx.output[0].value, Amount::from_sat(10_000));
+            assert_eq!(psbt.unsigned_tx.output[0].script_pubkey, address.script_pubkey());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `create_raw_transaction`.
+#[macro_export]
+macro_rules! impl_test_v17__createrawtransaction {
+    () => {
+        #[test]
+        fn create_raw_transaction() {
+            use bitcoin::hashes::Hash;
+            use bitcoin::{Amount, OutPoint, Txid};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            // Core does not check that inputs exist when creating a transaction.
+            let outpoint = OutPoint { txid: Txid::all_zeros(), vout: 1 };
+            let outputs = [(address.clone(), Amount::from_sat(10_000))];
+
+            let json = bitcoind
+                .client
+                .create_raw_transaction(&[outpoint], &outputs)
+                .expect("createrawtransaction");
+            let model = json.into_model().unwrap();
+            let tx = model.0;
+            assert_eq!(tx.input.len(), 1);
+            assert_eq!(tx.input[0].previous_output, outpoint);
+            assert_eq!(tx.output.len(), 1);
+            assert_eq!(tx.output[0].value, Amount::from_sat(10_000));
+            assert_eq!(tx.output[0].script_pubkey, address.script_pubkey());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+/// - `wallet_process_psbt`
+/// - `decode_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__decodepsbt {
+    () => {
+        #[test]
+        fn decode_psbt() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+
+            // The decoded fields must rebuild exactly the PSBT we sent.
+            let json = bitcoind.client.decode_psbt(&psbt).expect("decodepsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.psbt, psbt);
+            assert!(model.fee.is_some());
+
+            // Also cross-check a signed and finalized PSBT.
+            let signed = bitcoind
+                .client
+                .wallet_process_psbt(&psbt, true)
+                .expect("walletprocesspsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+            let json = bitcoind.client.decode_psbt(&signed).expect("decodepsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.psbt, signed);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_transaction`
+/// - `decode_raw_transaction`
+#[macro_export]
+macro_rules! impl_test_v17__decoderawtransaction {
+    () => {
+        #[test]
+        fn decode_raw_transaction() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let tx = bitcoind
+                .client
+                .get_transaction(txid)
+                .expect("gettransaction")
+                .into_model()
+                .unwrap()
+                .tx;
+
+            let json = bitcoind.client.decode_raw_transaction(&tx).expect("decoderawtransaction");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0, tx);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `decode_script`.
+#[macro_export]
+macro_rules! impl_test_v17__decodescript {
+    () => {
+        #[test]
+        fn decode_script() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            let json =
+                bitcoind.client.decode_script(&address.script_pubkey()).expect("decodescript");
+            let model = json.into_model().unwrap();
+            assert!(model.addresses.contains(address.as_unchecked()));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_raw_transaction`
+/// - `get_raw_transaction_verbose`
+#[macro_export]
+macro_rules! impl_test_v17__getrawtransaction {
+    () => {
+        #[test]
+        fn get_raw_transaction() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Without `-txindex` only mempool transactions can be looked up by txid alone.
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json = bitcoind.client.get_raw_transaction(txid).expect("getrawtransaction");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.compute_txid(), txid);
+
+            let json = bitcoind
+                .client
+                .get_raw_transaction_verbose(txid)
+                .expect("getrawtransaction verbose");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.transaction.compute_txid(), txid);
+            assert!(model.block_hash.is_none());
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement `get_best_block_hash`.
 #[macro_export]
 macro_rules! impl_test_v17__sendrawtransaction {
@@ -17,3 +331,195 @@ macro_rules! impl_test_v17__sendrawtransaction {
         }
     };
 }
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+/// - `wallet_process_psbt`
+/// - `finalize_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__finalizepsbt {
+    () => {
+        #[test]
+        fn finalize_psbt() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+            let psbt = bitcoind
+                .client
+                .wallet_process_psbt(&psbt, true)
+                .expect("walletprocesspsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+
+            let json = bitcoind.client.finalize_psbt(&psbt).expect("finalizepsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(model.tx.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `get_transaction`
+/// - `test_mempool_accept`
+#[macro_export]
+macro_rules! impl_test_v17__testmempoolaccept {
+    () => {
+        #[test]
+        fn test_mempool_accept() {
+            use bitcoin::Amount;
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // A transaction that is already in the mempool is rejected.
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let tx = bitcoind
+                .client
+                .get_transaction(txid)
+                .expect("gettransaction")
+                .into_model()
+                .unwrap()
+                .tx;
+
+            let json = bitcoind.client.test_mempool_accept(&[tx]).expect("testmempoolaccept");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.results.len(), 1);
+            assert_eq!(model.results[0].txid, txid);
+            assert!(!model.results[0].allowed);
+            assert_eq!(model.results[0].reject_reason, Some(model::RejectReason::AlreadyInMempool));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `fund_raw_transaction`.
+#[macro_export]
+macro_rules! impl_test_v17__fundrawtransaction {
+    () => {
+        #[test]
+        fn fund_raw_transaction() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::FundRawTransactionOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let tx = bitcoind
+                .client
+                .create_raw_transaction(&[], &outputs)
+                .expect("createrawtransaction")
+                .into_model()
+                .unwrap()
+                .0;
+
+            let json = bitcoind.client.fund_raw_transaction(&tx).expect("fundrawtransaction");
+            let model = json.into_model().unwrap();
+            assert!(!model.tx.input.is_empty());
+            assert!(model.fee > Amount::ZERO);
+
+            let options = FundRawTransactionOptions {
+                change_position: Some(0),
+                lock_unspents: Some(true),
+                ..Default::default()
+            };
+            let json = bitcoind
+                .client
+                .fund_raw_transaction_with_options(&tx, &options)
+                .expect("fundrawtransaction");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.change_position, Some(0));
+            assert_eq!(model.tx.output.len(), 2);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `sign_raw_transaction_with_key`.
+#[macro_export]
+macro_rules! impl_test_v17__signrawtransactionwithkey {
+    () => {
+        #[test]
+        fn sign_raw_transaction_with_key() {
+            use bitcoin::secp256k1::{Secp256k1, SecretKey};
+            use bitcoin::{Address, Amount, CompressedPublicKey, Network, OutPoint, PrivateKey};
+            use client::client_sync::v17::PreviousTx;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Pay to a key the wallet does not know about.
+            let secp = Secp256k1::new();
+            let secret_key = SecretKey::from_slice(&[0x2a; 32]).unwrap();
+            let key = PrivateKey::new(secret_key, Network::Regtest);
+            let pubkey = CompressedPublicKey::from_private_key(&secp, &key).unwrap();
+            let key_address = Address::p2wpkh(&pubkey, Network::Regtest);
+
+            let amount = Amount::from_sat(100_000);
+            let txid = bitcoind
+                .client
+                .send_to_address(&key_address, amount)
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let funding = bitcoind
+                .client
+                .get_raw_transaction(txid)
+                .expect("getrawtransaction")
+                .transaction()
+                .unwrap();
+            let vout = funding
+                .output
+                .iter()
+                .position(|txout| txout.script_pubkey == key_address.script_pubkey())
+                .unwrap() as u32;
+
+            let outpoint = OutPoint { txid, vout };
+            let outputs = [(address, Amount::from_sat(90_000))];
+            let tx = bitcoind
+                .client
+                .create_raw_transaction(&[outpoint], &outputs)
+                .expect("createrawtransaction")
+                .transaction()
+                .unwrap();
+            let prevtx = PreviousTx {
+                txid,
+                vout,
+                script_pubkey: key_address.script_pubkey(),
+                redeem_script: None,
+                witness_script: None,
+                amount: Some(amount),
+            };
+
+            let json = bitcoind
+                .client
+                .sign_raw_transaction_with_key(&tx, &[key], &[prevtx], None)
+                .expect("signrawtransactionwithkey");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(!model.tx.input[0].witness.is_empty());
+        }
+    };
+}
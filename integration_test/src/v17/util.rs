@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+
+/// Requires `Client` to be in scope and to implement `create_multisig`.
+#[macro_export]
+macro_rules! impl_test_v17__createmultisig {
+    () => {
+        #[test]
+        fn create_multisig() {
+            use bitcoin::secp256k1::Secp256k1;
+            use bitcoin::{Network, PrivateKey};
+
+            let secp = Secp256k1::new();
+            let keys = [[1_u8; 32], [2_u8; 32]]
+                .iter()
+                .map(|sk| PrivateKey::from_slice(sk, Network::Regtest).unwrap().public_key(&secp))
+                .collect::<Vec<_>>();
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.create_multisig(2, &keys).expect("createmultisig");
+            let model = json.into_model().unwrap();
+            assert!(model.redeem_script.is_multisig());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `estimate_smart_fee`.
+#[macro_export]
+macro_rules! impl_test_v17__estimatesmartfee {
+    () => {
+        #[test]
+        fn estimate_smart_fee() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.estimate_smart_fee(6).expect("estimatesmartfee");
+            let model = json.into_model().unwrap();
+            // A fresh regtest node has no fee data to estimate from.
+            assert!(model.fee_rate.is_none());
+            assert!(!model.errors.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `sign_message_with_priv_key` and
+/// `verify_message`.
+#[macro_export]
+macro_rules! impl_test_v17__signmessagewithprivkey {
+    () => {
+        #[test]
+        fn sign_message_with_priv_key() {
+            use bitcoin::secp256k1::Secp256k1;
+            use bitcoin::{Address, Network, PrivateKey};
+
+            let secp = Secp256k1::new();
+            let privkey = PrivateKey::from_slice(&[1_u8; 32], Network::Regtest).unwrap();
+            let address = Address::p2pkh(privkey.public_key(&secp), Network::Regtest);
+            let message = "integration test message";
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind
+                .client
+                .sign_message_with_priv_key(&privkey, message)
+                .expect("signmessagewithprivkey");
+            let signature = json.into_model().0;
+
+            let json = bitcoind
+                .client
+                .verify_message(&address, &signature, message)
+                .expect("verifymessage");
+            assert!(json.into_model().0);
+
+            let json = bitcoind
+                .client
+                .verify_message(&address, &signature, "some other message")
+                .expect("verifymessage");
+            assert!(!json.into_model().0);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `validate_address`.
+#[macro_export]
+macro_rules! impl_test_v17__validateaddress {
+    () => {
+        #[test]
+        fn validate_address() {
+            use bitcoin::secp256k1::Secp256k1;
+            use bitcoin::{Address, CompressedPublicKey, Network, PrivateKey, WitnessVersion};
+
+            let secp = Secp256k1::new();
+            let privkey = PrivateKey::from_slice(&[1_u8; 32], Network::Regtest).unwrap();
+            let pubkey = CompressedPublicKey::from_private_key(&secp, &privkey).unwrap();
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+
+            let address = Address::p2pkh(pubkey, Network::Regtest);
+            let json =
+                bitcoind.client.validate_address(&address.to_string()).expect("validateaddress");
+            let model = json.into_model().unwrap();
+            assert!(model.is_valid);
+            assert_eq!(model.is_witness, Some(false));
+            assert!(model.witness_program.is_none());
+
+            let address = Address::p2wpkh(&pubkey, Network::Regtest);
+            let json =
+                bitcoind.client.validate_address(&address.to_string()).expect("validateaddress");
+            let model = json.into_model().unwrap();
+            assert!(model.is_valid);
+            assert_eq!(model.script_pubkey, Some(address.script_pubkey()));
+            let program = model.witness_program.expect("witness program");
+            assert_eq!(program.version(), WitnessVersion::V0);
+            assert!(program.is_p2wpkh());
+
+            let json = bitcoind.client.validate_address("notanaddress").expect("validateaddress");
+            let model = json.into_model().unwrap();
+            assert!(!model.is_valid);
+            assert!(model.address.is_none());
+            assert!(model.script_pubkey.is_none());
+        }
+    };
+}
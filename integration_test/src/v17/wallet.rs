@@ -143,3 +143,642 @@ macro_rules! impl_test_v17__gettransaction {
         }
     };
 }
+
+/// Requires `Client` to be in scope and to implement:
+/// - `new_address`
+/// - `get_addresses_by_label`
+#[macro_export]
+macro_rules! impl_test_v17__getaddressesbylabel {
+    () => {
+        #[test]
+        fn get_addresses_by_label() {
+            use client::json::model::AddressPurpose;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            // New addresses are given the default label "".
+            let json = bitcoind.client.get_addresses_by_label("").expect("getaddressesbylabel");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.get(address.as_unchecked()), Some(&AddressPurpose::Receive));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `new_address_with_type`
+/// - `get_address_info`
+#[macro_export]
+macro_rules! impl_test_v17__getaddressinfo {
+    () => {
+        #[test]
+        fn get_address_info() {
+            use bitcoind::AddressType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind
+                .client
+                .new_address_with_type(AddressType::P2shSegwit)
+                .expect("failed to create new address");
+
+            let json = bitcoind.client.get_address_info(&address).expect("getaddressinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(&model.address, address.as_unchecked());
+            assert_eq!(model.script_pubkey, address.script_pubkey());
+            assert!(model.is_mine);
+            assert!(model.is_script);
+            assert!(model.hd_key_path.is_some());
+
+            let embedded = model.embedded.expect("P2SH-P2WPKH has an embedded address");
+            assert_eq!(embedded.is_witness, Some(true));
+            assert!(embedded.witness_program.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_wallet_info`.
+#[macro_export]
+macro_rules! impl_test_v17__getwalletinfo {
+    () => {
+        #[test]
+        fn get_wallet_info() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert!(model.private_keys_enabled);
+            assert!(model.hd_seed_id.is_some());
+            assert_eq!(model.tx_count, 0);
+            assert!(model.scanning.is_none());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `new_address`
+/// - `list_labels`
+#[macro_export]
+macro_rules! impl_test_v17__listlabels {
+    () => {
+        #[test]
+        fn list_labels() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let _ = bitcoind.client.new_address().expect("failed to create new address");
+
+            let json = bitcoind.client.list_labels().expect("listlabels");
+            assert!(json.into_model().0.contains(&"".to_string()));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `list_since_block`
+#[macro_export]
+macro_rules! impl_test_v17__listsinceblock {
+    () => {
+        #[test]
+        fn list_since_block() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let since = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.list_since_block(Some(since), true).expect("listsinceblock");
+            let model = json.into_model().unwrap();
+            assert!(model.transactions.iter().any(|tx| tx.txid == txid));
+            assert!(model.removed.is_empty());
+            assert_eq!(model.last_block, bitcoind.client.best_block_hash().unwrap());
+
+            let json = bitcoind.client.list_since_block(None, false).expect("listsinceblock");
+            assert!(json.into_model().unwrap().transactions.len() > model.transactions.len());
+        }
+
+        #[test]
+        fn list_since_block_removed() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let since = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            // Reorg out the block that mined the transaction.
+            let _ = bitcoind
+                .client
+                .call::<()>("invalidateblock", &[since.to_string().into()])
+                .expect("invalidateblock");
+
+            let json = bitcoind.client.list_since_block(Some(since), true).expect("listsinceblock");
+            let model = json.into_model().unwrap();
+            assert!(!model.removed.is_empty());
+            assert!(model.removed.iter().any(|tx| tx.txid == txid));
+
+            let json =
+                bitcoind.client.list_since_block(Some(since), false).expect("listsinceblock");
+            assert!(json.into_model().unwrap().removed.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `list_transactions`
+#[macro_export]
+macro_rules! impl_test_v17__listtransactions {
+    () => {
+        #[test]
+        fn list_transactions() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json =
+                bitcoind.client.list_transactions(None, None, None).expect("listtransactions");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 10);
+            assert!(model.0.iter().any(|tx| tx.txid == txid && tx.confirmations == 0));
+
+            let json = bitcoind
+                .client
+                .list_transactions(None, Some(3), Some(1))
+                .expect("listtransactions");
+            assert_eq!(json.into_model().unwrap().0.len(), 3);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `list_unspent`
+#[macro_export]
+macro_rules! impl_test_v17__listunspent {
+    () => {
+        #[test]
+        fn list_unspent() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::ListUnspentQueryOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            // Two mature coinbase outputs.
+            let _ = bitcoind.client.generate_to_address(102, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.list_unspent().expect("listunspent");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 2);
+            let utxo = &model.0[0];
+            assert_eq!(utxo.address, Some(address.as_unchecked().clone()));
+            assert_eq!(utxo.script_pubkey, address.script_pubkey());
+            assert!(utxo.spendable);
+
+            let options = ListUnspentQueryOptions { maximum_count: Some(1), ..Default::default() };
+            let json =
+                bitcoind.client.list_unspent_with_query_options(&options).expect("listunspent");
+            assert_eq!(json.into_model().unwrap().0.len(), 1);
+
+            let options = ListUnspentQueryOptions {
+                minimum_amount: Some(Amount::from_int_btc(100)),
+                ..Default::default()
+            };
+            let json =
+                bitcoind.client.list_unspent_with_query_options(&options).expect("listunspent");
+            assert!(json.into_model().unwrap().0.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `list_wallets`.
+#[macro_export]
+macro_rules! impl_test_v17__listwallets {
+    () => {
+        #[test]
+        fn list_wallets() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let json = bitcoind.client.list_wallets().expect("listwallets");
+            assert!(!json.into_model().0.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `new_address`
+/// - `set_label`
+/// - `get_addresses_by_label`
+/// - `list_labels`
+#[macro_export]
+macro_rules! impl_test_v17__setlabel {
+    () => {
+        #[test]
+        fn set_label() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            bitcoind.client.set_label(&address, "payments").expect("setlabel");
+
+            let json =
+                bitcoind.client.get_addresses_by_label("payments").expect("getaddressesbylabel");
+            let model = json.into_model().unwrap();
+            assert!(model.0.contains_key(address.as_unchecked()));
+
+            let labels = bitcoind.client.list_labels().expect("listlabels").into_model();
+            assert!(labels.0.contains(&"payments".to_string()));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__walletcreatefundedpsbt {
+    () => {
+        #[test]
+        fn wallet_create_funded_psbt() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let json = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > Amount::ZERO);
+            assert!(model.change_position.is_some());
+        }
+
+        #[test]
+        fn wallet_create_funded_psbt_with_options() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v17::WalletCreateFundedPsbtOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let options = WalletCreateFundedPsbtOptions {
+                change_position: Some(1),
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(10)),
+                subtract_fee_from_outputs: vec![0],
+                ..Default::default()
+            };
+            let outputs = [(address, Amount::from_sat(100_000))];
+            let json = bitcoind
+                .client
+                .wallet_create_funded_psbt_with_options(&outputs, &options)
+                .expect("walletcreatefundedpsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.change_position, Some(1));
+            let tx = model.psbt.unsigned_tx;
+            assert_eq!(tx.output[0].value, Amount::from_sat(100_000) - model.fee);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+/// - `wallet_process_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__walletprocesspsbt {
+    () => {
+        #[test]
+        fn wallet_process_psbt() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::{SighashType, WalletProcessPsbtOptions};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+
+            let json =
+                bitcoind.client.wallet_process_psbt(&psbt, false).expect("walletprocesspsbt");
+            assert!(!json.into_model().unwrap().complete);
+
+            let json = bitcoind.client.wallet_process_psbt(&psbt, true).expect("walletprocesspsbt");
+            assert!(json.into_model().unwrap().complete);
+
+            let options = WalletProcessPsbtOptions {
+                sighash_type: Some(SighashType::All),
+                bip32derivs: Some(true),
+                ..Default::default()
+            };
+            let json = bitcoind
+                .client
+                .wallet_process_psbt_with_options(&psbt, &options)
+                .expect("walletprocesspsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(!model.psbt.inputs[0].bip32_derivation.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `psbt_workflow`.
+#[macro_export]
+macro_rules! impl_test_psbt_workflow {
+    () => {
+        #[test]
+        fn psbt_workflow_send() {
+            use bitcoin::Amount;
+            use client::client_sync::SignerError;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let mut signed = 0;
+            let txid = bitcoind
+                .client
+                .psbt_workflow()
+                .signer(|_: &mut bitcoin::Psbt| {
+                    // The wallet already signed all inputs, an external signer has nothing to do.
+                    signed += 1;
+                    Ok::<_, SignerError>(())
+                })
+                .send(&[(address, Amount::from_sat(10_000))])
+                .expect("psbt workflow");
+
+            bitcoind.client.get_transaction(txid).expect("gettransaction");
+            assert_eq!(signed, 1);
+        }
+
+        #[test]
+        fn psbt_workflow_incomplete() {
+            use bitcoin::Amount;
+            use client::client_sync::{Error, PsbtWorkflowError};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Nobody signs so the PSBT can not be finalized.
+            let got = bitcoind
+                .client
+                .psbt_workflow()
+                .wallet_sign(false)
+                .send(&[(address, Amount::from_sat(10_000))])
+                .unwrap_err();
+            assert!(matches!(got, Error::PsbtWorkflow(PsbtWorkflowError::Incomplete(_))));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `abandon_transaction`
+/// - `bump_fee`
+#[macro_export]
+macro_rules! impl_test_v17__abandontransaction {
+    () => {
+        #[test]
+        fn abandon_transaction() {
+            let bitcoind = $crate::bitcoind_with_wallet_rbf();
+            let txid = $crate::create_replaceable_transaction(&bitcoind);
+
+            // Replacing the transaction evicts it from the mempool so it can be abandoned.
+            let _ = bitcoind.client.bump_fee(txid).expect("bumpfee");
+            let _ = bitcoind.client.abandon_transaction(txid).expect("abandontransaction");
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `bump_fee`.
+#[macro_export]
+macro_rules! impl_test_v17__bumpfee {
+    () => {
+        #[test]
+        fn bump_fee() {
+            use client::client_sync::v17::{BumpFeeOptions, EstimateMode};
+
+            let bitcoind = $crate::bitcoind_with_wallet_rbf();
+            let txid = $crate::create_replaceable_transaction(&bitcoind);
+
+            let json = bitcoind.client.bump_fee(txid).expect("bumpfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > model.original_fee);
+            assert!(model.errors.is_empty());
+
+            let options = BumpFeeOptions {
+                conf_target: Some(2),
+                replaceable: Some(true),
+                estimate_mode: Some(EstimateMode::Conservative),
+                ..Default::default()
+            };
+            let json =
+                bitcoind.client.bump_fee_with_options(model.txid, &options).expect("bumpfee");
+            let bumped = json.into_model().unwrap();
+            assert_eq!(bumped.original_fee, model.fee);
+            assert!(bumped.fee > model.fee);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `send_many`.
+#[macro_export]
+macro_rules! impl_test_v17__sendmany {
+    () => {
+        #[test]
+        fn send_many() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::SendManyOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let other = bitcoind.client.new_address().expect("failed to create new address");
+
+            let amounts =
+                [(address.clone(), Amount::from_sat(10_000)), (other, Amount::from_sat(20_000))];
+            let json = bitcoind.client.send_many(&amounts).expect("sendmany");
+            let txid = json.into_model().unwrap().0;
+
+            let options =
+                SendManyOptions { subtract_fee_from: vec![address], ..Default::default() };
+            let json =
+                bitcoind.client.send_many_with_options(&amounts, &options).expect("sendmany");
+            assert_ne!(json.into_model().unwrap().0, txid);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `sign_message` and `verify_message`.
+#[macro_export]
+macro_rules! impl_test_v17__signmessage {
+    () => {
+        #[test]
+        fn sign_message() {
+            use bitcoind::AddressType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            // Only legacy addresses can be used to sign messages.
+            let address = bitcoind
+                .client
+                .new_address_with_type(AddressType::Legacy)
+                .expect("failed to create new address");
+            let message = "foo";
+
+            let json = bitcoind.client.sign_message(&address, message).expect("signmessage");
+            let signature = json.into_model().0;
+
+            let json = bitcoind
+                .client
+                .verify_message(&address, &signature, message)
+                .expect("verifymessage");
+            assert!(json.into_model().0);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `sign_raw_transaction_with_wallet`.
+#[macro_export]
+macro_rules! impl_test_v17__signrawtransactionwithwallet {
+    () => {
+        #[test]
+        fn sign_raw_transaction_with_wallet() {
+            use bitcoin::hashes::Hash;
+            use bitcoin::{Amount, EcdsaSighashType, OutPoint, Txid};
+            use client::client_sync::v17::SighashType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address.clone(), Amount::from_sat(10_000))];
+            let tx = bitcoind
+                .client
+                .create_raw_transaction(&[], &outputs)
+                .expect("createrawtransaction")
+                .transaction()
+                .unwrap();
+            let tx = bitcoind
+                .client
+                .fund_raw_transaction(&tx)
+                .expect("fundrawtransaction")
+                .transaction()
+                .unwrap();
+
+            let json = bitcoind
+                .client
+                .sign_raw_transaction_with_wallet(&tx, &[], None)
+                .expect("signrawtransactionwithwallet");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(model.errors.is_empty());
+
+            let sighash_type = Some(SighashType::from(EcdsaSighashType::AllPlusAnyoneCanPay));
+            let json = bitcoind
+                .client
+                .sign_raw_transaction_with_wallet(&tx, &[], sighash_type)
+                .expect("signrawtransactionwithwallet");
+            assert!(json.into_model().unwrap().complete);
+
+            // Core does not check that inputs exist when creating a transaction.
+            let outpoint = OutPoint { txid: Txid::all_zeros(), vout: 1 };
+            let tx = bitcoind
+                .client
+                .create_raw_transaction(&[outpoint], &outputs)
+                .expect("createrawtransaction")
+                .transaction()
+                .unwrap();
+            let json = bitcoind
+                .client
+                .sign_raw_transaction_with_wallet(&tx, &[], None)
+                .expect("signrawtransactionwithwallet");
+            let model = json.into_model().unwrap();
+            assert!(!model.complete);
+            assert_eq!(model.errors.len(), 1);
+            assert_eq!(model.errors[0].txid, outpoint.txid);
+            assert_eq!(model.errors[0].vout, outpoint.vout);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `import_multi`.
+#[macro_export]
+macro_rules! impl_test_v17__importmulti {
+    () => {
+        #[test]
+        fn import_multi() {
+            use bitcoin::secp256k1::{Secp256k1, SecretKey};
+            use bitcoin::{Address, CompressedPublicKey, Network, PrivateKey};
+            use client::client_sync::v17::{
+                ImportMultiRequest, ImportMultiScriptPubkey, Timestamp,
+            };
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+
+            let secp = Secp256k1::new();
+            let secret_key = SecretKey::from_slice(&[0x2a; 32]).unwrap();
+            let key = PrivateKey::new(secret_key, Network::Regtest);
+            let pubkey = CompressedPublicKey::from_private_key(&secp, &key).unwrap();
+            let address = Address::p2wpkh(&pubkey, Network::Regtest);
+
+            let watch_only = ImportMultiRequest {
+                script_pubkey: Some(ImportMultiScriptPubkey::Address { address: address.clone() }),
+                timestamp: Timestamp::Now,
+                watchonly: Some(true),
+                ..Default::default()
+            };
+            // Core rejects labels on internal addresses.
+            let invalid = ImportMultiRequest {
+                script_pubkey: Some(ImportMultiScriptPubkey::Script(address.script_pubkey())),
+                timestamp: Timestamp::Time(0),
+                internal: Some(true),
+                label: Some("foo".to_string()),
+                ..Default::default()
+            };
+
+            let json = bitcoind.client.import_multi(&[watch_only, invalid]).expect("importmulti");
+            let model = json.into_model();
+            assert_eq!(model.0.len(), 2);
+            assert!(model.0[0].success);
+            assert!(model.0[0].error.is_none());
+            assert!(!model.0[1].success);
+            assert!(model.0[1].error.is_some());
+
+            let json = bitcoind.client.get_address_info(&address).expect("getaddressinfo");
+            assert!(json.is_watch_only);
+        }
+    };
+}
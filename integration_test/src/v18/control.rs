@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Control ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement `get_rpc_info`.
+#[macro_export]
+macro_rules! impl_test_v18__getrpcinfo {
+    () => {
+        #[test]
+        fn get_rpc_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_rpc_info().expect("getrpcinfo");
+            let model = json.into_model();
+            // The `getrpcinfo` call itself is always active.
+            assert!(model.active_commands.iter().any(|c| c.method == "getrpcinfo"));
+        }
+    };
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `best_block_hash`
+/// - `get_block`
+/// - `submit_header`
+#[macro_export]
+macro_rules! impl_test_v18__submitheader {
+    () => {
+        #[test]
+        fn submit_header() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+            let block = bitcoind.client.get_block(hash).expect("getblock");
+
+            // Submitting a header we already have is a no-op.
+            bitcoind.client.submit_header(&block.header).expect("submitheader");
+        }
+    };
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v0.18.1`.
+
+pub mod control;
+pub mod mining;
+pub mod network;
+pub mod raw_transactions;
+pub mod util;
+pub mod wallet;
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement `get_node_addresses`.
+#[macro_export]
+macro_rules! impl_test_v18__getnodeaddresses {
+    () => {
+        #[test]
+        fn get_node_addresses() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_node_addresses(10).expect("getnodeaddresses");
+            // A regtest node does not learn of any addresses from the network.
+            assert!(json.into_model().0.is_empty());
+        }
+    };
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+/// - `analyze_psbt`
+#[macro_export]
+macro_rules! impl_test_v18__analyzepsbt {
+    () => {
+        #[test]
+        fn analyze_psbt() {
+            use bitcoin::Amount;
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+
+            let json = bitcoind.client.analyze_psbt(&psbt).expect("analyzepsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.inputs.len(), psbt.inputs.len());
+            assert_eq!(model.next, model::PsbtRole::Signer);
+            assert!(model.fee.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_psbt`
+/// - `join_psbts`
+#[macro_export]
+macro_rules! impl_test_v18__joinpsbts {
+    () => {
+        #[test]
+        fn join_psbts() {
+            use bitcoin::hashes::Hash;
+            use bitcoin::{Amount, OutPoint, Txid};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbts = [0, 1]
+                .iter()
+                .map(|&vout| {
+                    let outpoint = OutPoint { txid: Txid::all_zeros(), vout };
+                    bitcoind
+                        .client
+                        .create_psbt(&[outpoint], &outputs)
+                        .expect("createpsbt")
+                        .psbt()
+                        .unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            let json = bitcoind.client.join_psbts(&psbts).expect("joinpsbts");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.inputs.len(), 2);
+            assert_eq!(model.0.outputs.len(), 2);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_transaction`
+/// - `create_psbt`
+/// - `utxo_update_psbt`
+#[macro_export]
+macro_rules! impl_test_v18__utxoupdatepsbt {
+    () => {
+        #[test]
+        fn utxo_update_psbt() {
+            use bitcoin::{Amount, OutPoint};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // The UTXO is looked up in the UTXO set so it must be confirmed.
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(100_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let tx = bitcoind
+                .client
+                .get_transaction(txid)
+                .expect("gettransaction")
+                .into_model()
+                .unwrap()
+                .tx;
+            let vout = tx
+                .output
+                .iter()
+                .position(|out| out.script_pubkey == address.script_pubkey())
+                .expect("output paying to address") as u32;
+
+            let outputs = [(address, Amount::from_sat(50_000))];
+            let psbt = bitcoind
+                .client
+                .create_psbt(&[OutPoint { txid, vout }], &outputs)
+                .expect("createpsbt")
+                .psbt()
+                .unwrap();
+            assert!(psbt.inputs[0].witness_utxo.is_none());
+
+            let json = bitcoind.client.utxo_update_psbt(&psbt).expect("utxoupdatepsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.0.inputs[0].witness_utxo.is_some());
+        }
+    };
+}
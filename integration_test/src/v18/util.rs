@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement `get_descriptor_info` and
+/// `derive_addresses`.
+#[macro_export]
+macro_rules! impl_test_v18__deriveaddresses {
+    () => {
+        #[test]
+        fn derive_addresses() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+
+            // A ranged descriptor from the Core `deriveaddresses` help text.
+            let descriptor = "wpkh([d34db33f/84h/0h/0h]tpubD6NzVbkrYhZ4WaWSyoBvQwbpLkojyoTZPRsgXELWz3Popb3qkjcJyJUGLnL4qHHoQvao8ESaAstxYSnhyswJ76uZPStJRJCTKvosUCJZL5B/0/*)";
+
+            let json = bitcoind.client.get_descriptor_info(descriptor).expect("getdescriptorinfo");
+            let info = json.into_model();
+            assert!(info.is_range);
+            assert!(!info.has_private_keys);
+
+            // The canonical descriptor includes the checksum required by `deriveaddresses`.
+            let json = bitcoind
+                .client
+                .derive_addresses(&info.descriptor, Some((0, 2)))
+                .expect("deriveaddresses");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 3);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_descriptor_info`.
+#[macro_export]
+macro_rules! impl_test_v18__getdescriptorinfo {
+    () => {
+        #[test]
+        fn get_descriptor_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let descriptor =
+                "pkh(02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5)";
+
+            let json = bitcoind.client.get_descriptor_info(descriptor).expect("getdescriptorinfo");
+            let model = json.into_model();
+            assert_eq!(model.descriptor, format!("{}#{}", descriptor, model.checksum));
+            assert!(!model.is_range);
+            assert!(model.is_solvable);
+            assert!(!model.has_private_keys);
+        }
+    };
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `new_address`
+/// - `get_address_info`
+#[macro_export]
+macro_rules! impl_test_v18__getaddressinfo {
+    () => {
+        #[test]
+        fn get_address_info() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            let json = bitcoind.client.get_address_info(&address).expect("getaddressinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(&model.address, address.as_unchecked());
+            assert!(model.is_mine);
+            assert_eq!(model.solvable, Some(true));
+            assert_eq!(model.is_change, Some(false));
+            assert!(model.descriptor.is_some());
+            assert!(model.hd_key_path.is_some());
+            assert!(model.hd_master_fingerprint.is_some());
+            assert_eq!(model.labels, vec!["".to_string()]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `list_wallet_dir`.
+#[macro_export]
+macro_rules! impl_test_v18__listwalletdir {
+    () => {
+        #[test]
+        fn list_wallet_dir() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let wallets = bitcoind.client.list_wallets().expect("listwallets").into_model().0;
+
+            let json = bitcoind.client.list_wallet_dir().expect("listwalletdir");
+            let model = json.into_model();
+            assert!(wallets.iter().all(|wallet| model.0.contains(wallet)));
+        }
+    };
+}
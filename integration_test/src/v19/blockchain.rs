@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.19.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `get_block_filter`
+#[macro_export]
+macro_rules! impl_test_v19__getblockfilter {
+    () => {
+        #[test]
+        fn get_block_filter() {
+            use bitcoin::bip158::{BlockFilter, Error};
+            use bitcoind::BitcoinD;
+
+            let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+            let conf = bitcoind::Conf::default();
+            let bitcoind =
+                BitcoinD::with_block_filter_index(exe, &conf).expect("failed to create BitcoinD");
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            bitcoind.wait_for_block_filter_index().expect("blockfilterindex failed to sync");
+
+            let hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+            let json = bitcoind.client.get_block_filter(&hash).expect("getblockfilter");
+            let model = json.into_model().unwrap();
+
+            // The block only contains a coinbase transaction so we need no prevout scripts.
+            let block = bitcoind.client.get_block(hash).expect("getblock");
+            let prevout_script = |o: &bitcoin::OutPoint| -> Result<bitcoin::ScriptBuf, Error> {
+                panic!("unexpected prevout {}", o)
+            };
+            let filter = BlockFilter::new_script_filter(&block, prevout_script)
+                .expect("failed to build filter");
+            assert_eq!(model.filter, filter);
+
+            let script = address.script_pubkey();
+            let mut query = core::iter::once(script.as_bytes());
+            assert!(model.filter.match_any(&hash, &mut query).expect("match_any"));
+        }
+
+        #[test]
+        fn verify_block_filter_headers() {
+            use bitcoind::BitcoinD;
+
+            let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+            let conf = bitcoind::Conf::default();
+            let bitcoind =
+                BitcoinD::with_block_filter_index(exe, &conf).expect("failed to create BitcoinD");
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+            bitcoind.wait_for_block_filter_index().expect("blockfilterindex failed to sync");
+
+            let filters = bitcoind.client.verify_block_filter_headers(0, 10).expect("from genesis");
+            assert_eq!(filters.len(), 11);
+
+            let filters =
+                bitcoind.client.verify_block_filter_headers(5, 10).expect("from height 5");
+            assert_eq!(filters.len(), 6);
+        }
+    };
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v0.19.1`.
+
+/// Requires `Client` to be in scope and to implement `get_peer_info`.
+#[macro_export]
+macro_rules! impl_test_v19__getpeerinfo {
+    () => {
+        #[test]
+        fn get_peer_info() {
+            let (node1, _node2) = $crate::two_node_network();
+            let json = node1.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+            assert!(model.0[0].minimum_fee_filter.is_some());
+        }
+    };
+}
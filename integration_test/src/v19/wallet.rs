@@ -12,3 +12,22 @@ macro_rules! impl_test_v19__getbalances {
         }
     };
 }
+
+/// Requires `Client` to be in scope and to implement `get_wallet_info`.
+#[macro_export]
+macro_rules! impl_test_v19__getwalletinfo {
+    () => {
+        #[test]
+        fn get_wallet_info() {
+            use client::json::model::GetWalletInfoScanning;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert!(model.private_keys_enabled);
+            assert_eq!(model.tx_count, 0);
+            assert_eq!(model.scanning, Some(GetWalletInfoScanning::NotScanning));
+        }
+    };
+}
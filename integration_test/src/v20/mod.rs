@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v0.20.2`.
+
+pub mod wallet;
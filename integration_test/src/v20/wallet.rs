@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.20.2`.
+
+/// Requires `Client` to be in scope and to implement `bump_fee`.
+#[macro_export]
+macro_rules! impl_test_v20__bumpfee {
+    () => {
+        #[test]
+        fn bump_fee() {
+            use bitcoin::FeeRate;
+            use client::client_sync::v20::BumpFeeOptions;
+
+            let bitcoind = $crate::bitcoind_with_wallet_rbf();
+            let txid = $crate::create_replaceable_transaction(&bitcoind);
+
+            let json = bitcoind.client.bump_fee(txid).expect("bumpfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > model.original_fee);
+
+            let options = BumpFeeOptions {
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(25)),
+                replaceable: Some(true),
+                ..Default::default()
+            };
+            let json =
+                bitcoind.client.bump_fee_with_options(model.txid, &options).expect("bumpfee");
+            let bumped = json.into_model().unwrap();
+            assert_eq!(bumped.original_fee, model.fee);
+            assert!(bumped.fee > model.fee);
+        }
+    };
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_raw_mempool_sequence`
+#[macro_export]
+macro_rules! impl_test_v21__getrawmempool_sequence {
+    () => {
+        #[test]
+        fn get_raw_mempool_sequence() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let (parent, child) = $crate::create_mempool_parent_child(&bitcoind);
+
+            let json = bitcoind.client.get_raw_mempool_sequence().expect("getrawmempool sequence");
+            let model = json.into_model().unwrap();
+            assert!(model.txids.contains(&parent));
+            assert!(model.txids.contains(&child));
+            assert!(model.mempool_sequence > 0);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `get_tx_out_set_info_with_hash_type`
+#[macro_export]
+macro_rules! impl_test_v21__gettxoutsetinfo {
+    () => {
+        #[test]
+        fn get_tx_out_set_info_muhash() {
+            use client::client_sync::v21::TxOutSetHashType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            let json = bitcoind
+                .client
+                .get_tx_out_set_info_with_hash_type(TxOutSetHashType::Muhash)
+                .expect("gettxoutsetinfo muhash");
+            let model = json.into_model().unwrap();
+            assert!(model.muhash.is_some());
+            assert!(model.hash_serialized.is_none());
+        }
+    };
+}
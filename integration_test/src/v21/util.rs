@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+
+/// Requires `Client` to be in scope and to implement `get_index_info`.
+#[macro_export]
+macro_rules! impl_test_v21__getindexinfo {
+    () => {
+        #[test]
+        fn get_index_info() {
+            use bitcoind::BitcoinD;
+
+            let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+            let mut conf = bitcoind::Conf::default();
+            conf.args.push("-txindex=1");
+            let bitcoind =
+                BitcoinD::with_block_filter_index(exe, &conf).expect("failed to create BitcoinD");
+
+            let json = bitcoind.client.get_index_info().expect("getindexinfo");
+            let model = json.into_model().unwrap();
+            assert!(model.0.contains_key("txindex"));
+            let index = model.0.get("basic block filter index").expect("blockfilterindex");
+            assert!(index.synced);
+            assert_eq!(index.best_block_height, 0);
+
+            let json = bitcoind.client.get_index_info_for("txindex").expect("getindexinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+        }
+    };
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+
+/// Requires `Client` to be in scope and to implement `bump_fee`.
+#[macro_export]
+macro_rules! impl_test_v21__bumpfee {
+    () => {
+        #[test]
+        fn bump_fee() {
+            use bitcoin::FeeRate;
+            use client::client_sync::v21::BumpFeeOptions;
+
+            let bitcoind = $crate::bitcoind_with_wallet_rbf();
+            let txid = $crate::create_replaceable_transaction(&bitcoind);
+
+            let json = bitcoind.client.bump_fee(txid).expect("bumpfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > model.original_fee);
+
+            let options = BumpFeeOptions {
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(25)),
+                replaceable: Some(true),
+                ..Default::default()
+            };
+            let json =
+                bitcoind.client.bump_fee_with_options(model.txid, &options).expect("bumpfee");
+            let bumped = json.into_model().unwrap();
+            assert_eq!(bumped.original_fee, model.fee);
+            assert!(bumped.fee > model.fee);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `psbt_bump_fee`.
+#[macro_export]
+macro_rules! impl_test_v21__psbtbumpfee {
+    () => {
+        #[test]
+        fn psbt_bump_fee() {
+            let bitcoind = $crate::bitcoind_with_wallet_rbf();
+            let txid = $crate::create_replaceable_transaction(&bitcoind);
+
+            let json = bitcoind.client.psbt_bump_fee(txid).expect("psbtbumpfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > model.original_fee);
+            assert!(!model.psbt.unsigned_tx.input.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `send` and `send_with_options`.
+#[macro_export]
+macro_rules! impl_test_v21__send {
+    () => {
+        #[test]
+        fn send() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v21::SendOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let json = bitcoind.client.send(&outputs).expect("send");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(model.txid.is_some());
+
+            // Without adding to the wallet we get the signed transaction back instead.
+            let options = SendOptions {
+                add_to_wallet: Some(false),
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(10)),
+                ..Default::default()
+            };
+            let json = bitcoind.client.send_with_options(&outputs, &options).expect("send");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(model.tx.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `send_many_verbose`.
+#[macro_export]
+macro_rules! impl_test_v21__sendmany_verbose {
+    () => {
+        #[test]
+        fn send_many_verbose() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::SendManyOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let amounts = [(address, Amount::from_sat(10_000))];
+            let json = bitcoind
+                .client
+                .send_many_verbose(&amounts, &SendManyOptions::default())
+                .expect("sendmany");
+            let model = json.into_model().unwrap();
+            assert!(!model.fee_reason.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_descriptor_info`
+/// - `import_descriptors`
+#[macro_export]
+macro_rules! impl_test_v21__importdescriptors {
+    () => {
+        #[test]
+        fn import_descriptors() {
+            use bitcoin::bip32::Xpriv;
+            use bitcoin::Network;
+            use client::client_sync::v17::Timestamp;
+            use client::client_sync::v21::ImportDescriptorsRequest;
+
+            let bitcoind = $crate::bitcoind_with_descriptor_wallet();
+
+            let xprv = Xpriv::new_master(Network::Regtest, &[0x2a; 32]).unwrap();
+            let desc = format!("wpkh({}/0/*)", xprv);
+            let info = bitcoind.client.get_descriptor_info(&desc).expect("getdescriptorinfo");
+
+            let request = ImportDescriptorsRequest {
+                desc: format!("{}#{}", desc, info.checksum),
+                range: Some((0, 5)),
+                timestamp: Timestamp::Now,
+                ..Default::default()
+            };
+            let json = bitcoind.client.import_descriptors(&[request]).expect("importdescriptors");
+            let model = json.into_model();
+            assert_eq!(model.0.len(), 1);
+            assert!(model.0[0].success);
+        }
+    };
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v22.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `get_tx_out_set_info`
+/// - `get_tx_out_set_info_at`
+///
+/// # Parameters
+///
+/// - `$hash_type`: The version specific `TxOutSetHashType` e.g.,
+///   `client::client_sync::v21::TxOutSetHashType`.
+#[macro_export]
+macro_rules! impl_test_v22__gettxoutsetinfo_with_index {
+    ($hash_type:path) => {
+        #[test]
+        fn get_tx_out_set_info_with_index() {
+            use bitcoind::BitcoinD;
+            use client::client_sync::v22::HashOrHeight;
+
+            type TxOutSetHashType = $hash_type;
+
+            let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+            let mut conf = bitcoind::Conf::default();
+            conf.args.push("-coinstatsindex=1");
+            let bitcoind = BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD");
+
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            // Core errors until the index has caught up with the chain tip.
+            let mut json = None;
+            for _ in 0..100 {
+                if let Ok(info) = bitcoind.client.get_tx_out_set_info() {
+                    if info.height == 10 {
+                        json = Some(info);
+                        break;
+                    }
+                }
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+            let model = json.expect("coinstatsindex failed to sync").into_model().unwrap();
+            assert!(model.transactions.is_none());
+            assert_eq!(model.total_unspendable_amount, Some(bitcoin::Amount::from_int_btc(50)));
+            let block_info = model.block_info.expect("block_info is available with the index");
+            assert_eq!(block_info.coinbase, bitcoin::Amount::from_int_btc(50));
+
+            let at_height = bitcoind
+                .client
+                .get_tx_out_set_info_at(TxOutSetHashType::None, Some(HashOrHeight::Height(5)), true)
+                .expect("gettxoutsetinfo at height")
+                .into_model()
+                .unwrap();
+            assert_eq!(at_height.height, 5);
+
+            let hash =
+                bitcoind.client.get_block_hash(5).expect("getblockhash").block_hash().unwrap();
+            let at_hash = bitcoind
+                .client
+                .get_tx_out_set_info_at(
+                    TxOutSetHashType::None,
+                    Some(HashOrHeight::Hash(hash)),
+                    true,
+                )
+                .expect("gettxoutsetinfo at hash")
+                .into_model()
+                .unwrap();
+            assert_eq!(at_hash.best_block, hash);
+            assert_eq!(at_hash, at_height);
+
+            // Without the index the statistics are calculated for the chain tip.
+            let no_index = bitcoind
+                .client
+                .get_tx_out_set_info_at(TxOutSetHashType::None, None, false)
+                .expect("gettxoutsetinfo without index")
+                .into_model()
+                .unwrap();
+            assert_eq!(no_index.height, 10);
+            assert!(no_index.block_info.is_none());
+        }
+    };
+}
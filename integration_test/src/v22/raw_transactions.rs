@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v22.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+/// - `wallet_process_psbt`
+/// - `finalize_psbt`
+/// - `test_mempool_accept`
+#[macro_export]
+macro_rules! impl_test_v22__testmempoolaccept_allowed {
+    () => {
+        #[test]
+        fn test_mempool_accept_allowed() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Sign a transaction without broadcasting it.
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+            let psbt = bitcoind
+                .client
+                .wallet_process_psbt(&psbt, true)
+                .expect("walletprocesspsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+            let tx = bitcoind
+                .client
+                .finalize_psbt(&psbt)
+                .expect("finalizepsbt")
+                .into_model()
+                .unwrap()
+                .tx
+                .expect("complete transaction");
+
+            let json = bitcoind
+                .client
+                .test_mempool_accept(std::slice::from_ref(&tx))
+                .expect("testmempoolaccept");
+            let model = json.into_model().unwrap();
+            let result = &model.results[0];
+            assert!(result.allowed);
+            assert_eq!(result.txid, tx.compute_txid());
+            assert_eq!(result.wtxid, Some(tx.compute_wtxid()));
+            assert!(result.vsize.is_some());
+            assert!(result.fees.is_some());
+            assert!(result.reject_reason.is_none());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `wallet_create_funded_psbt`
+/// - `wallet_process_psbt`
+/// - `finalize_psbt`
+/// - `test_mempool_accept`
+#[macro_export]
+macro_rules! impl_test_v22__testmempoolaccept_package {
+    () => {
+        #[test]
+        fn test_mempool_accept_package() {
+            use bitcoin::{
+                absolute, transaction, Amount, OutPoint, Psbt, Sequence, Transaction, TxIn, TxOut,
+                Witness,
+            };
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let sign = |psbt: &Psbt| -> Transaction {
+                let psbt = bitcoind
+                    .client
+                    .wallet_process_psbt(psbt, true)
+                    .expect("walletprocesspsbt")
+                    .into_model()
+                    .unwrap()
+                    .psbt;
+                bitcoind
+                    .client
+                    .finalize_psbt(&psbt)
+                    .expect("finalizepsbt")
+                    .into_model()
+                    .unwrap()
+                    .tx
+                    .expect("complete transaction")
+            };
+
+            // A parent paying to the wallet, neither it nor the child are broadcast.
+            let amount = Amount::ONE_BTC;
+            let outputs = [(address.clone(), amount)];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+            let parent = sign(&psbt);
+            let vout = parent
+                .output
+                .iter()
+                .position(|txout| {
+                    txout.value == amount && txout.script_pubkey == address.script_pubkey()
+                })
+                .expect("parent output");
+
+            // The wallet does not know the parent so provide the spent output for signing.
+            let child = Transaction {
+                version: transaction::Version::TWO,
+                lock_time: absolute::LockTime::ZERO,
+                input: vec![TxIn {
+                    previous_output: OutPoint::new(parent.compute_txid(), vout as u32),
+                    script_sig: Default::default(),
+                    sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                    witness: Witness::default(),
+                }],
+                output: vec![TxOut {
+                    value: amount - Amount::from_sat(10_000),
+                    script_pubkey: address.script_pubkey(),
+                }],
+            };
+            let mut psbt = Psbt::from_unsigned_tx(child).unwrap();
+            psbt.inputs[0].witness_utxo = Some(parent.output[vout].clone());
+            let child = sign(&psbt);
+
+            let json = bitcoind
+                .client
+                .test_mempool_accept(&[parent.clone(), child.clone()])
+                .expect("testmempoolaccept");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.results.len(), 2);
+            for (result, tx) in model.results.iter().zip([&parent, &child]) {
+                assert!(result.allowed);
+                assert_eq!(result.txid, tx.compute_txid());
+                assert_eq!(result.wtxid, Some(tx.compute_wtxid()));
+                assert!(result.package_error.is_none());
+                assert!(result.reject_reason.is_none());
+            }
+            assert_eq!(model.results[1].fees.as_ref().unwrap().base, Amount::from_sat(10_000));
+
+            // The child alone is rejected, its parent is unknown.
+            let json = bitcoind.client.test_mempool_accept(&[child]).expect("testmempoolaccept");
+            let model = json.into_model().unwrap();
+            assert!(!model.results[0].allowed);
+            assert!(model.results[0].reject_message.is_some());
+        }
+    };
+}
@@ -18,3 +18,55 @@ macro_rules! impl_test_v22__unloadwallet {
         }
     };
 }
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_descriptor_info`
+/// - `import_descriptors`
+/// - `list_descriptors`
+#[macro_export]
+macro_rules! impl_test_v22__listdescriptors {
+    () => {
+        #[test]
+        fn list_descriptors() {
+            use bitcoin::bip32::Xpriv;
+            use bitcoin::Network;
+            use client::client_sync::v17::Timestamp;
+            use client::client_sync::v21::ImportDescriptorsRequest;
+
+            let bitcoind = $crate::bitcoind_with_descriptor_wallet();
+
+            let xprv = Xpriv::new_master(Network::Regtest, &[0x2a; 32]).unwrap();
+            let desc = format!("wpkh({}/0/*)", xprv);
+            let info = bitcoind.client.get_descriptor_info(&desc).expect("getdescriptorinfo");
+
+            let request = ImportDescriptorsRequest {
+                desc: format!("{}#{}", desc, info.checksum),
+                range: Some((0, 5)),
+                timestamp: Timestamp::Now,
+                ..Default::default()
+            };
+            let json = bitcoind.client.import_descriptors(&[request]).expect("importdescriptors");
+            assert!(json.into_model().0[0].success);
+
+            let json = bitcoind.client.list_descriptors().expect("listdescriptors");
+            let model = json.into_model().unwrap();
+            let item = model
+                .descriptors
+                .iter()
+                .find(|item| item.descriptor == info.descriptor)
+                .expect("imported descriptor");
+            assert_eq!(item.range, Some((0, 5)));
+            assert!(!item.active);
+
+            #[cfg(feature = "miniscript")]
+            {
+                use std::str::FromStr as _;
+
+                use client::json::miniscript::{Descriptor, DescriptorPublicKey};
+
+                let want = Descriptor::<DescriptorPublicKey>::from_str(&info.descriptor).unwrap();
+                assert_eq!(item.parsed_descriptor, want);
+            }
+        }
+    };
+}
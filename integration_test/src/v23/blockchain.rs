@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v23.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_block_verbosity_three`
+#[macro_export]
+macro_rules! impl_test_v23__getblock_verbosity_3 {
+    () => {
+        #[test]
+        fn get_block_verbosity_3() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let json = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let block_hash = json.into_model().unwrap().0[0];
+
+            let json = bitcoind.client.get_block_verbosity_three(block_hash).expect("getblock 3");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.tx.len(), 2);
+
+            let coinbase = &model.tx[0];
+            assert!(coinbase.fee.is_none());
+            assert!(coinbase.spent_outputs().is_none());
+
+            let tx = &model.tx[1];
+            assert_eq!(tx.transaction.compute_txid(), txid);
+            let spent = tx.spent_outputs().expect("all prevouts present");
+            assert_eq!(spent.len(), tx.transaction.input.len());
+
+            let input_value: Amount = spent.iter().map(|out| out.value).sum();
+            let output_value: Amount = tx.transaction.output.iter().map(|out| out.value).sum();
+            assert_eq!(tx.fee, Some(input_value - output_value));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `get_deployment_info`
+/// - `get_deployment_info_at`
+#[macro_export]
+macro_rules! impl_test_v23__getdeploymentinfo {
+    () => {
+        #[test]
+        fn get_deployment_info() {
+            use bitcoind::BitcoinD;
+            use client::json::model::{Bip9SoftforkStatus, SoftforkType};
+
+            let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+            let mut conf = bitcoind::Conf::default();
+            conf.args.push("-vbparams=testdummy:0:9999999999");
+            let bitcoind = BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD");
+
+            let genesis = bitcoind.client.get_block_hash(0).expect("getblockhash");
+            let genesis = genesis.block_hash().unwrap();
+            let json = bitcoind.client.get_deployment_info_at(&genesis).expect("getdeploymentinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.hash, genesis);
+            assert_eq!(model.height, 0);
+            let testdummy = &model.deployments["testdummy"];
+            assert_eq!(testdummy.type_, SoftforkType::Bip9);
+            let bip9 = testdummy.bip9.as_ref().expect("bip9 info");
+            assert_eq!(bip9.status, Bip9SoftforkStatus::Defined);
+
+            // Regtest signalling periods are 144 blocks, the deployment starts in the second one.
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(154, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_deployment_info().expect("getdeploymentinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.height, 154);
+            let testdummy = &model.deployments["testdummy"];
+            assert!(!testdummy.active);
+            let bip9 = testdummy.bip9.as_ref().expect("bip9 info");
+            assert_eq!(bip9.status, Bip9SoftforkStatus::Started);
+            assert_eq!(bip9.bit, Some(28));
+            let statistics = bip9.statistics.as_ref().expect("statistics");
+            assert_eq!(statistics.period, 144);
+            let signalling = bip9.signalling.as_ref().expect("signalling");
+            assert_eq!(signalling.len(), statistics.elapsed as usize);
+        }
+    };
+}
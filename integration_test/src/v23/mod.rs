@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v23.2`.
+
+pub mod blockchain;
+pub mod util;
+pub mod wallet;
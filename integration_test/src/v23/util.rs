@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v23.2`.
+
+/// Requires `Client` to be in scope and to implement `validate_address`.
+#[macro_export]
+macro_rules! impl_test_v23__validateaddress_invalid {
+    () => {
+        #[test]
+        fn validate_address_invalid() {
+            use bitcoin::secp256k1::Secp256k1;
+            use bitcoin::{Address, CompressedPublicKey, Network, PrivateKey};
+
+            let secp = Secp256k1::new();
+            let privkey = PrivateKey::from_slice(&[1_u8; 32], Network::Regtest).unwrap();
+            let pubkey = CompressedPublicKey::from_private_key(&secp, &privkey).unwrap();
+
+            // Swap one character of the data part so the bech32 checksum fails.
+            let address = Address::p2wpkh(&pubkey, Network::Regtest).to_string();
+            let index = address.len() - 10;
+            let swapped = if &address[index..=index] == "q" { "p" } else { "q" };
+            let invalid = format!("{}{}{}", &address[..index], swapped, &address[index + 1..]);
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.validate_address(&invalid).expect("validateaddress");
+            let model = json.into_model().unwrap();
+            assert!(!model.is_valid);
+            assert!(model.error.is_some());
+            let error_locations = model.error_locations.expect("error_locations");
+            assert!(error_locations.contains(&(index as u32)));
+        }
+    };
+}
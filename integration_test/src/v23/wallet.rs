@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v23.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_descriptor_info`
+/// - `import_descriptors`
+/// - `list_descriptors_private`
+#[macro_export]
+macro_rules! impl_test_v23__listdescriptors_private {
+    () => {
+        #[test]
+        fn list_descriptors_private() {
+            use bitcoin::bip32::Xpriv;
+            use bitcoin::Network;
+            use client::client_sync::v17::Timestamp;
+            use client::client_sync::v21::ImportDescriptorsRequest;
+
+            let bitcoind = $crate::bitcoind_with_descriptor_wallet();
+
+            let xprv = Xpriv::new_master(Network::Regtest, &[0x2a; 32]).unwrap();
+            let desc = format!("wpkh({}/0/*)", xprv);
+            let info = bitcoind.client.get_descriptor_info(&desc).expect("getdescriptorinfo");
+
+            let request = ImportDescriptorsRequest {
+                desc: format!("{}#{}", desc, info.checksum),
+                range: Some((0, 5)),
+                timestamp: Timestamp::Now,
+                ..Default::default()
+            };
+            let json = bitcoind.client.import_descriptors(&[request]).expect("importdescriptors");
+            assert!(json.into_model().0[0].success);
+
+            let json = bitcoind.client.list_descriptors_private().expect("listdescriptors");
+            let model = json.into_model().unwrap();
+            let xprv = xprv.to_string();
+            assert!(model.descriptors.iter().any(|item| item.descriptor.contains(&xprv)));
+        }
+    };
+}
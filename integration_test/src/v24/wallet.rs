@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v24.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `list_since_block`
+#[macro_export]
+macro_rules! impl_test_v24__listsinceblock {
+    () => {
+        #[test]
+        fn list_since_block() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let since = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json =
+                bitcoind.client.list_since_block(Some(since), true, false).expect("listsinceblock");
+            let model = json.into_model().unwrap();
+            let tx = model.transactions.iter().find(|tx| tx.txid == txid).expect("sent tx");
+            assert!(tx.wtxid.is_some());
+            assert!(model.removed.is_empty());
+
+            // The change output is only listed when asked for.
+            let json =
+                bitcoind.client.list_since_block(Some(since), true, true).expect("listsinceblock");
+            assert!(json.into_model().unwrap().transactions.len() > model.transactions.len());
+        }
+
+        #[test]
+        fn list_since_block_removed() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let since = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            // Reorg out the block that mined the transaction.
+            let _ = bitcoind
+                .client
+                .call::<()>("invalidateblock", &[since.to_string().into()])
+                .expect("invalidateblock");
+
+            let json =
+                bitcoind.client.list_since_block(Some(since), true, false).expect("listsinceblock");
+            let model = json.into_model().unwrap();
+            assert!(!model.removed.is_empty());
+            assert!(model.removed.iter().any(|tx| tx.txid == txid));
+
+            let json = bitcoind
+                .client
+                .list_since_block(Some(since), false, false)
+                .expect("listsinceblock");
+            assert!(json.into_model().unwrap().removed.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `send_all`.
+#[macro_export]
+macro_rules! impl_test_v24__sendall {
+    () => {
+        #[test]
+        fn send_all() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let recipient = bitcoind.client.new_address().expect("failed to create new address");
+            let json = bitcoind.client.send_all(&[recipient]).expect("sendall");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(model.txid.is_some());
+        }
+    };
+}
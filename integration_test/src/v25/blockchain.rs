@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v25.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `scan_blocks_start`
+/// - `scan_blocks_status`
+/// - `scan_blocks_abort`
+#[macro_export]
+macro_rules! impl_test_v25__scanblocks {
+    () => {
+        #[test]
+        fn scan_blocks() {
+            use bitcoind::BitcoinD;
+            use client::client_sync::v17::ScanObject;
+
+            let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+            let conf = bitcoind::Conf::default();
+            let bitcoind =
+                BitcoinD::with_block_filter_index(exe, &conf).expect("failed to create BitcoinD");
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let json = bitcoind.client.generate_to_address(3, &address).expect("generatetoaddress");
+            let hashes = json.into_model().unwrap().0;
+            bitcoind.wait_for_block_filter_index().expect("blockfilterindex failed to sync");
+
+            let scan_objects = [ScanObject::new(&format!("addr({})", address))];
+            let json =
+                bitcoind.client.scan_blocks_start(&scan_objects, None, None).expect("scanblocks");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.from_height, 0);
+            assert_eq!(model.to_height, 3);
+            assert!(hashes.iter().all(|h| model.relevant_blocks.contains(h)));
+            assert_ne!(model.completed, Some(false));
+
+            let json = bitcoind
+                .client
+                .scan_blocks_start(&scan_objects, Some(2), Some(2))
+                .expect("scanblocks");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.relevant_blocks, vec![hashes[1]]);
+
+            // No scan is in progress once `start` has returned.
+            let status = bitcoind.client.scan_blocks_status().expect("scanblocks status");
+            assert!(status.is_none());
+            let json = bitcoind.client.scan_blocks_abort().expect("scanblocks abort");
+            assert!(!json.into_model().0);
+        }
+    };
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v25.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_raw_transaction_verbosity_two`
+/// - `get_raw_transaction_verbosity_two_in_block`
+#[macro_export]
+macro_rules! impl_test_v25__getrawtransaction_verbosity_two {
+    () => {
+        #[test]
+        fn get_raw_transaction_verbosity_two() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json = bitcoind
+                .client
+                .get_raw_transaction_verbosity_two(txid)
+                .expect("getrawtransaction verbosity 2");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.transaction.compute_txid(), txid);
+            // Prevouts are only included if undo data is available, either way there is one
+            // entry per input.
+            assert_eq!(model.prevouts.len(), model.transaction.input.len());
+        }
+
+        #[test]
+        fn get_raw_transaction_verbosity_two_in_block() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let block_hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            let json = bitcoind
+                .client
+                .get_raw_transaction_verbosity_two_in_block(txid, block_hash)
+                .expect("getrawtransaction verbosity 2");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.transaction.compute_txid(), txid);
+            assert_eq!(model.block_hash, Some(block_hash));
+            assert_eq!(model.in_active_chain, Some(true));
+
+            // Undo data is available for a mined transaction so the fee and prevouts are known.
+            let prevouts = model
+                .prevouts
+                .iter()
+                .map(|prevout| prevout.clone().expect("prevout"))
+                .collect::<Vec<_>>();
+            assert_eq!(prevouts.len(), model.transaction.input.len());
+            for prevout in &prevouts {
+                // The wallet only has coinbase outputs to spend, all below the new block.
+                assert!(prevout.generated);
+                assert!(prevout.height <= 101);
+            }
+
+            let spent = prevouts.iter().map(|prevout| prevout.tx_out.value).sum::<Amount>();
+            let created = model.transaction.output.iter().map(|txout| txout.value).sum::<Amount>();
+            let fee = model.fee.expect("fee");
+            assert!(fee > Amount::ZERO);
+            assert_eq!(fee, spent - created);
+        }
+    };
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `dump_tx_out_set`
+#[macro_export]
+macro_rules! impl_test_v26__dumptxoutset {
+    () => {
+        #[test]
+        fn dump_tx_out_set() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+            let tip = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            let json = bitcoind.client.dump_tx_out_set("utxo.dat").expect("dumptxoutset");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.base_hash, tip);
+            assert_eq!(model.base_height, 10);
+            assert_eq!(model.coins_written, 10);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_chain_states`.
+#[macro_export]
+macro_rules! impl_test_v26__getchainstates {
+    () => {
+        #[test]
+        fn get_chain_states() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_chain_states().expect("getchainstates");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.chain_states.len(), 1);
+            assert!(model.chain_states[0].validated);
+            assert!(model.chain_states[0].snapshot_block_hash.is_none());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `get_tx_out_set_info_with_hash_type`
+#[macro_export]
+macro_rules! impl_test_v26__gettxoutsetinfo {
+    () => {
+        #[test]
+        fn get_tx_out_set_info_muhash() {
+            use client::client_sync::v26::TxOutSetHashType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            let json = bitcoind
+                .client
+                .get_tx_out_set_info_with_hash_type(TxOutSetHashType::Muhash)
+                .expect("gettxoutsetinfo muhash");
+            let model = json.into_model().unwrap();
+            assert!(model.muhash.is_some());
+            assert!(model.hash_serialized.is_none());
+        }
+    };
+}
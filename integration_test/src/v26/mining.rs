@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `prioritise_transaction`
+/// - `get_prioritised_transactions`
+#[macro_export]
+macro_rules! impl_test_v26__getprioritisedtransactions {
+    () => {
+        #[test]
+        fn get_prioritised_transactions() {
+            use bitcoin::{Amount, SignedAmount};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let fee_delta = SignedAmount::from_sat(10_000);
+            let _ = bitcoind
+                .client
+                .prioritise_transaction(&txid, fee_delta)
+                .expect("prioritisetransaction");
+
+            let json =
+                bitcoind.client.get_prioritised_transactions().expect("getprioritisedtransactions");
+            let model = json.into_model().unwrap();
+            let tx = model.0.get(&txid).expect("txid is prioritised");
+            assert_eq!(tx.fee_delta, fee_delta);
+            assert!(tx.in_mempool);
+        }
+    };
+}
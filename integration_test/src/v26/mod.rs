@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v26.2`.
+
+pub mod blockchain;
+pub mod mining;
+pub mod network;
+pub mod raw_transactions;
+pub mod wallet;
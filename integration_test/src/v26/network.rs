@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement `get_addr_man_info`.
+#[macro_export]
+macro_rules! impl_test_v26__getaddrmaninfo {
+    () => {
+        #[test]
+        fn get_addr_man_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_addr_man_info().expect("getaddrmaninfo");
+            let model = json.into_model();
+            let all = model.0.get("all_networks").expect("all_networks");
+            assert_eq!(all.total, all.new + all.tried);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_peer_info`.
+#[macro_export]
+macro_rules! impl_test_v26__getpeerinfo {
+    () => {
+        #[test]
+        fn get_peer_info() {
+            let (node1, _node2) = $crate::two_node_network();
+            let json = node1.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+
+            let peer = &model.0[0];
+            assert!(peer.connection_type.is_some());
+            assert!(peer.transport_protocol_type.is_some());
+            assert!(peer.session_id.is_some());
+        }
+    };
+}
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_psbt`
+/// - `descriptor_process_psbt`
+#[macro_export]
+macro_rules! impl_test_v26__descriptorprocesspsbt {
+    () => {
+        #[test]
+        fn descriptor_process_psbt() {
+            use bitcoin::hashes::Hash;
+            use bitcoin::{Amount, OutPoint, Txid};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            let outpoint = OutPoint { txid: Txid::all_zeros(), vout: 1 };
+            let outputs = [(address.clone(), Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .create_psbt(&[outpoint], &outputs)
+                .expect("createpsbt")
+                .psbt()
+                .unwrap();
+
+            // A public-only descriptor can update but never sign.
+            let descriptors = [format!("addr({})", address)];
+            let json = bitcoind
+                .client
+                .descriptor_process_psbt(&psbt, &descriptors)
+                .expect("descriptorprocesspsbt");
+            let model = json.into_model().unwrap();
+            assert!(!model.complete);
+            assert!(model.tx.is_none());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address`
+/// - `get_transaction`
+/// - `create_psbt`
+/// - `wallet_process_psbt`
+/// - `finalize_psbt`
+/// - `submit_package`
+#[macro_export]
+macro_rules! impl_test_v26__submitpackage {
+    () => {
+        #[test]
+        fn submit_package() {
+            use bitcoin::{Amount, OutPoint};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // The parent is already in the mempool, the child spends it.
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(100_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let parent = bitcoind
+                .client
+                .get_transaction(txid)
+                .expect("gettransaction")
+                .into_model()
+                .unwrap()
+                .tx;
+            let vout = parent
+                .output
+                .iter()
+                .position(|out| out.script_pubkey == address.script_pubkey())
+                .expect("output paying to address") as u32;
+
+            let outputs = [(address, Amount::from_sat(90_000))];
+            let psbt = bitcoind
+                .client
+                .create_psbt(&[OutPoint { txid, vout }], &outputs)
+                .expect("createpsbt")
+                .psbt()
+                .unwrap();
+            let psbt = bitcoind
+                .client
+                .wallet_process_psbt(&psbt, true)
+                .expect("walletprocesspsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+            let child = bitcoind
+                .client
+                .finalize_psbt(&psbt)
+                .expect("finalizepsbt")
+                .into_model()
+                .unwrap()
+                .tx
+                .expect("complete transaction");
+
+            let json =
+                bitcoind.client.submit_package(&[parent, child.clone()]).expect("submitpackage");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.tx_results.len(), 2);
+            let result = model.tx_results.get(&child.compute_wtxid()).expect("child result");
+            assert_eq!(result.txid, child.compute_txid());
+            assert!(result.error.is_none());
+        }
+    };
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_wallet_info`
+/// - `get_best_block_hash`
+#[macro_export]
+macro_rules! impl_test_v26__getwalletinfo {
+    () => {
+        #[test]
+        fn get_wallet_info() {
+            use client::json::model::GetWalletInfoScanning;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let best_block_hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.descriptors, Some(true));
+            assert_eq!(model.external_signer, Some(false));
+            assert_eq!(model.blank, Some(false));
+            assert_eq!(model.scanning, Some(GetWalletInfoScanning::NotScanning));
+
+            let last_processed_block = model.last_processed_block.expect("lastprocessedblock");
+            assert_eq!(last_processed_block.hash, best_block_hash);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `bump_fee`.
+#[macro_export]
+macro_rules! impl_test_v26__bumpfee {
+    () => {
+        #[test]
+        fn bump_fee() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v26::BumpFeeOptions;
+
+            let bitcoind = $crate::bitcoind_with_wallet_rbf();
+            let txid = $crate::create_replaceable_transaction(&bitcoind);
+
+            let json = bitcoind.client.bump_fee(txid).expect("bumpfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > model.original_fee);
+
+            // Replace the outputs, the wallet adds a change output.
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let options = BumpFeeOptions {
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(25)),
+                outputs: vec![(address.clone(), Amount::from_btc(0.5).unwrap())],
+                ..Default::default()
+            };
+            let json =
+                bitcoind.client.bump_fee_with_options(model.txid, &options).expect("bumpfee");
+            let bumped = json.into_model().unwrap();
+            assert_eq!(bumped.original_fee, model.fee);
+            assert!(bumped.fee > model.fee);
+
+            let tx = bitcoind.client.get_transaction(bumped.txid).expect("gettransaction");
+            let tx = tx.into_model().unwrap().tx;
+            assert!(tx.output.iter().any(|out| out.script_pubkey == address.script_pubkey()));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `wallet_process_psbt_with_options`.
+#[macro_export]
+macro_rules! impl_test_v26__walletprocesspsbt {
+    () => {
+        #[test]
+        fn wallet_process_psbt_finalize() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::WalletProcessPsbtOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let outputs = [(address, Amount::from_sat(10_000))];
+            let psbt = bitcoind
+                .client
+                .wallet_create_funded_psbt(&outputs)
+                .expect("walletcreatefundedpsbt")
+                .into_model()
+                .unwrap()
+                .psbt;
+
+            let options = WalletProcessPsbtOptions { finalize: Some(false), ..Default::default() };
+            let json = bitcoind
+                .client
+                .wallet_process_psbt_with_options(&psbt, &options)
+                .expect("walletprocesspsbt");
+            assert!(json.into_model().unwrap().tx.is_none());
+
+            let options = WalletProcessPsbtOptions { finalize: Some(true), ..Default::default() };
+            let json = bitcoind
+                .client
+                .wallet_process_psbt_with_options(&psbt, &options)
+                .expect("walletprocesspsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert_eq!(model.tx.unwrap().compute_txid(), psbt.unsigned_tx.compute_txid());
+        }
+    };
+}
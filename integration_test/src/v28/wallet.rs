@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v28.0`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_wallet_descriptor`
+/// - `get_descriptor_info`
+/// - `get_hd_keys`
+/// - `get_hd_keys_private`
+/// - `import_descriptors`
+#[macro_export]
+macro_rules! impl_test_v28__gethdkeys {
+    () => {
+        #[test]
+        fn get_hd_keys_and_create_wallet_descriptor() {
+            use bitcoin::bip32::{Xpriv, Xpub};
+            use bitcoin::secp256k1::Secp256k1;
+            use bitcoin::Network;
+            use client::client_sync::v17::Timestamp;
+            use client::client_sync::v21::ImportDescriptorsRequest;
+            use client::client_sync::v23::AddressType;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            // A blank descriptor wallet, so the only HD key is the one imported below.
+            let _ = bitcoind
+                .client
+                .call::<client::json::v28::CreateWallet>(
+                    "createwallet",
+                    &["blank".into(), false.into(), true.into()],
+                )
+                .expect("createwallet");
+
+            let xprv = Xpriv::new_master(Network::Regtest, &[0x2a; 32]).unwrap();
+            let xpub = Xpub::from_priv(&Secp256k1::new(), &xprv);
+            let desc = format!("tr({}/0/*)", xprv);
+            let info = bitcoind.client.get_descriptor_info(&desc).expect("getdescriptorinfo");
+
+            let request = ImportDescriptorsRequest {
+                desc: format!("{}#{}", desc, info.checksum),
+                active: Some(true),
+                range: Some((0, 5)),
+                timestamp: Timestamp::Now,
+                ..Default::default()
+            };
+            let json = bitcoind.client.import_descriptors(&[request]).expect("importdescriptors");
+            assert!(json.into_model().0[0].success);
+
+            let json = bitcoind.client.get_hd_keys().expect("gethdkeys");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+            assert_eq!(model.0[0].xpub, xpub);
+            assert!(model.0[0].has_private);
+            assert!(model.0[0].xprv.is_none());
+            assert!(model.0[0].descriptors[0].active);
+
+            let json = bitcoind.client.get_hd_keys_private().expect("gethdkeys");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0[0].xprv, Some(xprv));
+
+            // The wallet has a taproot descriptor but no segwit v0 one yet.
+            let json = bitcoind
+                .client
+                .create_wallet_descriptor(AddressType::Bech32)
+                .expect("createwalletdescriptor");
+            let model = json.into_model().unwrap();
+            assert!(!model.descriptors.is_empty());
+            assert!(model.descriptors.iter().all(|desc| desc.starts_with("wpkh(")));
+        }
+    };
+}
@@ -11,14 +11,35 @@ mod blockchain {
     impl_test_v17__getbestblockhash!();
     impl_test_v17__getblock_verbosity_0!();
     impl_test_v17__getblock_verbosity_1!();
+    impl_test_v17__getblock_verbosity_2!();
     impl_test_v17__getblockchaininfo!();
+    impl_test_v17__getblockcount!();
+    impl_test_v19__getblockfilter!();
+    impl_test_v17__getblockhash!();
+    impl_test_v17__getmempoolancestors!();
+    impl_test_v17__getmempooldescendants!();
+    impl_test_v17__getmempoolentry!();
+    impl_test_v17__getmempoolinfo!();
+    impl_test_v17__getrawmempool!();
+    impl_test_v21__getrawmempool_sequence!();
+    impl_test_v17__gettxoutsetinfo!();
+    impl_test_v21__gettxoutsetinfo!();
+    impl_test_v17__scantxoutset!();
+    impl_test_v17__waitforblock!();
+    impl_test_v17__waitforblockheight!();
+    impl_test_v17__waitfornewblock!();
+    impl_test_wait_helpers!();
 }
 
 // == Control ==
 mod control {
     use super::*;
 
+    impl_test_v17__getmemoryinfo!();
+    impl_test_v18__getrpcinfo!();
+    impl_test_v17__logging!();
     impl_test_v17__stop!();
+    impl_test_v17__uptime!();
 }
 
 // == Generating ==
@@ -28,18 +49,71 @@ mod generating {
     impl_test_v17__generatetoaddress!();
 }
 
+// == Mining ==
+mod mining {
+    use super::*;
+
+    impl_test_v17__getblocktemplate!();
+    impl_test_v17__getmininginfo!();
+    impl_test_v17__getnetworkhashps!();
+    impl_test_v17__prioritisetransaction!();
+    impl_test_v17__submitblock!();
+    impl_test_v18__submitheader!();
+}
+
 // == Network ==
 mod network {
     use super::*;
 
+    impl_test_v17__addnode!();
+    impl_test_v17__clearbanned!();
+    impl_test_v17__disconnectnode!();
+    impl_test_v17__getaddednodeinfo!();
+    impl_test_v17__getconnectioncount!();
+    impl_test_v17__getnettotals!();
     impl_test_v17__getnetworkinfo!();
+    impl_test_v18__getnodeaddresses!();
+    impl_test_v19__getpeerinfo!();
+    impl_test_v17__listbanned!();
+    impl_test_v17__ping!();
+    impl_test_v17__setban!();
+    impl_test_v17__setnetworkactive!();
 }
 
 // == Rawtransactions ==
 mod raw_transactions {
     use super::*;
 
+    impl_test_v18__analyzepsbt!();
+    impl_test_v17__combinepsbt!();
+    impl_test_v17__combinerawtransaction!();
+    impl_test_v17__converttopsbt!();
+    impl_test_v17__createpsbt!();
+    impl_test_v17__createrawtransaction!();
+    impl_test_v17__decodepsbt!();
+    impl_test_v17__decoderawtransaction!();
+    impl_test_v17__decodescript!();
+    impl_test_v17__finalizepsbt!();
+    impl_test_v17__fundrawtransaction!();
+    impl_test_v17__getrawtransaction!();
+    impl_test_v18__joinpsbts!();
     impl_test_v17__sendrawtransaction!();
+    impl_test_v17__signrawtransactionwithkey!();
+    impl_test_v17__testmempoolaccept!();
+    impl_test_v18__utxoupdatepsbt!();
+}
+
+// == Util ==
+mod util {
+    use super::*;
+
+    impl_test_v17__createmultisig!();
+    impl_test_v18__deriveaddresses!();
+    impl_test_v17__estimatesmartfee!();
+    impl_test_v18__getdescriptorinfo!();
+    impl_test_v21__getindexinfo!();
+    impl_test_v17__signmessagewithprivkey!();
+    impl_test_v17__validateaddress!();
 }
 
 // == Wallet ==
@@ -49,9 +123,32 @@ mod wallet {
     impl_test_v17__createwallet!();
     impl_test_v17__loadwallet!();
 
+    impl_test_v17__abandontransaction!();
+    impl_test_v21__bumpfee!();
+    impl_test_v21__psbtbumpfee!();
     impl_test_v17__getnewaddress!();
     impl_test_v17__getbalance!();
     impl_test_v19__getbalances!();
+    impl_test_v17__sendmany!();
+    impl_test_v21__sendmany_verbose!();
+    impl_test_v21__send!();
     impl_test_v17__sendtoaddress!();
+    impl_test_v17__signmessage!();
+    impl_test_v17__signrawtransactionwithwallet!();
+    impl_test_v17__getaddressesbylabel!();
+    impl_test_v18__getaddressinfo!();
     impl_test_v17__gettransaction!();
+    impl_test_v19__getwalletinfo!();
+    impl_test_v17__importmulti!();
+    impl_test_v21__importdescriptors!();
+    impl_test_v17__listlabels!();
+    impl_test_v17__listsinceblock!();
+    impl_test_v17__listtransactions!();
+    impl_test_v17__listunspent!();
+    impl_test_v18__listwalletdir!();
+    impl_test_v17__listwallets!();
+    impl_test_v17__setlabel!();
+    impl_test_v17__walletcreatefundedpsbt!();
+    impl_test_v17__walletprocesspsbt!();
+    impl_test_psbt_workflow!();
 }
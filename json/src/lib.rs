@@ -5,6 +5,10 @@
 /// Re-export the `rust-bitcoin` crate.
 pub extern crate bitcoin;
 
+/// Re-export the `rust-miniscript` crate.
+#[cfg(feature = "miniscript")]
+pub extern crate miniscript;
+
 // TODO: Consider updating https://en.bitcoin.it/wiki/API_reference_%28JSON-RPC%29 when this is complete.
 
 // JSON types, for each specific version of `bitcoind`.
@@ -83,3 +87,13 @@ fn btc_per_kb(btc_per_kb: f64) -> Result<Option<FeeRate>, ParseAmountError> {
 
     Ok(rate)
 }
+
+/// Parses a descriptor string returned by Core, any private keys are replaced by their public keys.
+#[cfg(feature = "miniscript")]
+fn descriptor(
+    s: &str,
+) -> Result<miniscript::Descriptor<miniscript::DescriptorPublicKey>, miniscript::Error> {
+    let secp = bitcoin::secp256k1::Secp256k1::signing_only();
+    let (descriptor, _) = miniscript::Descriptor::parse_descriptor(&secp, s)?;
+    Ok(descriptor)
+}
@@ -8,12 +8,32 @@
 use std::collections::BTreeMap;
 
 use bitcoin::address::NetworkUnchecked;
+use bitcoin::bip158::{BlockFilter, FilterHeader};
 use bitcoin::{
-    block, Address, Amount, Block, BlockHash, CompactTarget, FeeRate, Network, TxMerkleNode, TxOut,
-    Txid, Weight, Work,
+    block, Address, Amount, Block, BlockHash, CompactTarget, FeeRate, Network, ScriptBuf,
+    Transaction, TxMerkleNode, TxOut, Txid, Weight, Work, Wtxid,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::model::RawTransactionPrevout;
+
+/// Models the result of JSON-RPC method `dumptxoutset`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DumpTxOutSet {
+    /// The number of coins written in the snapshot.
+    pub coins_written: u64,
+    /// The hash of the base of the snapshot.
+    pub base_hash: BlockHash,
+    /// The height of the base of the snapshot.
+    pub base_height: u32,
+    /// The absolute path that the snapshot was written to.
+    pub path: String,
+    /// The hash of the UTXO set contents.
+    pub txoutset_hash: String,
+    /// The number of transactions in the chain up to and including the base block.
+    pub n_chain_tx: u64,
+}
+
 /// Models the result of JSON-RPC method `getbestblockhash`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBestBlockHash(pub BlockHash);
@@ -63,6 +83,120 @@ pub struct GetBlockVerbosityOne {
     pub next_block_hash: Option<BlockHash>,
 }
 
+/// Models the result of JSON-RPC method `getblock` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockVerbosityTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: BlockHash,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: u32,
+    /// The block size excluding witness data.
+    pub stripped_size: Option<u32>,
+    /// The block weight as defined in BIP-141.
+    pub weight: Weight,
+    /// The block height or index.
+    pub height: u32,
+    /// The block version.
+    pub version: block::Version,
+    /// The merkle root.
+    pub merkle_root: String,
+    /// The transactions in the block.
+    pub tx: Vec<BlockTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: u32,
+    /// The median block time expressed in UNIX epoch time.
+    pub median_time: Option<u32>,
+    /// The nonce.
+    pub nonce: u32,
+    /// The bits.
+    pub bits: CompactTarget,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    pub chain_work: Work,
+    /// The number of transactions in the block.
+    pub n_tx: u32,
+    /// The hash of the previous block (if available).
+    pub previous_block_hash: Option<BlockHash>,
+    /// The hash of the next block (if available).
+    pub next_block_hash: Option<BlockHash>,
+}
+
+/// Models a transaction in the result of `getblock` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BlockTransaction {
+    /// The transaction.
+    pub transaction: Transaction,
+    /// The transaction fee, omitted for the coinbase or if block undo data is not available.
+    pub fee: Option<Amount>,
+}
+
+/// Models the result of JSON-RPC method `getblock` with verbosity set to 3.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockVerbosityThree {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: BlockHash,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: u32,
+    /// The block size excluding witness data.
+    pub stripped_size: Option<u32>,
+    /// The block weight as defined in BIP-141.
+    pub weight: Weight,
+    /// The block height or index.
+    pub height: u32,
+    /// The block version.
+    pub version: block::Version,
+    /// The merkle root.
+    pub merkle_root: String,
+    /// The transactions in the block.
+    pub tx: Vec<BlockTransactionWithPrevouts>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: u32,
+    /// The median block time expressed in UNIX epoch time.
+    pub median_time: Option<u32>,
+    /// The nonce.
+    pub nonce: u32,
+    /// The bits.
+    pub bits: CompactTarget,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    pub chain_work: Work,
+    /// The number of transactions in the block.
+    pub n_tx: u32,
+    /// The hash of the previous block (if available).
+    pub previous_block_hash: Option<BlockHash>,
+    /// The hash of the next block (if available).
+    pub next_block_hash: Option<BlockHash>,
+}
+
+/// Models a transaction in the result of `getblock` with verbosity set to 3.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BlockTransactionWithPrevouts {
+    /// The transaction.
+    pub transaction: Transaction,
+    /// The transaction fee, omitted for the coinbase or if block undo data is not available.
+    pub fee: Option<Amount>,
+    /// The output spent by each input, in input order. `None` for the coinbase input or if undo
+    /// data is not available.
+    pub prevouts: Vec<Option<RawTransactionPrevout>>,
+}
+
+impl BlockTransactionWithPrevouts {
+    /// Returns the outputs spent by this transaction, in input order.
+    ///
+    /// These are the outputs required to compute taproot signature hashes (see
+    /// [`bitcoin::sighash::Prevouts::All`]). Returns `None` if any input is missing its prevout,
+    /// as is the case for the coinbase.
+    pub fn spent_outputs(&self) -> Option<Vec<TxOut>> {
+        self.prevouts.iter().map(|prevout| prevout.as_ref().map(|p| p.tx_out.clone())).collect()
+    }
+}
+
 /// Models the result of JSON-RPC method `getblockchaininfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBlockchainInfo {
@@ -136,11 +270,17 @@ pub struct Bip9SoftforkInfo {
     /// The bit (0-28) in the block version field used to signal this softfork (only for "started" status).
     pub bit: Option<u8>,
     /// The minimum median time past of a block at which the bit gains its meaning.
-    pub start_time: u32,
+    ///
+    /// This is -1 for deployments that are always active.
+    pub start_time: i64,
     /// The median time past of a block at which the deployment is considered failed if not yet locked in.
-    pub timeout: u32,
+    ///
+    /// This is `i64::MAX` for deployments that never time out.
+    pub timeout: i64,
     /// Height of the first block to which the status applies.
     pub since: u32,
+    /// Minimum height of blocks for which the rules may be enforced (v0.21 and later).
+    pub min_activation_height: Option<u32>,
     /// Numeric statistics about BIP-9 signalling for a softfork (only for "started" status).
     pub statistics: Option<Bip9SoftforkStatistics>,
 }
@@ -179,6 +319,16 @@ pub struct Bip9SoftforkStatistics {
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GetBlockCount(pub u64);
 
+/// Models the result of JSON-RPC method `getblockfilter`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetBlockFilter {
+    /// The BIP-158 compact block filter.
+    #[serde(with = "block_filter_hex")]
+    pub filter: BlockFilter,
+    /// The filter header, commits to the filter and the previous filter header.
+    pub header: FilterHeader,
+}
+
 /// Models the result of JSON-RPC method `getblockhash`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GetBlockHash(pub BlockHash);
@@ -221,6 +371,7 @@ pub struct GetBlockHeaderVerbose {
 }
 
 /// Models the result of JSON-RPC method `getblockstats`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GetBlockStats {
     /// Average fee in the block.
     pub average_fee: Amount,
@@ -282,6 +433,36 @@ pub struct GetBlockStats {
     pub utxo_size_increase: i32,
 }
 
+/// Models the result of JSON-RPC method `getchainstates`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetChainStates {
+    /// The number of headers seen so far.
+    pub headers: u32,
+    /// List of the chainstates ordered by work, with the most-work (active) chainstate last.
+    pub chain_states: Vec<ChainState>,
+}
+
+/// Models a single chainstate. Part of `getchainstates`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChainState {
+    /// The number of blocks in this chainstate.
+    pub blocks: u32,
+    /// The blockhash of the tip.
+    pub best_block_hash: BlockHash,
+    /// The difficulty of the tip.
+    pub difficulty: f64,
+    /// Progress towards the network tip.
+    pub verification_progress: f64,
+    /// The base block of the snapshot this chainstate is based on, if any.
+    pub snapshot_block_hash: Option<BlockHash>,
+    /// The size of the coins cache in bytes.
+    pub coins_db_cache_bytes: u64,
+    /// The size of the coins tip cache in bytes.
+    pub coins_tip_cache_bytes: u64,
+    /// Whether the chainstate is fully validated.
+    pub validated: bool,
+}
+
 /// Result of JSON-RPC method `getchaintips`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetChainTips(pub Vec<ChainTips>);
@@ -334,17 +515,182 @@ pub struct GetChainTxStats {
     pub tx_rate: Option<u32>,
 }
 
+/// Models the result of JSON-RPC method `getdeploymentinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetDeploymentInfo {
+    /// Requested block hash (or tip).
+    pub hash: BlockHash,
+    /// Requested block height (or tip).
+    pub height: u32,
+    /// Deployments, maps deployment name -> [`DeploymentInfo`].
+    pub deployments: BTreeMap<String, DeploymentInfo>,
+}
+
+/// Models a deployment in the result of JSON-RPC method `getdeploymentinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeploymentInfo {
+    /// The [`SoftforkType`]: one of "buried", "bip9".
+    pub type_: SoftforkType,
+    /// Height of the first block which the rules are or will be enforced (only for "buried" type, or "bip9" type with "active" status).
+    pub height: Option<u32>,
+    /// `true` if the rules are enforced for the mempool and the next block.
+    pub active: bool,
+    /// Status of bip9 softforks (only for "bip9" type).
+    pub bip9: Option<Bip9DeploymentInfo>,
+}
+
+/// Models the status of a BIP-9 deployment in the result of JSON-RPC method `getdeploymentinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Bip9DeploymentInfo {
+    /// The bit (0-28) in the block version field used to signal this softfork (only for "started" and "locked_in" status).
+    pub bit: Option<u8>,
+    /// The minimum median time past of a block at which the bit gains its meaning.
+    ///
+    /// This is -1 for deployments that are always active.
+    pub start_time: i64,
+    /// The median time past of a block at which the deployment is considered failed if not yet locked in.
+    ///
+    /// This is `i64::MAX` for deployments that never time out.
+    pub timeout: i64,
+    /// Minimum height of blocks for which the rules may be enforced.
+    pub min_activation_height: u32,
+    /// Status of deployment at specified block.
+    pub status: Bip9SoftforkStatus,
+    /// Height of the first block to which the status applies.
+    pub since: u32,
+    /// Status of deployment at the next block.
+    pub status_next: Bip9SoftforkStatus,
+    /// Numeric statistics about signalling for a softfork (only for "started" and "locked_in" status).
+    pub statistics: Option<Bip9SoftforkStatistics>,
+    /// Whether each block in the current period signalled, oldest first (only for "started" and "locked_in" status).
+    pub signalling: Option<Vec<bool>>,
+}
+
 /// Result of JSON-RPC method `getdifficulty`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetDifficulty(pub f64);
 
-/// Result of JSON-RPC method `getmempoolancestors` with verbose set to false.
+/// Models the result of JSON-RPC method `getmempoolancestors` with verbose set to false.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetMempoolAncestors(pub Vec<Txid>);
 
-/// Result of JSON-RPC method `getmempoolancestors` with verbose set to true.
+/// Models the result of JSON-RPC method `getmempoolancestors` with verbose set to true.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolAncestorsVerbose(pub BTreeMap<Txid, MempoolEntry>);
+
+/// Models the result of JSON-RPC method `getmempooldescendants` with verbose set to false.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolDescendants(pub Vec<Txid>);
+
+/// Models the result of JSON-RPC method `getmempooldescendants` with verbose set to true.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolDescendantsVerbose(pub BTreeMap<Txid, MempoolEntry>);
+
+/// Models the result of JSON-RPC method `getmempoolentry`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+/// Models a mempool entry.
+///
+/// Part of `getmempoolancestors`, `getmempooldescendants`, `getmempoolentry` and `getrawmempool`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    ///
+    /// This is different from actual serialized size for witness transactions as witness data is
+    /// discounted.
+    pub vsize: u32,
+    /// Transaction weight as defined in BIP 141 (v21 and later).
+    pub weight: Option<Weight>,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: u32,
+    /// Block height when transaction entered pool.
+    pub height: u32,
+    /// Number of in-mempool descendant transactions (including this one).
+    pub descendant_count: u32,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    pub descendant_size: u32,
+    /// Number of in-mempool ancestor transactions (including this one).
+    pub ancestor_count: u32,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    pub ancestor_size: u32,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: Wtxid,
+    /// The fees of this transaction and its in-mempool ancestors and descendants.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction.
+    pub depends: Vec<Txid>,
+    /// Unconfirmed transactions spending outputs from this transaction.
+    pub spent_by: Vec<Txid>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee).
+    pub bip125_replaceable: bool,
+    /// Whether this transaction is currently unbroadcast (initial broadcast not yet acknowledged
+    /// by any peers, v21 and later).
+    pub unbroadcast: Option<bool>,
+}
+
+/// Models the fees of a mempool entry. Part of `MempoolEntry`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MempoolEntryFees {
+    /// Transaction fee.
+    pub base: Amount,
+    /// Transaction fee with fee deltas used for mining priority.
+    pub modified: Amount,
+    /// Modified fees (see above) of in-mempool ancestors (including this one).
+    pub ancestor: Amount,
+    /// Modified fees (see above) of in-mempool descendants (including this one).
+    pub descendant: Amount,
+}
+
+/// Models the result of JSON-RPC method `getmempoolinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-pub struct GetMempoolAncestorsVerbose {}
+pub struct GetMempoolInfo {
+    /// True if the mempool is fully loaded (v21 and later).
+    pub loaded: Option<bool>,
+    /// Current transaction count.
+    pub size: u32,
+    /// Sum of all virtual transaction sizes as defined in BIP 141.
+    ///
+    /// Differs from actual serialized size because witness data is discounted.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Total fees for the mempool, ignoring modified fees through prioritisetransaction (v24 and
+    /// later).
+    pub total_fee: Option<Amount>,
+    /// Maximum memory usage for the mempool.
+    pub max_mempool: u64,
+    /// Minimum fee rate for tx to be accepted.
+    ///
+    /// Is the maximum of `min_relay_tx_fee` and minimum mempool fee.
+    pub mempool_min_fee: Option<FeeRate>,
+    /// Current minimum relay fee for transactions.
+    pub min_relay_tx_fee: Option<FeeRate>,
+    /// Minimum fee rate increment for mempool limiting or replacement (v24 and later).
+    pub incremental_relay_fee: Option<FeeRate>,
+    /// Current number of transactions that haven't passed initial broadcast yet (v21 and later).
+    pub unbroadcast_count: Option<u32>,
+    /// True if the mempool accepts RBF without replaceability signaling inspection (v24 and
+    /// later).
+    pub full_rbf: Option<bool>,
+}
+
+/// Models the result of JSON-RPC method `getrawmempool` with verbose set to false.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawMempool(pub Vec<Txid>);
+
+/// Models the result of JSON-RPC method `getrawmempool` with verbose set to true.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<Txid, MempoolEntry>);
+
+/// Models the result of JSON-RPC method `getrawmempool` with `mempool_sequence` set to true.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawMempoolSequence {
+    /// The transaction ids in the mempool.
+    pub txids: Vec<Txid>,
+    /// The mempool sequence value.
+    pub mempool_sequence: u64,
+}
 
 /// Models the result of JSON-RPC method `gettxout`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -360,3 +706,198 @@ pub struct GetTxOut {
     /// Coinbase or not.
     pub coinbase: bool,
 }
+
+/// Models the result of JSON-RPC method `gettxoutsetinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The current block height (index).
+    pub height: u32,
+    /// The hash of the block at the tip of the chain.
+    pub best_block: BlockHash,
+    /// The number of unspent transaction outputs.
+    pub tx_outs: u64,
+    /// A meaningless metric for UTXO set size.
+    pub bogo_size: u64,
+    /// The serialized hash, `hash_serialized_2` before v26 and `hash_serialized_3` after.
+    ///
+    /// Only present if the `hash_serialized` hash type was requested.
+    pub hash_serialized: Option<String>,
+    /// The serialized hash (only present if the `muhash` hash type was requested).
+    pub muhash: Option<String>,
+    /// The number of transactions with unspent outputs (not available when `use_index` is set).
+    pub transactions: Option<u64>,
+    /// The estimated size of the chainstate on disk (not available when `use_index` is set).
+    pub disk_size: Option<u64>,
+    /// The total amount of coins in the UTXO set.
+    pub total_amount: Amount,
+    /// The total amount of coins permanently excluded from the UTXO set (only available if
+    /// `coinstatsindex` is used).
+    pub total_unspendable_amount: Option<Amount>,
+    /// Info on amounts in the block at this block height (only available if `coinstatsindex` is
+    /// used).
+    pub block_info: Option<TxOutSetBlockInfo>,
+}
+
+/// Models the amounts in the block at a given height. Part of `gettxoutsetinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TxOutSetBlockInfo {
+    /// Total amount of all prevouts spent in this block.
+    pub prevout_spent: Amount,
+    /// Coinbase subsidy amount of this block.
+    pub coinbase: Amount,
+    /// Total amount of new outputs created by this block.
+    pub new_outputs_ex_coinbase: Amount,
+    /// Total amount of unspendable outputs created in this block.
+    pub unspendable: Amount,
+    /// Detailed view of the unspendable categories.
+    pub unspendables: TxOutSetUnspendables,
+}
+
+/// Models the unspendable categories of a block. Part of `gettxoutsetinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TxOutSetUnspendables {
+    /// The unspendable amount of the Genesis block subsidy.
+    pub genesis_block: Amount,
+    /// Transactions overridden by duplicates (no longer possible with BIP30).
+    pub bip30: Amount,
+    /// Amounts sent to scripts that are unspendable (for example OP_RETURN outputs).
+    pub scripts: Amount,
+    /// Fee rewards that miners did not claim in their coinbase transaction.
+    pub unclaimed_rewards: Amount,
+}
+
+/// Models the result of JSON-RPC method `loadtxoutset`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LoadTxOutSet {
+    /// The number of coins loaded from the snapshot.
+    pub coins_loaded: u64,
+    /// The hash of the base of the snapshot.
+    pub tip_hash: BlockHash,
+    /// The height of the base of the snapshot.
+    pub base_height: u32,
+    /// The absolute path that the snapshot was loaded from.
+    pub path: String,
+}
+
+/// Models the result of JSON-RPC method `scanblocks` with action `abort`.
+///
+/// True if the abort was successful.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksAbort(pub bool);
+
+/// Models the result of JSON-RPC method `scanblocks` with action `start`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksStart {
+    /// The height we started the scan from.
+    pub from_height: u32,
+    /// The height we ended the scan at.
+    pub to_height: u32,
+    /// Blocks that may have matched a scan object.
+    pub relevant_blocks: Vec<BlockHash>,
+    /// Whether the scan ran to completion, `None` if not returned by this version of Core (v26+).
+    pub completed: Option<bool>,
+}
+
+/// Models the result of JSON-RPC method `scanblocks` with action `status`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanBlocksStatus {
+    /// The scan progress as a percentage.
+    pub progress: f64,
+    /// The height of the block currently being scanned.
+    pub current_height: u32,
+}
+
+/// Models the result of JSON-RPC method `scantxoutset` with action `abort`.
+///
+/// True if the abort was successful.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanTxOutSetAbort(pub bool);
+
+/// Models the result of JSON-RPC method `scantxoutset` with action `start`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// Whether the scan was completed (v0.18 onwards).
+    pub success: Option<bool>,
+    /// The number of unspent transaction outputs scanned (v0.18 onwards).
+    pub tx_outs: Option<u64>,
+    /// The current block height (v0.19 onwards).
+    pub height: Option<u32>,
+    /// The hash of the block at the tip of the chain (v0.19 onwards).
+    pub best_block: Option<BlockHash>,
+    /// The unspent outputs found.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs.
+    pub total_amount: Amount,
+}
+
+/// Models an unspent output found by `scantxoutset`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanTxOutSetUnspent {
+    /// The transaction id.
+    pub txid: Txid,
+    /// The vout value.
+    pub vout: u32,
+    /// The script pubkey.
+    pub script_pubkey: ScriptBuf,
+    /// A specialized descriptor for the matched script pubkey (v0.18 onwards).
+    pub descriptor: Option<String>,
+    /// The total amount of the unspent output.
+    pub amount: Amount,
+    /// Whether this is a coinbase output (v25 onwards).
+    pub coinbase: Option<bool>,
+    /// Height of the unspent transaction output.
+    pub height: u32,
+    /// Blockhash of the unspent transaction output (v25 onwards).
+    pub block_hash: Option<BlockHash>,
+}
+
+/// Models the result of JSON-RPC method `scantxoutset` with action `status`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStatus {
+    /// The scan progress as a percentage.
+    pub progress: f64,
+}
+
+/// Models the result of JSON-RPC method `waitforblock`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WaitForBlock {
+    /// The blockhash of the chain tip when the call returned.
+    pub hash: BlockHash,
+    /// The block height of the chain tip when the call returned.
+    pub height: u64,
+}
+
+/// Models the result of JSON-RPC method `waitforblockheight`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WaitForBlockHeight {
+    /// The blockhash of the chain tip when the call returned.
+    pub hash: BlockHash,
+    /// The block height of the chain tip when the call returned.
+    pub height: u64,
+}
+
+/// Models the result of JSON-RPC method `waitfornewblock`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WaitForNewBlock {
+    /// The blockhash of the chain tip when the call returned.
+    pub hash: BlockHash,
+    /// The block height of the chain tip when the call returned.
+    pub height: u64,
+}
+
+/// Serializes a [`BlockFilter`] as a hex string, the same as Core does.
+mod block_filter_hex {
+    use bitcoin::bip158::BlockFilter;
+    use bitcoin::hex::{DisplayHex, FromHex};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(filter: &BlockFilter, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(&filter.content.as_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<BlockFilter, D::Error> {
+        let hex = String::deserialize(d)?;
+        let content = Vec::<u8>::from_hex(&hex).map_err(serde::de::Error::custom)?;
+        Ok(BlockFilter { content })
+    }
+}
@@ -5,4 +5,117 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
-// Control types currently have no need for model equivalents.
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Models the result of JSON-RPC method `getrpcinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRpcInfo {
+    /// All active commands.
+    pub active_commands: Vec<ActiveCommand>,
+    /// The complete file path to the debug log (v0.20 and later).
+    pub log_path: Option<String>,
+}
+
+/// An active command, part of `getrpcinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ActiveCommand {
+    /// The name of the RPC command.
+    pub method: String,
+    /// The running time of the command.
+    pub duration: Duration,
+}
+
+/// Models the result of JSON-RPC method `logging`.
+///
+/// Map of logging category to whether debug logging is enabled for it, only contains the
+/// categories supported by the queried version of Core.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Logging(pub BTreeMap<LogCategory, bool>);
+
+impl Logging {
+    /// Returns `true` if debug logging is enabled for `category`.
+    pub fn is_enabled(&self, category: LogCategory) -> bool {
+        self.0.get(&category).copied().unwrap_or(false)
+    }
+}
+
+/// A debug logging category, used as an argument to, and in the result of, JSON-RPC method
+/// `logging`.
+///
+/// Not all categories are supported by all versions of Core, passing an unsupported category to
+/// `logging` causes the call to fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogCategory {
+    /// All categories, only valid as an argument.
+    All,
+    /// Network.
+    Net,
+    /// Tor.
+    Tor,
+    /// Mempool.
+    Mempool,
+    /// HTTP server.
+    Http,
+    /// Benchmarks.
+    Bench,
+    /// ZeroMQ.
+    Zmq,
+    /// Wallet database (renamed to `walletdb` in v0.20).
+    Db,
+    /// Wallet database (v0.20 and later).
+    WalletDb,
+    /// RPC server.
+    Rpc,
+    /// Fee estimation.
+    EstimateFee,
+    /// Address manager.
+    AddrMan,
+    /// Coin selection.
+    SelectCoins,
+    /// Reindexing.
+    Reindex,
+    /// Compact blocks.
+    CmpctBlock,
+    /// Random number generation.
+    Rand,
+    /// Pruning.
+    Prune,
+    /// Proxy.
+    Proxy,
+    /// Mempool rejections.
+    MempoolRej,
+    /// Libevent.
+    Libevent,
+    /// Coin database.
+    CoinDb,
+    /// The Qt GUI.
+    Qt,
+    /// LevelDB.
+    LevelDb,
+    /// Validation (v0.20 and later).
+    Validation,
+    /// I2P (v22 and later).
+    I2p,
+    /// Inter-process communication (v22 and later).
+    Ipc,
+    /// Lock contention (v23 and later).
+    Lock,
+    /// Utilities (v23 and later).
+    Util,
+    /// Block storage (v23 and later).
+    BlockStorage,
+    /// Transaction reconciliation (v25 and later).
+    TxReconciliation,
+    /// Block filter index scanning (v25 and later).
+    Scan,
+    /// Transaction packages (v26 and later).
+    TxPackages,
+}
+
+/// Models the result of JSON-RPC method `uptime`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Uptime(pub Duration);
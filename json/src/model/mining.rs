@@ -4,3 +4,150 @@
 //!
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
+
+use std::collections::BTreeMap;
+
+use bitcoin::{
+    block, Amount, BlockHash, CompactTarget, Network, ScriptBuf, SignedAmount, Target, Transaction,
+    Txid, Weight, Wtxid,
+};
+use serde::{Deserialize, Serialize};
+
+/// Models the result of JSON-RPC method `getblocktemplate`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockTemplate {
+    /// The features supported by the server e.g., 'proposal'.
+    pub capabilities: Vec<String>,
+    /// The preferred block version.
+    pub version: block::Version,
+    /// Specific block rules that are to be enforced.
+    pub rules: Vec<String>,
+    /// Set of pending, supported versionbit (BIP 9) softfork deployments, maps rule name to bit
+    /// number.
+    pub version_bits_available: BTreeMap<String, u32>,
+    /// Bit mask of versionbits the server requires set in submissions.
+    pub version_bits_required: u32,
+    /// The hash of current highest block.
+    pub previous_block_hash: BlockHash,
+    /// Contents of non-coinbase transactions that should be included in the next block.
+    pub transactions: Vec<BlockTemplateTransaction>,
+    /// Data that should be included in the coinbase's scriptSig content.
+    pub coinbase_aux: BTreeMap<String, String>,
+    /// Maximum allowable input to coinbase transaction, including the generation award and
+    /// transaction fees.
+    pub coinbase_value: Amount,
+    /// An id to include with a request to longpoll on an update to this template.
+    pub long_poll_id: String,
+    /// The hash target.
+    pub target: Target,
+    /// The minimum timestamp appropriate for next block time in seconds since epoch.
+    pub min_time: u32,
+    /// List of ways the block template may be changed, e.g. 'time', 'transactions', 'prevblock'.
+    pub mutable: Vec<String>,
+    /// A range of valid nonces.
+    pub nonce_range: String,
+    /// Limit of sigops in blocks.
+    pub sigop_limit: u32,
+    /// Limit of block size.
+    pub size_limit: u32,
+    /// Limit of block weight.
+    pub weight_limit: Weight,
+    /// Current timestamp in seconds since epoch.
+    pub current_time: u32,
+    /// Compressed target of next block.
+    pub bits: CompactTarget,
+    /// The height of the next block.
+    pub height: u32,
+    /// Only on signet, the signet challenge.
+    pub signet_challenge: Option<ScriptBuf>,
+    /// A valid witness commitment for the unmodified block template.
+    pub default_witness_commitment: Option<ScriptBuf>,
+}
+
+/// Models a transaction in the result of JSON-RPC method `getblocktemplate`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BlockTemplateTransaction {
+    /// The transaction.
+    pub data: Transaction,
+    /// The transaction id.
+    pub txid: Txid,
+    /// The witness transaction id.
+    pub wtxid: Wtxid,
+    /// Transactions before this one (by 1-based index in 'transactions' list) that must be
+    /// present in the final block if this one is.
+    pub depends: Vec<u32>,
+    /// Difference in value between transaction inputs and outputs.
+    pub fee: Amount,
+    /// Total SigOps cost, as counted for purposes of block limits.
+    pub sigops: u32,
+    /// Total transaction weight, as counted for purposes of block limits.
+    pub weight: Weight,
+}
+
+/// Models the result of JSON-RPC method `getmininginfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMiningInfo {
+    /// The current block.
+    pub blocks: u32,
+    /// The block weight of the last assembled block (only present if a block was ever assembled).
+    pub current_block_weight: Option<Weight>,
+    /// The number of block transactions of the last assembled block (only present if a block was
+    /// ever assembled).
+    pub current_block_tx: Option<u32>,
+    /// The current difficulty.
+    pub difficulty: f64,
+    /// The network hashes per second.
+    pub network_hash_ps: f64,
+    /// The size of the mempool.
+    pub pooled_tx: u32,
+    /// Current network name as defined in BIP70 (main, test, signet, regtest).
+    pub chain: Network,
+    /// Any network and blockchain warnings.
+    pub warnings: Vec<String>,
+}
+
+/// Models the result of JSON-RPC method `getnetworkhashps`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetNetworkHashPs(pub f64);
+
+/// Models the result of JSON-RPC method `getprioritisedtransactions`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPrioritisedTransactions(pub BTreeMap<Txid, PrioritisedTransaction>);
+
+/// Models a prioritised transaction in the result of JSON-RPC method `getprioritisedtransactions`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PrioritisedTransaction {
+    /// Transaction fee delta.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub fee_delta: SignedAmount,
+    /// Whether this transaction is currently in mempool.
+    pub in_mempool: bool,
+    /// Modified fee, only present if the transaction is in the mempool.
+    pub modified_fee: Option<Amount>,
+}
+
+/// Models the result of JSON-RPC method `prioritisetransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PrioritiseTransaction(pub bool);
+
+/// Models the result of JSON-RPC method `submitblock`, the BIP22 result of submitting a block.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SubmitBlock {
+    /// The block was accepted.
+    Accepted,
+    /// The block was already known.
+    Duplicate,
+    /// The block was already known and is invalid.
+    DuplicateInvalid,
+    /// The block was already known but has not been fully validated.
+    DuplicateInconclusive,
+    /// The block is valid but was not accepted as the best chain e.g., it is on a fork.
+    Inconclusive,
+    /// The block was rejected, with the reason e.g., "high-hash" or "bad-txnmrklroot".
+    Rejected(String),
+}
+
+impl SubmitBlock {
+    /// Returns true if the block was accepted.
+    pub fn is_accepted(&self) -> bool { *self == SubmitBlock::Accepted }
+}
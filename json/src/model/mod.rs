@@ -26,18 +26,53 @@ mod zmq;
 #[doc(inline)]
 pub use self::{
     blockchain::{
-        Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, ChainTips, ChainTipsStatus,
-        GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderVerbose,
-        GetBlockStats, GetBlockVerbosityOne, GetBlockVerbosityZero, GetBlockchainInfo,
-        GetChainTips, GetChainTxStats, GetDifficulty, GetMempoolAncestors,
-        GetMempoolAncestorsVerbose, GetTxOut, Softfork, SoftforkType,
+        Bip9DeploymentInfo, Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus,
+        BlockTransaction, BlockTransactionWithPrevouts, ChainState, ChainTips, ChainTipsStatus,
+        DeploymentInfo, DumpTxOutSet, GetBestBlockHash, GetBlockCount, GetBlockFilter,
+        GetBlockHash, GetBlockHeader, GetBlockHeaderVerbose, GetBlockStats, GetBlockVerbosityOne,
+        GetBlockVerbosityThree, GetBlockVerbosityTwo, GetBlockVerbosityZero, GetBlockchainInfo,
+        GetChainStates, GetChainTips, GetChainTxStats, GetDeploymentInfo, GetDifficulty,
+        GetMempoolAncestors, GetMempoolAncestorsVerbose, GetMempoolDescendants,
+        GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo, GetRawMempool,
+        GetRawMempoolSequence, GetRawMempoolVerbose, GetTxOut, GetTxOutSetInfo, LoadTxOutSet,
+        MempoolEntry, MempoolEntryFees, ScanBlocksAbort, ScanBlocksStart, ScanBlocksStatus,
+        ScanTxOutSetAbort, ScanTxOutSetStart, ScanTxOutSetStatus, ScanTxOutSetUnspent, Softfork,
+        SoftforkType, TxOutSetBlockInfo, TxOutSetUnspendables, WaitForBlock, WaitForBlockHeight,
+        WaitForNewBlock,
     },
+    control::{ActiveCommand, GetRpcInfo, LogCategory, Logging, Uptime},
     generating::{Generate, GenerateToAddress},
-    network::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork},
-    raw_transactions::SendRawTransaction,
+    mining::{
+        BlockTemplateTransaction, GetBlockTemplate, GetMiningInfo, GetNetworkHashPs,
+        GetPrioritisedTransactions, PrioritiseTransaction, PrioritisedTransaction, SubmitBlock,
+    },
+    network::{
+        AddrManInfoNetwork, Banned, GetAddrManInfo, GetConnectionCount, GetNetworkInfo,
+        GetNetworkInfoAddress, GetNetworkInfoNetwork, GetNodeAddresses, GetPeerInfo, ListBanned,
+        NodeAddress, PeerInfo,
+    },
+    raw_transactions::{
+        AnalyzePsbt, AnalyzePsbtInput, AnalyzePsbtInputMissing, CombinePsbt, CombineRawTransaction,
+        ConvertToPsbt, CreatePsbt, CreateRawTransaction, DecodePsbt, DecodeRawTransaction,
+        DecodeScript, DecodeScriptSegwit, DescriptorProcessPsbt, FinalizePsbt, FundRawTransaction,
+        GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerbosityTwo, JoinPsbts,
+        MempoolAcceptance, MempoolAcceptanceFees, PsbtRole, RawTransactionPrevout, RejectReason,
+        SendRawTransaction, SignFail, SignRawTransaction, SubmitPackage, SubmitPackageTxResult,
+        TestMempoolAccept, UtxoUpdatePsbt,
+    },
+    util::{
+        CreateMultisig, DeriveAddresses, EstimateSmartFee, GetDescriptorInfo, GetIndexInfo,
+        IndexInfo, SignMessageWithPrivKey, ValidateAddress, VerifyMessage,
+    },
     wallet::{
-        CreateWallet, GetBalance, GetBalances, GetBalancesMine, GetBalancesWatchOnly,
-        GetNewAddress, GetTransaction, GetTransactionDetail, GetTransactionDetailCategory,
-        LoadWallet, SendToAddress, UnloadWallet,
+        AddressPurpose, BumpFee, CreateWallet, CreateWalletDescriptor, GetAddressInfo,
+        GetAddressInfoEmbedded, GetAddressesByLabel, GetBalance, GetBalances, GetBalancesMine,
+        GetBalancesWatchOnly, GetHdKeys, GetNewAddress, GetTransaction, GetTransactionDetail,
+        GetTransactionDetailCategory, GetWalletInfo, GetWalletInfoScanning, HdKey, HdKeyDescriptor,
+        ImportDescriptors, ImportDescriptorsResult, ImportMulti, ImportMultiResult, JsonRpcError,
+        LastProcessedBlock, ListDescriptors, ListDescriptorsItem, ListLabels, ListSinceBlock,
+        ListTransactions, ListUnspent, ListUnspentItem, ListWalletDir, ListWallets, LoadWallet,
+        PsbtBumpFee, Send, SendAll, SendMany, SendManyVerbose, SendToAddress, SignMessage,
+        TransactionItem, UnloadWallet, WalletCreateFundedPsbt, WalletProcessPsbt,
     },
 };
@@ -5,6 +5,8 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
+use std::collections::BTreeMap;
+
 use bitcoin::FeeRate;
 use serde::{Deserialize, Serialize};
 
@@ -64,3 +66,158 @@ pub struct GetNetworkInfoAddress {
     /// Relative score.
     pub score: u32,
 }
+
+/// Models the result of JSON-RPC method `getaddrmaninfo`.
+///
+/// Map of network name (e.g. "ipv4") to address manager statistics for that network.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddrManInfo(pub BTreeMap<String, AddrManInfoNetwork>);
+
+/// Address manager statistics for a single network, part of `getaddrmaninfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddrManInfoNetwork {
+    /// Number of addresses in the new table.
+    pub new: u64,
+    /// Number of addresses in the tried table.
+    pub tried: u64,
+    /// Total number of addresses in both new and tried tables.
+    pub total: u64,
+}
+
+/// Models the result of JSON-RPC method `getconnectioncount`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetConnectionCount(pub u64);
+
+/// Models the result of JSON-RPC method `getnodeaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetNodeAddresses(pub Vec<NodeAddress>);
+
+/// An item from the list returned by the JSON-RPC method `getnodeaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NodeAddress {
+    /// The UNIX epoch time of when the node was last seen.
+    pub time: u64,
+    /// The services offered.
+    pub services: u64,
+    /// The address of the node.
+    pub address: String,
+    /// The port of the node.
+    pub port: u16,
+    /// The network the node connected through (v22 and later).
+    pub network: Option<String>,
+}
+
+/// Models the result of JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+/// An item from the list returned by the JSON-RPC method `getpeerinfo`.
+///
+/// Fields that are not returned by all versions of Core are optional.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u32,
+    /// The IP address and port of the peer ("host:port").
+    pub address: String,
+    /// Bind address of the connection to the peer ("ip:port").
+    pub address_bind: Option<String>,
+    /// Local address as reported by the peer.
+    pub address_local: Option<String>,
+    /// Network (ipv4, ipv6, onion, i2p, cjdns, not_publicly_routable) (v21 and later).
+    pub network: Option<String>,
+    /// The AS in the BGP route to the peer used for diversifying peer selection (v21 and later).
+    pub mapped_as: Option<u32>,
+    /// The services offered.
+    pub services: String,
+    /// The services offered, in human-readable form (v19 and later).
+    pub services_names: Option<Vec<String>>,
+    /// Whether peer has asked us to relay transactions to it.
+    pub relay_transactions: Option<bool>,
+    /// The time in seconds since epoch of the last send.
+    pub last_send: u32,
+    /// The time in seconds since epoch of the last receive.
+    pub last_received: u32,
+    /// The time in seconds since epoch of the last valid transaction received (v21 and later).
+    pub last_transaction: Option<u32>,
+    /// The time in seconds since epoch of the last block received (v21 and later).
+    pub last_block: Option<u32>,
+    /// The total bytes sent.
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    pub bytes_received: u64,
+    /// The connection time in seconds since epoch.
+    pub connection_time: u32,
+    /// The time offset in seconds.
+    pub time_offset: i64,
+    /// Ping time in seconds (if available).
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time in seconds (if any at all).
+    pub minimum_ping: Option<f64>,
+    /// Ping wait in seconds (if non-zero).
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version (e.g. "/Satoshi:0.8.5/").
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// Whether we selected peer as (compact blocks) high-bandwidth peer (v22 and later).
+    pub bip152_hb_to: Option<bool>,
+    /// Whether peer selected us as (compact blocks) high-bandwidth peer (v22 and later).
+    pub bip152_hb_from: Option<bool>,
+    /// Whether connection was due to addnode/-connect (before v21).
+    pub add_node: Option<bool>,
+    /// The starting height (block) of the peer.
+    pub starting_height: Option<i64>,
+    /// The ban score (before v22).
+    pub ban_score: Option<u64>,
+    /// The last header we have in common with this peer.
+    pub synced_headers: i64,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: i64,
+    /// The heights of blocks we're currently asking from this peer.
+    pub inflight: Vec<u32>,
+    /// Whether we participate in address relay with this peer (v23 and later).
+    pub addr_relay_enabled: Option<bool>,
+    /// The total number of addresses processed, excluding those dropped due to rate limiting
+    /// (v22 and later).
+    pub addr_processed: Option<u64>,
+    /// The total number of addresses dropped due to rate limiting (v22 and later).
+    pub addr_rate_limited: Option<u64>,
+    /// Any special permissions that have been granted to this peer (v19 and later).
+    pub permissions: Vec<String>,
+    /// Whether the peer is whitelisted (before v21).
+    pub whitelisted: Option<bool>,
+    /// The minimum fee rate for transactions this peer accepts (v19 and later).
+    pub minimum_fee_filter: Option<FeeRate>,
+    /// The total bytes sent aggregated by message type.
+    pub bytes_sent_per_message: BTreeMap<String, u64>,
+    /// The total bytes received aggregated by message type.
+    pub bytes_received_per_message: BTreeMap<String, u64>,
+    /// Type of connection e.g., "outbound-full-relay" (v21 and later).
+    pub connection_type: Option<String>,
+    /// Type of transport protocol, one of "detecting", "v1" or "v2" (v26 and later).
+    pub transport_protocol_type: Option<String>,
+    /// The session ID for this connection, or empty if there is none (v26 and later).
+    pub session_id: Option<String>,
+}
+
+/// Models the result of JSON-RPC method `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListBanned(pub Vec<Banned>);
+
+/// An item from the list returned by the JSON-RPC method `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Banned {
+    /// The banned IP address or subnet.
+    pub address: String,
+    /// The UNIX epoch time the ban was created.
+    pub ban_created: u32,
+    /// The UNIX epoch time the ban expires.
+    pub banned_until: u32,
+    /// The ban duration in seconds (v22 and later).
+    pub ban_duration: Option<u32>,
+    /// The time remaining until the ban expires in seconds (v22 and later).
+    pub time_remaining: Option<u32>,
+}
@@ -5,9 +5,394 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
-use bitcoin::Txid;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::{
+    Address, Amount, BlockHash, FeeRate, Psbt, PubkeyHash, ScriptBuf, ScriptHash, Sequence,
+    Transaction, TxOut, Txid, WScriptHash, Witness, Wtxid,
+};
 use serde::{Deserialize, Serialize};
 
+/// Models the result of JSON-RPC method `analyzepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AnalyzePsbt {
+    /// The analysis of each input.
+    pub inputs: Vec<AnalyzePsbtInput>,
+    /// Estimated vsize of the final signed transaction.
+    pub estimated_vsize: Option<u32>,
+    /// Estimated feerate of the final signed transaction. Shown only if all UTXO slots in the PSBT
+    /// have been filled.
+    pub estimated_fee_rate: Option<FeeRate>,
+    /// The transaction fee paid. Shown only if all UTXO slots in the PSBT have been filled.
+    pub fee: Option<Amount>,
+    /// Role of the next person that this PSBT needs to go to.
+    pub next: PsbtRole,
+    /// Error message if there is one.
+    pub error: Option<String>,
+}
+
+/// Models the analysis of a single input. Part of `analyzepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AnalyzePsbtInput {
+    /// Whether a UTXO is provided.
+    pub has_utxo: bool,
+    /// Whether the input is finalized.
+    pub is_final: bool,
+    /// Things that are missing that are required to complete this input.
+    pub missing: Option<AnalyzePsbtInputMissing>,
+    /// Role of the next person that this input needs to go to.
+    pub next: Option<PsbtRole>,
+}
+
+/// Models the things missing to complete an input. Part of `analyzepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AnalyzePsbtInputMissing {
+    /// Public key IDs of public keys whose BIP-32 derivation paths are missing.
+    pub pubkeys: Vec<PubkeyHash>,
+    /// Public key IDs of public keys whose signatures are missing.
+    pub signatures: Vec<PubkeyHash>,
+    /// Hash160 of the redeem script that is missing.
+    pub redeem_script: Option<ScriptHash>,
+    /// SHA256 of the witness script that is missing.
+    pub witness_script: Option<WScriptHash>,
+}
+
+/// The BIP-174 role of the next participant that needs to handle a PSBT. Part of `analyzepsbt`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum PsbtRole {
+    Creator,
+    Updater,
+    Signer,
+    Finalizer,
+    Extractor,
+}
+
+/// Models the result of JSON-RPC method `combinepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CombinePsbt(pub Psbt);
+
+/// Models the result of JSON-RPC method `combinerawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CombineRawTransaction(pub Transaction);
+
+/// Models the result of JSON-RPC method `converttopsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ConvertToPsbt(pub Psbt);
+
+/// Models the result of JSON-RPC method `createpsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CreatePsbt(pub Psbt);
+
+/// Models the result of JSON-RPC method `createrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CreateRawTransaction(pub Transaction);
+
+/// Models the result of JSON-RPC method `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The PSBT, rebuilt from the decoded fields.
+    pub psbt: Psbt,
+    /// The transaction fee paid if all UTXOs slots in the PSBT have been filled.
+    pub fee: Option<Amount>,
+}
+
+/// Models the result of JSON-RPC method `decoderawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeRawTransaction(pub Transaction);
+
+/// Models the result of JSON-RPC method `decodescript`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeScript {
+    /// The output type.
+    pub type_: String,
+    /// The required signatures, only returned by Core before v22.
+    pub required_signatures: Option<u32>,
+    /// The addresses, from v22 onwards Core returns at most one address.
+    pub addresses: Vec<Address<NetworkUnchecked>>,
+    /// Address of P2SH script wrapping this redeem script (not returned if the script is already a P2SH).
+    pub p2sh: Option<Address<NetworkUnchecked>>,
+    /// Result of a witness script public key wrapping this redeem script (not returned if the
+    /// script is a P2SH or witness).
+    pub segwit: Option<DecodeScriptSegwit>,
+}
+
+/// Models the segwit part of the result of JSON-RPC method `decodescript`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeScriptSegwit {
+    /// The witness script public key.
+    pub script_pubkey: ScriptBuf,
+    /// The output type.
+    pub type_: String,
+    /// The required signatures, only returned by Core before v22.
+    pub required_signatures: Option<u32>,
+    /// The addresses, from v22 onwards Core returns at most one address.
+    pub addresses: Vec<Address<NetworkUnchecked>>,
+    /// Address of the P2SH script wrapping this witness redeem script.
+    pub p2sh_segwit: Option<Address<NetworkUnchecked>>,
+}
+
+/// Models the result of JSON-RPC method `descriptorprocesspsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DescriptorProcessPsbt {
+    /// The partially signed transaction.
+    pub psbt: Psbt,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction if complete.
+    pub tx: Option<Transaction>,
+}
+
+/// Models the result of JSON-RPC method `finalizepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FinalizePsbt {
+    /// The partially signed transaction if not extracted.
+    pub psbt: Option<Psbt>,
+    /// The transaction if extracted.
+    pub tx: Option<Transaction>,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+}
+
+/// Models the result of JSON-RPC method `fundrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FundRawTransaction {
+    /// The resulting raw transaction.
+    pub tx: Transaction,
+    /// Fee the resulting transaction pays.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub fee: Amount,
+    /// The position of the added change output, or `None` if no change output was added.
+    pub change_position: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `getrawtransaction` with verbose set to `false`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransaction(pub Transaction);
+
+/// Models the result of JSON-RPC method `getrawtransaction` with verbose set to `true`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransactionVerbose {
+    /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
+    pub in_active_chain: Option<bool>,
+    /// The transaction.
+    pub transaction: Transaction,
+    /// The block hash.
+    pub block_hash: Option<BlockHash>,
+    /// The confirmations.
+    pub confirmations: Option<u32>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    pub transaction_time: Option<u32>,
+    /// The block time in seconds since epoch (Jan 1 1970 GMT).
+    pub block_time: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `getrawtransaction` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransactionVerbosityTwo {
+    /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
+    pub in_active_chain: Option<bool>,
+    /// The transaction.
+    pub transaction: Transaction,
+    /// The transaction fee, omitted if block undo data is not available.
+    pub fee: Option<Amount>,
+    /// The output spent by each input, in input order. `None` if undo data is not available.
+    pub prevouts: Vec<Option<RawTransactionPrevout>>,
+    /// The block hash.
+    pub block_hash: Option<BlockHash>,
+    /// The confirmations.
+    pub confirmations: Option<u32>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    pub transaction_time: Option<u32>,
+    /// The block time in seconds since epoch (Jan 1 1970 GMT).
+    pub block_time: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `joinpsbts`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct JoinPsbts(pub Psbt);
+
+/// Models the output spent by a transaction input. Part of `getrawtransaction` and `getblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RawTransactionPrevout {
+    /// Coinbase or not.
+    pub generated: bool,
+    /// The height of the prevout.
+    pub height: u32,
+    /// The spent output.
+    pub tx_out: TxOut,
+    /// The address the output pays to (only if a well-defined address exists).
+    pub address: Option<Address<NetworkUnchecked>>,
+}
+
 /// Models the result of JSON-RPC method `sendrawtransaction`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct SendRawTransaction(pub Txid);
+
+/// Models the result of JSON-RPC methods `signrawtransactionwithkey` and
+/// `signrawtransactionwithwallet`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SignRawTransaction {
+    /// The transaction with any signatures added.
+    pub tx: Transaction,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The inputs that could not be signed, empty if there were none.
+    pub errors: Vec<SignFail>,
+}
+
+/// An input that could not be signed. Part of `signrawtransactionwithkey` and
+/// `signrawtransactionwithwallet`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignFail {
+    /// The transaction id of the referenced previous output.
+    pub txid: Txid,
+    /// The output index of the referenced previous output.
+    pub vout: u32,
+    /// The witness of the input.
+    pub witness: Witness,
+    /// The unlocking script of the input.
+    pub script_sig: ScriptBuf,
+    /// The input sequence number.
+    pub sequence: Sequence,
+    /// Verification or signing error related to the input.
+    pub error: String,
+}
+
+/// Models the result of JSON-RPC method `submitpackage`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmitPackage {
+    /// The transaction package result message, "success" if all transactions were accepted into
+    /// or are already in the mempool (v28 and later).
+    pub package_msg: Option<String>,
+    /// Transaction results keyed by wtxid.
+    pub tx_results: BTreeMap<Wtxid, SubmitPackageTxResult>,
+    /// The txids of any transactions replaced.
+    pub replaced_transactions: Vec<Txid>,
+}
+
+/// Models the result of submitting a single package transaction. Part of `submitpackage`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmitPackageTxResult {
+    /// The transaction ID.
+    pub txid: Txid,
+    /// The wtxid of a different transaction with the same txid but different witness found in
+    /// the mempool, if so the submitted transaction was ignored.
+    pub other_wtxid: Option<Wtxid>,
+    /// Sigops-adjusted virtual transaction size.
+    pub vsize: Option<u32>,
+    /// Transaction fees.
+    pub fees: Option<MempoolAcceptanceFees>,
+    /// The reason the transaction was rejected by the mempool, if it was (v28 and later).
+    pub error: Option<RejectReason>,
+    /// The error string returned by Core, including any details e.g., "min relay fee not met,
+    /// 100 < 141" (v28 and later).
+    pub error_message: Option<String>,
+}
+
+/// Models the result of JSON-RPC method `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TestMempoolAccept {
+    /// Test results for each raw transaction in the input array.
+    pub results: Vec<MempoolAcceptance>,
+}
+
+/// Models a single mempool acceptance test result. Part of `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptance {
+    /// The transaction ID.
+    pub txid: Txid,
+    /// The transaction witness hash (v22 and later).
+    pub wtxid: Option<Wtxid>,
+    /// Package validation error, if any (v22 and later).
+    pub package_error: Option<String>,
+    /// If the mempool allows this tx to be inserted.
+    ///
+    /// From v22 Core omits this if the transaction was not fully validated because another
+    /// transaction in the package failed, we model that as `false`.
+    pub allowed: bool,
+    /// Virtual transaction size as defined in BIP 141 (only present when 'allowed' is true, v21
+    /// and later).
+    pub vsize: Option<u32>,
+    /// Transaction fees (only present when 'allowed' is true, v21 and later).
+    pub fees: Option<MempoolAcceptanceFees>,
+    /// Rejection reason (only present when 'allowed' is false).
+    pub reject_reason: Option<RejectReason>,
+    /// The rejection string returned by Core, including any details e.g., "min relay fee not met,
+    /// 100 < 141" (only present when 'allowed' is false).
+    pub reject_message: Option<String>,
+}
+
+/// Models the fees of a transaction accepted, or that would be accepted, to the mempool.
+///
+/// Part of `testmempoolaccept` and `submitpackage`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptanceFees {
+    /// Transaction fee.
+    pub base: Amount,
+    /// The effective feerate, may differ from the base feerate if the transaction was part of a
+    /// package (v25 and later).
+    pub effective_fee_rate: Option<FeeRate>,
+    /// The wtxids of transactions whose fees and vsizes are included in `effective_fee_rate`
+    /// (v25 and later).
+    pub effective_includes: Option<Vec<Wtxid>>,
+}
+
+/// Models the reason a transaction was rejected from the mempool.
+///
+/// Part of `testmempoolaccept` and `submitpackage`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RejectReason {
+    /// The transaction is already in the mempool (`txn-already-in-mempool`).
+    AlreadyInMempool,
+    /// The transaction is already known e.g., it is in a block (`txn-already-known`).
+    AlreadyKnown,
+    /// One or more inputs are unknown (`missing-inputs`).
+    MissingInputs,
+    /// One or more inputs are missing or already spent (`bad-txns-inputs-missingorspent`).
+    InputsMissingOrSpent,
+    /// The transaction conflicts with a mempool transaction (`txn-mempool-conflict`).
+    MempoolConflict,
+    /// The transaction does not pay enough fee to replace a mempool transaction (`insufficient fee`).
+    InsufficientFee,
+    /// The transaction fee is below the dynamic mempool minimum (`mempool min fee not met`).
+    MempoolMinFeeNotMet,
+    /// The transaction fee is below the minimum relay fee (`min relay fee not met`).
+    MinRelayFeeNotMet,
+    /// The transaction fee exceeds the maximum fee rate (`max-fee-exceeded`, `absurdly-high-fee`
+    /// before v19).
+    MaxFeeExceeded,
+    /// The transaction is not final (`non-final`).
+    NonFinal,
+    /// The transaction has a dust output (`dust`).
+    Dust,
+    /// Any other reason, with the string returned by Core e.g., "bad-txns-vout-empty".
+    Other(String),
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use RejectReason::*;
+
+        let s = match *self {
+            AlreadyInMempool => "txn-already-in-mempool",
+            AlreadyKnown => "txn-already-known",
+            MissingInputs => "missing-inputs",
+            InputsMissingOrSpent => "bad-txns-inputs-missingorspent",
+            MempoolConflict => "txn-mempool-conflict",
+            InsufficientFee => "insufficient fee",
+            MempoolMinFeeNotMet => "mempool min fee not met",
+            MinRelayFeeNotMet => "min relay fee not met",
+            MaxFeeExceeded => "max-fee-exceeded",
+            NonFinal => "non-final",
+            Dust => "dust",
+            Other(ref s) => s,
+        };
+        f.write_str(s)
+    }
+}
+
+/// Models the result of JSON-RPC method `utxoupdatepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UtxoUpdatePsbt(pub Psbt);
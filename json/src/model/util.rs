@@ -4,3 +4,133 @@
 //!
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
+
+use std::collections::BTreeMap;
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::{Address, FeeRate, ScriptBuf, WitnessProgram};
+use serde::{Deserialize, Serialize};
+
+/// Models the result of JSON-RPC method `createmultisig`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateMultisig {
+    /// The value of the new multisig address.
+    pub address: Address<NetworkUnchecked>,
+    /// The redeem script.
+    pub redeem_script: ScriptBuf,
+    /// The descriptor for this multisig (v0.20 and later).
+    pub descriptor: Option<String>,
+    /// Any warnings resulting from the creation of this multisig (v23 and later).
+    pub warnings: Vec<String>,
+}
+
+/// Models the result of JSON-RPC method `deriveaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeriveAddresses(pub Vec<Address<NetworkUnchecked>>);
+
+/// Models the result of JSON-RPC method `estimatesmartfee`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EstimateSmartFee {
+    /// Estimate fee rate.
+    pub fee_rate: Option<FeeRate>,
+    /// Errors encountered during processing.
+    pub errors: Vec<String>,
+    /// Block number where estimate was found.
+    pub blocks: u32,
+}
+
+/// Models the result of JSON-RPC method `getdescriptorinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetDescriptorInfo {
+    /// The descriptor in canonical form, without private keys.
+    pub descriptor: String,
+    /// The checksum for the input descriptor.
+    pub checksum: String,
+    /// Whether the descriptor is ranged.
+    pub is_range: bool,
+    /// Whether the descriptor is solvable.
+    pub is_solvable: bool,
+    /// Whether the input descriptor contained at least one private key.
+    pub has_private_keys: bool,
+}
+
+/// Models the result of JSON-RPC method `getindexinfo`.
+///
+/// Map of index name to the index status, only contains running indices.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetIndexInfo(pub BTreeMap<String, IndexInfo>);
+
+/// The status of a single index, part of `getindexinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IndexInfo {
+    /// Whether the index is synced or not.
+    pub synced: bool,
+    /// The block height to which the index is synced.
+    pub best_block_height: u32,
+}
+
+/// Models the result of JSON-RPC method `signmessagewithprivkey`.
+///
+/// The signature of the message, encoded in base 64.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignMessageWithPrivKey(pub String);
+
+/// Models the result of JSON-RPC method `validateaddress`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValidateAddress {
+    /// If the address is valid or not. If not, this is the only property returned before v23.
+    pub is_valid: bool,
+    /// The bitcoin address validated.
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The script pubkey generated by the address.
+    pub script_pubkey: Option<ScriptBuf>,
+    /// If the key is a script.
+    pub is_script: Option<bool>,
+    /// If the address is a witness address.
+    pub is_witness: Option<bool>,
+    /// The witness version and program, if the address is a witness address.
+    #[serde(with = "opt_witness_program")]
+    pub witness_program: Option<WitnessProgram>,
+    /// Error message, if any (v23 and later).
+    pub error: Option<String>,
+    /// Indices of likely error locations in the address, if known (v23 and later).
+    pub error_locations: Option<Vec<u32>>,
+}
+
+/// Models the result of JSON-RPC method `verifymessage`.
+///
+/// `true` if the signature is valid for the message and address.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VerifyMessage(pub bool);
+
+/// Serializes a witness program as its version and hex-encoded program.
+pub(super) mod opt_witness_program {
+    use bitcoin::hex::{DisplayHex, FromHex};
+    use bitcoin::{WitnessProgram, WitnessVersion};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        program: &Option<WitnessProgram>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        program
+            .as_ref()
+            .map(|p| (p.version().to_num(), p.program().as_bytes().to_lower_hex_string()))
+            .serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Option<WitnessProgram>, D::Error> {
+        use serde::de::Error;
+
+        match Option::<(u8, String)>::deserialize(d)? {
+            Some((version, hex)) => {
+                let version = WitnessVersion::try_from(version).map_err(D::Error::custom)?;
+                let program = Vec::<u8>::from_hex(&hex).map_err(D::Error::custom)?;
+                WitnessProgram::new(version, &program).map(Some).map_err(D::Error::custom)
+            }
+            None => Ok(None),
+        }
+    }
+}
@@ -5,8 +5,17 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
+use std::collections::BTreeMap;
+
 use bitcoin::address::{Address, NetworkUnchecked};
-use bitcoin::{Amount, SignedAmount, Transaction, Txid};
+use bitcoin::bip32::{DerivationPath, Fingerprint, Xpriv, Xpub};
+use bitcoin::hashes::hash160;
+use bitcoin::{
+    Amount, BlockHash, FeeRate, OutPoint, Psbt, PublicKey, ScriptBuf, SignedAmount, Transaction,
+    Txid, WitnessProgram, Wtxid,
+};
+#[cfg(feature = "miniscript")]
+use miniscript::{Descriptor, DescriptorPublicKey};
 use serde::{Deserialize, Serialize};
 
 /// Models the result of JSON-RPC method  `createwallet`.
@@ -36,6 +45,122 @@ pub struct UnloadWallet {
     pub warnings: Vec<String>,
 }
 
+/// Models the result of JSON-RPC method `bumpfee`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BumpFee {
+    /// The id of the new transaction.
+    pub txid: Txid,
+    /// Fee of the replaced transaction.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub original_fee: Amount,
+    /// Fee of the new transaction.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub fee: Amount,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+/// Models the result of JSON-RPC method `getaddressesbylabel`.
+///
+/// Map of the addresses with the label to the purpose of each address.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressesByLabel(pub BTreeMap<Address<NetworkUnchecked>, AddressPurpose>);
+
+/// The purpose of an address in the wallet's address book.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum AddressPurpose {
+    /// A sending address.
+    Send,
+    /// A receiving address.
+    Receive,
+    /// A refund address.
+    Refund,
+}
+
+/// Models the result of JSON-RPC method `getaddressinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfo {
+    /// The address validated.
+    pub address: Address<NetworkUnchecked>,
+    /// The script pubkey generated by the address.
+    pub script_pubkey: ScriptBuf,
+    /// If the address is yours.
+    pub is_mine: bool,
+    /// If the address is watchonly.
+    pub is_watch_only: bool,
+    /// If we know how to spend coins sent to this address, ignoring the possible lack of private
+    /// keys (v0.18 onwards).
+    pub solvable: Option<bool>,
+    /// A descriptor for spending coins sent to this address, only present when solvable (v0.18
+    /// onwards).
+    pub descriptor: Option<String>,
+    /// If the key is a script.
+    pub is_script: bool,
+    /// If the address was used for change output (v0.18 onwards).
+    pub is_change: Option<bool>,
+    /// If the address is a witness address.
+    pub is_witness: bool,
+    /// The witness version and program, if the address is a witness address.
+    #[serde(with = "super::util::opt_witness_program")]
+    pub witness_program: Option<WitnessProgram>,
+    /// The output script type, only if the address is a P2SH or P2WSH address.
+    ///
+    /// One of `nonstandard`, `pubkey`, `pubkeyhash`, `scripthash`, `multisig`, `nulldata`,
+    /// `witness_v0_keyhash`, `witness_v0_scripthash`, `witness_v1_taproot` or `witness_unknown`.
+    pub script: Option<String>,
+    /// The redeem script for the P2SH or P2WSH address.
+    pub hex: Option<ScriptBuf>,
+    /// The public keys associated with the known redeem script (only if `script` is `multisig`).
+    pub pubkeys: Option<Vec<PublicKey>>,
+    /// The number of signatures required to spend multisig output (only if `script` is
+    /// `multisig`).
+    pub sigs_required: Option<u32>,
+    /// The public key for the address, if known.
+    pub pubkey: Option<PublicKey>,
+    /// Information about the address embedded in P2SH or P2WSH, if relevant and known.
+    pub embedded: Option<GetAddressInfoEmbedded>,
+    /// If the pubkey is compressed.
+    pub is_compressed: Option<bool>,
+    /// The creation time of the key, if available, expressed in UNIX epoch time.
+    pub timestamp: Option<u32>,
+    /// The HD keypath, if the key is HD and available.
+    pub hd_key_path: Option<DerivationPath>,
+    /// The Hash160 of the HD seed.
+    pub hd_seed_id: Option<hash160::Hash>,
+    /// The fingerprint of the master key (v0.18 onwards).
+    pub hd_master_fingerprint: Option<Fingerprint>,
+    /// The labels associated with the address.
+    pub labels: Vec<String>,
+}
+
+/// The address embedded in P2SH or P2WSH, part of `getaddressinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfoEmbedded {
+    /// The embedded address.
+    pub address: Address<NetworkUnchecked>,
+    /// The script pubkey generated by the embedded address.
+    pub script_pubkey: ScriptBuf,
+    /// If the key is a script.
+    pub is_script: Option<bool>,
+    /// If the address is a witness address.
+    pub is_witness: Option<bool>,
+    /// The witness version and program, if the address is a witness address.
+    #[serde(with = "super::util::opt_witness_program")]
+    pub witness_program: Option<WitnessProgram>,
+    /// The output script type.
+    pub script: Option<String>,
+    /// The redeem script for the P2SH or P2WSH address.
+    pub hex: Option<ScriptBuf>,
+    /// The public keys associated with the known redeem script.
+    pub pubkeys: Option<Vec<PublicKey>>,
+    /// The number of signatures required to spend multisig output.
+    pub sigs_required: Option<u32>,
+    /// The public key for the address, if known.
+    pub pubkey: Option<PublicKey>,
+    /// If the pubkey is compressed.
+    pub is_compressed: Option<bool>,
+}
+
 /// Models the result of JSON-RPC method `getbalance`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBalance(pub Amount);
@@ -74,10 +199,128 @@ pub struct GetBalancesWatchOnly {
     pub immature: Amount,
 }
 
+/// Models the result of JSON-RPC method `getwalletinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    pub wallet_name: String,
+    /// The wallet version.
+    pub wallet_version: u32,
+    /// The database format, `bdb` or `sqlite` (v21 onwards).
+    pub format: Option<String>,
+    /// The total confirmed balance of the wallet (deprecated in later versions).
+    pub balance: Option<Amount>,
+    /// The total unconfirmed balance of the wallet (deprecated in later versions).
+    pub unconfirmed_balance: Option<Amount>,
+    /// The total immature balance of the wallet (deprecated in later versions).
+    pub immature_balance: Option<Amount>,
+    /// The total number of transactions in the wallet.
+    pub tx_count: u32,
+    /// The UNIX epoch time of the oldest pre-generated key in the key pool (legacy wallets only).
+    pub keypool_oldest: Option<u32>,
+    /// How many new keys are pre-generated (only counts external keys).
+    pub keypool_size: u32,
+    /// How many new keys are pre-generated for internal use (only appears if the wallet is using
+    /// this feature).
+    pub keypool_size_hd_internal: Option<u32>,
+    /// The UNIX epoch time until which the wallet is unlocked for transfers, or 0 if the wallet
+    /// is locked (only present for passphrase-encrypted wallets).
+    pub unlocked_until: Option<u32>,
+    /// The transaction fee configuration, `None` if not set.
+    pub pay_tx_fee: Option<FeeRate>,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    pub hd_seed_id: Option<hash160::Hash>,
+    /// False if private keys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse (v0.19 onwards).
+    pub avoid_reuse: Option<bool>,
+    /// Current scanning details, `None` before v0.18.
+    pub scanning: Option<GetWalletInfoScanning>,
+    /// Whether this wallet uses descriptors for output script management (v21 onwards).
+    pub descriptors: Option<bool>,
+    /// Whether this wallet is configured to use an external signer (v23 onwards).
+    pub external_signer: Option<bool>,
+    /// Whether this wallet intentionally does not contain any keys, scripts, or descriptors (v26
+    /// onwards).
+    pub blank: Option<bool>,
+    /// The start time for blocks scanning, expressed in UNIX epoch time (v26 onwards).
+    pub birthtime: Option<u32>,
+    /// The hash and height of the block this information was generated on (v26 onwards).
+    pub last_processed_block: Option<LastProcessedBlock>,
+}
+
+/// The wallet scanning state, part of `getwalletinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub enum GetWalletInfoScanning {
+    /// The wallet is not scanning.
+    NotScanning,
+    /// The wallet is scanning.
+    Scanning {
+        /// Elapsed seconds since scan start.
+        duration: u64,
+        /// Scanning progress percentage, between 0.0 and 1.0.
+        progress: f64,
+    },
+}
+
+/// Hash and height of the block this information was generated on, part of `getwalletinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LastProcessedBlock {
+    /// Hash of the block this information was generated on.
+    pub hash: BlockHash,
+    /// Height of the block this information was generated on.
+    pub height: u32,
+}
+
 /// Models the result of JSON-RPC method `getnewaddress`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetNewAddress(pub Address<NetworkUnchecked>);
 
+/// Models the result of JSON-RPC method `send`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Send {
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction id for the send, only returned if the transaction was complete.
+    pub txid: Option<Txid>,
+    /// The transaction, only returned if it was complete and not added to the wallet.
+    pub tx: Option<Transaction>,
+    /// The partially signed transaction, only returned if the transaction was not complete.
+    pub psbt: Option<Psbt>,
+}
+
+/// Models the result of JSON-RPC method `sendall`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SendAll {
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction id for the send, only returned if the transaction was complete.
+    pub txid: Option<Txid>,
+    /// The transaction, only returned if it was complete and not added to the wallet.
+    pub tx: Option<Transaction>,
+    /// The partially signed transaction, only returned if the transaction was not complete.
+    pub psbt: Option<Psbt>,
+}
+
+/// Models the result of JSON-RPC method `sendmany`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SendMany(pub Txid);
+
+/// Models the result of JSON-RPC method `sendmany` with verbose set to `true`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SendManyVerbose {
+    /// The transaction id for the send.
+    pub txid: Txid,
+    /// The transaction fee reason.
+    pub fee_reason: String,
+}
+
+/// Models the result of JSON-RPC method `signmessage`.
+///
+/// The signature of the message, encoded in base 64.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignMessage(pub String);
+
 /// Models the result of JSON-RPC method `sendtoaddress`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct SendToAddress {
@@ -124,3 +367,275 @@ pub enum GetTransactionDetailCategory {
     Immature,
     Orphan,
 }
+
+/// Models the result of JSON-RPC method `listlabels`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListLabels(pub Vec<String>);
+
+/// Models the result of JSON-RPC method `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListSinceBlock {
+    /// All the wallet transactions since the requested block.
+    pub transactions: Vec<TransactionItem>,
+    /// Transactions that were removed due to a reorg, empty unless `include_removed` is set.
+    pub removed: Vec<TransactionItem>,
+    /// The hash of the block (target_confirmations-1) from the best block on the main chain.
+    pub last_block: BlockHash,
+}
+
+/// Models the result of JSON-RPC method `listtransactions`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListTransactions(pub Vec<TransactionItem>);
+
+/// A wallet transaction, an item returned by `listtransactions` and `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TransactionItem {
+    /// Only returns true if imported addresses were involved in transaction.
+    pub involves_watch_only: Option<bool>,
+    /// The bitcoin address of the transaction (not present for all outputs).
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The transaction category.
+    pub category: GetTransactionDetailCategory,
+    /// The amount, negative for the `send` category.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub amount: SignedAmount,
+    /// A comment for the address/transaction, if any.
+    ///
+    /// This is the `account` field for wallets still using the deprecated accounts API.
+    pub label: Option<String>,
+    /// The vout value.
+    pub vout: u32,
+    /// The amount of the fee, negative and only present for the `send` category.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc::opt")]
+    pub fee: Option<SignedAmount>,
+    /// The number of confirmations, negative if the transaction conflicts with the best chain.
+    pub confirmations: i64,
+    /// Only present if the transaction is a coinbase.
+    pub generated: Option<bool>,
+    /// Whether we consider the transaction to be trusted and safe to spend from.
+    pub trusted: Option<bool>,
+    /// The block hash containing the transaction.
+    pub block_hash: Option<BlockHash>,
+    /// The block height containing the transaction (v0.20 onwards).
+    pub block_height: Option<u32>,
+    /// The index of the transaction in the block that includes it.
+    pub block_index: Option<u32>,
+    /// The block time expressed in UNIX epoch time.
+    pub block_time: Option<u64>,
+    /// The transaction id.
+    pub txid: Txid,
+    /// The hash of the serialized transaction, including witness data (v24 onwards).
+    pub wtxid: Option<Wtxid>,
+    /// Conflicting transaction ids.
+    pub wallet_conflicts: Vec<Txid>,
+    /// The transaction time expressed in UNIX epoch time.
+    pub time: u64,
+    /// The time received expressed in UNIX epoch time.
+    pub time_received: u64,
+    /// If a comment is associated with the transaction.
+    pub comment: Option<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee).
+    ///
+    /// One of `yes`, `no` or `unknown`.
+    pub bip125_replaceable: String,
+    /// Descriptors of the wallet's matching output script (v24 onwards).
+    pub parent_descriptors: Option<Vec<String>>,
+    /// Whether the transaction has been abandoned, only present for the `send` category.
+    pub abandoned: Option<bool>,
+}
+
+/// Models the result of JSON-RPC method `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspent(pub Vec<ListUnspentItem>);
+
+/// An unspent transaction output, an item returned by `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspentItem {
+    /// The unspent output.
+    pub outpoint: OutPoint,
+    /// The bitcoin address, if the output script corresponds to one.
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The associated label, if any.
+    pub label: Option<String>,
+    /// The output script.
+    pub script_pubkey: ScriptBuf,
+    /// The output value.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub amount: Amount,
+    /// The number of confirmations.
+    pub confirmations: u32,
+    /// The redeem script, if the output script is P2SH.
+    pub redeem_script: Option<ScriptBuf>,
+    /// The witness script, if the output script is P2WSH or P2SH-P2WSH (v0.18 onwards).
+    pub witness_script: Option<ScriptBuf>,
+    /// Whether we have the private keys to spend this output.
+    pub spendable: bool,
+    /// Whether we know how to spend this output, ignoring the lack of keys.
+    pub solvable: bool,
+    /// Whether this output is reused, only present if the wallet has `avoid_reuse` set.
+    pub reused: Option<bool>,
+    /// A descriptor for spending this output, only present if solvable (v0.18 onwards).
+    pub descriptor: Option<String>,
+    /// Descriptors of the wallet's matching output script (v24 onwards).
+    pub parent_descriptors: Option<Vec<String>>,
+    /// Whether this output is considered safe to spend.
+    ///
+    /// Unconfirmed transactions from outside keys and unconfirmed replacement transactions are
+    /// considered unsafe and are not eligible for spending by `fundrawtransaction` and
+    /// `sendtoaddress`.
+    pub safe: bool,
+}
+
+/// Models the result of JSON-RPC method `listwalletdir`.
+///
+/// The names of the wallets in the wallet directory.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWalletDir(pub Vec<String>);
+
+/// Models the result of JSON-RPC method `listwallets`.
+///
+/// The names of the currently loaded wallets.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWallets(pub Vec<String>);
+
+/// Models the result of JSON-RPC method `psbtbumpfee`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtBumpFee {
+    /// The unsigned PSBT of the replacement transaction.
+    pub psbt: Psbt,
+    /// Fee of the replaced transaction.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub original_fee: Amount,
+    /// Fee of the new transaction.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub fee: Amount,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+/// Models the result of JSON-RPC method `walletcreatefundedpsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletCreateFundedPsbt {
+    /// The resulting raw transaction.
+    pub psbt: Psbt,
+    /// Fee the resulting transaction pays.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub fee: Amount,
+    /// The position of the added change output, or `None` if no change output was added.
+    pub change_position: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `walletprocesspsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletProcessPsbt {
+    /// The partially signed transaction.
+    pub psbt: Psbt,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction if complete, only returned by v26 and later.
+    pub tx: Option<Transaction>,
+}
+
+/// Models the result of JSON-RPC method `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptors(pub Vec<ImportDescriptorsResult>);
+
+/// The result of importing a single descriptor. Part of `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptorsResult {
+    /// Whether the descriptor was imported.
+    pub success: bool,
+    /// Warnings, empty if there were none.
+    pub warnings: Vec<String>,
+    /// The error if the import failed.
+    pub error: Option<JsonRpcError>,
+}
+
+/// Models the result of JSON-RPC method `importmulti`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMulti(pub Vec<ImportMultiResult>);
+
+/// The result of a single import request. Part of `importmulti`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMultiResult {
+    /// Whether the request was imported.
+    pub success: bool,
+    /// Warnings, empty if there were none.
+    pub warnings: Vec<String>,
+    /// The error if the import failed.
+    pub error: Option<JsonRpcError>,
+}
+
+/// An error returned for a single request. Part of `importdescriptors` and `importmulti`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JsonRpcError {
+    /// The JSON-RPC error code.
+    pub code: i64,
+    /// The error message.
+    pub message: String,
+}
+
+/// Models the result of JSON-RPC method `listdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptors {
+    /// Name of wallet this operation was performed on.
+    pub wallet_name: String,
+    /// The descriptors of the wallet.
+    pub descriptors: Vec<ListDescriptorsItem>,
+}
+
+/// A descriptor of the wallet. Part of `listdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsItem {
+    /// Descriptor string representation, including the checksum.
+    pub descriptor: String,
+    /// The parsed descriptor, any private keys are replaced by their public keys.
+    #[cfg(feature = "miniscript")]
+    pub parsed_descriptor: Descriptor<DescriptorPublicKey>,
+    /// The creation time of the descriptor (UNIX epoch time).
+    pub timestamp: u32,
+    /// Whether this descriptor is currently used to generate new addresses.
+    pub active: bool,
+    /// Whether this is an internal or external descriptor, `None` for inactive descriptors.
+    pub internal: Option<bool>,
+    /// The range of indices this descriptor is defined for, `None` if not ranged.
+    pub range: Option<(u32, u32)>,
+    /// The next index to generate addresses from, `None` if not ranged.
+    pub next: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `createwalletdescriptor`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateWalletDescriptor {
+    /// The public descriptors that were added to the wallet.
+    pub descriptors: Vec<String>,
+    /// The parsed public descriptors, in the same order as `descriptors`.
+    #[cfg(feature = "miniscript")]
+    pub parsed_descriptors: Vec<Descriptor<DescriptorPublicKey>>,
+}
+
+/// Models the result of JSON-RPC method `gethdkeys`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetHdKeys(pub Vec<HdKey>);
+
+/// An HD key used by the wallet. Part of `gethdkeys`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HdKey {
+    /// The extended public key.
+    pub xpub: Xpub,
+    /// Whether the wallet has the private key for this xpub.
+    pub has_private: bool,
+    /// The extended private key, only returned if private keys were requested.
+    pub xprv: Option<Xpriv>,
+    /// The descriptors that use this HD key.
+    pub descriptors: Vec<HdKeyDescriptor>,
+}
+
+/// A descriptor using an HD key. Part of `gethdkeys`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HdKeyDescriptor {
+    /// Descriptor string representation.
+    pub descriptor: String,
+    /// Whether this descriptor is currently used to generate new addresses.
+    pub active: bool,
+}
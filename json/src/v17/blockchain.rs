@@ -13,11 +13,12 @@ use bitcoin::error::UnprefixedHexError;
 use bitcoin::hex::FromHex;
 use bitcoin::{
     address, amount, block, hex, network, Address, Amount, Block, BlockHash, CompactTarget,
-    FeeRate, Network, ScriptBuf, TxMerkleNode, TxOut, Txid, Weight, Work,
+    FeeRate, Network, ScriptBuf, Transaction, TxMerkleNode, TxOut, Txid, Weight, Work, Wtxid,
 };
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
+use super::{RawTransactionInput, RawTransactionOutput};
 use crate::{model, NumericError};
 
 /// Result of JSON-RPC method `getbestblockhash`.
@@ -216,6 +217,214 @@ impl From<NumericError> for GetBlockVerbosityOneError {
     fn from(e: NumericError) -> Self { Self::Numeric(e) }
 }
 
+/// Result of JSON-RPC method `getblock` with verbosity set to 2.
+///
+/// > getblock "blockhash" ( verbosity )
+/// >
+/// > If verbosity is 2, returns an Object with information about block <hash> and information about each transaction.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockVerbosityTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: String,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: i64,
+    /// The block size excluding witness data.
+    #[serde(rename = "strippedsize")]
+    pub stripped_size: Option<i64>,
+    /// The block weight as defined in BIP-141.
+    pub weight: u64,
+    /// The block height or index.
+    pub height: i64,
+    /// The block version.
+    pub version: i32,
+    /// The block version formatted in hexadecimal.
+    #[serde(rename = "versionHex")]
+    pub version_hex: String,
+    /// The merkle root
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The transactions in the block.
+    pub tx: Vec<GetBlockVerbosityTwoTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: i64,
+    /// The median block time expressed in UNIX epoch time.
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<i64>,
+    /// The nonce (this should be only 4 bytes).
+    pub nonce: i64,
+    /// The bits.
+    pub bits: String,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The number of transactions in the block.
+    #[serde(rename = "nTx")]
+    pub n_tx: i64,
+    /// The hash of the previous block (if available).
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// The hash of the next block (if available).
+    #[serde(rename = "nextblockhash")]
+    pub next_block_hash: Option<String>,
+}
+
+impl GetBlockVerbosityTwo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockVerbosityTwo, GetBlockVerbosityTwoError> {
+        use GetBlockVerbosityTwoError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let stripped_size =
+            self.stripped_size.map(|size| crate::to_u32(size, "stripped_size")).transpose()?;
+        let weight = Weight::from_wu(self.weight);
+        let version = block::Version::from_consensus(self.version);
+        let tx = self.tx.into_iter().map(|tx| tx.into_model()).collect::<Result<Vec<_>, _>>()?;
+        let median_time = self.median_time.map(|t| crate::to_u32(t, "median_time")).transpose()?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
+
+        Ok(model::GetBlockVerbosityTwo {
+            hash,
+            confirmations: self.confirmations,
+            size: crate::to_u32(self.size, "size")?,
+            stripped_size,
+            weight,
+            height: crate::to_u32(self.height, "height")?,
+            version,
+            merkle_root: self.merkle_root,
+            tx,
+            time: crate::to_u32(self.time, "time")?,
+            median_time,
+            nonce: crate::to_u32(self.nonce, "nonce")?,
+            bits,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: crate::to_u32(self.n_tx, "n_tx")?,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+}
+
+/// A transaction in the result of `getblock` with verbosity set to 2.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockVerbosityTwoTransaction {
+    /// The transaction id.
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The version.
+    pub version: i32,
+    /// The serialized transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4-3 and vsize*4).
+    pub weight: i64,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInput>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+    /// The serialized, hex-encoded data for the transaction.
+    pub hex: String,
+    /// Transaction fee in BTC, omitted for the coinbase or if block undo data is not available.
+    ///
+    /// Only returned by Bitcoin Core v0.21 and later.
+    pub fee: Option<f64>,
+}
+
+impl GetBlockVerbosityTwoTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::BlockTransaction, GetBlockVerbosityTwoError> {
+        use GetBlockVerbosityTwoError as E;
+
+        let transaction =
+            encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Transaction)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        Ok(model::BlockTransaction { transaction, fee })
+    }
+}
+
+/// Error when converting a `GetBlockVerbosityTwo` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockVerbosityTwoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of a transaction `hex` field failed.
+    Transaction(encode::FromHexError),
+    /// Conversion of a transaction `fee` field failed.
+    Fee(amount::ParseAmountError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `chain_work` field failed.
+    ChainWork(UnprefixedHexError),
+    /// Conversion of the `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the `next_block_hash` field failed.
+    NextBlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetBlockVerbosityTwoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBlockVerbosityTwoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+            E::Transaction(ref e) =>
+                write_err!(f, "conversion of a transaction `hex` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of a transaction `fee` field failed"; e),
+            E::Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            E::ChainWork(ref e) => write_err!(f, "conversion of the `chain_work` field failed"; e),
+            E::PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            E::NextBlockHash(ref e) =>
+                write_err!(f, "conversion of the `next_block_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetBlockVerbosityTwoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBlockVerbosityTwoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Hash(ref e) => Some(e),
+            E::Transaction(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+            E::Bits(ref e) => Some(e),
+            E::ChainWork(ref e) => Some(e),
+            E::PreviousBlockHash(ref e) => Some(e),
+            E::NextBlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetBlockVerbosityTwoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
 /// Result of JSON-RPC method `getblockchaininfo`.
 ///
 /// Method call: `getblockchaininfo`
@@ -269,18 +478,18 @@ pub struct GetBlockchainInfo {
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Softfork {
     /// Name of softfork.
-    id: String,
+    pub id: String,
     /// Block version.
-    version: i64,
+    pub version: i64,
     /// Progress toward rejecting pre-softfork blocks.
-    reject: SoftforkReject,
+    pub reject: SoftforkReject,
 }
 
 /// Progress toward rejecting pre-softfork blocks.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct SoftforkReject {
     /// `true` if threshold reached.
-    status: bool,
+    pub status: bool,
 }
 
 /// Status of BIP-9 softforksin progress.
@@ -297,6 +506,8 @@ pub struct Bip9Softfork {
     pub timeout: i64,
     /// Height of the first block to which the status applies.
     pub since: i64,
+    /// Numeric statistics about BIP-9 signalling for a softfork (only for "started" status).
+    pub statistics: Option<Bip9SoftforkStatistics>,
 }
 
 /// BIP-9 softfork status: one of "defined", "started", "locked_in", "active", "failed".
@@ -315,6 +526,21 @@ pub enum Bip9SoftforkStatus {
     Failed,
 }
 
+/// Statistics for a BIP-9 softfork.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct Bip9SoftforkStatistics {
+    /// The length in blocks of the BIP9 signalling period.
+    pub period: i64,
+    /// The number of blocks with the version bit set required to activate the feature.
+    pub threshold: Option<i64>,
+    /// The number of blocks elapsed since the beginning of the current period.
+    pub elapsed: i64,
+    /// The number of blocks with the version bit set in the current period.
+    pub count: i64,
+    /// `false` if there are not enough blocks left in this period to pass activation threshold.
+    pub possible: Option<bool>,
+}
+
 impl GetBlockchainInfo {
     /// Converts version specific type to a version in-specific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
@@ -328,7 +554,13 @@ impl GetBlockchainInfo {
             self.prune_height.map(|h| crate::to_u32(h, "prune_height")).transpose()?;
         let prune_target_size =
             self.prune_target_size.map(|h| crate::to_u32(h, "prune_target_size")).transpose()?;
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let mut softforks = BTreeMap::new();
+        for softfork in self.softforks {
+            softforks.insert(softfork.id.clone(), softfork.into_model());
+        }
+        for (name, softfork) in self.bip9_softforks {
+            softforks.insert(name, softfork.into_model()?);
+        }
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -351,6 +583,44 @@ impl GetBlockchainInfo {
     }
 }
 
+impl Softfork {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    ///
+    /// The rules of these softforks are enforced once the reject threshold is reached.
+    pub fn into_model(self) -> model::Softfork {
+        model::Softfork {
+            type_: model::SoftforkType::Buried,
+            bip9: None,
+            height: None,
+            active: self.reject.status,
+        }
+    }
+}
+
+impl Bip9Softfork {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Softfork, NumericError> {
+        let active = self.status == Bip9SoftforkStatus::Active;
+        let statistics = self.statistics.map(|s| s.into_model()).transpose()?;
+        let bip9 = model::Bip9SoftforkInfo {
+            status: self.status.into_model(),
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            since: crate::to_u32(self.since, "since")?,
+            min_activation_height: None,
+            statistics,
+        };
+
+        Ok(model::Softfork {
+            type_: model::SoftforkType::Bip9,
+            bip9: Some(bip9),
+            height: None,
+            active,
+        })
+    }
+}
+
 impl Bip9SoftforkStatus {
     /// Converts version specific type to a version in-specific, more strongly typed type.
     pub fn into_model(self) -> model::Bip9SoftforkStatus {
@@ -366,6 +636,19 @@ impl Bip9SoftforkStatus {
     }
 }
 
+impl Bip9SoftforkStatistics {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Bip9SoftforkStatistics, NumericError> {
+        Ok(model::Bip9SoftforkStatistics {
+            period: crate::to_u32(self.period, "period")?,
+            threshold: self.threshold.map(|t| crate::to_u32(t, "threshold")).transpose()?,
+            elapsed: crate::to_u32(self.elapsed, "elapsed")?,
+            count: crate::to_u32(self.count, "count")?,
+            possible: self.possible,
+        })
+    }
+}
+
 /// Error when converting a `GetBlockchainInfo` type into the model type.
 #[derive(Debug)]
 pub enum GetBlockchainInfoError {
@@ -1070,24 +1353,458 @@ pub struct GetMempoolAncestors(pub Vec<String>);
 
 impl GetMempoolAncestors {
     /// Converts version specific type to a version in-specific, more strongly typed type.
-    pub fn into_model(self) -> Result<model::GetMempoolAncestors, encode::FromHexError> {
-        let v = self
-            .0
-            .iter()
-            .map(|t| encode::deserialize_hex::<Txid>(t))
-            .collect::<Result<Vec<_>, _>>()?;
+    pub fn into_model(self) -> Result<model::GetMempoolAncestors, hex::HexToArrayError> {
+        let v = self.0.iter().map(|t| t.parse::<Txid>()).collect::<Result<Vec<_>, _>>()?;
         Ok(model::GetMempoolAncestors(v))
     }
 }
 
-/// Result of JSON-RPC method `getmempoolancestors` with verbose set to true
+/// Result of JSON-RPC method `getmempoolancestors` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry` i.e., an ancestor.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-pub struct GetMempoolAncestorsVerbose {}
+pub struct GetMempoolAncestorsVerbose(pub BTreeMap<String, MempoolEntry>);
 
 impl GetMempoolAncestorsVerbose {
     /// Converts version specific type to a version in-specific, more strongly typed type.
-    pub fn into_model(self) -> model::GetMempoolAncestorsVerbose {
-        model::GetMempoolAncestorsVerbose {}
+    pub fn into_model(self) -> Result<model::GetMempoolAncestorsVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetMempoolAncestorsVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempooldescendants` with verbose set to false.
+///
+/// > getmempooldescendants txid (verbose)
+/// >
+/// > If txid is in the mempool, returns all in-mempool descendants.
+/// >
+/// > Arguments:
+/// > 1. "txid"                 (string, required) The transaction id (must be in mempool)
+/// > 2. verbose                  (boolean, optional, default=false) True for a json object, false for array of transaction ids
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolDescendants(pub Vec<String>);
+
+impl GetMempoolDescendants {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolDescendants, hex::HexToArrayError> {
+        let v = self.0.iter().map(|t| t.parse::<Txid>()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::GetMempoolDescendants(v))
+    }
+}
+
+/// Result of JSON-RPC method `getmempooldescendants` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry` i.e., a descendant.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolDescendantsVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetMempoolDescendantsVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolDescendantsVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetMempoolDescendantsVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolentry`.
+///
+/// > getmempoolentry txid
+/// >
+/// > Returns mempool data for given transaction
+/// >
+/// > Arguments:
+/// > 1. "txid"                 (string, required) The transaction id (must be in mempool)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+impl GetMempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolEntry, MempoolEntryError> {
+        Ok(model::GetMempoolEntry(self.0.into_model()?))
+    }
+}
+
+/// A relative (ancestor or descendant) transaction of a transaction in the mempool.
+///
+/// Part of `getmempoolancestors`, `getmempooldescendants`, `getmempoolentry` and `getrawmempool`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    ///
+    /// This is different from actual serialized size for witness transactions as witness data is discounted.
+    pub size: i64,
+    /// Transaction fee in BTC (DEPRECATED).
+    pub fee: f64,
+    /// Transaction fee with fee deltas used for mining priority (DEPRECATED).
+    #[serde(rename = "modifiedfee")]
+    pub modified_fee: f64,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: i64,
+    /// Block height when transaction entered pool.
+    pub height: i64,
+    /// Number of in-mempool descendant transactions (including this one).
+    #[serde(rename = "descendantcount")]
+    pub descendant_count: i64,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    #[serde(rename = "descendantsize")]
+    pub descendant_size: i64,
+    /// Modified fees (see above) of in-mempool descendants (including this one) (DEPRECATED).
+    #[serde(rename = "descendantfees")]
+    pub descendant_fees: f64,
+    /// Number of in-mempool ancestor transactions (including this one).
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: i64,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: i64,
+    /// Modified fees (see above) of in-mempool ancestors (including this one) (DEPRECATED).
+    #[serde(rename = "ancestorfees")]
+    pub ancestor_fees: f64,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: String,
+    /// Fee object which contains the base fee, modified fee (with fee deltas), and
+    /// ancestor/descendant fee totals all in BTC.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction (parent transaction id).
+    pub depends: Vec<String>,
+    /// Unconfirmed transactions spending outputs from this transaction (child transaction id).
+    #[serde(rename = "spentby")]
+    pub spent_by: Vec<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee)
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: bool,
+}
+
+impl MempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntry, MempoolEntryError> {
+        use MempoolEntryError as E;
+
+        let vsize = crate::to_u32(self.size, "size")?;
+        let time = crate::to_u32(self.time, "time")?;
+        let height = crate::to_u32(self.height, "height")?;
+        let descendant_count = crate::to_u32(self.descendant_count, "descendant_count")?;
+        let descendant_size = crate::to_u32(self.descendant_size, "descendant_size")?;
+        let ancestor_count = crate::to_u32(self.ancestor_count, "ancestor_count")?;
+        let ancestor_size = crate::to_u32(self.ancestor_size, "ancestor_size")?;
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let fees = self.fees.into_model().map_err(E::Fees)?;
+        let depends = self
+            .depends
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::Depends)?;
+        let spent_by = self
+            .spent_by
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::SpentBy)?;
+
+        Ok(model::MempoolEntry {
+            vsize,
+            weight: None,
+            time,
+            height,
+            descendant_count,
+            descendant_size,
+            ancestor_count,
+            ancestor_size,
+            wtxid,
+            fees,
+            depends,
+            spent_by,
+            bip125_replaceable: self.bip125_replaceable,
+            unbroadcast: None,
+        })
+    }
+}
+
+/// The fees of a mempool entry, all in BTC. Part of `MempoolEntry`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntryFees {
+    /// Transaction fee in BTC.
+    pub base: f64,
+    /// Transaction fee with fee deltas used for mining priority in BTC.
+    pub modified: f64,
+    /// Modified fees (see above) of in-mempool ancestors (including this one) in BTC
+    pub ancestor: f64,
+    /// Modified fees (see above) of in-mempool descendants (including this one) in BTC.
+    pub descendant: f64,
+}
+
+impl MempoolEntryFees {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntryFees, MempoolEntryFeesError> {
+        use MempoolEntryFeesError as E;
+
+        Ok(model::MempoolEntryFees {
+            base: Amount::from_btc(self.base).map_err(E::Base)?,
+            modified: Amount::from_btc(self.modified).map_err(E::Modified)?,
+            ancestor: Amount::from_btc(self.ancestor).map_err(E::Ancestor)?,
+            descendant: Amount::from_btc(self.descendant).map_err(E::Descendant)?,
+        })
+    }
+}
+
+/// Error when converting a `MempoolEntry` type into the model type.
+#[derive(Debug)]
+pub enum MempoolEntryError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `wtxid` field failed.
+    Wtxid(hex::HexToArrayError),
+    /// Conversion of the `fees` field failed.
+    Fees(MempoolEntryFeesError),
+    /// Conversion of one of the `depends` txids failed.
+    Depends(hex::HexToArrayError),
+    /// Conversion of one of the `spent_by` txids failed.
+    SpentBy(hex::HexToArrayError),
+}
+
+impl fmt::Display for MempoolEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use MempoolEntryError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Wtxid(ref e) => write_err!(f, "conversion of the `wtxid` field failed"; e),
+            E::Fees(ref e) => write_err!(f, "conversion of the `fees` field failed"; e),
+            E::Depends(ref e) =>
+                write_err!(f, "conversion of one of the `depends` txids failed"; e),
+            E::SpentBy(ref e) =>
+                write_err!(f, "conversion of one of the `spent_by` txids failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for MempoolEntryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use MempoolEntryError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Wtxid(ref e) => Some(e),
+            E::Fees(ref e) => Some(e),
+            E::Depends(ref e) => Some(e),
+            E::SpentBy(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for MempoolEntryError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Error when converting a `MempoolEntryFees` type into the model type.
+#[derive(Debug)]
+pub enum MempoolEntryFeesError {
+    /// Conversion of the `base` field failed.
+    Base(amount::ParseAmountError),
+    /// Conversion of the `modified` field failed.
+    Modified(amount::ParseAmountError),
+    /// Conversion of the `ancestor` field failed.
+    Ancestor(amount::ParseAmountError),
+    /// Conversion of the `descendant` field failed.
+    Descendant(amount::ParseAmountError),
+}
+
+impl fmt::Display for MempoolEntryFeesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use MempoolEntryFeesError as E;
+
+        match *self {
+            E::Base(ref e) => write_err!(f, "conversion of the `base` field failed"; e),
+            E::Modified(ref e) => write_err!(f, "conversion of the `modified` field failed"; e),
+            E::Ancestor(ref e) => write_err!(f, "conversion of the `ancestor` field failed"; e),
+            E::Descendant(ref e) => write_err!(f, "conversion of the `descendant` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for MempoolEntryFeesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use MempoolEntryFeesError as E;
+
+        match *self {
+            E::Base(ref e) => Some(e),
+            E::Modified(ref e) => Some(e),
+            E::Ancestor(ref e) => Some(e),
+            E::Descendant(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a map of txid to `MempoolEntry` into the model type.
+#[derive(Debug)]
+pub enum MapMempoolEntryError {
+    /// Conversion of a `txid` map key failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of a `MempoolEntry` map value failed.
+    MempoolEntry(MempoolEntryError),
+}
+
+impl fmt::Display for MapMempoolEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use MapMempoolEntryError as E;
+
+        match *self {
+            E::Txid(ref e) => write_err!(f, "conversion of a `txid` map key failed"; e),
+            E::MempoolEntry(ref e) =>
+                write_err!(f, "conversion of a `MempoolEntry` map value failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for MapMempoolEntryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use MapMempoolEntryError as E;
+
+        match *self {
+            E::Txid(ref e) => Some(e),
+            E::MempoolEntry(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolinfo`.
+///
+/// > getmempoolinfo
+/// >
+/// > Returns details on the active state of the TX memory pool.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolInfo {
+    /// Current tx count.
+    pub size: i64,
+    /// Sum of all virtual transaction sizes as defined in BIP 141.
+    ///
+    /// Differs from actual serialized size because witness data is discounted.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Maximum memory usage for the mempool.
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate in BTC/kB for tx to be accepted.
+    ///
+    /// Is the maximum of minrelaytxfee and minimum mempool fee.
+    #[serde(rename = "mempoolminfee")]
+    pub mempool_min_fee: f64,
+    /// Current minimum relay fee for transactions.
+    #[serde(rename = "minrelaytxfee")]
+    pub min_relay_tx_fee: f64,
+}
+
+impl GetMempoolInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolInfo, GetMempoolInfoError> {
+        use GetMempoolInfoError as E;
+
+        let size = crate::to_u32(self.size, "size")?;
+        let mempool_min_fee = crate::btc_per_kb(self.mempool_min_fee).map_err(E::MempoolMinFee)?;
+        let min_relay_tx_fee =
+            crate::btc_per_kb(self.min_relay_tx_fee).map_err(E::MinRelayTxFee)?;
+
+        Ok(model::GetMempoolInfo {
+            loaded: None,
+            size,
+            bytes: self.bytes,
+            usage: self.usage,
+            total_fee: None,
+            max_mempool: self.max_mempool,
+            mempool_min_fee,
+            min_relay_tx_fee,
+            incremental_relay_fee: None,
+            unbroadcast_count: None,
+            full_rbf: None,
+        })
+    }
+}
+
+/// Error when converting a `GetMempoolInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetMempoolInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `total_fee` field failed.
+    TotalFee(amount::ParseAmountError),
+    /// Conversion of the `mempool_min_fee` field failed.
+    MempoolMinFee(amount::ParseAmountError),
+    /// Conversion of the `min_relay_tx_fee` field failed.
+    MinRelayTxFee(amount::ParseAmountError),
+    /// Conversion of the `incremental_relay_fee` field failed.
+    IncrementalRelayFee(amount::ParseAmountError),
+}
+
+impl fmt::Display for GetMempoolInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetMempoolInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::TotalFee(ref e) => write_err!(f, "conversion of the `total_fee` field failed"; e),
+            E::MempoolMinFee(ref e) =>
+                write_err!(f, "conversion of the `mempool_min_fee` field failed"; e),
+            E::MinRelayTxFee(ref e) =>
+                write_err!(f, "conversion of the `min_relay_tx_fee` field failed"; e),
+            E::IncrementalRelayFee(ref e) =>
+                write_err!(f, "conversion of the `incremental_relay_fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetMempoolInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetMempoolInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::TotalFee(ref e) => Some(e),
+            E::MempoolMinFee(ref e) => Some(e),
+            E::MinRelayTxFee(ref e) => Some(e),
+            E::IncrementalRelayFee(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetMempoolInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to false.
+///
+/// > getrawmempool ( verbose )
+/// >
+/// > Returns all transaction ids in memory pool as a json array of string transaction ids.
+/// >
+/// > Hint: use getmempoolentry to fetch a specific transaction from the mempool.
+/// >
+/// > Arguments:
+/// > 1. verbose (boolean, optional, default=false) True for a json object, false for array of transaction ids
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawMempool(pub Vec<String>);
+
+impl GetRawMempool {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempool, hex::HexToArrayError> {
+        let v = self.0.iter().map(|t| t.parse::<Txid>()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::GetRawMempool(v))
+    }
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetRawMempoolVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempoolVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetRawMempoolVerbose(map))
     }
 }
 
@@ -1206,3 +1923,402 @@ impl std::error::Error for GetTxOutError {
 impl From<NumericError> for GetTxOutError {
     fn from(e: NumericError) -> Self { Self::Numeric(e) }
 }
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The current block height (index).
+    pub height: i64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of transactions with unspent outputs.
+    pub transactions: u64,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// A meaningless metric for UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash.
+    pub hash_serialized_2: String,
+    /// The estimated size of the chainstate on disk.
+    pub disk_size: u64,
+    /// The total amount.
+    pub total_amount: f64,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height,
+            best_block,
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: Some(self.hash_serialized_2),
+            muhash: None,
+            transactions: Some(self.transactions),
+            disk_size: Some(self.disk_size),
+            total_amount,
+            total_unspendable_amount: None,
+            block_info: None,
+        })
+    }
+}
+
+/// Error when converting a `GetTxOutSetInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetTxOutSetInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `best_block` field failed.
+    BestBlock(hex::HexToArrayError),
+    /// Conversion of the `total_amount` field failed.
+    TotalAmount(amount::ParseAmountError),
+    /// Conversion of the `total_unspendable_amount` field failed.
+    TotalUnspendableAmount(amount::ParseAmountError),
+    /// Conversion of one of the `block_info` amounts failed.
+    BlockInfo(amount::ParseAmountError),
+}
+
+impl fmt::Display for GetTxOutSetInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetTxOutSetInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::BestBlock(ref e) => write_err!(f, "conversion of the `best_block` field failed"; e),
+            E::TotalAmount(ref e) =>
+                write_err!(f, "conversion of the `total_amount` field failed"; e),
+            E::TotalUnspendableAmount(ref e) =>
+                write_err!(f, "conversion of the `total_unspendable_amount` field failed"; e),
+            E::BlockInfo(ref e) => write_err!(f, "conversion of the `block_info` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetTxOutSetInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetTxOutSetInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::BestBlock(ref e) => Some(e),
+            E::TotalAmount(ref e) => Some(e),
+            E::TotalUnspendableAmount(ref e) => Some(e),
+            E::BlockInfo(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetTxOutSetInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action `abort`.
+///
+/// > scantxoutset <action> ( <scanobjects> )
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. "action"                       (string, required) The action to execute
+/// >                                       "start" for starting a scan
+/// >                                       "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                       "status" for progress report (in %) of the current scan
+/// > 2. "scanobjects"                  (array, required) Array of scan objects
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanTxOutSetAbort(pub bool);
+
+impl ScanTxOutSetAbort {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ScanTxOutSetAbort { model::ScanTxOutSetAbort(self.0) }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action `start`.
+///
+/// > scantxoutset <action> ( <scanobjects> )
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. "action"                       (string, required) The action to execute
+/// >                                       "start" for starting a scan
+/// >                                       "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                       "status" for progress report (in %) of the current scan
+/// > 2. "scanobjects"                  (array, required) Array of scan objects
+/// >     [                             Every scan object is either a string descriptor or an object:
+/// >         "descriptor",             (string, optional) An output descriptor
+/// >         {                         (object, optional) An object with output descriptor and metadata
+/// >           "desc": "descriptor",   (string, required) An output descriptor
+/// >           "range": n,             (numeric, optional) Up to what child index HD chains should be explored (default: 1000)
+/// >         },
+/// >         ...
+/// >     ]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// The unspent outputs found.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs in BTC.
+    pub total_amount: f64,
+}
+
+impl ScanTxOutSetStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetStart, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let unspents =
+            self.unspents.into_iter().map(|u| u.into_model()).collect::<Result<_, _>>()?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::ScanTxOutSetStart {
+            success: None,
+            tx_outs: None,
+            height: None,
+            best_block: None,
+            unspents,
+            total_amount,
+        })
+    }
+}
+
+/// An unspent output found by the scan. Part of `scantxoutset`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetUnspent {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    pub vout: i64,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// The total amount in BTC of the unspent output.
+    pub amount: f64,
+    /// Height of the unspent transaction output.
+    pub height: i64,
+}
+
+impl ScanTxOutSetUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetUnspent, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let vout = crate::to_u32(self.vout, "vout")?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let height = crate::to_u32(self.height, "height")?;
+
+        Ok(model::ScanTxOutSetUnspent {
+            txid,
+            vout,
+            script_pubkey,
+            descriptor: None,
+            amount,
+            coinbase: None,
+            height,
+            block_hash: None,
+        })
+    }
+}
+
+/// Error when converting a `ScanTxOutSetStart` type into the model type.
+#[derive(Debug)]
+pub enum ScanTxOutSetError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `best_block` field failed.
+    BestBlock(hex::HexToArrayError),
+    /// Conversion of the `total_amount` field failed.
+    TotalAmount(amount::ParseAmountError),
+    /// Conversion of an unspent output's `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of an unspent output's `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of an unspent output's `amount` field failed.
+    Amount(amount::ParseAmountError),
+    /// Conversion of an unspent output's `block_hash` field failed.
+    BlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for ScanTxOutSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ScanTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::BestBlock(ref e) => write_err!(f, "conversion of the `best_block` field failed"; e),
+            E::TotalAmount(ref e) =>
+                write_err!(f, "conversion of the `total_amount` field failed"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the unspent `txid` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the unspent `script_pubkey` field failed"; e),
+            E::Amount(ref e) => write_err!(f, "conversion of the unspent `amount` field failed"; e),
+            E::BlockHash(ref e) =>
+                write_err!(f, "conversion of the unspent `block_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ScanTxOutSetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ScanTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::BestBlock(ref e) => Some(e),
+            E::TotalAmount(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+            E::BlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for ScanTxOutSetError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action `status`.
+///
+/// Core returns `null` if there is no scan in progress.
+///
+/// > scantxoutset <action> ( <scanobjects> )
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. "action"                       (string, required) The action to execute
+/// >                                       "start" for starting a scan
+/// >                                       "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                       "status" for progress report (in %) of the current scan
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStatus {
+    /// The scan progress as a percentage.
+    pub progress: f64,
+}
+
+impl ScanTxOutSetStatus {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ScanTxOutSetStatus {
+        model::ScanTxOutSetStatus { progress: self.progress }
+    }
+}
+
+/// Result of JSON-RPC method `waitforblock`.
+///
+/// This is a hidden RPC method, it is not included in the output of `help`.
+///
+/// > waitforblock <blockhash> (timeout)
+/// >
+/// > Waits for a specific new block and returns useful info about it.
+/// >
+/// > Returns the current block on timeout or exit.
+/// >
+/// > Arguments:
+/// > 1. "blockhash" (required, string) Block hash to wait for.
+/// > 2. timeout       (int, optional, default=0) Time in milliseconds to wait for a response. 0 indicates no timeout.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WaitForBlock {
+    /// The blockhash.
+    pub hash: String,
+    /// Block height.
+    pub height: u64,
+}
+
+impl WaitForBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WaitForBlock, hex::HexToArrayError> {
+        let hash = self.hash.parse::<BlockHash>()?;
+        Ok(model::WaitForBlock { hash, height: self.height })
+    }
+}
+
+/// Result of JSON-RPC method `waitforblockheight`.
+///
+/// This is a hidden RPC method, it is not included in the output of `help`.
+///
+/// > waitforblockheight <height> (timeout)
+/// >
+/// > Waits for (at least) block height and returns the height and hash
+/// > of the current tip.
+/// >
+/// > Returns the current block on timeout or exit.
+/// >
+/// > Arguments:
+/// > 1. height  (required, int) Block height to wait for (int)
+/// > 2. timeout (int, optional, default=0) Time in milliseconds to wait for a response. 0 indicates no timeout.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WaitForBlockHeight {
+    /// The blockhash.
+    pub hash: String,
+    /// Block height.
+    pub height: u64,
+}
+
+impl WaitForBlockHeight {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WaitForBlockHeight, hex::HexToArrayError> {
+        let hash = self.hash.parse::<BlockHash>()?;
+        Ok(model::WaitForBlockHeight { hash, height: self.height })
+    }
+}
+
+/// Result of JSON-RPC method `waitfornewblock`.
+///
+/// This is a hidden RPC method, it is not included in the output of `help`.
+///
+/// > waitfornewblock (timeout)
+/// >
+/// > Waits for a specific new block and returns useful info about it.
+/// >
+/// > Returns the current block on timeout or exit.
+/// >
+/// > Arguments:
+/// > 1. timeout (int, optional, default=0) Time in milliseconds to wait for a response. 0 indicates no timeout.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WaitForNewBlock {
+    /// The blockhash.
+    pub hash: String,
+    /// Block height.
+    pub height: u64,
+}
+
+impl WaitForNewBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WaitForNewBlock, hex::HexToArrayError> {
+        let hash = self.hash.parse::<BlockHash>()?;
+        Ok(model::WaitForNewBlock { hash, height: self.height })
+    }
+}
+
+/// Converts a map of txid to mempool entry, as returned by the verbose mempool methods.
+pub(crate) fn mempool_entries<T>(
+    entries: BTreeMap<String, T>,
+    into_model: fn(T) -> Result<model::MempoolEntry, MempoolEntryError>,
+) -> Result<BTreeMap<Txid, model::MempoolEntry>, MapMempoolEntryError> {
+    use MapMempoolEntryError as E;
+
+    entries
+        .into_iter()
+        .map(|(txid, entry)| {
+            let txid = txid.parse::<Txid>().map_err(E::Txid)?;
+            let entry = into_model(entry).map_err(E::MempoolEntry)?;
+            Ok((txid, entry))
+        })
+        .collect()
+}
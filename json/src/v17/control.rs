@@ -5,9 +5,13 @@
 //! Types for methods found under the `== Control ==` section of the API docs.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use crate::model;
+use crate::model::LogCategory;
+
 /// Result of JSON-RPC method `getmemoryinfo`.
 ///
 /// We only support the default "stats" mode.
@@ -45,11 +49,25 @@ pub struct Locked {
     pub chunks_free: u64,
 }
 
+/// Result of JSON-RPC method `getmemoryinfo` with mode "mallocinfo".
+///
+/// > getmemoryinfo ("mode")
+///
+/// An XML string describing low-level heap state (only available if compiled with glibc 2.10+).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetMemoryInfoMallocInfo(pub String);
+
 /// Result of JSON-RPC method `logging`.
 ///
 /// > logging ( `<include>` `<exclude>` )
 ///
 /// > Gets and sets the logging configuration.
+/// > When called without an argument, returns the list of categories with status that are currently being debug logged or not.
+/// > When called with arguments, adds or removes categories from debug logging and return the lists above.
+/// >
+/// > Arguments:
+/// > 1. "include"        (array of strings, optional) A json array of categories to add debug logging
+/// > 2. "exclude"        (array of strings, optional) A json array of categories to remove debug logging
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Logging {
     pub net: bool,
@@ -75,13 +93,49 @@ pub struct Logging {
     pub leveldb: bool,
 }
 
+impl Logging {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        use LogCategory as C;
+
+        let categories = [
+            (C::Net, self.net),
+            (C::Tor, self.tor),
+            (C::Mempool, self.mempool),
+            (C::Http, self.http),
+            (C::Bench, self.bench),
+            (C::Zmq, self.zmq),
+            (C::Db, self.db),
+            (C::Rpc, self.rpc),
+            (C::EstimateFee, self.estimatefee),
+            (C::AddrMan, self.addrman),
+            (C::SelectCoins, self.selectcoins),
+            (C::Reindex, self.reindex),
+            (C::CmpctBlock, self.cmpctblock),
+            (C::Rand, self.rand),
+            (C::Prune, self.prune),
+            (C::Proxy, self.proxy),
+            (C::MempoolRej, self.mempoolrej),
+            (C::Libevent, self.libevent),
+            (C::CoinDb, self.coindb),
+            (C::Qt, self.qt),
+            (C::LevelDb, self.leveldb),
+        ];
+        model::Logging(categories.into_iter().collect())
+    }
+}
+
 /// Result of JSON-RPC method `uptime`.
 ///
 /// > uptime
 /// >
 /// > Returns the total uptime of the server.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-pub struct Uptime {
-    /// The number of seconds that the server has been running.
-    ttt: u32,
+///
+/// The number of seconds that the server has been running.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Uptime(pub u64);
+
+impl Uptime {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Uptime { model::Uptime(Duration::from_secs(self.0)) }
 }
@@ -3,3 +3,470 @@
 //! The JSON-RPC API for Bitcoin Core v0.17.1 - mining.
 //!
 //! Types for methods found under the `== Mining ==` section of the API docs.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::consensus::encode;
+use bitcoin::error::UnprefixedHexError;
+use bitcoin::{
+    block, hex, network, Amount, BlockHash, CompactTarget, Network, ScriptBuf, Target, Txid,
+    Weight, Wtxid,
+};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::{model, NumericError};
+
+/// Result of the JSON-RPC method `getblocktemplate`.
+///
+/// > getblocktemplate ( TemplateRequest )
+/// >
+/// > If the request parameters include a 'mode' key, that is used to explicitly select between the default 'template' request or a 'proposal'.
+/// > It returns data needed to construct a block to work on.
+/// > For full specification, see BIPs 22, 23, 9, and 145:
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0022.mediawiki>
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0023.mediawiki>
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0009.mediawiki#getblocktemplate_changes>
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0145.mediawiki>
+/// >
+/// > Arguments:
+/// > 1. template_request         (json object, optional) A json object in the following spec
+/// >      {
+/// >        "mode":"template"    (string, optional) This must be set to "template", "proposal" (see BIP 23), or omitted
+/// >        "capabilities":[     (array, optional) A list of strings
+/// >            "support"          (string) client side supported feature, 'longpoll', 'coinbasetxn', 'coinbasevalue', 'proposal', 'serverlist', 'workid'
+/// >            ,...
+/// >        ],
+/// >        "rules":[            (array, optional) A list of strings
+/// >            "support"          (string) client side supported softfork deployment
+/// >            ,...
+/// >        ]
+/// >      }
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockTemplate {
+    /// The features supported by the server e.g., 'proposal'.
+    pub capabilities: Vec<String>,
+    /// The preferred block version.
+    pub version: i32,
+    /// Specific block rules that are to be enforced.
+    pub rules: Vec<String>,
+    /// Set of pending, supported versionbit (BIP 9) softfork deployments.
+    ///
+    /// Map of rule name to bit number - identifies the bit number as indicating acceptance and
+    /// readiness for the named softfork rule.
+    #[serde(rename = "vbavailable")]
+    pub version_bits_available: BTreeMap<String, u32>,
+    /// Bit mask of versionbits the server requires set in submissions.
+    #[serde(rename = "vbrequired")]
+    pub version_bits_required: u32,
+    /// The hash of current highest block.
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: String,
+    /// Contents of non-coinbase transactions that should be included in the next block.
+    pub transactions: Vec<BlockTemplateTransaction>,
+    /// Data that should be included in the coinbase's scriptSig content.
+    ///
+    /// Key name is to be ignored, and value included in scriptSig.
+    #[serde(rename = "coinbaseaux")]
+    pub coinbase_aux: BTreeMap<String, String>,
+    /// Maximum allowable input to coinbase transaction, including the generation award and
+    /// transaction fees (in satoshis).
+    #[serde(rename = "coinbasevalue")]
+    pub coinbase_value: u64,
+    /// An id to include with a request to longpoll on an update to this template.
+    #[serde(rename = "longpollid")]
+    pub long_poll_id: String,
+    /// The hash target.
+    pub target: String,
+    /// The minimum timestamp appropriate for next block time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "mintime")]
+    pub min_time: i64,
+    /// List of ways the block template may be changed, e.g. 'time', 'transactions', 'prevblock'.
+    pub mutable: Vec<String>,
+    /// A range of valid nonces.
+    #[serde(rename = "noncerange")]
+    pub nonce_range: String,
+    /// Limit of sigops in blocks.
+    #[serde(rename = "sigoplimit")]
+    pub sigop_limit: i64,
+    /// Limit of block size.
+    #[serde(rename = "sizelimit")]
+    pub size_limit: i64,
+    /// Limit of block weight.
+    #[serde(rename = "weightlimit")]
+    pub weight_limit: u64,
+    /// Current timestamp in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "curtime")]
+    pub current_time: i64,
+    /// Compressed target of next block.
+    pub bits: String,
+    /// The height of the next block.
+    pub height: i64,
+    /// Only on signet, the signet challenge (v0.21 and later).
+    pub signet_challenge: Option<String>,
+    /// A valid witness commitment for the unmodified block template (only present if the
+    /// template contains segwit transactions or the `segwit` rule was requested).
+    pub default_witness_commitment: Option<String>,
+}
+
+/// A transaction in a block template. Part of `getblocktemplate`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BlockTemplateTransaction {
+    /// Transaction data encoded in hexadecimal (byte-for-byte).
+    pub data: String,
+    /// Transaction id encoded in little-endian hexadecimal.
+    pub txid: String,
+    /// Hash encoded in little-endian hexadecimal (including witness data).
+    pub hash: String,
+    /// Transactions before this one (by 1-based index in 'transactions' list) that must be
+    /// present in the final block if this one is.
+    pub depends: Vec<i64>,
+    /// Difference in value between transaction inputs and outputs (in satoshis).
+    pub fee: u64,
+    /// Total SigOps cost, as counted for purposes of block limits.
+    pub sigops: i64,
+    /// Total transaction weight, as counted for purposes of block limits.
+    pub weight: u64,
+}
+
+impl GetBlockTemplate {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockTemplate, GetBlockTemplateError> {
+        use GetBlockTemplateError as E;
+
+        let previous_block_hash =
+            self.previous_block_hash.parse::<BlockHash>().map_err(E::PreviousBlockHash)?;
+        let transactions = self
+            .transactions
+            .into_iter()
+            .map(|t| t.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Transactions)?;
+        let target = Target::from_unprefixed_hex(&self.target).map_err(E::Target)?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let signet_challenge = self
+            .signet_challenge
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::SignetChallenge)?;
+        let default_witness_commitment = self
+            .default_witness_commitment
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::DefaultWitnessCommitment)?;
+
+        Ok(model::GetBlockTemplate {
+            capabilities: self.capabilities,
+            version: block::Version::from_consensus(self.version),
+            rules: self.rules,
+            version_bits_available: self.version_bits_available,
+            version_bits_required: self.version_bits_required,
+            previous_block_hash,
+            transactions,
+            coinbase_aux: self.coinbase_aux,
+            coinbase_value: Amount::from_sat(self.coinbase_value),
+            long_poll_id: self.long_poll_id,
+            target,
+            min_time: crate::to_u32(self.min_time, "min_time")?,
+            mutable: self.mutable,
+            nonce_range: self.nonce_range,
+            sigop_limit: crate::to_u32(self.sigop_limit, "sigop_limit")?,
+            size_limit: crate::to_u32(self.size_limit, "size_limit")?,
+            weight_limit: Weight::from_wu(self.weight_limit),
+            current_time: crate::to_u32(self.current_time, "current_time")?,
+            bits,
+            height: crate::to_u32(self.height, "height")?,
+            signet_challenge,
+            default_witness_commitment,
+        })
+    }
+}
+
+impl BlockTemplateTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::BlockTemplateTransaction, BlockTemplateTransactionError> {
+        use BlockTemplateTransactionError as E;
+
+        let data = encode::deserialize_hex(&self.data).map_err(E::Data)?;
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wtxid = self.hash.parse::<Wtxid>().map_err(E::Hash)?;
+        let depends = self
+            .depends
+            .into_iter()
+            .map(|d| crate::to_u32(d, "depend"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(model::BlockTemplateTransaction {
+            data,
+            txid,
+            wtxid,
+            depends,
+            fee: Amount::from_sat(self.fee),
+            sigops: crate::to_u32(self.sigops, "sigops")?,
+            weight: Weight::from_wu(self.weight),
+        })
+    }
+}
+
+/// Error when converting a `GetBlockTemplate` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockTemplateError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the `transactions` field failed.
+    Transactions(BlockTemplateTransactionError),
+    /// Conversion of the `target` field failed.
+    Target(UnprefixedHexError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `signet_challenge` field failed.
+    SignetChallenge(hex::HexToBytesError),
+    /// Conversion of the `default_witness_commitment` field failed.
+    DefaultWitnessCommitment(hex::HexToBytesError),
+}
+
+impl fmt::Display for GetBlockTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBlockTemplateError::*;
+
+        match *self {
+            Numeric(ref e) => write_err!(f, "numeric"; e),
+            PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            Transactions(ref e) =>
+                write_err!(f, "conversion of the `transactions` field failed"; e),
+            Target(ref e) => write_err!(f, "conversion of the `target` field failed"; e),
+            Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            SignetChallenge(ref e) =>
+                write_err!(f, "conversion of the `signet_challenge` field failed"; e),
+            DefaultWitnessCommitment(ref e) =>
+                write_err!(f, "conversion of the `default_witness_commitment` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetBlockTemplateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBlockTemplateError::*;
+
+        match *self {
+            Numeric(ref e) => Some(e),
+            PreviousBlockHash(ref e) => Some(e),
+            Transactions(ref e) => Some(e),
+            Target(ref e) => Some(e),
+            Bits(ref e) => Some(e),
+            SignetChallenge(ref e) => Some(e),
+            DefaultWitnessCommitment(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetBlockTemplateError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Error when converting a `BlockTemplateTransaction` type into the model type.
+#[derive(Debug)]
+pub enum BlockTemplateTransactionError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `data` field failed.
+    Data(encode::FromHexError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+}
+
+impl fmt::Display for BlockTemplateTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use BlockTemplateTransactionError::*;
+
+        match *self {
+            Numeric(ref e) => write_err!(f, "numeric"; e),
+            Data(ref e) => write_err!(f, "conversion of the `data` field failed"; e),
+            Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for BlockTemplateTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use BlockTemplateTransactionError::*;
+
+        match *self {
+            Numeric(ref e) => Some(e),
+            Data(ref e) => Some(e),
+            Txid(ref e) => Some(e),
+            Hash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for BlockTemplateTransactionError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of the JSON-RPC method `getmininginfo`.
+///
+/// > getmininginfo
+/// >
+/// > Returns a json object containing mining-related information.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMiningInfo {
+    /// The current block.
+    pub blocks: i64,
+    /// The last block weight.
+    #[serde(rename = "currentblockweight")]
+    pub current_block_weight: Option<u64>,
+    /// The last block transaction.
+    #[serde(rename = "currentblocktx")]
+    pub current_block_tx: Option<i64>,
+    /// The current difficulty.
+    pub difficulty: f64,
+    /// The network hashes per second.
+    #[serde(rename = "networkhashps")]
+    pub network_hash_ps: f64,
+    /// The size of the mempool.
+    #[serde(rename = "pooledtx")]
+    pub pooled_tx: i64,
+    /// Current network name as defined in BIP70 (main, test, regtest).
+    pub chain: String,
+    /// Any network and blockchain warnings.
+    pub warnings: String,
+}
+
+impl GetMiningInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMiningInfo, GetMiningInfoError> {
+        use GetMiningInfoError as E;
+
+        let current_block_tx =
+            self.current_block_tx.map(|t| crate::to_u32(t, "current_block_tx")).transpose()?;
+        let chain = Network::from_core_arg(&self.chain).map_err(E::Chain)?;
+
+        Ok(model::GetMiningInfo {
+            blocks: crate::to_u32(self.blocks, "blocks")?,
+            current_block_weight: self.current_block_weight.map(Weight::from_wu),
+            current_block_tx,
+            difficulty: self.difficulty,
+            network_hash_ps: self.network_hash_ps,
+            pooled_tx: crate::to_u32(self.pooled_tx, "pooled_tx")?,
+            chain,
+            warnings: vec![self.warnings],
+        })
+    }
+}
+
+/// Error when converting a `GetMiningInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetMiningInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `chain` field failed.
+    Chain(network::ParseNetworkError),
+}
+
+impl fmt::Display for GetMiningInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetMiningInfoError::*;
+
+        match *self {
+            Numeric(ref e) => write_err!(f, "numeric"; e),
+            Chain(ref e) => write_err!(f, "conversion of the `chain` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetMiningInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetMiningInfoError::*;
+
+        match *self {
+            Numeric(ref e) => Some(e),
+            Chain(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetMiningInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of the JSON-RPC method `getnetworkhashps`.
+///
+/// > getnetworkhashps ( nblocks height )
+/// >
+/// > Returns the estimated network hashes per second based on the last n blocks.
+/// > Pass in [blocks] to override # of blocks, -1 specifies since last difficulty change.
+/// > Pass in [height] to estimate the network speed at the time when a certain block was found.
+/// >
+/// > Arguments:
+/// > 1. nblocks     (numeric, optional, default=120) The number of blocks, or -1 for blocks since last difficulty change.
+/// > 2. height      (numeric, optional, default=-1) To estimate at the time of the given height.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetNetworkHashPs(pub f64);
+
+impl GetNetworkHashPs {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetNetworkHashPs { model::GetNetworkHashPs(self.0) }
+}
+
+/// Result of the JSON-RPC method `prioritisetransaction`.
+///
+/// > prioritisetransaction <txid> <dummy value> <fee delta>
+/// >
+/// > Accepts the transaction into mined blocks at a higher (or lower) priority
+/// >
+/// > Arguments:
+/// > 1. "txid"       (string, required) The transaction id.
+/// > 2. dummy (numeric, optional) API-Compatibility for previous API. Must be zero or null.
+/// >                   DEPRECATED. For forward compatibility use named arguments and omit this parameter.
+/// > 3. fee_delta      (numeric, required) The fee value (in satoshis) to add (or subtract, if negative).
+/// >                   Note, that this value is not a fee rate. It is a value to modify absolute fee of the TX.
+/// >                   The fee is not actually paid, only the algorithm for selecting transactions into a block
+/// >                   considers the transaction as it would have paid a higher (or lower) fee.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PrioritiseTransaction(pub bool);
+
+impl PrioritiseTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::PrioritiseTransaction { model::PrioritiseTransaction(self.0) }
+}
+
+/// Result of the JSON-RPC method `submitblock`.
+///
+/// > submitblock "hexdata"  ( "dummy" )
+/// >
+/// > Attempts to submit new block to network.
+/// > See <https://en.bitcoin.it/wiki/BIP_0022> for full specification.
+/// >
+/// > Arguments
+/// > 1. "hexdata"        (string, required) the hex-encoded block data to submit
+/// > 2. "dummy"          (optional) dummy value, for compatibility with BIP22. This value is ignored.
+///
+/// The result is `null` if the block was accepted, otherwise a BIP22 result string.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmitBlock(pub Option<String>);
+
+impl SubmitBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::SubmitBlock {
+        use model::SubmitBlock::*;
+
+        match self.0.as_deref() {
+            None => Accepted,
+            Some("duplicate") => Duplicate,
+            Some("duplicate-invalid") => DuplicateInvalid,
+            Some("duplicate-inconclusive") => DuplicateInconclusive,
+            Some("inconclusive") => Inconclusive,
+            Some(reason) => Rejected(reason.to_owned()),
+        }
+    }
+}
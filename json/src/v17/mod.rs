@@ -35,7 +35,7 @@
 //! - [x] `preciousblock "blockhash"`
 //! - [-] `pruneblockchain`
 //! - [-] `savemempool`
-//! - [-] `scantxoutset <action> ( <scanobjects> )`
+//! - [x] `scantxoutset <action> ( <scanobjects> )`
 //! - [x] `verifychain ( checklevel nblocks )`
 //! - [-] `verifytxoutproof "proof"`
 //!
@@ -51,56 +51,56 @@
 //! - [x] `generatetoaddress nblocks address (maxtries)`
 //!
 //! **== Mining ==**
-//! - [ ] `getblocktemplate ( TemplateRequest )`
-//! - [ ] `getmininginfo`
-//! - [ ] `getnetworkhashps ( nblocks height )`
-//! - [ ] `prioritisetransaction <txid> <dummy value> <fee delta>`
-//! - [ ] `submitblock "hexdata"  ( "dummy" )`
+//! - [x] `getblocktemplate ( TemplateRequest )`
+//! - [x] `getmininginfo`
+//! - [x] `getnetworkhashps ( nblocks height )`
+//! - [x] `prioritisetransaction <txid> <dummy value> <fee delta>`
+//! - [x] `submitblock "hexdata"  ( "dummy" )`
 //!
 //! **== Network ==**
 //! - [-] `addnode "node" "add|remove|onetry"`
 //! - [-] `clearbanned`
 //! - [-] `disconnectnode "[address]" [nodeid]`
 //! - [x] `getaddednodeinfo ( "node" )`
-//! - [-] `getconnectioncount`
+//! - [x] `getconnectioncount`
 //! - [x] `getnettotals`
 //! - [x] `getnetworkinfo`
 //! - [x] `getpeerinfo`
-//! - [-] `listbanned`
+//! - [x] `listbanned`
 //! - [-] `ping`
 //! - [-] `setban "subnet" "add|remove" (bantime) (absolute)`
 //! - [-] `setnetworkactive true|false`
 //!
 //! **== Rawtransactions ==**
-//! - [ ] `combinepsbt ["psbt",...]`
-//! - [ ] `combinerawtransaction ["hexstring",...]`
-//! - [ ] `converttopsbt "hexstring" ( permitsigdata iswitness )`
-//! - [ ] `createpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )`
-//! - [ ] `createrawtransaction [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )`
-//! - [ ] `decodepsbt "psbt"`
-//! - [ ] `decoderawtransaction "hexstring" ( iswitness )`
-//! - [ ] `decodescript "hexstring"`
-//! - [ ] `finalizepsbt "psbt" ( extract )`
-//! - [ ] `fundrawtransaction "hexstring" ( options iswitness )`
-//! - [ ] `getrawtransaction "txid" ( verbose "blockhash" )`
+//! - [x] `combinepsbt ["psbt",...]`
+//! - [x] `combinerawtransaction ["hexstring",...]`
+//! - [x] `converttopsbt "hexstring" ( permitsigdata iswitness )`
+//! - [x] `createpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )`
+//! - [x] `createrawtransaction [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )`
+//! - [x] `decodepsbt "psbt"`
+//! - [x] `decoderawtransaction "hexstring" ( iswitness )`
+//! - [x] `decodescript "hexstring"`
+//! - [x] `finalizepsbt "psbt" ( extract )`
+//! - [x] `fundrawtransaction "hexstring" ( options iswitness )`
+//! - [x] `getrawtransaction "txid" ( verbose "blockhash" )`
 //! - [ ] `sendrawtransaction "hexstring" ( allowhighfees )`
 //! - [ ] `signrawtransaction "hexstring" ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] ["privatekey1",...] sighashtype )`
-//! - [ ] `signrawtransactionwithkey "hexstring" ["privatekey1",...] ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )`
-//! - [ ] `testmempoolaccept ["rawtxs"] ( allowhighfees )`
+//! - [x] `signrawtransactionwithkey "hexstring" ["privatekey1",...] ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )`
+//! - [x] `testmempoolaccept ["rawtxs"] ( allowhighfees )`
 //!
 //! **== Util ==**
-//! - [ ] `createmultisig nrequired ["key",...] ( "address_type" )`
-//! - [ ] `estimatesmartfee conf_target ("estimate_mode")`
-//! - [ ] `signmessagewithprivkey "privkey" "message"`
-//! - [ ] `validateaddress "address"`
-//! - [ ] `verifymessage "address" "signature" "message"`
+//! - [x] `createmultisig nrequired ["key",...] ( "address_type" )`
+//! - [x] `estimatesmartfee conf_target ("estimate_mode")`
+//! - [x] `signmessagewithprivkey "privkey" "message"`
+//! - [x] `validateaddress "address"`
+//! - [x] `verifymessage "address" "signature" "message"`
 //!
 //! **== Wallet ==**
-//! - [ ] `abandontransaction "txid"`
+//! - [-] `abandontransaction "txid"`
 //! - [ ] `abortrescan`
 //! - [ ] `addmultisigaddress nrequired ["key",...] ( "label" "address_type" )`
 //! - [ ] `backupwallet "destination"`
-//! - [ ] `bumpfee "txid" ( options ) `
+//! - [x] `bumpfee "txid" ( options ) `
 //! - [x] `createwallet "wallet_name" ( disable_private_keys )`
 //! - [ ] `dumpprivkey "address"`
 //! - [ ] `dumpwallet "filename"`
@@ -108,8 +108,8 @@
 //! - [ ] `getaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `getaccountaddress (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `getaddressbyaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
-//! - [ ] `getaddressesbylabel "label"`
-//! - [ ] `getaddressinfo "address"`
+//! - [x] `getaddressesbylabel "label"`
+//! - [x] `getaddressinfo "address"`
 //! - [x] `getbalance ( "(dummy)" minconf include_watchonly )`
 //! - [x] `getnewaddress ( "label" "address_type" )`
 //! - [ ] `getrawchangeaddress ( "address_type" )`
@@ -117,9 +117,9 @@
 //! - [ ] `getreceivedbyaddress "address" ( minconf )`
 //! - [x] `gettransaction "txid" ( include_watchonly )`
 //! - [ ] `getunconfirmedbalance`
-//! - [ ] `getwalletinfo`
+//! - [x] `getwalletinfo`
 //! - [ ] `importaddress "address" ( "label" rescan p2sh )`
-//! - [ ] `importmulti "requests" ( "options" )`
+//! - [x] `importmulti "requests" ( "options" )`
 //! - [ ] `importprivkey "privkey" ( "label" ) ( rescan )`
 //! - [ ] `importprunedfunds`
 //! - [ ] `importpubkey "pubkey" ( "label" rescan )`
@@ -127,36 +127,41 @@
 //! - [ ] `keypoolrefill ( newsize )`
 //! - [ ] `listaccounts (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `listaddressgroupings`
-//! - [ ] `listlabels ( "purpose" )`
+//! - [x] `listlabels ( "purpose" )`
 //! - [ ] `listlockunspent`
 //! - [ ] `listreceivedbyaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `listreceivedbyaddress ( minconf include_empty include_watchonly address_filter )`
-//! - [ ] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
-//! - [ ] `listtransactions (label count skip include_watchonly)`
-//! - [ ] `listunspent ( minconf maxconf  ["addresses",...] [include_unsafe] [query_options])`
-//! - [ ] `listwallets`
+//! - [x] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
+//! - [x] `listtransactions (label count skip include_watchonly)`
+//! - [x] `listunspent ( minconf maxconf  ["addresses",...] [include_unsafe] [query_options])`
+//! - [x] `listwallets`
 //! - [x] `loadwallet "filename"`
 //! - [ ] `lockunspent unlock ([{"txid":"txid","vout":n},...])`
 //! - [ ] `move (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `removeprunedfunds "txid"`
 //! - [ ] `rescanblockchain ("start_height") ("stop_height")`
 //! - [ ] `sendfrom (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
-//! - [ ] `sendmany "" {"address":amount,...} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode")`
+//! - [x] `sendmany "" {"address":amount,...} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode")`
 //! - [x] `sendtoaddress "address" amount ( "comment" "comment_to" subtractfeefromamount replaceable conf_target "estimate_mode")`
 //! - [ ] `setaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `sethdseed ( "newkeypool" "seed" )`
 //! - [ ] `settxfee amount`
-//! - [ ] `signmessage "address" "message"`
-//! - [ ] `signrawtransactionwithwallet "hexstring" ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )`
+//! - [x] `signmessage "address" "message"`
+//! - [x] `signrawtransactionwithwallet "hexstring" ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )`
 //! - [ ] `unloadwallet ( "wallet_name" )`
-//! - [ ] `walletcreatefundedpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable ) ( options bip32derivs )`
+//! - [x] `walletcreatefundedpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable ) ( options bip32derivs )`
 //! - [ ] `walletlock`
 //! - [ ] `walletpassphrase "passphrase" timeout`
 //! - [ ] `walletpassphrasechange "oldpassphrase" "newpassphrase"`
-//! - [ ] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
+//! - [x] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
 //!
 //! **== Zmq ==**
 //! - [ ] `getzmqnotifications`
+//!
+//! **== Hidden ==**
+//! - [x] `waitforblock <blockhash> (timeout)`
+//! - [x] `waitforblockheight <height> (timeout)`
+//! - [x] `waitfornewblock (timeout)`
 
 /// JSON-RPC types by API section.
 mod blockchain;
@@ -169,25 +174,60 @@ mod util;
 mod wallet;
 mod zmq;
 
+pub(crate) use self::blockchain::mempool_entries;
+pub(crate) use self::raw_transactions::{psbt_sighash_type, psbt_unknown, reject_reason};
+pub(crate) use self::util::witness_program;
 #[doc(inline)]
 pub use self::{
     blockchain::{
-        Bip9Softfork, Bip9SoftforkStatus, ChainTips, ChainTipsStatus, GetBestBlockHash,
-        GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderVerbose, GetBlockStats,
-        GetBlockVerbosityOne, GetBlockVerbosityZero, GetBlockchainInfo, GetChainTips,
-        GetChainTxStats, GetDifficulty, GetMempoolAncestors, GetMempoolAncestorsVerbose, GetTxOut,
-        ScriptPubkey, Softfork, SoftforkReject,
+        Bip9Softfork, Bip9SoftforkStatistics, Bip9SoftforkStatus, ChainTips, ChainTipsStatus,
+        GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderVerbose,
+        GetBlockStats, GetBlockVerbosityOne, GetBlockVerbosityTwo, GetBlockVerbosityTwoError,
+        GetBlockVerbosityTwoTransaction, GetBlockVerbosityZero, GetBlockchainInfo, GetChainTips,
+        GetChainTxStats, GetDifficulty, GetMempoolAncestors, GetMempoolAncestorsVerbose,
+        GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo,
+        GetMempoolInfoError, GetRawMempool, GetRawMempoolVerbose, GetTxOut, GetTxOutSetInfo,
+        GetTxOutSetInfoError, MapMempoolEntryError, MempoolEntry, MempoolEntryError,
+        MempoolEntryFees, MempoolEntryFeesError, ScanTxOutSetAbort, ScanTxOutSetError,
+        ScanTxOutSetStart, ScanTxOutSetStatus, ScanTxOutSetUnspent, ScriptPubkey, Softfork,
+        SoftforkReject, WaitForBlock, WaitForBlockHeight, WaitForNewBlock,
     },
-    control::{GetMemoryInfoStats, Locked, Logging, Uptime},
+    control::{GetMemoryInfoMallocInfo, GetMemoryInfoStats, Locked, Logging, Uptime},
     generating::{Generate, GenerateToAddress},
+    mining::{
+        BlockTemplateTransaction, BlockTemplateTransactionError, GetBlockTemplate,
+        GetBlockTemplateError, GetMiningInfo, GetMiningInfoError, GetNetworkHashPs,
+        PrioritiseTransaction, SubmitBlock,
+    },
     network::{
-        AddedNode, AddedNodeAddress, Banned, GetAddedNodeInfo, GetNetTotals, GetNetworkInfo,
-        GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork, GetPeerInfo, ListBanned,
-        PeerInfo, UploadTarget,
+        AddedNode, AddedNodeAddress, Banned, GetAddedNodeInfo, GetConnectionCount, GetNetTotals,
+        GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork,
+        GetPeerInfo, ListBanned, PeerInfo, UploadTarget,
+    },
+    raw_transactions::{
+        Bip32Deriv, Bip32DerivError, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreatePsbt,
+        CreateRawTransaction, DecodePsbt, DecodePsbtError, DecodeRawTransaction, DecodeScript,
+        DecodeScriptError, DecodeScriptSegwit, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, GetRawTransaction, GetRawTransactionVerbose,
+        GetRawTransactionVerboseError, MempoolAcceptance, PsbtInput, PsbtInputError, PsbtOutput,
+        PsbtOutputError, PsbtScript, PsbtUnknownError, RawTransactionError, RawTransactionInput,
+        RawTransactionOutput, RawTransactionScriptPubkey, RawTransactionScriptSig,
+        SendRawTransaction, SignFail, SignFailError, SignRawTransaction, SignRawTransactionError,
+        TestMempoolAccept, WitnessUtxo,
+    },
+    util::{
+        CreateMultisig, CreateMultisigError, EstimateSmartFee, EstimateSmartFeeError,
+        SignMessageWithPrivKey, ValidateAddress, ValidateAddressError, VerifyMessage,
     },
-    raw_transactions::SendRawTransaction,
     wallet::{
-        CreateWallet, GetBalance, GetNewAddress, GetTransaction, GetTransactionDetail,
-        GetTransactionDetailCategory, LoadWallet, SendToAddress,
+        AddressInformation, AddressPurpose, BumpFee, BumpFeeError, CreateWallet, GetAddressInfo,
+        GetAddressInfoEmbedded, GetAddressInfoEmbeddedError, GetAddressInfoError,
+        GetAddressInfoLabel, GetAddressesByLabel, GetBalance, GetNewAddress, GetTransaction,
+        GetTransactionDetail, GetTransactionDetailCategory, GetWalletInfo, GetWalletInfoError,
+        ImportMulti, ImportMultiResult, JsonRpcError, ListLabels, ListSinceBlock,
+        ListSinceBlockError, ListTransactions, ListUnspent, ListUnspentItem, ListUnspentItemError,
+        ListWallets, LoadWallet, SendMany, SendToAddress, SignMessage, TransactionItem,
+        TransactionItemError, WalletCreateFundedPsbt, WalletCreateFundedPsbtError,
+        WalletProcessPsbt,
     },
 };
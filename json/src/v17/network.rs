@@ -3,9 +3,7 @@
 //! The JSON-RPC API for Bitcoin Core v0.17.1 - network.
 //!
 //! Types for methods found under the `== Network ==` section of the API docs.
-//!
-/// These types do not implement `into_model` because apart from fee rate there is no additional
-/// `rust-bitcoin` types needed.
+
 use core::fmt;
 use std::collections::BTreeMap;
 
@@ -13,7 +11,7 @@ use bitcoin::amount::ParseAmountError;
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
-use crate::model;
+use crate::{model, NumericError};
 
 /// Result of JSON-RPC method `getaddednodeinfo`.
 ///
@@ -234,6 +232,19 @@ impl GetNetworkInfoAddress {
     }
 }
 
+/// Result of JSON-RPC method `getconnectioncount`.
+///
+/// > getconnectioncount
+/// >
+/// > Returns the number of connections to other nodes.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetConnectionCount(pub u64);
+
+impl GetConnectionCount {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetConnectionCount { model::GetConnectionCount(self.0) }
+}
+
 /// Result of JSON-RPC method `getpeerinfo`.
 ///
 /// > getpeerinfo
@@ -252,10 +263,10 @@ pub struct PeerInfo {
     pub address: String,
     /// Bind address of the connection to the peer ("ip:port").
     #[serde(rename = "addrbind")]
-    pub address_bind: String,
+    pub address_bind: Option<String>,
     /// Local address as reported by the peer.
     #[serde(rename = "addrlocal")]
-    pub address_local: String,
+    pub address_local: Option<String>,
     /// The services offered.
     pub services: String,
     /// Whether peer has asked us to relay transactions to it.
@@ -278,16 +289,16 @@ pub struct PeerInfo {
     pub connection_time: u32,
     /// The time offset in seconds.
     #[serde(rename = "timeoffset")]
-    pub time_offset: u32,
-    /// Ping time (if available).
+    pub time_offset: i64,
+    /// Ping time in seconds (if available).
     #[serde(rename = "pingtime")]
-    pub ping_time: u32,
-    /// Minimum observed ping time (if any at all).
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time in seconds (if any at all).
     #[serde(rename = "minping")]
-    pub minimum_ping: u32,
-    /// Ping wait (if non-zero).
+    pub minimum_ping: Option<f64>,
+    /// Ping wait in seconds (if non-zero).
     #[serde(rename = "pingwait")]
-    pub ping_wait: u32,
+    pub ping_wait: Option<f64>,
     /// The peer version, such as 70001.
     pub version: u32,
     /// The string version (e.g. "/Satoshi:0.8.5/").
@@ -300,14 +311,14 @@ pub struct PeerInfo {
     pub add_node: bool,
     /// The starting height (block) of the peer.
     #[serde(rename = "startingheight")]
-    pub starting_height: u32,
+    pub starting_height: i64,
     /// The ban score.
     #[serde(rename = "banscore")]
     pub ban_score: u64,
     /// The last header we have in common with this peer.
-    pub synced_headers: u32,
+    pub synced_headers: i64,
     /// The last block we have in common with this peer.
-    pub synced_blocks: u32,
+    pub synced_blocks: i64,
     /// The heights of blocks we're currently asking from this peer.
     pub inflight: Vec<u32>,
     /// Whether the peer is whitelisted.
@@ -320,14 +331,101 @@ pub struct PeerInfo {
     pub bytes_received_per_message: BTreeMap<String, u64>,
 }
 
+impl GetPeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetPeerInfo {
+        model::GetPeerInfo(self.0.into_iter().map(|p| p.into_model()).collect())
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::PeerInfo {
+        model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            network: None,
+            mapped_as: None,
+            services: self.services,
+            services_names: None,
+            relay_transactions: Some(self.relay_transactions),
+            last_send: self.last_send,
+            last_received: self.last_received,
+            last_transaction: None,
+            last_block: None,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            bip152_hb_to: None,
+            bip152_hb_from: None,
+            add_node: Some(self.add_node),
+            starting_height: Some(self.starting_height),
+            ban_score: Some(self.ban_score),
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            addr_relay_enabled: None,
+            addr_processed: None,
+            addr_rate_limited: None,
+            permissions: vec![],
+            whitelisted: Some(self.whitelisted),
+            minimum_fee_filter: None,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: None,
+            transport_protocol_type: None,
+            session_id: None,
+        }
+    }
+}
+
 /// Result of JSON-RPC method `listbanned`.
 ///
 /// > listbanned
 ///
 /// > List all banned IPs/Subnets.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ListBanned(pub Vec<Banned>);
 
 /// An item from the list returned by the JSON-RPC method `listbanned`
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-pub struct Banned(String); // FIXME: The docs are empty so I don't know what shape this is.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Banned {
+    /// The banned IP address or subnet.
+    pub address: String,
+    /// The UNIX epoch time the ban expires.
+    pub banned_until: i64,
+    /// The UNIX epoch time the ban was created.
+    pub ban_created: i64,
+    /// The reason for the ban (removed in v0.20).
+    pub ban_reason: Option<String>,
+}
+
+impl ListBanned {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListBanned, NumericError> {
+        let banned = self.0.into_iter().map(|b| b.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::ListBanned(banned))
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Banned, NumericError> {
+        Ok(model::Banned {
+            address: self.address,
+            ban_created: crate::to_u32(self.ban_created, "ban_created")?,
+            banned_until: crate::to_u32(self.banned_until, "banned_until")?,
+            ban_duration: None,
+            time_remaining: None,
+        })
+    }
+}
@@ -4,10 +4,1323 @@
 //!
 //! Types for methods found under the `== Rawtransactions ==` section of the API docs.
 
-use bitcoin::{hex, Txid};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::address::{self, Address, NetworkUnchecked};
+use bitcoin::bip32::{self, DerivationPath, Fingerprint, KeySource};
+use bitcoin::consensus::encode;
+use bitcoin::hex::{self, FromHex as _};
+use bitcoin::psbt::{self, raw, Psbt, PsbtParseError, PsbtSighashType};
+use bitcoin::sighash::SighashTypeParseError;
+use bitcoin::{
+    absolute, amount, ecdsa, key, secp256k1, transaction, Amount, BlockHash, OutPoint, PublicKey,
+    ScriptBuf, Sequence, Transaction, TxIn, TxOut, Txid, Witness,
+};
+use internals::write_err;
 use serde::{Deserialize, Serialize};
 
-use crate::model;
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `combinepsbt`.
+///
+/// > combinepsbt ["psbt",...]
+/// >
+/// > Combine multiple partially signed Bitcoin transactions into one transaction.
+/// > Implements the Combiner role.
+/// >
+/// > Arguments:
+/// > 1. "txs"                   (string) A json array of base64 strings of partially signed transactions
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CombinePsbt(pub String); // The base64 encoded PSBT.
+
+impl CombinePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CombinePsbt, PsbtParseError> {
+        let psbt = self.0.parse::<Psbt>()?;
+        Ok(model::CombinePsbt(psbt))
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, PsbtParseError> { Ok(self.into_model()?.0) }
+}
+
+/// Result of JSON-RPC method `combinerawtransaction`.
+///
+/// > combinerawtransaction ["hexstring",...]
+/// >
+/// > Combine multiple partially signed transactions into one transaction.
+/// > The combined transaction may be another partially signed transaction or a
+/// > fully signed transaction.
+/// > Arguments:
+/// > 1. "txs"         (string) A json array of hex strings of partially signed transactions
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CombineRawTransaction(pub String); // The hex encoded transaction.
+
+impl CombineRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CombineRawTransaction, encode::FromHexError> {
+        let tx = encode::deserialize_hex::<Transaction>(&self.0)?;
+        Ok(model::CombineRawTransaction(tx))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, encode::FromHexError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// Result of JSON-RPC method `converttopsbt`.
+///
+/// > converttopsbt "hexstring" ( permitsigdata iswitness )
+/// >
+/// > Converts a network serialized transaction to a PSBT. This should be used only with createrawtransaction and fundrawtransaction
+/// > createpsbt and walletcreatefundedpsbt should be used for new applications.
+/// >
+/// > Arguments:
+/// > 1. "hexstring"              (string, required) The hex string of a raw transaction
+/// > 2. permitsigdata           (boolean, optional, default=false) If true, any signatures in the input will be discarded and conversion.
+/// >                               will continue. If false, RPC will fail if any signatures are present.
+/// > 3. iswitness               (boolean, optional) Whether the transaction hex is a serialized witness transaction.
+/// >                               If iswitness is not present, heuristic tests will be used in decoding. If true, only witness deserializaion
+/// >                               will be tried. If false, only non-witness deserialization wil be tried. Only has an effect if
+/// >                               permitsigdata is true.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ConvertToPsbt(pub String); // The base64 encoded PSBT.
+
+impl ConvertToPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ConvertToPsbt, PsbtParseError> {
+        let psbt = self.0.parse::<Psbt>()?;
+        Ok(model::ConvertToPsbt(psbt))
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, PsbtParseError> { Ok(self.into_model()?.0) }
+}
+
+/// Result of JSON-RPC method `createpsbt`.
+///
+/// > createpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )
+/// >
+/// > Creates a transaction in the Partially Signed Transaction format.
+/// > Implements the Creator role.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CreatePsbt(pub String); // The base64 encoded PSBT.
+
+impl CreatePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CreatePsbt, PsbtParseError> {
+        let psbt = self.0.parse::<Psbt>()?;
+        Ok(model::CreatePsbt(psbt))
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, PsbtParseError> { Ok(self.into_model()?.0) }
+}
+
+/// Result of JSON-RPC method `createrawtransaction`.
+///
+/// > createrawtransaction [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )
+/// >
+/// > Create a transaction spending the given inputs and creating new outputs.
+/// > Outputs can be addresses or data.
+/// > Returns hex-encoded raw transaction.
+/// > Note that the transaction's inputs are not signed, and
+/// > it is not stored in the wallet or transmitted to the network.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CreateRawTransaction(pub String); // The hex encoded transaction.
+
+impl CreateRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CreateRawTransaction, encode::FromHexError> {
+        let tx = encode::deserialize_hex::<Transaction>(&self.0)?;
+        Ok(model::CreateRawTransaction(tx))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, encode::FromHexError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// Result of JSON-RPC method `decodepsbt`.
+///
+/// > decodepsbt "psbt"
+/// >
+/// > Return a JSON object representing the serialized, base64-encoded partially signed Bitcoin transaction.
+/// >
+/// > Arguments:
+/// > 1. "psbt"            (string, required) The PSBT base64 string
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The decoded network-serialized unsigned transaction.
+    pub tx: DecodeRawTransaction,
+    /// The unknown global fields.
+    pub unknown: BTreeMap<String, String>,
+    /// The PSBT inputs.
+    pub inputs: Vec<PsbtInput>,
+    /// The PSBT outputs.
+    pub outputs: Vec<PsbtOutput>,
+    /// The transaction fee paid if all UTXOs slots in the PSBT have been filled.
+    pub fee: Option<f64>,
+}
+
+impl DecodePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodePsbt, DecodePsbtError> {
+        use DecodePsbtError as E;
+
+        let unsigned_tx = self.tx.transaction().map_err(E::Tx)?;
+        let unknown = psbt_unknown(self.unknown).map_err(E::Unknown)?;
+        let inputs = self
+            .inputs
+            .into_iter()
+            .map(|input| input.into_input())
+            .collect::<Result<_, _>>()
+            .map_err(E::Inputs)?;
+        let outputs = self
+            .outputs
+            .into_iter()
+            .map(|output| output.into_output())
+            .collect::<Result<_, _>>()
+            .map_err(E::Outputs)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        let psbt = Psbt {
+            unsigned_tx,
+            version: 0,
+            xpub: BTreeMap::new(),
+            proprietary: BTreeMap::new(),
+            unknown,
+            inputs,
+            outputs,
+        };
+        Ok(model::DecodePsbt { psbt, fee })
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, DecodePsbtError> { Ok(self.into_model()?.psbt) }
+}
+
+/// An input of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtInput {
+    /// Decoded network transaction for non-witness UTXOs.
+    pub non_witness_utxo: Option<DecodeRawTransaction>,
+    /// Transaction output for witness UTXOs.
+    pub witness_utxo: Option<WitnessUtxo>,
+    /// The public keys with their signatures.
+    pub partial_signatures: Option<BTreeMap<String, String>>,
+    /// The sighash type to be used.
+    pub sighash: Option<String>,
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<BTreeMap<String, Bip32Deriv>>,
+    /// The final scriptsig.
+    #[serde(rename = "final_scriptsig")]
+    pub final_script_sig: Option<RawTransactionScriptSig>,
+    /// The final script witness, hex-encoded witness data items.
+    #[serde(rename = "final_scriptwitness")]
+    pub final_script_witness: Option<Vec<String>>,
+    /// The unknown input fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtInput {
+    /// Converts the input to a `bitcoin::psbt::Input`.
+    pub fn into_input(self) -> Result<psbt::Input, PsbtInputError> {
+        use PsbtInputError as E;
+
+        let non_witness_utxo = self
+            .non_witness_utxo
+            .map(|tx| tx.transaction())
+            .transpose()
+            .map_err(E::NonWitnessUtxo)?;
+        let witness_utxo = match self.witness_utxo {
+            Some(utxo) => Some(TxOut {
+                value: Amount::from_btc(utxo.amount).map_err(E::WitnessUtxoAmount)?,
+                script_pubkey: ScriptBuf::from_hex(&utxo.script_pubkey.hex)
+                    .map_err(E::WitnessUtxoScriptPubkey)?,
+            }),
+            None => None,
+        };
+        let partial_sigs = self
+            .partial_signatures
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, sig)| {
+                let pubkey = pubkey.parse::<PublicKey>().map_err(E::PartialSignaturePubkey)?;
+                let sig = sig.parse::<ecdsa::Signature>().map_err(E::PartialSignature)?;
+                Ok((pubkey, sig))
+            })
+            .collect::<Result<_, _>>()?;
+        let sighash_type =
+            self.sighash.map(|s| psbt_sighash_type(&s)).transpose().map_err(E::Sighash)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, deriv)| deriv.into_bip32_derivation(&pubkey))
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let final_script_sig = self
+            .final_script_sig
+            .map(|script_sig| ScriptBuf::from_hex(&script_sig.hex))
+            .transpose()
+            .map_err(E::FinalScriptSig)?;
+        let final_script_witness = self
+            .final_script_witness
+            .map(|witness| {
+                witness
+                    .iter()
+                    .map(|item| Vec::<u8>::from_hex(item))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|items| Witness::from_slice(&items))
+            })
+            .transpose()
+            .map_err(E::FinalScriptWitness)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Input {
+            non_witness_utxo,
+            witness_utxo,
+            partial_sigs,
+            sighash_type,
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            final_script_sig,
+            final_script_witness,
+            unknown,
+            ..Default::default()
+        })
+    }
+}
+
+/// An output of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtOutput {
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<BTreeMap<String, Bip32Deriv>>,
+    /// The unknown output fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtOutput {
+    /// Converts the output to a `bitcoin::psbt::Output`.
+    pub fn into_output(self) -> Result<psbt::Output, PsbtOutputError> {
+        use PsbtOutputError as E;
+
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, deriv)| deriv.into_bip32_derivation(&pubkey))
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Output {
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            unknown,
+            ..Default::default()
+        })
+    }
+}
+
+/// The UTXO spent by a witness input. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WitnessUtxo {
+    /// The value in BTC.
+    pub amount: f64,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: PsbtScript,
+}
+
+/// A script. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PsbtScript {
+    /// The asm.
+    pub asm: String,
+    /// The hex.
+    pub hex: String,
+    /// The type, eg 'pubkeyhash'.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The Bitcoin address, only present for the witness UTXO script pubkey.
+    pub address: Option<String>,
+}
+
+/// The BIP-32 key origin of a public key. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Bip32Deriv {
+    /// The fingerprint of the master key.
+    pub master_fingerprint: String,
+    /// The path.
+    pub path: String,
+}
+
+impl Bip32Deriv {
+    /// Converts the key origin of `pubkey` to a BIP-32 derivation map entry.
+    pub fn into_bip32_derivation(
+        self,
+        pubkey: &str,
+    ) -> Result<(secp256k1::PublicKey, KeySource), Bip32DerivError> {
+        use Bip32DerivError as E;
+
+        let pubkey = pubkey.parse::<secp256k1::PublicKey>().map_err(E::Pubkey)?;
+        let fingerprint =
+            self.master_fingerprint.parse::<Fingerprint>().map_err(E::MasterFingerprint)?;
+        let path = self.path.parse::<DerivationPath>().map_err(E::Path)?;
+        Ok((pubkey, (fingerprint, path)))
+    }
+}
+
+/// Error when converting a `DecodePsbt` type into the model type.
+#[derive(Debug)]
+pub enum DecodePsbtError {
+    /// Conversion of the unsigned transaction `tx` field failed.
+    Tx(RawTransactionError),
+    /// Conversion of the global `unknown` field failed.
+    Unknown(PsbtUnknownError),
+    /// Conversion of one of the PSBT inputs failed.
+    Inputs(PsbtInputError),
+    /// Conversion of one of the PSBT outputs failed.
+    Outputs(PsbtOutputError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+}
+
+impl fmt::Display for DecodePsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodePsbtError as E;
+
+        match *self {
+            E::Tx(ref e) => write_err!(f, "conversion of the `tx` field failed"; e),
+            E::Unknown(ref e) =>
+                write_err!(f, "conversion of the global `unknown` field failed"; e),
+            E::Inputs(ref e) => write_err!(f, "conversion of one of the PSBT inputs failed"; e),
+            E::Outputs(ref e) => write_err!(f, "conversion of one of the PSBT outputs failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DecodePsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodePsbtError as E;
+
+        match *self {
+            E::Tx(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+            E::Inputs(ref e) => Some(e),
+            E::Outputs(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `PsbtInput` type into a `bitcoin::psbt::Input`.
+#[derive(Debug)]
+pub enum PsbtInputError {
+    /// Conversion of the `non_witness_utxo` field failed.
+    NonWitnessUtxo(RawTransactionError),
+    /// Conversion of the `witness_utxo` amount failed.
+    WitnessUtxoAmount(amount::ParseAmountError),
+    /// Conversion of the `witness_utxo` script pubkey failed.
+    WitnessUtxoScriptPubkey(hex::HexToBytesError),
+    /// Conversion of one of the `partial_signatures` public keys failed.
+    PartialSignaturePubkey(key::ParsePublicKeyError),
+    /// Conversion of one of the `partial_signatures` signatures failed.
+    PartialSignature(ecdsa::Error),
+    /// Conversion of the `sighash` field failed.
+    Sighash(SighashTypeParseError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivError),
+    /// Conversion of the `final_scriptsig` field failed.
+    FinalScriptSig(hex::HexToBytesError),
+    /// Conversion of the `final_scriptwitness` field failed.
+    FinalScriptWitness(hex::HexToBytesError),
+    /// Conversion of the `unknown` field failed.
+    Unknown(PsbtUnknownError),
+}
+
+impl fmt::Display for PsbtInputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtInputError as E;
+
+        match *self {
+            E::NonWitnessUtxo(ref e) =>
+                write_err!(f, "conversion of the `non_witness_utxo` field failed"; e),
+            E::WitnessUtxoAmount(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` amount failed"; e),
+            E::WitnessUtxoScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` script pubkey failed"; e),
+            E::PartialSignaturePubkey(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` public key failed"; e),
+            E::PartialSignature(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` signature failed"; e),
+            E::Sighash(ref e) => write_err!(f, "conversion of the `sighash` field failed"; e),
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            E::Bip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+            E::FinalScriptSig(ref e) =>
+                write_err!(f, "conversion of the `final_scriptsig` field failed"; e),
+            E::FinalScriptWitness(ref e) =>
+                write_err!(f, "conversion of the `final_scriptwitness` field failed"; e),
+            E::Unknown(ref e) => write_err!(f, "conversion of the `unknown` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtInputError as E;
+
+        match *self {
+            E::NonWitnessUtxo(ref e) => Some(e),
+            E::WitnessUtxoAmount(ref e) => Some(e),
+            E::WitnessUtxoScriptPubkey(ref e) => Some(e),
+            E::PartialSignaturePubkey(ref e) => Some(e),
+            E::PartialSignature(ref e) => Some(e),
+            E::Sighash(ref e) => Some(e),
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+            E::Bip32Derivs(ref e) => Some(e),
+            E::FinalScriptSig(ref e) => Some(e),
+            E::FinalScriptWitness(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `PsbtOutput` type into a `bitcoin::psbt::Output`.
+#[derive(Debug)]
+pub enum PsbtOutputError {
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivError),
+    /// Conversion of the `unknown` field failed.
+    Unknown(PsbtUnknownError),
+}
+
+impl fmt::Display for PsbtOutputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtOutputError as E;
+
+        match *self {
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            E::Bip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+            E::Unknown(ref e) => write_err!(f, "conversion of the `unknown` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtOutputError as E;
+
+        match *self {
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+            E::Bip32Derivs(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a BIP-32 derivation of a `decodepsbt` map.
+#[derive(Debug)]
+pub enum Bip32DerivError {
+    /// Conversion of the public key failed.
+    Pubkey(secp256k1::Error),
+    /// Conversion of the `master_fingerprint` field failed.
+    MasterFingerprint(hex::HexToArrayError),
+    /// Conversion of the `path` field failed.
+    Path(bip32::Error),
+}
+
+impl fmt::Display for Bip32DerivError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Bip32DerivError as E;
+
+        match *self {
+            E::Pubkey(ref e) => write_err!(f, "conversion of the public key failed"; e),
+            E::MasterFingerprint(ref e) =>
+                write_err!(f, "conversion of the `master_fingerprint` field failed"; e),
+            E::Path(ref e) => write_err!(f, "conversion of the `path` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for Bip32DerivError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use Bip32DerivError as E;
+
+        match *self {
+            E::Pubkey(ref e) => Some(e),
+            E::MasterFingerprint(ref e) => Some(e),
+            E::Path(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting the `unknown` map of a `decodepsbt` map.
+#[derive(Debug)]
+pub enum PsbtUnknownError {
+    /// Conversion of a key failed.
+    Key(hex::HexToBytesError),
+    /// A key was empty, i.e. it was missing the key type.
+    EmptyKey,
+    /// Conversion of a value failed.
+    Value(hex::HexToBytesError),
+}
+
+impl fmt::Display for PsbtUnknownError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtUnknownError as E;
+
+        match *self {
+            E::Key(ref e) => write_err!(f, "conversion of a key failed"; e),
+            E::EmptyKey => write!(f, "key is missing the key type"),
+            E::Value(ref e) => write_err!(f, "conversion of a value failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtUnknownError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtUnknownError as E;
+
+        match *self {
+            E::Key(ref e) => Some(e),
+            E::EmptyKey => None,
+            E::Value(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `decoderawtransaction`.
+///
+/// > decoderawtransaction "hexstring" ( iswitness )
+/// >
+/// > Return a JSON object representing the serialized, hex-encoded transaction.
+/// >
+/// > Arguments:
+/// > 1. "hexstring"      (string, required) The transaction hex string
+/// > 2. iswitness          (boolean, optional) Whether the transaction hex is a serialized witness transaction
+/// >                          If iswitness is not present, heuristic tests will be used in decoding
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeRawTransaction {
+    /// The transaction id.
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4 - 3 and vsize*4).
+    pub weight: i64,
+    /// The version.
+    pub version: i32,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInput>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+}
+
+impl DecodeRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeRawTransaction, RawTransactionError> {
+        let input =
+            self.vin.into_iter().map(|input| input.into_tx_in()).collect::<Result<_, _>>()?;
+        let output =
+            self.vout.into_iter().map(|output| output.into_tx_out()).collect::<Result<_, _>>()?;
+
+        let tx = Transaction {
+            version: transaction::Version(self.version),
+            lock_time: absolute::LockTime::from_consensus(crate::to_u32(
+                self.lock_time,
+                "lock_time",
+            )?),
+            input,
+            output,
+        };
+        Ok(model::DecodeRawTransaction(tx))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, RawTransactionError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// A transaction input. Part of `decoderawtransaction` and `getrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RawTransactionInput {
+    /// The coinbase script, hex-encoded (only present for coinbase transactions).
+    pub coinbase: Option<String>,
+    /// The transaction id (not present for coinbase transactions).
+    pub txid: Option<String>,
+    /// The output number (not present for coinbase transactions).
+    pub vout: Option<i64>,
+    /// The script (not present for coinbase transactions).
+    #[serde(rename = "scriptSig")]
+    pub script_sig: Option<RawTransactionScriptSig>,
+    /// Hex-encoded witness data (if any).
+    #[serde(rename = "txinwitness")]
+    pub witness: Option<Vec<String>>,
+    /// The script sequence number.
+    pub sequence: i64,
+}
+
+impl RawTransactionInput {
+    /// Converts the input to a `bitcoin::TxIn`.
+    pub fn into_tx_in(self) -> Result<TxIn, RawTransactionError> {
+        use RawTransactionError as E;
+
+        let (previous_output, script_sig) = match self.coinbase {
+            Some(coinbase) =>
+                (OutPoint::null(), ScriptBuf::from_hex(&coinbase).map_err(E::Coinbase)?),
+            None => {
+                let txid = self.txid.ok_or(E::MissingOutPoint)?.parse::<Txid>().map_err(E::Txid)?;
+                let vout = crate::to_u32(self.vout.ok_or(E::MissingOutPoint)?, "vout")?;
+                let script_sig = self
+                    .script_sig
+                    .map(|script_sig| ScriptBuf::from_hex(&script_sig.hex))
+                    .transpose()
+                    .map_err(E::ScriptSig)?
+                    .unwrap_or_default();
+                (OutPoint { txid, vout }, script_sig)
+            }
+        };
+        let witness = match self.witness {
+            Some(witness) => {
+                let items = witness
+                    .iter()
+                    .map(|item| Vec::<u8>::from_hex(item))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(E::Witness)?;
+                Witness::from_slice(&items)
+            }
+            None => Witness::default(),
+        };
+
+        Ok(TxIn {
+            previous_output,
+            script_sig,
+            sequence: Sequence(crate::to_u32(self.sequence, "sequence")?),
+            witness,
+        })
+    }
+}
+
+/// A script sig. Part of `decoderawtransaction` and `getrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RawTransactionScriptSig {
+    /// Script assembly.
+    pub asm: String,
+    /// Script hex.
+    pub hex: String,
+}
+
+/// A transaction output. Part of `decoderawtransaction` and `getrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RawTransactionOutput {
+    /// The value in BTC.
+    pub value: f64,
+    /// Index.
+    pub n: i64,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: RawTransactionScriptPubkey,
+}
+
+impl RawTransactionOutput {
+    /// Converts the output to a `bitcoin::TxOut`.
+    pub fn into_tx_out(self) -> Result<TxOut, RawTransactionError> {
+        use RawTransactionError as E;
+
+        let value = Amount::from_btc(self.value).map_err(E::Value)?;
+        let script_pubkey =
+            ScriptBuf::from_hex(&self.script_pubkey.hex).map_err(E::ScriptPubkey)?;
+        Ok(TxOut { value, script_pubkey })
+    }
+}
+
+/// A script pubkey. Part of `decoderawtransaction` and `getrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RawTransactionScriptPubkey {
+    /// Script assembly.
+    pub asm: String,
+    /// Script hex.
+    pub hex: String,
+    /// The required signatures (not present for non-standard scripts).
+    #[serde(rename = "reqSigs")]
+    pub required_signatures: Option<i64>,
+    /// The type, eg 'pubkeyhash'.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The bitcoin addresses (not present for non-standard scripts).
+    pub addresses: Option<Vec<String>>,
+}
+
+/// Error when converting a decoded transaction into a `bitcoin::Transaction`.
+#[derive(Debug)]
+pub enum RawTransactionError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// A non-coinbase input is missing its `txid` or `vout` field.
+    MissingOutPoint,
+    /// Conversion of the input `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the input `coinbase` field failed.
+    Coinbase(hex::HexToBytesError),
+    /// Conversion of the input `script_sig` field failed.
+    ScriptSig(hex::HexToBytesError),
+    /// Conversion of the input `witness` field failed.
+    Witness(hex::HexToBytesError),
+    /// Conversion of the output `value` field failed.
+    Value(amount::ParseAmountError),
+    /// Conversion of the output `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+}
+
+impl fmt::Display for RawTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use RawTransactionError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::MissingOutPoint => write!(f, "input is missing the `txid` or `vout` field"),
+            E::Txid(ref e) => write_err!(f, "conversion of the input `txid` field failed"; e),
+            E::Coinbase(ref e) =>
+                write_err!(f, "conversion of the input `coinbase` field failed"; e),
+            E::ScriptSig(ref e) =>
+                write_err!(f, "conversion of the input `script_sig` field failed"; e),
+            E::Witness(ref e) => write_err!(f, "conversion of the input `witness` field failed"; e),
+            E::Value(ref e) => write_err!(f, "conversion of the output `value` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the output `script_pubkey` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for RawTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use RawTransactionError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::MissingOutPoint => None,
+            E::Txid(ref e) => Some(e),
+            E::Coinbase(ref e) => Some(e),
+            E::ScriptSig(ref e) => Some(e),
+            E::Witness(ref e) => Some(e),
+            E::Value(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for RawTransactionError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `decodescript`.
+///
+/// > decodescript "hexstring"
+/// >
+/// > Decode a hex-encoded script.
+/// >
+/// > Arguments:
+/// > 1. "hexstring"     (string) the hex encoded script
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeScript {
+    /// Script public key.
+    pub asm: String,
+    /// The output type.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The required signatures.
+    #[serde(rename = "reqSigs")]
+    pub required_signatures: Option<i64>,
+    /// The bitcoin addresses.
+    pub addresses: Option<Vec<String>>,
+    /// Address of P2SH script wrapping this redeem script (not returned if the script is already a P2SH).
+    pub p2sh: Option<String>,
+    /// Result of a witness script public key wrapping this redeem script (not returned if the
+    /// script is a P2SH or witness).
+    pub segwit: Option<DecodeScriptSegwit>,
+}
+
+impl DecodeScript {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeScript, DecodeScriptError> {
+        use DecodeScriptError as E;
+
+        let required_signatures = self
+            .required_signatures
+            .map(|n| crate::to_u32(n, "required_signatures"))
+            .transpose()?;
+        let addresses = self
+            .addresses
+            .unwrap_or_default()
+            .iter()
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .collect::<Result<_, _>>()
+            .map_err(E::Addresses)?;
+        let p2sh = self
+            .p2sh
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::P2sh)?;
+        let segwit = self.segwit.map(|s| s.into_model()).transpose()?;
+
+        Ok(model::DecodeScript { type_: self.type_, required_signatures, addresses, p2sh, segwit })
+    }
+}
+
+/// The segwit part of the result of JSON-RPC method `decodescript`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeScriptSegwit {
+    /// Script public key.
+    pub asm: String,
+    /// Hex encoded public key.
+    pub hex: String,
+    /// The output type.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The required signatures.
+    #[serde(rename = "reqSigs")]
+    pub required_signatures: Option<i64>,
+    /// The bitcoin addresses.
+    pub addresses: Option<Vec<String>>,
+    /// Address of the P2SH script wrapping this witness redeem script.
+    #[serde(rename = "p2sh-segwit")]
+    pub p2sh_segwit: Option<String>,
+}
+
+impl DecodeScriptSegwit {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeScriptSegwit, DecodeScriptError> {
+        use DecodeScriptError as E;
+
+        let script_pubkey = ScriptBuf::from_hex(&self.hex).map_err(E::Hex)?;
+        let required_signatures = self
+            .required_signatures
+            .map(|n| crate::to_u32(n, "required_signatures"))
+            .transpose()?;
+        let addresses = self
+            .addresses
+            .unwrap_or_default()
+            .iter()
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .collect::<Result<_, _>>()
+            .map_err(E::Addresses)?;
+        let p2sh_segwit = self
+            .p2sh_segwit
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::P2shSegwit)?;
+
+        Ok(model::DecodeScriptSegwit {
+            script_pubkey,
+            type_: self.type_,
+            required_signatures,
+            addresses,
+            p2sh_segwit,
+        })
+    }
+}
+
+/// Error when converting a `DecodeScript` type into the model type.
+#[derive(Debug)]
+pub enum DecodeScriptError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `hex` field failed.
+    Hex(hex::HexToBytesError),
+    /// Conversion of the `address` or `addresses` field failed.
+    Addresses(address::ParseError),
+    /// Conversion of the `p2sh` field failed.
+    P2sh(address::ParseError),
+    /// Conversion of the `p2sh-segwit` field failed.
+    P2shSegwit(address::ParseError),
+}
+
+impl fmt::Display for DecodeScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodeScriptError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Addresses(ref e) => write_err!(f, "conversion of the `addresses` field failed"; e),
+            E::P2sh(ref e) => write_err!(f, "conversion of the `p2sh` field failed"; e),
+            E::P2shSegwit(ref e) =>
+                write_err!(f, "conversion of the `p2sh-segwit` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DecodeScriptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodeScriptError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Hex(ref e) => Some(e),
+            E::Addresses(ref e) => Some(e),
+            E::P2sh(ref e) => Some(e),
+            E::P2shSegwit(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for DecodeScriptError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `finalizepsbt`.
+///
+/// > finalizepsbt "psbt" ( extract )
+/// >
+/// > Finalize the inputs of a PSBT. If the transaction is fully signed, it will produce a
+/// > network serialized transaction which can be broadcast with sendrawtransaction. Otherwise a PSBT will be
+/// > created which has the final_scriptSig and final_scriptWitness fields filled for inputs that are complete.
+/// > Implements the Finalizer and Extractor roles.
+/// >
+/// > Arguments:
+/// > 1. "psbt"                 (string) A base64 string of a PSBT
+/// > 2. "extract"              (boolean, optional, default=true) If true and the transaction is complete,
+/// >                              extract and return the complete transaction in normal network serialization instead of the PSBT.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FinalizePsbt {
+    /// The base64-encoded partially signed transaction if not extracted.
+    pub psbt: Option<String>,
+    /// The hex-encoded network transaction if extracted.
+    pub hex: Option<String>,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+}
+
+impl FinalizePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::FinalizePsbt, FinalizePsbtError> {
+        use FinalizePsbtError as E;
+
+        let psbt = self.psbt.map(|s| s.parse::<Psbt>()).transpose().map_err(E::Psbt)?;
+        let tx = self
+            .hex
+            .map(|s| encode::deserialize_hex::<Transaction>(&s))
+            .transpose()
+            .map_err(E::Tx)?;
+
+        Ok(model::FinalizePsbt { psbt, tx, complete: self.complete })
+    }
+}
+
+/// Error when converting a `FinalizePsbt` type into the model type.
+#[derive(Debug)]
+pub enum FinalizePsbtError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the transaction `hex` field failed.
+    Tx(encode::FromHexError),
+}
+
+impl fmt::Display for FinalizePsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use FinalizePsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for FinalizePsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use FinalizePsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::Tx(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `fundrawtransaction`.
+///
+/// > fundrawtransaction "hexstring" ( options iswitness )
+/// >
+/// > Add inputs to a transaction until it has enough in value to meet its out value.
+/// > This will not modify existing inputs, and will add at most one change output to the outputs.
+/// > No existing outputs will be modified unless "subtractFeeFromOutputs" is specified.
+/// > Note that inputs which were signed may need to be resigned after completion since in/outputs have been added.
+/// > The inputs added will not be signed, use signrawtransaction for that.
+/// > Note that all existing inputs must have their previous output transaction be in the wallet.
+/// > Note that all inputs selected must be of standard form and P2SH scripts must be
+/// > in the wallet using importaddress or addmultisigaddress (to calculate fees).
+/// > You can see whether this is the case by checking the "solvable" field in the listunspent output.
+/// > Only pay-to-pubkey, multisig, and P2SH versions thereof are currently supported for watch-only
+/// >
+/// > Arguments:
+/// > 1. "hexstring"           (string, required) The hex string of the raw transaction
+/// > 2. options                 (object, optional)
+/// > 3. iswitness               (boolean, optional) Whether the transaction hex is a serialized witness transaction
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FundRawTransaction {
+    /// The resulting raw transaction (hex-encoded string).
+    pub hex: String,
+    /// Fee in BTC the resulting transaction pays.
+    pub fee: f64,
+    /// The position of the added change output, or -1.
+    #[serde(rename = "changepos")]
+    pub change_position: i64,
+}
+
+impl FundRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::FundRawTransaction, FundRawTransactionError> {
+        use FundRawTransactionError as E;
+
+        let tx = encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Tx)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+        let change_position = u32::try_from(self.change_position).ok();
+
+        Ok(model::FundRawTransaction { tx, fee, change_position })
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, FundRawTransactionError> {
+        Ok(self.into_model()?.tx)
+    }
+}
+
+/// Error when converting a `FundRawTransaction` type into the model type.
+#[derive(Debug)]
+pub enum FundRawTransactionError {
+    /// Conversion of the transaction `hex` field failed.
+    Tx(encode::FromHexError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+}
+
+impl fmt::Display for FundRawTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use FundRawTransactionError as E;
+
+        match *self {
+            E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for FundRawTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use FundRawTransactionError as E;
+
+        match *self {
+            E::Tx(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getrawtransaction` with verbose set to false.
+///
+/// > getrawtransaction "txid" ( verbose "blockhash" )
+/// >
+/// > NOTE: By default this function only works for mempool transactions. If the -txindex option is
+/// > enabled, it also works for blockchain transactions. If the block which contains the transaction
+/// > is known, its hash can be provided even for nodes without -txindex. Note that if a blockhash is
+/// > provided, only that block will be searched and if the transaction is in the mempool or other
+/// > blocks, or if this node does not have the given block available, the transaction will not be found.
+/// > DEPRECATED: for now, it also works for transactions with unspent outputs.
+/// >
+/// > Return the raw transaction data.
+/// >
+/// > If verbose is 'true', returns an Object with information about 'txid'.
+/// > If verbose is 'false' or omitted, returns a string that is serialized, hex-encoded data for 'txid'.
+/// >
+/// > Arguments:
+/// > 1. "txid"      (string, required) The transaction id
+/// > 2. verbose     (bool, optional, default=false) If false, return a string, otherwise return a json object
+/// > 3. "blockhash" (string, optional) The block in which to look for the transaction
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransaction(pub String); // The hex encoded transaction.
+
+impl GetRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawTransaction, encode::FromHexError> {
+        let tx = encode::deserialize_hex::<Transaction>(&self.0)?;
+        Ok(model::GetRawTransaction(tx))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, encode::FromHexError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// Result of JSON-RPC method `getrawtransaction` with verbose set to `true`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransactionVerbose {
+    /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
+    pub in_active_chain: Option<bool>,
+    /// The serialized, hex-encoded data for 'txid'.
+    pub hex: String,
+    /// The transaction id (same as provided).
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The serialized transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4-3 and vsize*4).
+    pub weight: i64,
+    /// The version.
+    pub version: i32,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInput>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+    /// The block hash.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The confirmations.
+    pub confirmations: Option<i64>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "time")]
+    pub transaction_time: Option<i64>,
+    /// The block time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<i64>,
+}
+
+impl GetRawTransactionVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::GetRawTransactionVerbose, GetRawTransactionVerboseError> {
+        use GetRawTransactionVerboseError as E;
+
+        let transaction =
+            encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Transaction)?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+        let confirmations =
+            self.confirmations.map(|n| crate::to_u32(n, "confirmations")).transpose()?;
+        let transaction_time =
+            self.transaction_time.map(|t| crate::to_u32(t, "transaction_time")).transpose()?;
+        let block_time = self.block_time.map(|t| crate::to_u32(t, "block_time")).transpose()?;
+
+        Ok(model::GetRawTransactionVerbose {
+            in_active_chain: self.in_active_chain,
+            transaction,
+            block_hash,
+            confirmations,
+            transaction_time,
+            block_time,
+        })
+    }
+}
+
+/// Error when converting a `GetRawTransactionVerbose` type into the model type.
+#[derive(Debug)]
+pub enum GetRawTransactionVerboseError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the transaction `hex` field failed.
+    Transaction(encode::FromHexError),
+    /// Conversion of the `block_hash` field failed.
+    BlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetRawTransactionVerboseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetRawTransactionVerboseError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Transaction(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::BlockHash(ref e) => write_err!(f, "conversion of the `block_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetRawTransactionVerboseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetRawTransactionVerboseError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Transaction(ref e) => Some(e),
+            E::BlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetRawTransactionVerboseError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
 
 /// Result of JSON-RPC method `sendrawtransaction`.
 ///
@@ -36,3 +1349,332 @@ impl SendRawTransaction {
         Ok(model.0)
     }
 }
+
+/// Result of JSON-RPC method `signrawtransactionwithkey`.
+///
+/// > signrawtransactionwithkey "hexstring" ["privatekey1",...] ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )
+/// >
+/// > Sign inputs for raw transaction (serialized, hex-encoded).
+/// > The second argument is an array of base58-encoded private
+/// > keys that will be the only keys used to sign the transaction.
+/// > The third optional argument (may be null) is an array of previous transaction outputs that
+/// > this transaction depends on but may not yet be in the block chain.
+/// >
+/// > Arguments:
+/// > 1. "hexstring"                      (string, required) The transaction hex string
+/// > 2. "privkeys"                       (string, required) A json array of base58-encoded private keys for signing
+/// > 3. "prevtxs"                        (string, optional) An json array of previous dependent transaction outputs
+/// > 4. "sighashtype"                    (string, optional, default=ALL) The signature hash type.
+///
+/// This is also the result of `signrawtransactionwithwallet`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SignRawTransaction {
+    /// The hex-encoded raw transaction with signature(s).
+    pub hex: String,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// Script verification errors (if there are any).
+    #[serde(default)]
+    pub errors: Vec<SignFail>,
+}
+
+impl SignRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SignRawTransaction, SignRawTransactionError> {
+        use SignRawTransactionError as E;
+
+        let tx = encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Tx)?;
+        let errors = self
+            .errors
+            .into_iter()
+            .map(|e| e.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Errors)?;
+
+        Ok(model::SignRawTransaction { tx, complete: self.complete, errors })
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, SignRawTransactionError> {
+        Ok(self.into_model()?.tx)
+    }
+}
+
+/// An input that could not be signed. Part of `signrawtransactionwithkey` and
+/// `signrawtransactionwithwallet`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignFail {
+    /// The hash of the referenced, previous transaction.
+    pub txid: String,
+    /// The index of the output to spent and used as input.
+    pub vout: i64,
+    /// Hex-encoded witness data.
+    #[serde(default)]
+    pub witness: Vec<String>,
+    /// The hex-encoded signature script.
+    #[serde(rename = "scriptSig")]
+    pub script_sig: String,
+    /// Script sequence number.
+    pub sequence: i64,
+    /// Verification or signing error related to the input.
+    pub error: String,
+}
+
+impl SignFail {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SignFail, SignFailError> {
+        use SignFailError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let vout = crate::to_u32(self.vout, "vout")?;
+        let witness = self
+            .witness
+            .iter()
+            .map(|item| Vec::<u8>::from_hex(item))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Witness)?;
+        let script_sig = ScriptBuf::from_hex(&self.script_sig).map_err(E::ScriptSig)?;
+        let sequence = Sequence(crate::to_u32(self.sequence, "sequence")?);
+
+        Ok(model::SignFail {
+            txid,
+            vout,
+            witness: Witness::from_slice(&witness),
+            script_sig,
+            sequence,
+            error: self.error,
+        })
+    }
+}
+
+/// Error when converting a `SignRawTransaction` type into the model type.
+#[derive(Debug)]
+pub enum SignRawTransactionError {
+    /// Conversion of the transaction `hex` field failed.
+    Tx(encode::FromHexError),
+    /// Conversion of one of the `errors` entries failed.
+    Errors(SignFailError),
+}
+
+impl fmt::Display for SignRawTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SignRawTransactionError as E;
+
+        match *self {
+            E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Errors(ref e) => write_err!(f, "conversion of an `errors` entry failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for SignRawTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SignRawTransactionError as E;
+
+        match *self {
+            E::Tx(ref e) => Some(e),
+            E::Errors(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `SignFail` type into the model type.
+#[derive(Debug)]
+pub enum SignFailError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `witness` field failed.
+    Witness(hex::HexToBytesError),
+    /// Conversion of the `script_sig` field failed.
+    ScriptSig(hex::HexToBytesError),
+}
+
+impl fmt::Display for SignFailError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SignFailError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Witness(ref e) => write_err!(f, "conversion of the `witness` field failed"; e),
+            E::ScriptSig(ref e) => write_err!(f, "conversion of the `script_sig` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for SignFailError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SignFailError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::Witness(ref e) => Some(e),
+            E::ScriptSig(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for SignFailError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `testmempoolaccept`.
+///
+/// > testmempoolaccept ["rawtxs"] ( allowhighfees )
+/// >
+/// > Returns if raw transaction (serialized, hex-encoded) would be accepted by mempool.
+/// >
+/// > This checks if the transaction violates the consensus or policy rules.
+/// >
+/// > See sendrawtransaction call.
+/// >
+/// > Arguments:
+/// > 1. ["rawtxs"]       (array, required) An array of hex strings of raw transactions.
+/// >                                         Length must be one for now.
+/// > 2. allowhighfees    (boolean, optional, default=false) Allow high fees
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
+
+/// A single mempool acceptance test result. Part of `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptance {
+    /// The transaction hash in hex.
+    pub txid: String,
+    /// If the mempool allows this tx to be inserted.
+    pub allowed: bool,
+    /// Rejection string (only present when 'allowed' is false).
+    #[serde(rename = "reject-reason")]
+    pub reject_reason: Option<String>,
+}
+
+impl TestMempoolAccept {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TestMempoolAccept, hex::HexToArrayError> {
+        let results = self.0.into_iter().map(|r| r.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::TestMempoolAccept { results })
+    }
+}
+
+impl MempoolAcceptance {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolAcceptance, hex::HexToArrayError> {
+        let txid = self.txid.parse::<Txid>()?;
+        Ok(model::MempoolAcceptance {
+            txid,
+            wtxid: None,
+            package_error: None,
+            allowed: self.allowed,
+            vsize: None,
+            fees: None,
+            reject_reason: self.reject_reason.as_deref().map(reject_reason),
+            reject_message: self.reject_reason,
+        })
+    }
+}
+
+/// Converts a sighash type as returned by Core (e.g. `ALL|ANYONECANPAY`) to a `PsbtSighashType`.
+pub(crate) fn psbt_sighash_type(sighash: &str) -> Result<PsbtSighashType, SighashTypeParseError> {
+    let sighash =
+        sighash.split('|').map(|flag| format!("SIGHASH_{}", flag)).collect::<Vec<_>>().join("|");
+    sighash.parse()
+}
+
+/// Converts a `decodepsbt` map of unknown key-value pairs, the hex-encoded key includes the type.
+pub(crate) fn psbt_unknown(
+    unknown: BTreeMap<String, String>,
+) -> Result<BTreeMap<raw::Key, Vec<u8>>, PsbtUnknownError> {
+    use PsbtUnknownError as E;
+
+    unknown
+        .into_iter()
+        .map(|(key, value)| {
+            let key = Vec::<u8>::from_hex(&key).map_err(E::Key)?;
+            let (type_value, key) = key.split_first().ok_or(E::EmptyKey)?;
+            let value = Vec::<u8>::from_hex(&value).map_err(E::Value)?;
+            Ok((raw::Key { type_value: *type_value, key: key.to_vec() }, value))
+        })
+        .collect()
+}
+
+/// Converts a mempool reject reason string as returned by Core to a `RejectReason`.
+///
+/// Any details following the reason are dropped, the model types keep the full string as well.
+pub(crate) fn reject_reason(reason: &str) -> model::RejectReason {
+    use model::RejectReason as R;
+
+    // Before v20 the reason is prefixed with a reject code e.g., "18: txn-already-in-mempool".
+    let stripped = match reason.split_once(": ") {
+        Some((code, rest)) if code.parse::<u32>().is_ok() => rest,
+        _ => reason,
+    };
+    // Some reasons are followed by details e.g., "min relay fee not met, 100 < 141".
+    let kind = stripped.split(',').next().unwrap_or(stripped);
+
+    match kind {
+        "txn-already-in-mempool" => R::AlreadyInMempool,
+        "txn-already-known" => R::AlreadyKnown,
+        "missing-inputs" => R::MissingInputs,
+        "bad-txns-inputs-missingorspent" => R::InputsMissingOrSpent,
+        "txn-mempool-conflict" => R::MempoolConflict,
+        "insufficient fee" => R::InsufficientFee,
+        "mempool min fee not met" => R::MempoolMinFeeNotMet,
+        "min relay fee not met" => R::MinRelayFeeNotMet,
+        "max-fee-exceeded" | "absurdly-high-fee" => R::MaxFeeExceeded,
+        "non-final" => R::NonFinal,
+        "dust" => R::Dust,
+        _ => R::Other(reason.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::RejectReason;
+
+    #[test]
+    fn reject_reason_known() {
+        assert_eq!(reject_reason("txn-already-in-mempool"), RejectReason::AlreadyInMempool);
+        assert_eq!(reject_reason("absurdly-high-fee"), RejectReason::MaxFeeExceeded);
+        assert_eq!(reject_reason("max-fee-exceeded"), RejectReason::MaxFeeExceeded);
+        assert_eq!(
+            reject_reason("min relay fee not met, 100 < 141"),
+            RejectReason::MinRelayFeeNotMet
+        );
+    }
+
+    #[test]
+    fn reject_reason_with_reject_code() {
+        assert_eq!(reject_reason("18: txn-already-in-mempool"), RejectReason::AlreadyInMempool);
+        assert_eq!(
+            reject_reason("66: insufficient fee, rejecting replacement"),
+            RejectReason::InsufficientFee
+        );
+    }
+
+    #[test]
+    fn reject_reason_other_keeps_full_string() {
+        assert_eq!(
+            reject_reason("bad-txns-vout-empty"),
+            RejectReason::Other("bad-txns-vout-empty".to_owned())
+        );
+        assert_eq!(
+            reject_reason("16: bad-txns-vout-empty, details"),
+            RejectReason::Other("16: bad-txns-vout-empty, details".to_owned())
+        );
+    }
+
+    #[test]
+    fn mempool_acceptance_keeps_reject_message() {
+        let json = MempoolAcceptance {
+            txid: "0000000000000000000000000000000000000000000000000000000000000001".to_owned(),
+            allowed: false,
+            reject_reason: Some("66: min relay fee not met, 100 < 141".to_owned()),
+        };
+        let model = json.into_model().unwrap();
+        assert_eq!(model.reject_reason, Some(RejectReason::MinRelayFeeNotMet));
+        assert_eq!(model.reject_message.as_deref(), Some("66: min relay fee not met, 100 < 141"));
+    }
+}
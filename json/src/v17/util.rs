@@ -3,3 +3,320 @@
 //! The JSON-RPC API for Bitcoin Core v0.17.1 - util.
 //!
 //! Types for methods found under the `== Util ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::hex::FromHex as _;
+use bitcoin::{
+    address, amount, hex, witness_program, witness_version, Address, ScriptBuf, WitnessProgram,
+    WitnessVersion,
+};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `createmultisig`.
+///
+/// > createmultisig nrequired ["key",...] ( "address_type" )
+/// >
+/// > Creates a multi-signature address with n signature of m keys required.
+/// > It returns a json object with the address and redeemScript.
+/// >
+/// > Arguments:
+/// > 1. nrequired                    (numeric, required) The number of required signatures out of the n keys.
+/// > 2. "keys"                       (string, required) A json array of hex-encoded public keys
+/// > 3. "address_type"               (string, optional) The address type to use. Options are "legacy", "p2sh-segwit", and "bech32". Default is legacy.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateMultisig {
+    /// The value of the new multisig address.
+    pub address: String,
+    /// The string value of the hex-encoded redemption script.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: String,
+}
+
+impl CreateMultisig {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CreateMultisig, CreateMultisigError> {
+        use CreateMultisigError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let redeem_script = ScriptBuf::from_hex(&self.redeem_script).map_err(E::RedeemScript)?;
+
+        Ok(model::CreateMultisig { address, redeem_script, descriptor: None, warnings: vec![] })
+    }
+}
+
+/// Error when converting a `CreateMultisig` type into the model type.
+#[derive(Debug)]
+pub enum CreateMultisigError {
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+}
+
+impl fmt::Display for CreateMultisigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use CreateMultisigError as E;
+
+        match *self {
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for CreateMultisigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use CreateMultisigError as E;
+
+        match *self {
+            E::Address(ref e) => Some(e),
+            E::RedeemScript(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `estimatesmartfee`.
+///
+/// > estimatesmartfee conf_target ("estimate_mode")
+/// >
+/// > Estimates the approximate fee per kilobyte needed for a transaction to begin
+/// > confirmation within conf_target blocks if possible and return the number of blocks
+/// > for which the estimate is valid. Uses virtual transaction size as defined
+/// > in BIP 141 (witness data is discounted).
+/// >
+/// > Arguments:
+/// > 1. conf_target     (numeric) Confirmation target in blocks (1 - 1008)
+/// > 2. "estimate_mode" (string, optional, default=CONSERVATIVE) The fee estimate mode.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EstimateSmartFee {
+    /// Estimate fee rate in BTC/kB.
+    #[serde(rename = "feerate")]
+    pub fee_rate: Option<f64>,
+    /// Errors encountered during processing.
+    pub errors: Option<Vec<String>>,
+    /// Block number where estimate was found.
+    pub blocks: i64,
+}
+
+impl EstimateSmartFee {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::EstimateSmartFee, EstimateSmartFeeError> {
+        use EstimateSmartFeeError as E;
+
+        let fee_rate =
+            self.fee_rate.map(crate::btc_per_kb).transpose().map_err(E::FeeRate)?.flatten();
+
+        Ok(model::EstimateSmartFee {
+            fee_rate,
+            errors: self.errors.unwrap_or_default(),
+            blocks: crate::to_u32(self.blocks, "blocks")?,
+        })
+    }
+}
+
+/// Error when converting a `EstimateSmartFee` type into the model type.
+#[derive(Debug)]
+pub enum EstimateSmartFeeError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `fee_rate` field failed.
+    FeeRate(amount::ParseAmountError),
+}
+
+impl fmt::Display for EstimateSmartFeeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use EstimateSmartFeeError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::FeeRate(ref e) => write_err!(f, "conversion of the `fee_rate` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for EstimateSmartFeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use EstimateSmartFeeError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::FeeRate(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for EstimateSmartFeeError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `signmessagewithprivkey`.
+///
+/// > signmessagewithprivkey "privkey" "message"
+/// >
+/// > Sign a message with the private key of an address
+/// >
+/// > Arguments:
+/// > 1. "privkey"         (string, required) The private key to sign the message with.
+/// > 2. "message"         (string, required) The message to create a signature of.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignMessageWithPrivKey(pub String);
+
+impl SignMessageWithPrivKey {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::SignMessageWithPrivKey {
+        model::SignMessageWithPrivKey(self.0)
+    }
+}
+
+/// Result of JSON-RPC method `validateaddress`.
+///
+/// > validateaddress "address"
+/// >
+/// > Return information about the given bitcoin address.
+/// >
+/// > Arguments:
+/// > 1. "address"                    (string, required) The bitcoin address to validate
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValidateAddress {
+    /// If the address is valid or not. If not, this is the only property returned.
+    #[serde(rename = "isvalid")]
+    pub is_valid: bool,
+    /// The bitcoin address validated.
+    pub address: Option<String>,
+    /// The hex encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: Option<String>,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: Option<bool>,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: Option<bool>,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+}
+
+impl ValidateAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ValidateAddress, ValidateAddressError> {
+        use ValidateAddressError as E;
+
+        let address =
+            self.address.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::Address)?;
+        let script_pubkey = self
+            .script_pubkey
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::ScriptPubkey)?;
+        let witness_program = witness_program(self.witness_version, self.witness_program)?;
+
+        Ok(model::ValidateAddress {
+            is_valid: self.is_valid,
+            address,
+            script_pubkey,
+            is_script: self.is_script,
+            is_witness: self.is_witness,
+            witness_program,
+            error: None,
+            error_locations: None,
+        })
+    }
+}
+
+/// Error when converting a `ValidateAddress` type into the model type.
+#[derive(Debug)]
+pub enum ValidateAddressError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `witness_version` field failed.
+    WitnessVersion(witness_version::TryFromError),
+    /// Conversion of the `witness_program` field hex failed.
+    WitnessProgramHex(hex::HexToBytesError),
+    /// The `witness_program` field is not a valid program for its witness version.
+    WitnessProgram(witness_program::Error),
+}
+
+impl fmt::Display for ValidateAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ValidateAddressError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            E::WitnessVersion(ref e) =>
+                write_err!(f, "conversion of the `witness_version` field failed"; e),
+            E::WitnessProgramHex(ref e) =>
+                write_err!(f, "conversion of the `witness_program` field hex failed"; e),
+            E::WitnessProgram(ref e) =>
+                write_err!(f, "the `witness_program` field is not a valid witness program"; e),
+        }
+    }
+}
+
+impl std::error::Error for ValidateAddressError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ValidateAddressError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Address(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::WitnessVersion(ref e) => Some(e),
+            E::WitnessProgramHex(ref e) => Some(e),
+            E::WitnessProgram(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for ValidateAddressError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `verifymessage`.
+///
+/// > verifymessage "address" "signature" "message"
+/// >
+/// > Verify a signed message
+/// >
+/// > Arguments:
+/// > 1. "address"         (string, required) The bitcoin address to use for the signature.
+/// > 2. "signature"       (string, required) The signature provided by the signer in base 64 encoding (see signmessage).
+/// > 3. "message"         (string, required) The message that was signed.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VerifyMessage(pub bool);
+
+impl VerifyMessage {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::VerifyMessage { model::VerifyMessage(self.0) }
+}
+
+/// Converts the `witness_version` and `witness_program` fields returned by `validateaddress`.
+pub(crate) fn witness_program(
+    version: Option<u8>,
+    program: Option<String>,
+) -> Result<Option<WitnessProgram>, ValidateAddressError> {
+    use ValidateAddressError as E;
+
+    match (version, program) {
+        (Some(version), Some(program)) => {
+            let version = WitnessVersion::try_from(version).map_err(E::WitnessVersion)?;
+            let program = Vec::<u8>::from_hex(&program).map_err(E::WitnessProgramHex)?;
+            let program = WitnessProgram::new(version, &program).map_err(E::WitnessProgram)?;
+            Ok(Some(program))
+        }
+        _ => Ok(None),
+    }
+}
@@ -4,17 +4,25 @@
 //!
 //! Types for methods found under the `== Wallet ==` section of the API docs.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
 use bitcoin::address::NetworkUnchecked;
 use bitcoin::amount::ParseAmountError;
+use bitcoin::bip32::{self, DerivationPath};
 use bitcoin::consensus::encode;
-use bitcoin::{address, hex, Address, Amount, SignedAmount, Transaction, Txid};
+use bitcoin::hashes::hash160;
+use bitcoin::psbt::{Psbt, PsbtParseError};
+use bitcoin::{
+    address, hex, key, Address, Amount, BlockHash, OutPoint, PublicKey, ScriptBuf, SignedAmount,
+    Transaction, Txid,
+};
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
-use crate::model;
+use crate::v17::{witness_program, ValidateAddressError};
+use crate::{model, NumericError};
 
 /// Result of the JSON-RPC method `createwallet`.
 ///
@@ -130,6 +138,35 @@ impl GetBalance {
     }
 }
 
+/// Result of the JSON-RPC method `sendmany`.
+///
+/// > sendmany "" {"address":amount,...} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode")
+/// >
+/// > Send multiple times. Amounts are double-precision floating point numbers.
+/// >
+/// > Arguments:
+/// > 1. "dummy"               (string, required) Must be set to "" for backwards compatibility.
+/// > 2. "amounts"             (string, required) A json object with addresses and amounts
+/// > 3. minconf                 (numeric, optional, default=1) Only use the balance confirmed at least this many times.
+/// > 4. "comment"             (string, optional) A comment
+/// > 5. subtractfeefrom         (array, optional) A json array with addresses.
+/// > 6. replaceable            (boolean, optional) Allow this transaction to be replaced by a transaction with higher fees via BIP 125
+/// > 7. conf_target            (numeric, optional) Confirmation target (in blocks)
+/// > 8. "estimate_mode"      (string, optional, default=UNSET) The fee estimate mode
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SendMany(pub String); // The hex encoded txid.
+
+impl SendMany {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SendMany, hex::HexToArrayError> {
+        let txid = self.0.parse::<Txid>()?;
+        Ok(model::SendMany(txid))
+    }
+
+    /// Converts json straight to a `bitcoin::Txid`.
+    pub fn txid(self) -> Result<Txid, hex::HexToArrayError> { Ok(self.into_model()?.0) }
+}
+
 /// Result of the JSON-RPC method `sendtoaddress`.
 ///
 /// > sendtoaddress "address" amount ( "comment" "comment_to" subtractfeefromamount replaceable conf_target "estimate_mode")
@@ -153,6 +190,23 @@ impl SendToAddress {
     pub fn txid(self) -> Result<Txid, hex::HexToArrayError> { Ok(self.into_model()?.txid) }
 }
 
+/// Result of the JSON-RPC method `signmessage`.
+///
+/// > signmessage "address" "message"
+/// >
+/// > Sign a message with the private key of an address
+/// >
+/// > Arguments:
+/// > 1. "address"         (string, required) The bitcoin address to use for the private key.
+/// > 2. "message"         (string, required) The message to create a signature of.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignMessage(pub String); // The base 64 encoded signature.
+
+impl SignMessage {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::SignMessage { model::SignMessage(self.0) }
+}
+
 /// Result of the JSON-RPC method `gettransaction`.
 ///
 /// > gettransaction "txid" ( include_watchonly )
@@ -352,3 +406,1267 @@ impl GetTransactionDetailCategory {
         }
     }
 }
+
+/// Result of the JSON-RPC method `bumpfee`.
+///
+/// > bumpfee "txid" ( options )
+/// >
+/// > Bumps the fee of an opt-in-RBF transaction T, replacing it with a new transaction B.
+/// > An opt-in RBF transaction with the given txid must be in the wallet.
+/// > The command will pay the additional fee by decreasing (or perhaps removing) its change output.
+/// > If the change output is not big enough to cover the increased fee, the command will currently fail
+/// > instead of adding new inputs to compensate. (A future implementation could improve this.)
+/// > The command will fail if the wallet or mempool contains a transaction that spends one of T's outputs.
+/// > By default, the new fee will be calculated automatically using estimatesmartfee.
+/// > The user can specify a confirmation target for estimatesmartfee.
+/// > Alternatively, the user can specify totalFee, or use RPC settxfee to set a higher fee rate.
+/// > At a minimum, the new fee rate must be high enough to pay an additional new relay fee (incrementalfee
+/// > returned by getnetworkinfo) to enter the node's mempool.
+/// >
+/// > Arguments:
+/// > 1. txid                  (string, required) The txid to be bumped
+/// > 2. options               (object, optional)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BumpFee {
+    /// The id of the new transaction.
+    pub txid: String,
+    /// Fee of the replaced transaction.
+    #[serde(rename = "origfee")]
+    pub original_fee: f64,
+    /// Fee of the new transaction.
+    pub fee: f64,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+impl BumpFee {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::BumpFee, BumpFeeError> {
+        use BumpFeeError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let original_fee = Amount::from_btc(self.original_fee).map_err(E::OriginalFee)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+
+        Ok(model::BumpFee { txid, original_fee, fee, errors: self.errors })
+    }
+}
+
+/// Error when converting a `BumpFee` type into the model type.
+#[derive(Debug)]
+pub enum BumpFeeError {
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `original_fee` field failed.
+    OriginalFee(ParseAmountError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+}
+
+impl fmt::Display for BumpFeeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use BumpFeeError as E;
+
+        match *self {
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::OriginalFee(ref e) =>
+                write_err!(f, "conversion of the `original_fee` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for BumpFeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use BumpFeeError as E;
+
+        match *self {
+            E::Txid(ref e) => Some(e),
+            E::OriginalFee(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `getaddressesbylabel`.
+///
+/// > getaddressesbylabel "label"
+/// >
+/// > Returns the list of addresses assigned the specified label.
+/// >
+/// > Arguments:
+/// > 1. "label"  (string, required) The label.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressesByLabel(pub BTreeMap<String, AddressInformation>);
+
+/// Information about an address, an entry in the map returned by `getaddressesbylabel`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddressInformation {
+    /// Purpose of address.
+    pub purpose: AddressPurpose,
+}
+
+/// The purpose of an address in the wallet's address book.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressPurpose {
+    /// A sending address.
+    Send,
+    /// A receiving address.
+    Receive,
+    /// A refund address.
+    Refund,
+}
+
+impl GetAddressesByLabel {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddressesByLabel, address::ParseError> {
+        let mut addresses = BTreeMap::new();
+        for (address, info) in self.0 {
+            let address = address.parse::<Address<NetworkUnchecked>>()?;
+            addresses.insert(address, info.purpose.into_model());
+        }
+        Ok(model::GetAddressesByLabel(addresses))
+    }
+}
+
+impl AddressPurpose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::AddressPurpose {
+        use AddressPurpose::*;
+
+        match self {
+            Send => model::AddressPurpose::Send,
+            Receive => model::AddressPurpose::Receive,
+            Refund => model::AddressPurpose::Refund,
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `getaddressinfo`.
+///
+/// > getaddressinfo "address"
+/// >
+/// > Return information about the given bitcoin address. Some information requires the address
+/// > to be in the wallet.
+/// >
+/// > Arguments:
+/// > 1. "address"                    (string, required) The bitcoin address to get the information of.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfo {
+    /// The bitcoin address validated.
+    pub address: String,
+    /// The hex encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// If the address is yours or not.
+    #[serde(rename = "ismine")]
+    pub is_mine: bool,
+    /// If the address is watchonly.
+    #[serde(rename = "iswatchonly")]
+    pub is_watch_only: bool,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: bool,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: bool,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+    /// The output script type.
+    ///
+    /// Only if "isscript" is true and the redeemscript is known.
+    pub script: Option<String>,
+    /// The redeemscript for the p2sh address.
+    pub hex: Option<String>,
+    /// Array of pubkeys associated with the known redeemscript (only if "script" is "multisig").
+    pub pubkeys: Option<Vec<String>>,
+    /// Number of signatures required to spend multisig output (only if "script" is "multisig").
+    #[serde(rename = "sigsrequired")]
+    pub sigs_required: Option<i64>,
+    /// The hex value of the raw public key, for single-key addresses (possibly embedded in P2SH or
+    /// P2WSH).
+    pub pubkey: Option<String>,
+    /// Information about the address embedded in P2SH or P2WSH, if relevant and known.
+    pub embedded: Option<GetAddressInfoEmbedded>,
+    /// If the address is compressed.
+    #[serde(rename = "iscompressed")]
+    pub is_compressed: Option<bool>,
+    /// The label associated with the address, "" is the default account.
+    pub label: Option<String>,
+    /// The creation time of the key if available in seconds since epoch (Jan 1 1970 GMT).
+    pub timestamp: Option<i64>,
+    /// The HD keypath if the key is HD and available.
+    #[serde(rename = "hdkeypath")]
+    pub hd_key_path: Option<String>,
+    /// The Hash160 of the HD seed.
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// Alias for hdseedid maintained for backwards compatibility. Will be removed in V0.18.
+    #[serde(rename = "hdmasterkeyid")]
+    pub hd_master_key_id: Option<String>,
+    /// Array of labels associated with the address.
+    pub labels: Vec<GetAddressInfoLabel>,
+}
+
+/// The address embedded in P2SH or P2WSH, part of `getaddressinfo`.
+///
+/// Includes all getaddressinfo output fields for the embedded address, excluding metadata
+/// ("timestamp", "hdkeypath", "hdseedid") and relation to the wallet ("ismine", "iswatchonly").
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfoEmbedded {
+    /// The bitcoin address validated.
+    pub address: String,
+    /// The hex encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: Option<bool>,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: Option<bool>,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+    /// The output script type.
+    pub script: Option<String>,
+    /// The redeemscript for the p2sh address.
+    pub hex: Option<String>,
+    /// Array of pubkeys associated with the known redeemscript (only if "script" is "multisig").
+    pub pubkeys: Option<Vec<String>>,
+    /// Number of signatures required to spend multisig output (only if "script" is "multisig").
+    #[serde(rename = "sigsrequired")]
+    pub sigs_required: Option<i64>,
+    /// The hex value of the raw public key, for single-key addresses.
+    pub pubkey: Option<String>,
+    /// If the address is compressed.
+    #[serde(rename = "iscompressed")]
+    pub is_compressed: Option<bool>,
+}
+
+/// A label associated with an address, part of `getaddressinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfoLabel {
+    /// The label.
+    pub name: String,
+    /// Purpose of address ("send" for sending address, "receive" for receiving address).
+    pub purpose: AddressPurpose,
+}
+
+impl GetAddressInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddressInfo, GetAddressInfoError> {
+        use GetAddressInfoError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let witness_program = witness_program(self.witness_version, self.witness_program)
+            .map_err(E::WitnessProgram)?;
+        let hex = self.hex.map(|s| ScriptBuf::from_hex(&s)).transpose().map_err(E::Hex)?;
+        let pubkeys = self
+            .pubkeys
+            .map(|keys| keys.iter().map(|s| s.parse::<PublicKey>()).collect::<Result<Vec<_>, _>>())
+            .transpose()
+            .map_err(E::Pubkeys)?;
+        let sigs_required =
+            self.sigs_required.map(|n| crate::to_u32(n, "sigsrequired")).transpose()?;
+        let pubkey = self.pubkey.map(|s| s.parse::<PublicKey>()).transpose().map_err(E::Pubkey)?;
+        let embedded = self.embedded.map(|e| e.into_model()).transpose().map_err(E::Embedded)?;
+        let timestamp = self.timestamp.map(|t| crate::to_u32(t, "timestamp")).transpose()?;
+        let hd_key_path = self
+            .hd_key_path
+            .map(|s| s.parse::<DerivationPath>())
+            .transpose()
+            .map_err(E::HdKeyPath)?;
+        let hd_seed_id = self
+            .hd_seed_id
+            .or(self.hd_master_key_id)
+            .map(|s| s.parse::<hash160::Hash>())
+            .transpose()
+            .map_err(E::HdSeedId)?;
+        let labels = self.labels.into_iter().map(|label| label.name).collect();
+
+        Ok(model::GetAddressInfo {
+            address,
+            script_pubkey,
+            is_mine: self.is_mine,
+            is_watch_only: self.is_watch_only,
+            solvable: None,
+            descriptor: None,
+            is_script: self.is_script,
+            is_change: None,
+            is_witness: self.is_witness,
+            witness_program,
+            script: self.script,
+            hex,
+            pubkeys,
+            sigs_required,
+            pubkey,
+            embedded,
+            is_compressed: self.is_compressed,
+            timestamp,
+            hd_key_path,
+            hd_seed_id,
+            hd_master_fingerprint: None,
+            labels,
+        })
+    }
+}
+
+/// Error when converting a `GetAddressInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetAddressInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `witness_version` and `witness_program` fields failed.
+    WitnessProgram(ValidateAddressError),
+    /// Conversion of the `hex` field failed.
+    Hex(hex::HexToBytesError),
+    /// Conversion of an item in the `pubkeys` list failed.
+    Pubkeys(key::ParsePublicKeyError),
+    /// Conversion of the `pubkey` field failed.
+    Pubkey(key::ParsePublicKeyError),
+    /// Conversion of the `embedded` field failed.
+    Embedded(GetAddressInfoEmbeddedError),
+    /// Conversion of the `hd_key_path` field failed.
+    HdKeyPath(bip32::Error),
+    /// Conversion of the `hd_seed_id` field failed.
+    HdSeedId(hex::HexToArrayError),
+    /// Conversion of the `hd_master_fingerprint` field failed.
+    HdMasterFingerprint(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetAddressInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetAddressInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            E::WitnessProgram(ref e) =>
+                write_err!(f, "conversion of the `witness_program` field failed"; e),
+            E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Pubkeys(ref e) => write_err!(f, "conversion of the `pubkeys` field failed"; e),
+            E::Pubkey(ref e) => write_err!(f, "conversion of the `pubkey` field failed"; e),
+            E::Embedded(ref e) => write_err!(f, "conversion of the `embedded` field failed"; e),
+            E::HdKeyPath(ref e) => write_err!(f, "conversion of the `hd_key_path` field failed"; e),
+            E::HdSeedId(ref e) => write_err!(f, "conversion of the `hd_seed_id` field failed"; e),
+            E::HdMasterFingerprint(ref e) =>
+                write_err!(f, "conversion of the `hd_master_fingerprint` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetAddressInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetAddressInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Address(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::WitnessProgram(ref e) => Some(e),
+            E::Hex(ref e) => Some(e),
+            E::Pubkeys(ref e) => Some(e),
+            E::Pubkey(ref e) => Some(e),
+            E::Embedded(ref e) => Some(e),
+            E::HdKeyPath(ref e) => Some(e),
+            E::HdSeedId(ref e) => Some(e),
+            E::HdMasterFingerprint(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetAddressInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+impl GetAddressInfoEmbedded {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddressInfoEmbedded, GetAddressInfoEmbeddedError> {
+        use GetAddressInfoEmbeddedError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let witness_program = witness_program(self.witness_version, self.witness_program)
+            .map_err(E::WitnessProgram)?;
+        let hex = self.hex.map(|s| ScriptBuf::from_hex(&s)).transpose().map_err(E::Hex)?;
+        let pubkeys = self
+            .pubkeys
+            .map(|keys| keys.iter().map(|s| s.parse::<PublicKey>()).collect::<Result<Vec<_>, _>>())
+            .transpose()
+            .map_err(E::Pubkeys)?;
+        let sigs_required =
+            self.sigs_required.map(|n| crate::to_u32(n, "sigsrequired")).transpose()?;
+        let pubkey = self.pubkey.map(|s| s.parse::<PublicKey>()).transpose().map_err(E::Pubkey)?;
+
+        Ok(model::GetAddressInfoEmbedded {
+            address,
+            script_pubkey,
+            is_script: self.is_script,
+            is_witness: self.is_witness,
+            witness_program,
+            script: self.script,
+            hex,
+            pubkeys,
+            sigs_required,
+            pubkey,
+            is_compressed: self.is_compressed,
+        })
+    }
+}
+
+/// Error when converting a `GetAddressInfoEmbedded` type into the model type.
+#[derive(Debug)]
+pub enum GetAddressInfoEmbeddedError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `witness_version` and `witness_program` fields failed.
+    WitnessProgram(ValidateAddressError),
+    /// Conversion of the `hex` field failed.
+    Hex(hex::HexToBytesError),
+    /// Conversion of an item in the `pubkeys` list failed.
+    Pubkeys(key::ParsePublicKeyError),
+    /// Conversion of the `pubkey` field failed.
+    Pubkey(key::ParsePublicKeyError),
+}
+
+impl fmt::Display for GetAddressInfoEmbeddedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetAddressInfoEmbeddedError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            E::WitnessProgram(ref e) =>
+                write_err!(f, "conversion of the `witness_program` field failed"; e),
+            E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Pubkeys(ref e) => write_err!(f, "conversion of the `pubkeys` field failed"; e),
+            E::Pubkey(ref e) => write_err!(f, "conversion of the `pubkey` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetAddressInfoEmbeddedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetAddressInfoEmbeddedError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Address(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::WitnessProgram(ref e) => Some(e),
+            E::Hex(ref e) => Some(e),
+            E::Pubkeys(ref e) => Some(e),
+            E::Pubkey(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetAddressInfoEmbeddedError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: i64,
+    /// The total confirmed balance of the wallet in BTC.
+    pub balance: f64,
+    /// The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: f64,
+    /// The total immature balance of the wallet in BTC.
+    pub immature_balance: f64,
+    /// The total number of transactions in the wallet.
+    #[serde(rename = "txcount")]
+    pub tx_count: i64,
+    /// The UNIX epoch time of the oldest pre-generated key in the key pool.
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: i64,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: i64,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    pub keypool_size_hd_internal: Option<i64>,
+    /// The UNIX epoch time until which the wallet is unlocked for transfers, or 0 if the wallet
+    /// is locked (only present for passphrase-encrypted wallets).
+    pub unlocked_until: Option<i64>,
+    /// The transaction fee configuration, set in BTC/kvB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let wallet_version = crate::to_u32(self.wallet_version, "walletversion")?;
+        let balance = Some(Amount::from_btc(self.balance).map_err(E::Balance)?);
+        let unconfirmed_balance =
+            Some(Amount::from_btc(self.unconfirmed_balance).map_err(E::UnconfirmedBalance)?);
+        let immature_balance =
+            Some(Amount::from_btc(self.immature_balance).map_err(E::ImmatureBalance)?);
+        let keypool_oldest = Some(crate::to_u32(self.keypool_oldest, "keypoololdest")?);
+        let tx_count = crate::to_u32(self.tx_count, "txcount")?;
+        let keypool_size = crate::to_u32(self.keypool_size, "keypoolsize")?;
+        let keypool_size_hd_internal = self
+            .keypool_size_hd_internal
+            .map(|n| crate::to_u32(n, "keypoolsize_hd_internal"))
+            .transpose()?;
+        let unlocked_until =
+            self.unlocked_until.map(|t| crate::to_u32(t, "unlocked_until")).transpose()?;
+        let pay_tx_fee = crate::btc_per_kb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version,
+            format: None,
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count,
+            keypool_oldest,
+            keypool_size,
+            keypool_size_hd_internal,
+            unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            avoid_reuse: None,
+            scanning: None,
+            descriptors: None,
+            external_signer: None,
+            blank: None,
+            birthtime: None,
+            last_processed_block: None,
+        })
+    }
+}
+
+/// Error when converting a `GetWalletInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetWalletInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `balance` field failed.
+    Balance(ParseAmountError),
+    /// Conversion of the `unconfirmed_balance` field failed.
+    UnconfirmedBalance(ParseAmountError),
+    /// Conversion of the `immature_balance` field failed.
+    ImmatureBalance(ParseAmountError),
+    /// Conversion of the `pay_tx_fee` field failed.
+    PayTxFee(ParseAmountError),
+    /// Conversion of the `hd_seed_id` field failed.
+    HdSeedId(hex::HexToArrayError),
+    /// Conversion of the `last_processed_block` field failed.
+    LastProcessedBlock(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetWalletInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetWalletInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Balance(ref e) => write_err!(f, "conversion of the `balance` field failed"; e),
+            E::UnconfirmedBalance(ref e) =>
+                write_err!(f, "conversion of the `unconfirmed_balance` field failed"; e),
+            E::ImmatureBalance(ref e) =>
+                write_err!(f, "conversion of the `immature_balance` field failed"; e),
+            E::PayTxFee(ref e) => write_err!(f, "conversion of the `pay_tx_fee` field failed"; e),
+            E::HdSeedId(ref e) => write_err!(f, "conversion of the `hd_seed_id` field failed"; e),
+            E::LastProcessedBlock(ref e) =>
+                write_err!(f, "conversion of the `last_processed_block` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetWalletInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetWalletInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Balance(ref e) => Some(e),
+            E::UnconfirmedBalance(ref e) => Some(e),
+            E::ImmatureBalance(ref e) => Some(e),
+            E::PayTxFee(ref e) => Some(e),
+            E::HdSeedId(ref e) => Some(e),
+            E::LastProcessedBlock(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetWalletInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of the JSON-RPC method `importmulti`.
+///
+/// > importmulti "requests" ( "options" )
+/// >
+/// > Import addresses/scripts (with private or public keys, redeem script (P2SH)), rescanning all addresses in one-shot-only (rescan can be disabled via options). Requires a new wallet backup.
+/// >
+/// > Arguments:
+/// > 1. requests     (array, required) Data to be imported
+/// > 2. options      (json, optional)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMulti(pub Vec<ImportMultiResult>);
+
+impl ImportMulti {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportMulti {
+        model::ImportMulti(self.0.into_iter().map(|r| r.into_model()).collect())
+    }
+}
+
+/// The result of a single import request. Part of `importmulti`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMultiResult {
+    /// Whether the request was imported.
+    pub success: bool,
+    /// Warnings, not returned by v0.17.
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// The error if the import failed.
+    pub error: Option<JsonRpcError>,
+}
+
+impl ImportMultiResult {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportMultiResult {
+        model::ImportMultiResult {
+            success: self.success,
+            warnings: self.warnings,
+            error: self.error.map(|e| e.into_model()),
+        }
+    }
+}
+
+/// An error returned for a single request. Part of `importmulti` and `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JsonRpcError {
+    /// The JSON-RPC error code.
+    pub code: i64,
+    /// The error message.
+    pub message: String,
+}
+
+impl JsonRpcError {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::JsonRpcError {
+        model::JsonRpcError { code: self.code, message: self.message }
+    }
+}
+
+/// Result of the JSON-RPC method `listlabels`.
+///
+/// > listlabels ( "purpose" )
+/// >
+/// > Returns the list of all labels, or labels that are assigned to addresses with a specific purpose.
+/// >
+/// > Arguments:
+/// > 1. "purpose"    (string, optional) Address purpose to list labels for ('send','receive'). An empty string is the same as not providing this argument.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListLabels(pub Vec<String>);
+
+impl ListLabels {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListLabels { model::ListLabels(self.0) }
+}
+
+/// Result of the JSON-RPC method `listsinceblock`.
+///
+/// > listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )
+/// >
+/// > Get all transactions in blocks since block [blockhash], or all transactions if omitted.
+/// > If "blockhash" is no longer a part of the main chain, transactions from the fork point onward are included.
+/// > Additionally, if include_removed is set, transactions affecting the wallet which were removed are returned in the "removed" array.
+/// >
+/// > Arguments:
+/// > 1. "blockhash"            (string, optional) The block hash to list transactions since
+/// > 2. target_confirmations:    (numeric, optional, default=1) Return the nth block hash from the main chain. e.g. 1 would mean the best block hash. Note: this is not used as a filter, but only affects [lastblock] in the return value
+/// > 3. include_watchonly:       (bool, optional, default=false) Include transactions to watch-only addresses (see 'importaddress')
+/// > 4. include_removed:         (bool, optional, default=true) Show transactions that were removed due to a reorg in the "removed" array
+/// >                                                            (not guaranteed to work on pruned nodes)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListSinceBlock {
+    /// All the transactions.
+    pub transactions: Vec<TransactionItem>,
+    /// Transactions that were removed due to a reorg, only present if `include_removed` is set.
+    #[serde(default)]
+    pub removed: Vec<TransactionItem>,
+    /// The hash of the block (target_confirmations-1) from the best block on the main chain.
+    #[serde(rename = "lastblock")]
+    pub last_block: String,
+}
+
+impl ListSinceBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListSinceBlock, ListSinceBlockError> {
+        use ListSinceBlockError as E;
+
+        let transactions = self
+            .transactions
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Transactions)?;
+        let removed = self
+            .removed
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Removed)?;
+        let last_block = self.last_block.parse::<BlockHash>().map_err(E::LastBlock)?;
+
+        Ok(model::ListSinceBlock { transactions, removed, last_block })
+    }
+}
+
+/// Error when converting a `ListSinceBlock` type into the model type.
+#[derive(Debug)]
+pub enum ListSinceBlockError {
+    /// Conversion of item in `transactions` list failed.
+    Transactions(TransactionItemError),
+    /// Conversion of item in `removed` list failed.
+    Removed(TransactionItemError),
+    /// Conversion of the `last_block` field failed.
+    LastBlock(hex::HexToArrayError),
+}
+
+impl fmt::Display for ListSinceBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ListSinceBlockError as E;
+
+        match *self {
+            E::Transactions(ref e) =>
+                write_err!(f, "conversion of the `transactions` field failed"; e),
+            E::Removed(ref e) => write_err!(f, "conversion of the `removed` field failed"; e),
+            E::LastBlock(ref e) => write_err!(f, "conversion of the `last_block` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ListSinceBlockError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ListSinceBlockError as E;
+
+        match *self {
+            E::Transactions(ref e) => Some(e),
+            E::Removed(ref e) => Some(e),
+            E::LastBlock(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `listtransactions`.
+///
+/// > listtransactions (label count skip include_watchonly)
+/// >
+/// > If a label name is provided, this will return only incoming transactions paying to addresses with the specified label.
+/// >
+/// > Returns up to 'count' most recent transactions skipping the first 'from' transactions.
+/// > Note that the "account" argument and "otheraccount" output field can only be used if the -deprecatedrpc=accounts option is set.
+/// >
+/// > Arguments:
+/// > 1. "label"    (string, optional) If set, should be a valid label name to return only incoming transactions
+/// >               with the specified label, or "*" to disable filtering and return all transactions.
+/// > 2. count          (numeric, optional, default=10) The number of transactions to return
+/// > 3. skip           (numeric, optional, default=0) The number of transactions to skip
+/// > 4. include_watchonly (bool, optional, default=false) Include transactions to watch-only addresses (see 'importaddress')
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListTransactions(pub Vec<TransactionItem>);
+
+impl ListTransactions {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListTransactions, TransactionItemError> {
+        let transactions =
+            self.0.into_iter().map(|tx| tx.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListTransactions(transactions))
+    }
+}
+
+/// A wallet transaction, an item returned by `listtransactions` and `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TransactionItem {
+    /// Only returns true if imported addresses were involved in transaction.
+    #[serde(rename = "involvesWatchonly")]
+    pub involves_watch_only: Option<bool>,
+    /// DEPRECATED. The account name associated with the transaction.
+    ///
+    /// Only present if the `-deprecatedrpc=accounts` option is set.
+    pub account: Option<String>,
+    /// The bitcoin address of the transaction.
+    pub address: Option<String>,
+    /// The transaction category.
+    pub category: GetTransactionDetailCategory,
+    /// The amount in BTC, negative for the `send` category.
+    pub amount: f64,
+    /// A comment for the address/transaction, if any.
+    pub label: Option<String>,
+    /// The vout value.
+    pub vout: u32,
+    /// The amount of the fee in BTC, negative and only available for the `send` category.
+    pub fee: Option<f64>,
+    /// The number of confirmations for the transaction.
+    ///
+    /// Negative confirmations indicate the transaction conflicts with the block chain.
+    pub confirmations: i64,
+    /// Only present if transaction only input is a coinbase one.
+    pub generated: Option<bool>,
+    /// Only present if we consider the transaction to be trusted and safe to spend from.
+    pub trusted: Option<bool>,
+    /// The block hash containing the transaction.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The index of the transaction in the block that includes it.
+    #[serde(rename = "blockindex")]
+    pub block_index: Option<i64>,
+    /// The block time in seconds since epoch (1 Jan 1970 GMT).
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<u64>,
+    /// The transaction id.
+    pub txid: String,
+    /// Conflicting transaction ids.
+    #[serde(rename = "walletconflicts")]
+    pub wallet_conflicts: Vec<String>,
+    /// The transaction time in seconds since epoch (midnight Jan 1 1970 GMT).
+    pub time: u64,
+    /// The time received in seconds since epoch (midnight Jan 1 1970 GMT).
+    #[serde(rename = "timereceived")]
+    pub time_received: u64,
+    /// If a comment is associated with the transaction.
+    pub comment: Option<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee).
+    ///
+    /// May be unknown for unconfirmed transactions not in the mempool.
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: String,
+    /// Only available for the `send` category of transactions.
+    ///
+    /// True if the transaction has been abandoned (inputs are respendable).
+    pub abandoned: Option<bool>,
+}
+
+impl TransactionItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TransactionItem, TransactionItemError> {
+        use TransactionItemError as E;
+
+        let address = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Address)?;
+        let amount = SignedAmount::from_btc(self.amount).map_err(E::Amount)?;
+        let fee = self.fee.map(SignedAmount::from_btc).transpose().map_err(E::Fee)?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+        let block_index = self.block_index.map(|i| crate::to_u32(i, "blockindex")).transpose()?;
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wallet_conflicts = self
+            .wallet_conflicts
+            .iter()
+            .map(|s| s.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::WalletConflicts)?;
+
+        Ok(model::TransactionItem {
+            involves_watch_only: self.involves_watch_only,
+            address,
+            category: self.category.into_model(),
+            amount,
+            label: self.label.or(self.account),
+            vout: self.vout,
+            fee,
+            confirmations: self.confirmations,
+            generated: self.generated,
+            trusted: self.trusted,
+            block_hash,
+            block_height: None,
+            block_index,
+            block_time: self.block_time,
+            txid,
+            wtxid: None,
+            wallet_conflicts,
+            time: self.time,
+            time_received: self.time_received,
+            comment: self.comment,
+            bip125_replaceable: self.bip125_replaceable,
+            parent_descriptors: None,
+            abandoned: self.abandoned,
+        })
+    }
+}
+
+/// Error when converting a `TransactionItem` type into the model type.
+#[derive(Debug)]
+pub enum TransactionItemError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `amount` field failed.
+    Amount(ParseAmountError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+    /// Conversion of the `block_hash` field failed.
+    BlockHash(hex::HexToArrayError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `wtxid` field failed.
+    Wtxid(hex::HexToArrayError),
+    /// Conversion of an item in the `wallet_conflicts` list failed.
+    WalletConflicts(hex::HexToArrayError),
+}
+
+impl fmt::Display for TransactionItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TransactionItemError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::Amount(ref e) => write_err!(f, "conversion of the `amount` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+            E::BlockHash(ref e) => write_err!(f, "conversion of the `block_hash` field failed"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Wtxid(ref e) => write_err!(f, "conversion of the `wtxid` field failed"; e),
+            E::WalletConflicts(ref e) =>
+                write_err!(f, "conversion of the `wallet_conflicts` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TransactionItemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TransactionItemError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Address(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+            E::BlockHash(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::Wtxid(ref e) => Some(e),
+            E::WalletConflicts(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for TransactionItemError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of the JSON-RPC method `listunspent`.
+///
+/// > listunspent ( minconf maxconf  ["addresses",...] [include_unsafe] [query_options])
+/// >
+/// > Returns array of unspent transaction outputs
+/// > with between minconf and maxconf (inclusive) confirmations.
+/// > Optionally filter to only include txouts paid to specified addresses.
+/// >
+/// > Arguments:
+/// > 1. minconf          (numeric, optional, default=1) The minimum confirmations to filter
+/// > 2. maxconf          (numeric, optional, default=9999999) The maximum confirmations to filter
+/// > 3. "addresses"      (string) A json array of bitcoin addresses to filter
+/// > 4. include_unsafe (bool, optional, default=true) Include outputs that are not safe to spend
+/// >                   See description of "safe" attribute below.
+/// > 5. query_options    (json, optional) JSON with query options
+/// >     {
+/// >       "minimumAmount"    (numeric or string, default=0) Minimum value of each UTXO in BTC
+/// >       "maximumAmount"    (numeric or string, default=unlimited) Maximum value of each UTXO in BTC
+/// >       "maximumCount"     (numeric or string, default=unlimited) Maximum number of UTXOs
+/// >       "minimumSumAmount" (numeric or string, default=unlimited) Minimum sum value of all UTXOs in BTC
+/// >     }
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspent(pub Vec<ListUnspentItem>);
+
+impl ListUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspent, ListUnspentItemError> {
+        let unspent =
+            self.0.into_iter().map(|utxo| utxo.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListUnspent(unspent))
+    }
+}
+
+/// An unspent transaction output, an item returned by `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspentItem {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    pub vout: u32,
+    /// The bitcoin address.
+    pub address: Option<String>,
+    /// The associated label, or "" for the default label.
+    pub label: Option<String>,
+    /// DEPRECATED. The account name associated with the address.
+    ///
+    /// Only present if the `-deprecatedrpc=accounts` option is set.
+    pub account: Option<String>,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// The transaction output amount in BTC.
+    pub amount: f64,
+    /// The number of confirmations.
+    pub confirmations: i64,
+    /// The redeemScript if scriptPubKey is P2SH.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: Option<String>,
+    /// Whether we have the private keys to spend this output.
+    pub spendable: bool,
+    /// Whether we know how to spend this output, ignoring the lack of keys.
+    pub solvable: bool,
+    /// Whether this output is considered safe to spend.
+    ///
+    /// Unconfirmed transactions from outside keys and unconfirmed replacement transactions are
+    /// considered unsafe and are not eligible for spending by fundrawtransaction and
+    /// sendtoaddress.
+    pub safe: bool,
+}
+
+impl ListUnspentItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspentItem, ListUnspentItemError> {
+        use ListUnspentItemError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let address = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let confirmations = crate::to_u32(self.confirmations, "confirmations")?;
+        let redeem_script = self
+            .redeem_script
+            .map(|hex| ScriptBuf::from_hex(&hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+
+        Ok(model::ListUnspentItem {
+            outpoint: OutPoint { txid, vout: self.vout },
+            address,
+            label: self.label.or(self.account),
+            script_pubkey,
+            amount,
+            confirmations,
+            redeem_script,
+            witness_script: None,
+            spendable: self.spendable,
+            solvable: self.solvable,
+            reused: None,
+            descriptor: None,
+            parent_descriptors: None,
+            safe: self.safe,
+        })
+    }
+}
+
+/// Error when converting a `ListUnspentItem` type into the model type.
+#[derive(Debug)]
+pub enum ListUnspentItemError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `amount` field failed.
+    Amount(ParseAmountError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+}
+
+impl fmt::Display for ListUnspentItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ListUnspentItemError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            E::Amount(ref e) => write_err!(f, "conversion of the `amount` field failed"; e),
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ListUnspentItemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ListUnspentItemError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::Address(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for ListUnspentItemError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of the JSON-RPC method `listwallets`.
+///
+/// > listwallets
+/// >
+/// > Returns a list of currently loaded wallets.
+/// > For full information on the wallet, use "getwalletinfo"
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWallets(pub Vec<String>);
+
+impl ListWallets {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListWallets { model::ListWallets(self.0) }
+}
+
+/// Result of the JSON-RPC method `walletcreatefundedpsbt`.
+///
+/// > walletcreatefundedpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable ) ( options bip32derivs )
+/// >
+/// > Creates and funds a transaction in the Partially Signed Transaction format. Inputs will be added if supplied inputs are not enough
+/// > Implements the Creator and Updater roles.
+/// >
+/// > Arguments:
+/// > 1. "inputs"                (array, required) A json array of json objects
+/// > 2. "outputs"               (array, required) a json array with outputs (key-value pairs)
+/// > 3. locktime                  (numeric, optional, default=0) Raw locktime. Non-0 value also locktime-activates inputs
+/// > 4. options                 (object, optional)
+/// > 5. bip32derivs                    (boolean, optional, default=false) If true, includes the BIP 32 derivation paths for public keys if we know them
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletCreateFundedPsbt {
+    /// The resulting raw transaction (base64-encoded string).
+    pub psbt: String,
+    /// Fee in BTC the resulting transaction pays.
+    pub fee: f64,
+    /// The position of the added change output, or -1.
+    #[serde(rename = "changepos")]
+    pub change_position: i64,
+}
+
+impl WalletCreateFundedPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WalletCreateFundedPsbt, WalletCreateFundedPsbtError> {
+        use WalletCreateFundedPsbtError as E;
+
+        let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+        let change_position = u32::try_from(self.change_position).ok();
+
+        Ok(model::WalletCreateFundedPsbt { psbt, fee, change_position })
+    }
+}
+
+/// Error when converting a `WalletCreateFundedPsbt` type into the model type.
+#[derive(Debug)]
+pub enum WalletCreateFundedPsbtError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+}
+
+impl fmt::Display for WalletCreateFundedPsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use WalletCreateFundedPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for WalletCreateFundedPsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use WalletCreateFundedPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `walletprocesspsbt`.
+///
+/// > walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )
+/// >
+/// > Update a PSBT with input information from our wallet and then sign inputs
+/// > that we can sign for.
+/// >
+/// > Arguments:
+/// > 1. "psbt"                      (string, required) The transaction base64 string
+/// > 2. sign                          (boolean, optional, default=true) Also sign the transaction when updating
+/// > 3. "sighashtype"            (string, optional, default=ALL) The signature hash type to sign with if not specified by the PSBT.
+/// > 4. bip32derivs                    (boolean, optional, default=false) If true, includes the BIP 32 derivation paths for public keys if we know them
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletProcessPsbt {
+    /// The base64-encoded partially signed transaction.
+    pub psbt: String,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+}
+
+impl WalletProcessPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WalletProcessPsbt, PsbtParseError> {
+        let psbt = self.psbt.parse::<Psbt>()?;
+        Ok(model::WalletProcessPsbt { psbt, complete: self.complete, tx: None })
+    }
+}
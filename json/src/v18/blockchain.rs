@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.18.1 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use bitcoin::{Amount, ScriptBuf, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::ScanTxOutSetError;
+
+/// Result of JSON-RPC method `scantxoutset` with action `start`.
+///
+/// > scantxoutset "action" [scanobjects,...]
+/// >
+/// > EXPERIMENTAL warning: this call may be removed or changed in future releases.
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+/// > 2. scanobjects                   (json array, required) Array of scan objects
+/// >                                  Every scan object is either a string descriptor or an object:
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// Whether the scan was completed.
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    pub searched_items: u64,
+    /// The unspent outputs found.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs in BTC.
+    pub total_amount: f64,
+}
+
+impl ScanTxOutSetStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetStart, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let unspents =
+            self.unspents.into_iter().map(|u| u.into_model()).collect::<Result<_, _>>()?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::ScanTxOutSetStart {
+            success: Some(self.success),
+            tx_outs: Some(self.searched_items),
+            height: None,
+            best_block: None,
+            unspents,
+            total_amount,
+        })
+    }
+}
+
+/// An unspent output found by the scan. Part of `scantxoutset`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetUnspent {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    pub vout: i64,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// A specialized descriptor for the matched scriptPubKey.
+    #[serde(rename = "desc")]
+    pub descriptor: String,
+    /// The total amount in BTC of the unspent output.
+    pub amount: f64,
+    /// Height of the unspent transaction output.
+    pub height: i64,
+}
+
+impl ScanTxOutSetUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetUnspent, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let vout = crate::to_u32(self.vout, "vout")?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let height = crate::to_u32(self.height, "height")?;
+
+        Ok(model::ScanTxOutSetUnspent {
+            txid,
+            vout,
+            script_pubkey,
+            descriptor: Some(self.descriptor),
+            amount,
+            coinbase: None,
+            height,
+            block_hash: None,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.18.1 - control.
+//!
+//! Types for methods found under the `== Control ==` section of the API docs.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `getrpcinfo`.
+///
+/// > getrpcinfo
+/// >
+/// > Returns details of the RPC server.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRpcInfo {
+    /// All active commands.
+    pub active_commands: Vec<ActiveCommand>,
+    /// The complete file path to the debug log (v0.20 and later).
+    #[serde(rename = "logpath")]
+    pub log_path: Option<String>,
+}
+
+/// Information about an active command, part of `getrpcinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ActiveCommand {
+    /// The name of the RPC command.
+    pub method: String,
+    /// The running time in microseconds.
+    pub duration: u64,
+}
+
+impl GetRpcInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetRpcInfo {
+        model::GetRpcInfo {
+            active_commands: self.active_commands.into_iter().map(|c| c.into_model()).collect(),
+            log_path: self.log_path,
+        }
+    }
+}
+
+impl ActiveCommand {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ActiveCommand {
+        model::ActiveCommand { method: self.method, duration: Duration::from_micros(self.duration) }
+    }
+}
@@ -9,103 +9,103 @@
 //! - [x] `getbestblockhash`
 //! - [x] `getblock "blockhash" ( verbosity )`
 //! - [x] `getblockchaininfo`
-//! - [ ] `getblockcount`
-//! - [ ] `getblockhash height`
+//! - [x] `getblockcount`
+//! - [x] `getblockhash height`
 //! - [ ] `getblockheader "blockhash" ( verbose )`
 //! - [ ] `getblockstats hash_or_height ( stats )`
 //! - [ ] `getchaintips`
 //! - [ ] `getchaintxstats ( nblocks "blockhash" )`
 //! - [ ] `getdifficulty`
-//! - [ ] `getmempoolancestors "txid" ( verbose )`
-//! - [ ] `getmempooldescendants "txid" ( verbose )`
-//! - [ ] `getmempoolentry "txid"`
-//! - [ ] `getmempoolinfo`
-//! - [ ] `getrawmempool ( verbose )`
+//! - [x] `getmempoolancestors "txid" ( verbose )`
+//! - [x] `getmempooldescendants "txid" ( verbose )`
+//! - [x] `getmempoolentry "txid"`
+//! - [x] `getmempoolinfo`
+//! - [x] `getrawmempool ( verbose )`
 //! - [ ] `gettxout "txid" n ( include_mempool )`
 //! - [ ] `gettxoutproof ["txid",...] ( "blockhash" )`
-//! - [ ] `gettxoutsetinfo`
+//! - [x] `gettxoutsetinfo`
 //! - [ ] `preciousblock "blockhash"`
 //! - [ ] `pruneblockchain height`
 //! - [ ] `savemempool`
-//! - [ ] `scantxoutset "action" [scanobjects,...]`
+//! - [x] `scantxoutset "action" [scanobjects,...]`
 //! - [ ] `verifychain ( checklevel nblocks )`
 //! - [ ] `verifytxoutproof "proof"`
 //!
-//! ** == Control ==**
-//! - [ ] `getmemoryinfo ( "mode" )`
-//! - [ ] `getrpcinfo`
-//! - [ ] `help ( "command" )`
-//! - [ ] `logging ( ["include_category",...] ["exclude_category",...] )`
+//! **== Control ==**
+//! - [x] `getmemoryinfo ( "mode" )`
+//! - [x] `getrpcinfo`
+//! - [-] `help ( "command" )`
+//! - [x] `logging ( ["include_category",...] ["exclude_category",...] )`
 //! - [x] `stop`
-//! - [ ] `uptime`
+//! - [x] `uptime`
 //!
 //! ** == Generating ==**
 //! - [ ] `generate nblocks ( maxtries )`
 //! - [x] `generatetoaddress nblocks "address" ( maxtries )`
 //!
 //! ** == Mining ==**
-//! - [ ] `getblocktemplate "template_request"`
-//! - [ ] `getmininginfo`
-//! - [ ] `getnetworkhashps ( nblocks height )`
-//! - [ ] `prioritisetransaction "txid" ( dummy ) fee_delta`
-//! - [ ] `submitblock "hexdata" ( "dummy" )`
-//! - [ ] `submitheader "hexdata"`
+//! - [x] `getblocktemplate "template_request"`
+//! - [x] `getmininginfo`
+//! - [x] `getnetworkhashps ( nblocks height )`
+//! - [x] `prioritisetransaction "txid" ( dummy ) fee_delta`
+//! - [x] `submitblock "hexdata" ( "dummy" )`
+//! - [x] `submitheader "hexdata"`
 //!
-//! ** == Network ==**
-//! - [ ] `addnode "node" "command"`
-//! - [ ] `clearbanned`
-//! - [ ] `disconnectnode ( "address" nodeid )`
-//! - [ ] `getaddednodeinfo ( "node" )`
-//! - [ ] `getconnectioncount`
-//! - [ ] `getnettotals`
+//! **== Network ==**
+//! - [-] `addnode "node" "command"`
+//! - [-] `clearbanned`
+//! - [-] `disconnectnode ( "address" nodeid )`
+//! - [x] `getaddednodeinfo ( "node" )`
+//! - [x] `getconnectioncount`
+//! - [x] `getnettotals`
 //! - [x] `getnetworkinfo`
-//! - [ ] `getnodeaddresses ( count )`
-//! - [ ] `getpeerinfo`
-//! - [ ] `listbanned`
-//! - [ ] `ping`
-//! - [ ] `setban "subnet" "command" ( bantime absolute )`
-//! - [ ] `setnetworkactive state`
+//! - [x] `getnodeaddresses ( count )`
+//! - [x] `getpeerinfo`
+//! - [x] `listbanned`
+//! - [-] `ping`
+//! - [-] `setban "subnet" "command" ( bantime absolute )`
+//! - [-] `setnetworkactive state`
 //!
 //! ** == Rawtransactions ==**
-//! - [ ] `analyzepsbt "psbt"`
-//! - [ ] `combinepsbt ["psbt",...]`
-//! - [ ] `combinerawtransaction ["hexstring",...]`
-//! - [ ] `converttopsbt "hexstring" ( permitsigdata iswitness )`
-//! - [ ] `createpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime replaceable )`
-//! - [ ] `createrawtransaction [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime replaceable )`
-//! - [ ] `decodepsbt "psbt"`
-//! - [ ] `decoderawtransaction "hexstring" ( iswitness )`
-//! - [ ] `decodescript "hexstring"`
-//! - [ ] `finalizepsbt "psbt" ( extract )`
-//! - [ ] `fundrawtransaction "hexstring" ( options iswitness )`
-//! - [ ] `getrawtransaction "txid" ( verbose "blockhash" )`
-//! - [ ] `joinpsbts ["psbt",...]`
+//! - [x] `analyzepsbt "psbt"`
+//! - [x] `combinepsbt ["psbt",...]`
+//! - [x] `combinerawtransaction ["hexstring",...]`
+//! - [x] `converttopsbt "hexstring" ( permitsigdata iswitness )`
+//! - [x] `createpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime replaceable )`
+//! - [x] `createrawtransaction [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime replaceable )`
+//! - [x] `decodepsbt "psbt"`
+//! - [x] `decoderawtransaction "hexstring" ( iswitness )`
+//! - [x] `decodescript "hexstring"`
+//! - [x] `finalizepsbt "psbt" ( extract )`
+//! - [x] `fundrawtransaction "hexstring" ( options iswitness )`
+//! - [x] `getrawtransaction "txid" ( verbose "blockhash" )`
+//! - [x] `joinpsbts ["psbt",...]`
 //! - [ ] `sendrawtransaction "hexstring" ( allowhighfees )`
-//! - [ ] `signrawtransactionwithkey "hexstring" ["privatekey",...] ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
-//! - [ ] `testmempoolaccept ["rawtx",...] ( allowhighfees )`
-//! - [ ] `utxoupdatepsbt "psbt"`
+//! - [x] `signrawtransactionwithkey "hexstring" ["privatekey",...] ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
+//! - [x] `testmempoolaccept ["rawtx",...] ( allowhighfees )`
+//! - [x] `utxoupdatepsbt "psbt"`
 //!
 //! ** == Util ==**
-//! - [ ] `createmultisig nrequired ["key",...] ( "address_type" )`
-//! - [ ] `deriveaddresses "descriptor" ( range )`
-//! - [ ] `estimatesmartfee conf_target ( "estimate_mode" )`
-//! - [ ] `getdescriptorinfo "descriptor"`
-//! - [ ] `signmessagewithprivkey "privkey" "message"`
-//! - [ ] `validateaddress "address"`
-//! - [ ] `verifymessage "address" "signature" "message"`
+//! - [x] `createmultisig nrequired ["key",...] ( "address_type" )`
+//! - [x] `deriveaddresses "descriptor" ( range )`
+//! - [x] `estimatesmartfee conf_target ( "estimate_mode" )`
+//! - [x] `getdescriptorinfo "descriptor"`
+//! - [x] `signmessagewithprivkey "privkey" "message"`
+//! - [x] `validateaddress "address"`
+//! - [x] `verifymessage "address" "signature" "message"`
 //!
 //! ** == Wallet ==**
-//! - [ ] `abandontransaction "txid"`
+//! - [-] `abandontransaction "txid"`
 //! - [ ] `abortrescan`
 //! - [ ] `addmultisigaddress nrequired ["key",...] ( "label" "address_type" )`
 //! - [ ] `backupwallet "destination"`
-//! - [ ] `bumpfee "txid" ( options )`
+//! - [x] `bumpfee "txid" ( options )`
 //! - [x] `createwallet "wallet_name" ( disable_private_keys blank )`
 //! - [ ] `dumpprivkey "address"`
 //! - [ ] `dumpwallet "filename"`
 //! - [ ] `encryptwallet "passphrase"`
-//! - [ ] `getaddressesbylabel "label"`
-//! - [ ] `getaddressinfo "address"`
+//! - [x] `getaddressesbylabel "label"`
+//! - [x] `getaddressinfo "address"`
 //! - [x] `getbalance ( "dummy" minconf include_watchonly )`
 //! - [x] `getnewaddress ( "label" "address_type" )`
 //! - [ ] `getrawchangeaddress ( "address_type" )`
@@ -113,50 +113,106 @@
 //! - [ ] `getreceivedbylabel "label" ( minconf )`
 //! - [x] `gettransaction "txid" ( include_watchonly )`
 //! - [ ] `getunconfirmedbalance`
-//! - [ ] `getwalletinfo`
+//! - [x] `getwalletinfo`
 //! - [ ] `importaddress "address" ( "label" rescan p2sh )`
-//! - [ ] `importmulti "requests" ( "options" )`
+//! - [x] `importmulti "requests" ( "options" )`
 //! - [ ] `importprivkey "privkey" ( "label" rescan )`
 //! - [ ] `importprunedfunds "rawtransaction" "txoutproof"`
 //! - [ ] `importpubkey "pubkey" ( "label" rescan )`
 //! - [ ] `importwallet "filename"`
 //! - [ ] `keypoolrefill ( newsize )`
 //! - [ ] `listaddressgroupings`
-//! - [ ] `listlabels ( "purpose" )`
+//! - [x] `listlabels ( "purpose" )`
 //! - [ ] `listlockunspent`
 //! - [ ] `listreceivedbyaddress ( minconf include_empty include_watchonly "address_filter" )`
 //! - [ ] `listreceivedbylabel ( minconf include_empty include_watchonly )`
-//! - [ ] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
-//! - [ ] `listtransactions ( "label" count skip include_watchonly )`
-//! - [ ] `listunspent ( minconf maxconf ["address",...] include_unsafe query_options )`
-//! - [ ] `listwalletdir`
-//! - [ ] `listwallets`
+//! - [x] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
+//! - [x] `listtransactions ( "label" count skip include_watchonly )`
+//! - [x] `listunspent ( minconf maxconf ["address",...] include_unsafe query_options )`
+//! - [x] `listwalletdir`
+//! - [x] `listwallets`
 //! - [x] `loadwallet "filename"`
 //! - [ ] `lockunspent unlock ( [{"txid":"hex","vout":n},...] )`
 //! - [ ] `removeprunedfunds "txid"`
 //! - [ ] `rescanblockchain ( start_height stop_height )`
-//! - [ ] `sendmany "" {"address":amount} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode" )`
+//! - [x] `sendmany "" {"address":amount} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode" )`
 //! - [x] `sendtoaddress "address" amount ( "comment" "comment_to" subtractfeefromamount replaceable conf_target "estimate_mode" )`
 //! - [ ] `sethdseed ( newkeypool "seed" )`
-//! - [ ] `setlabel "address" "label"`
+//! - [-] `setlabel "address" "label"`
 //! - [ ] `settxfee amount`
-//! - [ ] `signmessage "address" "message"`
-//! - [ ] `signrawtransactionwithwallet "hexstring" ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
+//! - [x] `signmessage "address" "message"`
+//! - [x] `signrawtransactionwithwallet "hexstring" ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
 //! - [ ] `unloadwallet ( "wallet_name" )`
-//! - [ ] `walletcreatefundedpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime options bip32derivs )`
+//! - [x] `walletcreatefundedpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime options bip32derivs )`
 //! - [ ] `walletlock`
 //! - [ ] `walletpassphrase "passphrase" timeout`
 //! - [ ] `walletpassphrasechange "oldpassphrase" "newpassphrase"`
-//! - [ ] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
+//! - [x] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
 //! - [ ] `
 //! - [ ] `//! ** == Zmq ==**`
 //! - [ ] `getzmqnotifications`
+//!
+//! **== Hidden ==**
+//! - [x] `waitforblock <blockhash> (timeout)`
+//! - [x] `waitforblockheight <height> (timeout)`
+//! - [x] `waitfornewblock (timeout)`
+
+mod blockchain;
+mod control;
+mod network;
+mod raw_transactions;
+mod util;
+mod wallet;
 
+#[doc(inline)]
+pub use self::blockchain::{ScanTxOutSetStart, ScanTxOutSetUnspent};
+#[doc(inline)]
+pub use self::control::{ActiveCommand, GetRpcInfo};
+#[doc(inline)]
+pub use self::network::{GetNodeAddresses, NodeAddress};
+#[doc(inline)]
+pub use self::raw_transactions::{
+    AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing, Bip32Deriv,
+    DecodePsbt, JoinPsbts, PsbtInput, PsbtOutput, PsbtRole, UtxoUpdatePsbt,
+};
+#[doc(inline)]
+pub use self::util::{DeriveAddresses, GetDescriptorInfo};
+#[doc(inline)]
+pub use self::wallet::{
+    GetAddressInfo, ListUnspent, ListUnspentItem, ListWalletDir, ListWalletDirWallet,
+};
 #[doc(inline)]
 pub use crate::v17::{
-    Bip9Softfork, Bip9SoftforkStatus, CreateWallet, GenerateToAddress, GetBalance,
-    GetBestBlockHash, GetBlockVerbosityOne, GetBlockVerbosityZero, GetBlockchainInfo,
-    GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, GetNewAddress, GetTransaction,
-    GetTransactionDetail, GetTransactionDetailCategory, GetTxOut, LoadWallet, ScriptPubkey,
-    SendRawTransaction, SendToAddress, Softfork, SoftforkReject,
+    AddedNode, AddedNodeAddress, AddressInformation, AddressPurpose, Banned, Bip32DerivError,
+    Bip9Softfork, Bip9SoftforkStatistics, Bip9SoftforkStatus, BlockTemplateTransaction,
+    BlockTemplateTransactionError, BumpFee, BumpFeeError, CombinePsbt, CombineRawTransaction,
+    ConvertToPsbt, CreateMultisig, CreateMultisigError, CreatePsbt, CreateRawTransaction,
+    CreateWallet, DecodePsbtError, DecodeRawTransaction, DecodeScript, DecodeScriptError,
+    DecodeScriptSegwit, EstimateSmartFee, EstimateSmartFeeError, FinalizePsbt, FinalizePsbtError,
+    FundRawTransaction, FundRawTransactionError, GenerateToAddress, GetAddedNodeInfo,
+    GetAddressInfoEmbedded, GetAddressInfoEmbeddedError, GetAddressInfoError, GetAddressInfoLabel,
+    GetAddressesByLabel, GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash,
+    GetBlockTemplate, GetBlockTemplateError, GetBlockVerbosityOne, GetBlockVerbosityTwo,
+    GetBlockVerbosityTwoError, GetBlockVerbosityTwoTransaction, GetBlockVerbosityZero,
+    GetBlockchainInfo, GetConnectionCount, GetMemoryInfoMallocInfo, GetMemoryInfoStats,
+    GetMempoolAncestors, GetMempoolAncestorsVerbose, GetMempoolDescendants,
+    GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError,
+    GetMiningInfo, GetMiningInfoError, GetNetTotals, GetNetworkHashPs, GetNetworkInfo,
+    GetNetworkInfoAddress, GetNetworkInfoNetwork, GetNewAddress, GetPeerInfo, GetRawMempool,
+    GetRawMempoolVerbose, GetRawTransaction, GetRawTransactionVerbose,
+    GetRawTransactionVerboseError, GetTransaction, GetTransactionDetail,
+    GetTransactionDetailCategory, GetTxOut, GetTxOutSetInfo, GetTxOutSetInfoError, GetWalletInfo,
+    GetWalletInfoError, ImportMulti, ImportMultiResult, JsonRpcError, ListBanned, ListLabels,
+    ListSinceBlock, ListSinceBlockError, ListTransactions, ListUnspentItemError, ListWallets,
+    LoadWallet, Locked, Logging, MapMempoolEntryError, MempoolAcceptance, MempoolEntry,
+    MempoolEntryError, MempoolEntryFees, MempoolEntryFeesError, PeerInfo, PrioritiseTransaction,
+    PsbtInputError, PsbtOutputError, PsbtScript, PsbtUnknownError, RawTransactionError,
+    RawTransactionInput, RawTransactionOutput, RawTransactionScriptPubkey, RawTransactionScriptSig,
+    ScanTxOutSetAbort, ScanTxOutSetError, ScanTxOutSetStatus, ScriptPubkey, SendMany,
+    SendRawTransaction, SendToAddress, SignFail, SignFailError, SignMessage,
+    SignMessageWithPrivKey, SignRawTransaction, SignRawTransactionError, Softfork, SoftforkReject,
+    SubmitBlock, TestMempoolAccept, TransactionItem, TransactionItemError, UploadTarget, Uptime,
+    ValidateAddress, ValidateAddressError, VerifyMessage, WaitForBlock, WaitForBlockHeight,
+    WaitForNewBlock, WalletCreateFundedPsbt, WalletCreateFundedPsbtError, WalletProcessPsbt,
+    WitnessUtxo,
 };
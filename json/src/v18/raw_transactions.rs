@@ -0,0 +1,525 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.18 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+//!
+//! From v0.18 `decodepsbt` returns the BIP-32 derivations as an array instead of a map keyed by
+//! public key.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::bip32::{DerivationPath, Fingerprint, KeySource};
+use bitcoin::hex::{self, FromHex as _};
+use bitcoin::psbt::{self, Psbt, PsbtParseError};
+use bitcoin::{
+    amount, ecdsa, secp256k1, Amount, PubkeyHash, PublicKey, ScriptBuf, ScriptHash, TxOut,
+    WScriptHash, Witness,
+};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::{
+    psbt_sighash_type, psbt_unknown, Bip32DerivError, DecodePsbtError, DecodeRawTransaction,
+    PsbtInputError, PsbtOutputError, PsbtScript, RawTransactionScriptSig, WitnessUtxo,
+};
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `analyzepsbt`.
+///
+/// > analyzepsbt "psbt"
+/// >
+/// > Analyzes and provides information about the current status of a PSBT and its inputs
+/// >
+/// > Arguments:
+/// > 1. psbt    (string, required) A base64 string of a PSBT
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AnalyzePsbt {
+    /// The analysis of each input.
+    pub inputs: Vec<AnalyzePsbtInput>,
+    /// Estimated vsize of the final signed transaction.
+    pub estimated_vsize: Option<i64>,
+    /// Estimated feerate of the final signed transaction in BTC/kB. Shown only if all UTXO slots
+    /// in the PSBT have been filled.
+    #[serde(rename = "estimated_feerate")]
+    pub estimated_fee_rate: Option<f64>,
+    /// The transaction fee paid. Shown only if all UTXO slots in the PSBT have been filled.
+    pub fee: Option<f64>,
+    /// Role of the next person that this psbt needs to go to.
+    pub next: PsbtRole,
+    /// Error message if there is one.
+    pub error: Option<String>,
+}
+
+impl AnalyzePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::AnalyzePsbt, AnalyzePsbtError> {
+        use AnalyzePsbtError as E;
+
+        let inputs =
+            self.inputs.into_iter().map(|input| input.into_model()).collect::<Result<_, _>>()?;
+        let estimated_vsize =
+            self.estimated_vsize.map(|v| crate::to_u32(v, "estimated_vsize")).transpose()?;
+        let estimated_fee_rate = match self.estimated_fee_rate {
+            Some(rate) => crate::btc_per_kb(rate).map_err(E::EstimatedFeeRate)?,
+            None => None,
+        };
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        Ok(model::AnalyzePsbt {
+            inputs,
+            estimated_vsize,
+            estimated_fee_rate,
+            fee,
+            next: self.next.into_model(),
+            error: self.error,
+        })
+    }
+}
+
+/// The analysis of a single input. Part of `analyzepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AnalyzePsbtInput {
+    /// Whether a UTXO is provided.
+    pub has_utxo: bool,
+    /// Whether the input is finalized.
+    pub is_final: bool,
+    /// Things that are missing that are required to complete this input.
+    pub missing: Option<AnalyzePsbtInputMissing>,
+    /// Role of the next person that this input needs to go to.
+    pub next: Option<PsbtRole>,
+}
+
+impl AnalyzePsbtInput {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::AnalyzePsbtInput, AnalyzePsbtError> {
+        Ok(model::AnalyzePsbtInput {
+            has_utxo: self.has_utxo,
+            is_final: self.is_final,
+            missing: self.missing.map(|missing| missing.into_model()).transpose()?,
+            next: self.next.map(|next| next.into_model()),
+        })
+    }
+}
+
+/// The things missing to complete an input. Part of `analyzepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AnalyzePsbtInputMissing {
+    /// Public key IDs (hash160 of the public key) of public keys whose BIP 32 derivation paths
+    /// are missing.
+    pub pubkeys: Option<Vec<String>>,
+    /// Public key IDs (hash160 of the public key) of public keys whose signatures are missing.
+    pub signatures: Option<Vec<String>>,
+    /// Hash160 of the redeemScript that is missing.
+    #[serde(rename = "redeemscript")]
+    pub redeem_script: Option<String>,
+    /// SHA256 of the witnessScript that is missing.
+    #[serde(rename = "witnessscript")]
+    pub witness_script: Option<String>,
+}
+
+impl AnalyzePsbtInputMissing {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::AnalyzePsbtInputMissing, AnalyzePsbtError> {
+        use AnalyzePsbtError as E;
+
+        let pubkeys = self
+            .pubkeys
+            .unwrap_or_default()
+            .iter()
+            .map(|id| id.parse::<PubkeyHash>())
+            .collect::<Result<_, _>>()
+            .map_err(E::MissingPubkeys)?;
+        let signatures = self
+            .signatures
+            .unwrap_or_default()
+            .iter()
+            .map(|id| id.parse::<PubkeyHash>())
+            .collect::<Result<_, _>>()
+            .map_err(E::MissingSignatures)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|hash| hash.parse::<ScriptHash>())
+            .transpose()
+            .map_err(E::MissingRedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|hash| hash.parse::<WScriptHash>())
+            .transpose()
+            .map_err(E::MissingWitnessScript)?;
+
+        Ok(model::AnalyzePsbtInputMissing { pubkeys, signatures, redeem_script, witness_script })
+    }
+}
+
+/// The BIP-174 role of the next participant that needs to handle a PSBT. Part of `analyzepsbt`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PsbtRole {
+    Creator,
+    Updater,
+    Signer,
+    Finalizer,
+    Extractor,
+}
+
+impl PsbtRole {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::PsbtRole {
+        use PsbtRole::*;
+
+        match self {
+            Creator => model::PsbtRole::Creator,
+            Updater => model::PsbtRole::Updater,
+            Signer => model::PsbtRole::Signer,
+            Finalizer => model::PsbtRole::Finalizer,
+            Extractor => model::PsbtRole::Extractor,
+        }
+    }
+}
+
+/// Error when converting an `AnalyzePsbt` type into the model type.
+#[derive(Debug)]
+pub enum AnalyzePsbtError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `estimated_feerate` field failed.
+    EstimatedFeeRate(amount::ParseAmountError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+    /// Conversion of one of the missing `pubkeys` failed.
+    MissingPubkeys(hex::HexToArrayError),
+    /// Conversion of one of the missing `signatures` failed.
+    MissingSignatures(hex::HexToArrayError),
+    /// Conversion of the missing `redeemscript` field failed.
+    MissingRedeemScript(hex::HexToArrayError),
+    /// Conversion of the missing `witnessscript` field failed.
+    MissingWitnessScript(hex::HexToArrayError),
+}
+
+impl fmt::Display for AnalyzePsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use AnalyzePsbtError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::EstimatedFeeRate(ref e) =>
+                write_err!(f, "conversion of the `estimated_feerate` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+            E::MissingPubkeys(ref e) =>
+                write_err!(f, "conversion of the missing `pubkeys` field failed"; e),
+            E::MissingSignatures(ref e) =>
+                write_err!(f, "conversion of the missing `signatures` field failed"; e),
+            E::MissingRedeemScript(ref e) =>
+                write_err!(f, "conversion of the missing `redeemscript` field failed"; e),
+            E::MissingWitnessScript(ref e) =>
+                write_err!(f, "conversion of the missing `witnessscript` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for AnalyzePsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use AnalyzePsbtError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::EstimatedFeeRate(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+            E::MissingPubkeys(ref e) => Some(e),
+            E::MissingSignatures(ref e) => Some(e),
+            E::MissingRedeemScript(ref e) => Some(e),
+            E::MissingWitnessScript(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for AnalyzePsbtError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `decodepsbt`.
+///
+/// > decodepsbt "psbt"
+/// >
+/// > Return a JSON object representing the serialized, base64-encoded partially signed Bitcoin transaction.
+/// >
+/// > Arguments:
+/// > 1. psbt    (string, required) The PSBT base64 string
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The decoded network-serialized unsigned transaction.
+    pub tx: DecodeRawTransaction,
+    /// The unknown global fields.
+    pub unknown: BTreeMap<String, String>,
+    /// The PSBT inputs.
+    pub inputs: Vec<PsbtInput>,
+    /// The PSBT outputs.
+    pub outputs: Vec<PsbtOutput>,
+    /// The transaction fee paid if all UTXOs slots in the PSBT have been filled.
+    pub fee: Option<f64>,
+}
+
+impl DecodePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodePsbt, DecodePsbtError> {
+        use DecodePsbtError as E;
+
+        let unsigned_tx = self.tx.transaction().map_err(E::Tx)?;
+        let unknown = psbt_unknown(self.unknown).map_err(E::Unknown)?;
+        let inputs = self
+            .inputs
+            .into_iter()
+            .map(|input| input.into_input())
+            .collect::<Result<_, _>>()
+            .map_err(E::Inputs)?;
+        let outputs = self
+            .outputs
+            .into_iter()
+            .map(|output| output.into_output())
+            .collect::<Result<_, _>>()
+            .map_err(E::Outputs)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        let psbt = Psbt {
+            unsigned_tx,
+            version: 0,
+            xpub: BTreeMap::new(),
+            proprietary: BTreeMap::new(),
+            unknown,
+            inputs,
+            outputs,
+        };
+        Ok(model::DecodePsbt { psbt, fee })
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, DecodePsbtError> { Ok(self.into_model()?.psbt) }
+}
+
+/// An input of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtInput {
+    /// Decoded network transaction for non-witness UTXOs.
+    pub non_witness_utxo: Option<DecodeRawTransaction>,
+    /// Transaction output for witness UTXOs.
+    pub witness_utxo: Option<WitnessUtxo>,
+    /// The public keys with their signatures.
+    pub partial_signatures: Option<BTreeMap<String, String>>,
+    /// The sighash type to be used.
+    pub sighash: Option<String>,
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<Vec<Bip32Deriv>>,
+    /// The final scriptsig.
+    #[serde(rename = "final_scriptsig")]
+    pub final_script_sig: Option<RawTransactionScriptSig>,
+    /// The final script witness, hex-encoded witness data items.
+    #[serde(rename = "final_scriptwitness")]
+    pub final_script_witness: Option<Vec<String>>,
+    /// The unknown input fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtInput {
+    /// Converts the input to a `bitcoin::psbt::Input`.
+    pub fn into_input(self) -> Result<psbt::Input, PsbtInputError> {
+        use PsbtInputError as E;
+
+        let non_witness_utxo = self
+            .non_witness_utxo
+            .map(|tx| tx.transaction())
+            .transpose()
+            .map_err(E::NonWitnessUtxo)?;
+        let witness_utxo = match self.witness_utxo {
+            Some(utxo) => Some(TxOut {
+                value: Amount::from_btc(utxo.amount).map_err(E::WitnessUtxoAmount)?,
+                script_pubkey: ScriptBuf::from_hex(&utxo.script_pubkey.hex)
+                    .map_err(E::WitnessUtxoScriptPubkey)?,
+            }),
+            None => None,
+        };
+        let partial_sigs = self
+            .partial_signatures
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, sig)| {
+                let pubkey = pubkey.parse::<PublicKey>().map_err(E::PartialSignaturePubkey)?;
+                let sig = sig.parse::<ecdsa::Signature>().map_err(E::PartialSignature)?;
+                Ok((pubkey, sig))
+            })
+            .collect::<Result<_, _>>()?;
+        let sighash_type =
+            self.sighash.map(|s| psbt_sighash_type(&s)).transpose().map_err(E::Sighash)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_bip32_derivation())
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let final_script_sig = self
+            .final_script_sig
+            .map(|script_sig| ScriptBuf::from_hex(&script_sig.hex))
+            .transpose()
+            .map_err(E::FinalScriptSig)?;
+        let final_script_witness = self
+            .final_script_witness
+            .map(|witness| {
+                witness
+                    .iter()
+                    .map(|item| Vec::<u8>::from_hex(item))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|items| Witness::from_slice(&items))
+            })
+            .transpose()
+            .map_err(E::FinalScriptWitness)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Input {
+            non_witness_utxo,
+            witness_utxo,
+            partial_sigs,
+            sighash_type,
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            final_script_sig,
+            final_script_witness,
+            unknown,
+            ..Default::default()
+        })
+    }
+}
+
+/// An output of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtOutput {
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<Vec<Bip32Deriv>>,
+    /// The unknown output fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtOutput {
+    /// Converts the output to a `bitcoin::psbt::Output`.
+    pub fn into_output(self) -> Result<psbt::Output, PsbtOutputError> {
+        use PsbtOutputError as E;
+
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_bip32_derivation())
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Output {
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            unknown,
+            ..Default::default()
+        })
+    }
+}
+
+/// A public key with its BIP-32 key origin. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Bip32Deriv {
+    /// The public key this path corresponds to.
+    pub pubkey: String,
+    /// The fingerprint of the master key.
+    pub master_fingerprint: String,
+    /// The path.
+    pub path: String,
+}
+
+impl Bip32Deriv {
+    /// Converts the derivation to a BIP-32 derivation map entry.
+    pub fn into_bip32_derivation(
+        self,
+    ) -> Result<(secp256k1::PublicKey, KeySource), Bip32DerivError> {
+        use Bip32DerivError as E;
+
+        let pubkey = self.pubkey.parse::<secp256k1::PublicKey>().map_err(E::Pubkey)?;
+        let fingerprint =
+            self.master_fingerprint.parse::<Fingerprint>().map_err(E::MasterFingerprint)?;
+        let path = self.path.parse::<DerivationPath>().map_err(E::Path)?;
+        Ok((pubkey, (fingerprint, path)))
+    }
+}
+
+/// Result of JSON-RPC method `joinpsbts`.
+///
+/// > joinpsbts ["psbt",...]
+/// >
+/// > Joins multiple distinct PSBTs with different inputs and outputs into one PSBT with inputs and outputs from all of the PSBTs
+/// > No input in any of the PSBTs can be in more than one of the PSBTs.
+/// >
+/// > Arguments:
+/// > 1. txs            (json array, required) A json array of base64 strings of partially signed transactions
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct JoinPsbts(pub String); // The base64 encoded PSBT.
+
+impl JoinPsbts {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::JoinPsbts, PsbtParseError> {
+        let psbt = self.0.parse::<Psbt>()?;
+        Ok(model::JoinPsbts(psbt))
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, PsbtParseError> { Ok(self.into_model()?.0) }
+}
+
+/// Result of JSON-RPC method `utxoupdatepsbt`.
+///
+/// > utxoupdatepsbt "psbt"
+/// >
+/// > Updates a PSBT with witness UTXOs retrieved from the UTXO set or the mempool.
+/// >
+/// > Arguments:
+/// > 1. psbt    (string, required) A base64 string of a PSBT
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UtxoUpdatePsbt(pub String); // The base64 encoded PSBT.
+
+impl UtxoUpdatePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::UtxoUpdatePsbt, PsbtParseError> {
+        let psbt = self.0.parse::<Psbt>()?;
+        Ok(model::UtxoUpdatePsbt(psbt))
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, PsbtParseError> { Ok(self.into_model()?.0) }
+}
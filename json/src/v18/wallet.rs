@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.18.1 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::bip32::{DerivationPath, Fingerprint};
+use bitcoin::hashes::hash160;
+use bitcoin::{Address, Amount, OutPoint, PublicKey, ScriptBuf, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{
+    witness_program, GetAddressInfoEmbedded, GetAddressInfoError, GetAddressInfoLabel,
+    ListUnspentItemError,
+};
+
+/// Result of the JSON-RPC method `getaddressinfo`.
+///
+/// > getaddressinfo "address"
+/// >
+/// > Return information about the given bitcoin address. Some information requires the address
+/// > to be in the wallet.
+/// >
+/// > Arguments:
+/// > 1. address    (string, required) The bitcoin address to get the information of.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfo {
+    /// The bitcoin address validated.
+    pub address: String,
+    /// The hex encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// If the address is yours or not.
+    #[serde(rename = "ismine")]
+    pub is_mine: bool,
+    /// If the address is watchonly.
+    #[serde(rename = "iswatchonly")]
+    pub is_watch_only: bool,
+    /// Whether we know how to spend coins sent to this address, ignoring the possible lack of
+    /// private keys.
+    pub solvable: bool,
+    /// A descriptor for spending coins sent to this address (only when solvable).
+    pub desc: Option<String>,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: bool,
+    /// If the address was used for change output.
+    #[serde(rename = "ischange")]
+    pub is_change: bool,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: bool,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+    /// The output script type.
+    ///
+    /// Only if "isscript" is true and the redeemscript is known.
+    pub script: Option<String>,
+    /// The redeemscript for the p2sh address.
+    pub hex: Option<String>,
+    /// Array of pubkeys associated with the known redeemscript (only if "script" is "multisig").
+    pub pubkeys: Option<Vec<String>>,
+    /// Number of signatures required to spend multisig output (only if "script" is "multisig").
+    #[serde(rename = "sigsrequired")]
+    pub sigs_required: Option<i64>,
+    /// The hex value of the raw public key, for single-key addresses (possibly embedded in P2SH or
+    /// P2WSH).
+    pub pubkey: Option<String>,
+    /// Information about the address embedded in P2SH or P2WSH, if relevant and known.
+    pub embedded: Option<GetAddressInfoEmbedded>,
+    /// If the address is compressed.
+    #[serde(rename = "iscompressed")]
+    pub is_compressed: Option<bool>,
+    /// The label associated with the address, "" is the default label.
+    pub label: Option<String>,
+    /// The creation time of the key if available in seconds since epoch (Jan 1 1970 GMT).
+    pub timestamp: Option<i64>,
+    /// The HD keypath if the key is HD and available.
+    #[serde(rename = "hdkeypath")]
+    pub hd_key_path: Option<String>,
+    /// The Hash160 of the HD seed.
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// The fingperprint of the master key.
+    #[serde(rename = "hdmasterfingerprint")]
+    pub hd_master_fingerprint: Option<String>,
+    /// Array of labels associated with the address.
+    pub labels: Vec<GetAddressInfoLabel>,
+}
+
+impl GetAddressInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddressInfo, GetAddressInfoError> {
+        use GetAddressInfoError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let witness_program = witness_program(self.witness_version, self.witness_program)
+            .map_err(E::WitnessProgram)?;
+        let hex = self.hex.map(|s| ScriptBuf::from_hex(&s)).transpose().map_err(E::Hex)?;
+        let pubkeys = self
+            .pubkeys
+            .map(|keys| keys.iter().map(|s| s.parse::<PublicKey>()).collect::<Result<Vec<_>, _>>())
+            .transpose()
+            .map_err(E::Pubkeys)?;
+        let sigs_required =
+            self.sigs_required.map(|n| crate::to_u32(n, "sigsrequired")).transpose()?;
+        let pubkey = self.pubkey.map(|s| s.parse::<PublicKey>()).transpose().map_err(E::Pubkey)?;
+        let embedded = self.embedded.map(|e| e.into_model()).transpose().map_err(E::Embedded)?;
+        let timestamp = self.timestamp.map(|t| crate::to_u32(t, "timestamp")).transpose()?;
+        let hd_key_path = self
+            .hd_key_path
+            .map(|s| s.parse::<DerivationPath>())
+            .transpose()
+            .map_err(E::HdKeyPath)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+        let hd_master_fingerprint = self
+            .hd_master_fingerprint
+            .map(|s| s.parse::<Fingerprint>())
+            .transpose()
+            .map_err(E::HdMasterFingerprint)?;
+        let labels = self.labels.into_iter().map(|label| label.name).collect();
+
+        Ok(model::GetAddressInfo {
+            address,
+            script_pubkey,
+            is_mine: self.is_mine,
+            is_watch_only: self.is_watch_only,
+            solvable: Some(self.solvable),
+            descriptor: self.desc,
+            is_script: self.is_script,
+            is_change: Some(self.is_change),
+            is_witness: self.is_witness,
+            witness_program,
+            script: self.script,
+            hex,
+            pubkeys,
+            sigs_required,
+            pubkey,
+            embedded,
+            is_compressed: self.is_compressed,
+            timestamp,
+            hd_key_path,
+            hd_seed_id,
+            hd_master_fingerprint,
+            labels,
+        })
+    }
+}
+
+/// Result of the JSON-RPC method `listwalletdir`.
+///
+/// > listwalletdir
+/// >
+/// > Returns a list of wallets in the wallet directory.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWalletDir {
+    /// The wallets in the wallet directory.
+    pub wallets: Vec<ListWalletDirWallet>,
+}
+
+/// A wallet in the wallet directory, part of `listwalletdir`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWalletDirWallet {
+    /// The wallet name.
+    pub name: String,
+}
+
+impl ListWalletDir {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListWalletDir {
+        model::ListWalletDir(self.wallets.into_iter().map(|w| w.name).collect())
+    }
+}
+
+/// Result of the JSON-RPC method `listunspent`.
+///
+/// > listunspent ( minconf maxconf ["address",...] include_unsafe query_options )
+/// >
+/// > Returns array of unspent transaction outputs
+/// > with between minconf and maxconf (inclusive) confirmations.
+/// > Optionally filter to only include txouts paid to specified addresses.
+/// >
+/// > Arguments:
+/// > 1. minconf                            (numeric, optional, default=1) The minimum confirmations to filter
+/// > 2. maxconf                            (numeric, optional, default=9999999) The maximum confirmations to filter
+/// > 3. addresses                          (json array, optional, default=empty array) A json array of bitcoin addresses to filter
+/// > 4. include_unsafe                     (boolean, optional, default=true) Include outputs that are not safe to spend
+/// >                                       See description of "safe" attribute below.
+/// > 5. query_options                      (json object, optional) JSON with query options
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspent(pub Vec<ListUnspentItem>);
+
+impl ListUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspent, ListUnspentItemError> {
+        let unspent =
+            self.0.into_iter().map(|utxo| utxo.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListUnspent(unspent))
+    }
+}
+
+/// An unspent transaction output, an item returned by `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspentItem {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    pub vout: u32,
+    /// The bitcoin address.
+    pub address: Option<String>,
+    /// The associated label, or "" for the default label.
+    pub label: Option<String>,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// The transaction output amount in BTC.
+    pub amount: f64,
+    /// The number of confirmations.
+    pub confirmations: i64,
+    /// The redeemScript if scriptPubKey is P2SH.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: Option<String>,
+    /// The witnessScript if the scriptPubKey is P2WSH or P2SH-P2WSH.
+    #[serde(rename = "witnessScript")]
+    pub witness_script: Option<String>,
+    /// Whether we have the private keys to spend this output.
+    pub spendable: bool,
+    /// Whether we know how to spend this output, ignoring the lack of keys.
+    pub solvable: bool,
+    /// A descriptor for spending this output (only when solvable).
+    pub desc: Option<String>,
+    /// Whether this output is considered safe to spend.
+    ///
+    /// Unconfirmed transactions from outside keys and unconfirmed replacement transactions are
+    /// considered unsafe and are not eligible for spending by fundrawtransaction and
+    /// sendtoaddress.
+    pub safe: bool,
+}
+
+impl ListUnspentItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspentItem, ListUnspentItemError> {
+        use ListUnspentItemError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let address = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let confirmations = crate::to_u32(self.confirmations, "confirmations")?;
+        let redeem_script = self
+            .redeem_script
+            .map(|hex| ScriptBuf::from_hex(&hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|hex| ScriptBuf::from_hex(&hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+
+        Ok(model::ListUnspentItem {
+            outpoint: OutPoint { txid, vout: self.vout },
+            address,
+            label: self.label,
+            script_pubkey,
+            amount,
+            confirmations,
+            redeem_script,
+            witness_script,
+            spendable: self.spendable,
+            solvable: self.solvable,
+            reused: None,
+            descriptor: self.desc,
+            parent_descriptors: None,
+            safe: self.safe,
+        })
+    }
+}
@@ -7,11 +7,18 @@
 use core::fmt;
 use std::collections::BTreeMap;
 
+use bitcoin::bip158::{BlockFilter, FilterHeader};
 use bitcoin::error::UnprefixedHexError;
-use bitcoin::{hex, network, BlockHash, Network, Work};
+use bitcoin::hex::FromHex as _;
+use bitcoin::{hex, network, Amount, BlockHash, Network, Txid, Work, Wtxid};
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
+use crate::v17::{
+    mempool_entries, Bip9SoftforkStatistics, MapMempoolEntryError, MempoolEntryError,
+    MempoolEntryFees, ScanTxOutSetError,
+};
+use crate::v18::ScanTxOutSetUnspent;
 use crate::{model, NumericError};
 
 #[rustfmt::skip]                // Keep public re-exports separate.
@@ -105,6 +112,8 @@ pub struct Bip9SoftforkInfo {
     pub timeout: i64,
     /// Height of the first block to which the status applies.
     pub since: i64,
+    /// Minimum height of blocks for which the rules may be enforced (v0.21 and later).
+    pub min_activation_height: Option<i64>,
     /// Numeric statistics about BIP-9 signalling for a softfork (only for "started" status).
     pub statistics: Option<Bip9SoftforkStatistics>,
 }
@@ -125,21 +134,6 @@ pub enum Bip9SoftforkStatus {
     Failed,
 }
 
-/// Statistics for a BIP-9 softfork.
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
-pub struct Bip9SoftforkStatistics {
-    /// The length in blocks of the BIP9 signalling period.
-    pub period: i64,
-    /// The number of blocks with the version bit set required to activate the feature.
-    pub threshold: Option<i64>,
-    /// The number of blocks elapsed since the beginning of the current period.
-    pub elapsed: i64,
-    /// The number of blocks with the version bit set in the current period.
-    pub count: i64,
-    /// `false` if there are not enough blocks left in this period to pass activation threshold.
-    pub possible: Option<bool>,
-}
-
 impl GetBlockchainInfo {
     /// Converts version specific type to a version in-specific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
@@ -153,7 +147,11 @@ impl GetBlockchainInfo {
             self.prune_height.map(|h| crate::to_u32(h, "prune_height")).transpose()?;
         let prune_target_size =
             self.prune_target_size.map(|h| crate::to_u32(h, "prune_target_size")).transpose()?;
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let softforks = self
+            .softforks
+            .into_iter()
+            .map(|(name, softfork)| Ok((name, softfork.into_model()?)))
+            .collect::<Result<BTreeMap<_, _>, NumericError>>()?;
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -176,6 +174,62 @@ impl GetBlockchainInfo {
     }
 }
 
+impl Softfork {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Softfork, NumericError> {
+        let bip9 = self.bip9.map(|info| info.into_model()).transpose()?;
+        let height = self.height.map(|h| crate::to_u32(h, "height")).transpose()?;
+
+        Ok(model::Softfork { type_: self.type_.into_model(), bip9, height, active: self.active })
+    }
+}
+
+impl SoftforkType {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::SoftforkType {
+        match self {
+            Self::Buried => model::SoftforkType::Buried,
+            Self::Bip9 => model::SoftforkType::Bip9,
+        }
+    }
+}
+
+impl Bip9SoftforkInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Bip9SoftforkInfo, NumericError> {
+        let min_activation_height = self
+            .min_activation_height
+            .map(|h| crate::to_u32(h, "min_activation_height"))
+            .transpose()?;
+        let statistics = self.statistics.map(|s| s.into_model()).transpose()?;
+
+        Ok(model::Bip9SoftforkInfo {
+            status: self.status.into_model(),
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            since: crate::to_u32(self.since, "since")?,
+            min_activation_height,
+            statistics,
+        })
+    }
+}
+
+impl Bip9SoftforkStatus {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Bip9SoftforkStatus {
+        use model::Bip9SoftforkStatus::*;
+
+        match self {
+            Self::Defined => Defined,
+            Self::Started => Started,
+            Self::LockedIn => LockedIn,
+            Self::Active => Active,
+            Self::Failed => Failed,
+        }
+    }
+}
+
 /// Error when converting a `GetBlockchainInfo` type into the model type.
 #[derive(Debug)]
 pub enum GetBlockchainInfoError {
@@ -220,3 +274,265 @@ impl std::error::Error for GetBlockchainInfoError {
 impl From<NumericError> for GetBlockchainInfoError {
     fn from(e: NumericError) -> Self { Self::Numeric(e) }
 }
+
+/// Result of JSON-RPC method `getblockfilter`.
+///
+/// > getblockfilter "blockhash" ( "filtertype" )
+/// >
+/// > Retrieve a BIP 157 content filter for a particular block.
+/// >
+/// > Arguments:
+/// > 1. blockhash     (string, required) The hash of the block
+/// > 2. filtertype    (string, optional, default=basic) The type name of the filter
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetBlockFilter {
+    /// The hex-encoded filter data.
+    pub filter: String,
+    /// The hex-encoded filter header.
+    pub header: String,
+}
+
+impl GetBlockFilter {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockFilter, GetBlockFilterError> {
+        use GetBlockFilterError as E;
+
+        let content = Vec::<u8>::from_hex(&self.filter).map_err(E::Filter)?;
+        let filter = BlockFilter::new(&content);
+        let header = self.header.parse::<FilterHeader>().map_err(E::Header)?;
+
+        Ok(model::GetBlockFilter { filter, header })
+    }
+}
+
+/// Error when converting a `GetBlockFilter` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockFilterError {
+    /// Conversion of the `filter` field failed.
+    Filter(hex::HexToBytesError),
+    /// Conversion of the `header` field failed.
+    Header(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetBlockFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBlockFilterError as E;
+
+        match *self {
+            E::Filter(ref e) => write_err!(f, "conversion of the `filter` field failed"; e),
+            E::Header(ref e) => write_err!(f, "conversion of the `header` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetBlockFilterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBlockFilterError as E;
+
+        match *self {
+            E::Filter(ref e) => Some(e),
+            E::Header(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolancestors` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry` i.e., an ancestor.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolAncestorsVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetMempoolAncestorsVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolAncestorsVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetMempoolAncestorsVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempooldescendants` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry` i.e., a descendant.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolDescendantsVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetMempoolDescendantsVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolDescendantsVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetMempoolDescendantsVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolentry`.
+///
+/// > getmempoolentry "txid"
+/// >
+/// > Returns mempool data for given transaction
+/// >
+/// > Arguments:
+/// > 1. txid    (string, required) The transaction id (must be in mempool)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+impl GetMempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolEntry, MempoolEntryError> {
+        Ok(model::GetMempoolEntry(self.0.into_model()?))
+    }
+}
+
+/// A relative (ancestor or descendant) transaction of a transaction in the mempool.
+///
+/// Part of `getmempoolancestors`, `getmempooldescendants`, `getmempoolentry` and `getrawmempool`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    ///
+    /// This is different from actual serialized size for witness transactions as witness data is discounted.
+    pub vsize: i64,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: i64,
+    /// Block height when transaction entered pool.
+    pub height: i64,
+    /// Number of in-mempool descendant transactions (including this one).
+    #[serde(rename = "descendantcount")]
+    pub descendant_count: i64,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    #[serde(rename = "descendantsize")]
+    pub descendant_size: i64,
+    /// Number of in-mempool ancestor transactions (including this one).
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: i64,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: i64,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: String,
+    /// Fee object which contains the base fee, modified fee (with fee deltas), and
+    /// ancestor/descendant fee totals all in BTC.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction (parent transaction id).
+    pub depends: Vec<String>,
+    /// Unconfirmed transactions spending outputs from this transaction (child transaction id).
+    #[serde(rename = "spentby")]
+    pub spent_by: Vec<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee)
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: bool,
+}
+
+impl MempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntry, MempoolEntryError> {
+        use MempoolEntryError as E;
+
+        let vsize = crate::to_u32(self.vsize, "vsize")?;
+        let time = crate::to_u32(self.time, "time")?;
+        let height = crate::to_u32(self.height, "height")?;
+        let descendant_count = crate::to_u32(self.descendant_count, "descendant_count")?;
+        let descendant_size = crate::to_u32(self.descendant_size, "descendant_size")?;
+        let ancestor_count = crate::to_u32(self.ancestor_count, "ancestor_count")?;
+        let ancestor_size = crate::to_u32(self.ancestor_size, "ancestor_size")?;
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let fees = self.fees.into_model().map_err(E::Fees)?;
+        let depends = self
+            .depends
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::Depends)?;
+        let spent_by = self
+            .spent_by
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::SpentBy)?;
+
+        Ok(model::MempoolEntry {
+            vsize,
+            weight: None,
+            time,
+            height,
+            descendant_count,
+            descendant_size,
+            ancestor_count,
+            ancestor_size,
+            wtxid,
+            fees,
+            depends,
+            spent_by,
+            bip125_replaceable: self.bip125_replaceable,
+            unbroadcast: None,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetRawMempoolVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempoolVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetRawMempoolVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action `start`.
+///
+/// > scantxoutset "action" ( [scanobjects,...] )
+/// >
+/// > EXPERIMENTAL warning: this call may be removed or changed in future releases.
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+/// > 2. scanobjects                   (json array) Array of scan objects. Required for "start" action
+/// >                                  Every scan object is either a string descriptor or an object:
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// Whether the scan was completed.
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// The current block height (index).
+    pub height: i64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The unspent outputs found.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs in BTC.
+    pub total_amount: f64,
+}
+
+impl ScanTxOutSetStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetStart, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let unspents =
+            self.unspents.into_iter().map(|u| u.into_model()).collect::<Result<_, _>>()?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::ScanTxOutSetStart {
+            success: Some(self.success),
+            tx_outs: Some(self.tx_outs),
+            height: Some(height),
+            best_block: Some(best_block),
+            unspents,
+            total_amount,
+        })
+    }
+}
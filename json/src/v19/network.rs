@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.19.1 - network.
+//!
+//! Types for methods found under the `== Network ==` section of the API docs.
+
+use core::fmt;
+use std::collections::BTreeMap;
+
+use bitcoin::amount::ParseAmountError;
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `getpeerinfo`.
+///
+/// > getpeerinfo
+/// >
+/// > Returns data about each connected network node as a json array of objects.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+/// An item from the list returned by the JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u32,
+    /// The IP address and port of the peer ("host:port").
+    #[serde(rename = "addr")]
+    pub address: String,
+    /// Bind address of the connection to the peer ("ip:port").
+    #[serde(rename = "addrbind")]
+    pub address_bind: Option<String>,
+    /// Local address as reported by the peer.
+    #[serde(rename = "addrlocal")]
+    pub address_local: Option<String>,
+    /// The services offered.
+    pub services: String,
+    /// The services offered, in human-readable form.
+    #[serde(rename = "servicesnames")]
+    pub services_names: Option<Vec<String>>,
+    /// Whether peer has asked us to relay transactions to it.
+    #[serde(rename = "relaytxes")]
+    pub relay_transactions: bool,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send.
+    #[serde(rename = "lastsend")]
+    pub last_send: u32,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last receive.
+    #[serde(rename = "lastrecv")]
+    pub last_received: u32,
+    /// The total bytes sent.
+    #[serde(rename = "bytessent")]
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    #[serde(rename = "bytesrecv")]
+    pub bytes_received: u64,
+    /// The connection time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "conntime")]
+    pub connection_time: u32,
+    /// The time offset in seconds.
+    #[serde(rename = "timeoffset")]
+    pub time_offset: i64,
+    /// Ping time in seconds (if available).
+    #[serde(rename = "pingtime")]
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time in seconds (if any at all).
+    #[serde(rename = "minping")]
+    pub minimum_ping: Option<f64>,
+    /// Ping wait in seconds (if non-zero).
+    #[serde(rename = "pingwait")]
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version (e.g. "/Satoshi:0.8.5/").
+    #[serde(rename = "subver")]
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// Whether connection was due to addnode/-connect or if it was an automatic/inbound connection.
+    #[serde(rename = "addnode")]
+    pub add_node: bool,
+    /// The starting height (block) of the peer.
+    #[serde(rename = "startingheight")]
+    pub starting_height: i64,
+    /// The ban score.
+    #[serde(rename = "banscore")]
+    pub ban_score: u64,
+    /// The last header we have in common with this peer.
+    pub synced_headers: i64,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: i64,
+    /// The heights of blocks we're currently asking from this peer.
+    pub inflight: Vec<u32>,
+    /// Whether the peer is whitelisted (deprecated, use `permissions`).
+    pub whitelisted: Option<bool>,
+    /// Any special permissions that have been granted to this peer.
+    pub permissions: Vec<String>,
+    /// The minimum fee rate for transactions this peer accepts in BTC/kB.
+    #[serde(rename = "minfeefilter")]
+    pub minimum_fee_filter: f64,
+    /// The total bytes sent aggregated by message type.
+    #[serde(rename = "bytessent_per_msg")]
+    pub bytes_sent_per_message: BTreeMap<String, u64>,
+    /// The total bytes received aggregated by message type.
+    #[serde(rename = "bytesrecv_per_msg")]
+    pub bytes_received_per_message: BTreeMap<String, u64>,
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        let peers = self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetPeerInfo(peers))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        use PeerInfoError as E;
+
+        let minimum_fee_filter =
+            crate::btc_per_kb(self.minimum_fee_filter).map_err(E::MinimumFeeFilter)?;
+
+        Ok(model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            network: None,
+            mapped_as: None,
+            services: self.services,
+            services_names: self.services_names,
+            relay_transactions: Some(self.relay_transactions),
+            last_send: self.last_send,
+            last_received: self.last_received,
+            last_transaction: None,
+            last_block: None,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            bip152_hb_to: None,
+            bip152_hb_from: None,
+            add_node: Some(self.add_node),
+            starting_height: Some(self.starting_height),
+            ban_score: Some(self.ban_score),
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            addr_relay_enabled: None,
+            addr_processed: None,
+            addr_rate_limited: None,
+            permissions: self.permissions,
+            whitelisted: self.whitelisted,
+            minimum_fee_filter,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: None,
+            transport_protocol_type: None,
+            session_id: None,
+        })
+    }
+}
+
+/// Error when converting a `PeerInfo` type into the model type.
+#[derive(Debug)]
+pub enum PeerInfoError {
+    /// Conversion of the `minimum_fee_filter` field failed.
+    MinimumFeeFilter(ParseAmountError),
+}
+
+impl fmt::Display for PeerInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PeerInfoError as E;
+
+        match *self {
+            E::MinimumFeeFilter(ref e) =>
+                write_err!(f, "conversion of the `minimum_fee_filter` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PeerInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PeerInfoError as E;
+
+        match *self {
+            E::MinimumFeeFilter(ref e) => Some(e),
+        }
+    }
+}
@@ -5,10 +5,12 @@
 //! Types for methods found under the `== Wallet ==` section of the API docs.
 
 use bitcoin::amount::ParseAmountError;
+use bitcoin::hashes::hash160;
 use bitcoin::Amount;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::model;
+use crate::v17::GetWalletInfoError;
 
 /// Result of the JSON-RPC method `getbalances`.
 ///
@@ -81,3 +83,170 @@ impl GetBalancesWatchOnly {
         Ok(model::GetBalancesWatchOnly { trusted, untrusted_pending, immature })
     }
 }
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: i64,
+    /// DEPRECATED. The total confirmed balance of the wallet in BTC.
+    pub balance: f64,
+    /// DEPRECATED. The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: f64,
+    /// DEPRECATED. The total immature balance of the wallet in BTC.
+    pub immature_balance: f64,
+    /// The total number of transactions in the wallet.
+    #[serde(rename = "txcount")]
+    pub tx_count: i64,
+    /// The UNIX epoch time of the oldest pre-generated key in the key pool.
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: i64,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: i64,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    pub keypool_size_hd_internal: Option<i64>,
+    /// The UNIX epoch time until which the wallet is unlocked for transfers, or 0 if the wallet
+    /// is locked (only present for passphrase-encrypted wallets).
+    pub unlocked_until: Option<i64>,
+    /// The transaction fee configuration, set in BTC/kvB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse.
+    pub avoid_reuse: bool,
+    /// Current scanning details, or false if no scan is in progress.
+    pub scanning: GetWalletInfoScanning,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let wallet_version = crate::to_u32(self.wallet_version, "walletversion")?;
+        let balance = Some(Amount::from_btc(self.balance).map_err(E::Balance)?);
+        let unconfirmed_balance =
+            Some(Amount::from_btc(self.unconfirmed_balance).map_err(E::UnconfirmedBalance)?);
+        let immature_balance =
+            Some(Amount::from_btc(self.immature_balance).map_err(E::ImmatureBalance)?);
+        let keypool_oldest = Some(crate::to_u32(self.keypool_oldest, "keypoololdest")?);
+        let tx_count = crate::to_u32(self.tx_count, "txcount")?;
+        let keypool_size = crate::to_u32(self.keypool_size, "keypoolsize")?;
+        let keypool_size_hd_internal = self
+            .keypool_size_hd_internal
+            .map(|n| crate::to_u32(n, "keypoolsize_hd_internal"))
+            .transpose()?;
+        let unlocked_until =
+            self.unlocked_until.map(|t| crate::to_u32(t, "unlocked_until")).transpose()?;
+        let pay_tx_fee = crate::btc_per_kb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version,
+            format: None,
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count,
+            keypool_oldest,
+            keypool_size,
+            keypool_size_hd_internal,
+            unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            avoid_reuse: Some(self.avoid_reuse),
+            scanning: Some(self.scanning.into_model()),
+            descriptors: None,
+            external_signer: None,
+            blank: None,
+            birthtime: None,
+            last_processed_block: None,
+        })
+    }
+}
+
+/// The wallet scanning state, part of `getwalletinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum GetWalletInfoScanning {
+    /// Current scanning details.
+    Scanning {
+        /// Elapsed seconds since scan start.
+        duration: u64,
+        /// Scanning progress percentage [0.0, 1.0].
+        progress: f64,
+    },
+    /// Not scanning, Core returns `false` (`true` is never returned and is rejected).
+    #[serde(deserialize_with = "deserialize_false", serialize_with = "serialize_false")]
+    NotScanning,
+}
+
+impl GetWalletInfoScanning {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetWalletInfoScanning {
+        match self {
+            Self::Scanning { duration, progress } =>
+                model::GetWalletInfoScanning::Scanning { duration, progress },
+            Self::NotScanning => model::GetWalletInfoScanning::NotScanning,
+        }
+    }
+}
+
+/// Deserializes the JSON value `false`, any other value is an error.
+fn deserialize_false<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+    match bool::deserialize(deserializer)? {
+        false => Ok(()),
+        true => Err(de::Error::invalid_value(de::Unexpected::Bool(true), &"false")),
+    }
+}
+
+/// Serializes the JSON value `false`.
+fn serialize_false<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bool(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_wallet_info_scanning_not_scanning() {
+        let json: GetWalletInfoScanning = serde_json::from_str("false").unwrap();
+        assert_eq!(json, GetWalletInfoScanning::NotScanning);
+        assert_eq!(serde_json::to_string(&json).unwrap(), "false");
+        assert_eq!(json.into_model(), model::GetWalletInfoScanning::NotScanning);
+    }
+
+    #[test]
+    fn get_wallet_info_scanning_rejects_true() {
+        assert!(serde_json::from_str::<GetWalletInfoScanning>("true").is_err());
+    }
+
+    #[test]
+    fn get_wallet_info_scanning_scanning() {
+        let json: GetWalletInfoScanning =
+            serde_json::from_str(r#"{"duration": 12, "progress": 0.25}"#).unwrap();
+        assert_eq!(json, GetWalletInfoScanning::Scanning { duration: 12, progress: 0.25 });
+        assert_eq!(
+            json.into_model(),
+            model::GetWalletInfoScanning::Scanning { duration: 12, progress: 0.25 }
+        );
+    }
+}
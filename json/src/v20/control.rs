@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.20.2 - control.
+//!
+//! Types for methods found under the `== Control ==` section of the API docs.
+
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::model::LogCategory;
+
+/// Result of JSON-RPC method `logging`.
+///
+/// > logging ( ["include_category",...] ["exclude_category",...] )
+/// >
+/// > Gets and sets the logging configuration.
+/// > When called without an argument, returns the list of categories with status that are currently being debug logged or not.
+/// > When called with arguments, adds or removes categories from debug logging and return the lists above.
+/// > The arguments are evaluated in order "include", "exclude".
+/// > If an item is both included and excluded, it will thus end up being excluded.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Logging {
+    pub net: bool,
+    pub tor: bool,
+    pub mempool: bool,
+    pub http: bool,
+    pub bench: bool,
+    pub zmq: bool,
+    pub walletdb: bool,
+    pub rpc: bool,
+    pub estimatefee: bool,
+    pub addrman: bool,
+    pub selectcoins: bool,
+    pub reindex: bool,
+    pub cmpctblock: bool,
+    pub rand: bool,
+    pub prune: bool,
+    pub proxy: bool,
+    pub mempoolrej: bool,
+    pub libevent: bool,
+    pub coindb: bool,
+    pub qt: bool,
+    pub leveldb: bool,
+    pub validation: bool,
+}
+
+impl Logging {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        use LogCategory as C;
+
+        let categories = [
+            (C::Net, self.net),
+            (C::Tor, self.tor),
+            (C::Mempool, self.mempool),
+            (C::Http, self.http),
+            (C::Bench, self.bench),
+            (C::Zmq, self.zmq),
+            (C::WalletDb, self.walletdb),
+            (C::Rpc, self.rpc),
+            (C::EstimateFee, self.estimatefee),
+            (C::AddrMan, self.addrman),
+            (C::SelectCoins, self.selectcoins),
+            (C::Reindex, self.reindex),
+            (C::CmpctBlock, self.cmpctblock),
+            (C::Rand, self.rand),
+            (C::Prune, self.prune),
+            (C::Proxy, self.proxy),
+            (C::MempoolRej, self.mempoolrej),
+            (C::Libevent, self.libevent),
+            (C::CoinDb, self.coindb),
+            (C::Qt, self.qt),
+            (C::LevelDb, self.leveldb),
+            (C::Validation, self.validation),
+        ];
+        model::Logging(categories.into_iter().collect())
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.20.2 - util.
+//!
+//! Types for methods found under the `== Util ==` section of the API docs.
+
+use bitcoin::{Address, ScriptBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::CreateMultisigError;
+
+/// Result of JSON-RPC method `createmultisig`.
+///
+/// > createmultisig nrequired ["key",...] ( "address_type" )
+/// >
+/// > Creates a multi-signature address with n signature of m keys required.
+/// > It returns a json object with the address and redeemScript.
+/// >
+/// > Arguments:
+/// > 1. nrequired       (numeric, required) The number of required signatures out of the n keys.
+/// > 2. keys            (json array, required) The hex-encoded public keys.
+/// > 3. address_type    (string, optional, default=legacy) The address type to use. Options are "legacy", "p2sh-segwit", and "bech32".
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateMultisig {
+    /// The value of the new multisig address.
+    pub address: String,
+    /// The string value of the hex-encoded redemption script.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: String,
+    /// The descriptor for this multisig.
+    pub descriptor: String,
+}
+
+impl CreateMultisig {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CreateMultisig, CreateMultisigError> {
+        use CreateMultisigError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let redeem_script = ScriptBuf::from_hex(&self.redeem_script).map_err(E::RedeemScript)?;
+
+        Ok(model::CreateMultisig {
+            address,
+            redeem_script,
+            descriptor: Some(self.descriptor),
+            warnings: vec![],
+        })
+    }
+}
@@ -0,0 +1,344 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.20.2 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::bip32::{DerivationPath, Fingerprint};
+use bitcoin::hashes::hash160;
+use bitcoin::{Address, BlockHash, PublicKey, ScriptBuf, SignedAmount, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{
+    witness_program, GetAddressInfoEmbedded, GetAddressInfoError, GetTransactionDetailCategory,
+    ListSinceBlockError, TransactionItemError,
+};
+
+/// Result of the JSON-RPC method `getaddressinfo`.
+///
+/// > getaddressinfo "address"
+/// >
+/// > Return information about the given bitcoin address. Some information requires the address
+/// > to be in the wallet.
+/// >
+/// > Arguments:
+/// > 1. address    (string, required) The bitcoin address to get the information of.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfo {
+    /// The bitcoin address validated.
+    pub address: String,
+    /// The hex encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// If the address is yours or not.
+    #[serde(rename = "ismine")]
+    pub is_mine: bool,
+    /// If the address is watchonly.
+    #[serde(rename = "iswatchonly")]
+    pub is_watch_only: bool,
+    /// Whether we know how to spend coins sent to this address, ignoring the possible lack of
+    /// private keys.
+    pub solvable: bool,
+    /// A descriptor for spending coins sent to this address (only when solvable).
+    pub desc: Option<String>,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: bool,
+    /// If the address was used for change output.
+    #[serde(rename = "ischange")]
+    pub is_change: bool,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: bool,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+    /// The output script type.
+    ///
+    /// Only if "isscript" is true and the redeemscript is known.
+    pub script: Option<String>,
+    /// The redeemscript for the p2sh address.
+    pub hex: Option<String>,
+    /// Array of pubkeys associated with the known redeemscript (only if "script" is "multisig").
+    pub pubkeys: Option<Vec<String>>,
+    /// Number of signatures required to spend multisig output (only if "script" is "multisig").
+    #[serde(rename = "sigsrequired")]
+    pub sigs_required: Option<i64>,
+    /// The hex value of the raw public key, for single-key addresses (possibly embedded in P2SH or
+    /// P2WSH).
+    pub pubkey: Option<String>,
+    /// Information about the address embedded in P2SH or P2WSH, if relevant and known.
+    pub embedded: Option<GetAddressInfoEmbedded>,
+    /// If the address is compressed.
+    #[serde(rename = "iscompressed")]
+    pub is_compressed: Option<bool>,
+    /// DEPRECATED. The label associated with the address. Defaults to "". Replaced by the labels
+    /// array below.
+    pub label: Option<String>,
+    /// The creation time of the key if available in seconds since epoch (Jan 1 1970 GMT).
+    pub timestamp: Option<i64>,
+    /// The HD keypath if the key is HD and available.
+    #[serde(rename = "hdkeypath")]
+    pub hd_key_path: Option<String>,
+    /// The Hash160 of the HD seed.
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// The fingperprint of the master key.
+    #[serde(rename = "hdmasterfingerprint")]
+    pub hd_master_fingerprint: Option<String>,
+    /// Array of labels associated with the address. Currently limited to one label but returned
+    /// as an array to keep the API stable if multiple labels are enabled in the future.
+    pub labels: Vec<String>,
+}
+
+impl GetAddressInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddressInfo, GetAddressInfoError> {
+        use GetAddressInfoError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let witness_program = witness_program(self.witness_version, self.witness_program)
+            .map_err(E::WitnessProgram)?;
+        let hex = self.hex.map(|s| ScriptBuf::from_hex(&s)).transpose().map_err(E::Hex)?;
+        let pubkeys = self
+            .pubkeys
+            .map(|keys| keys.iter().map(|s| s.parse::<PublicKey>()).collect::<Result<Vec<_>, _>>())
+            .transpose()
+            .map_err(E::Pubkeys)?;
+        let sigs_required =
+            self.sigs_required.map(|n| crate::to_u32(n, "sigsrequired")).transpose()?;
+        let pubkey = self.pubkey.map(|s| s.parse::<PublicKey>()).transpose().map_err(E::Pubkey)?;
+        let embedded = self.embedded.map(|e| e.into_model()).transpose().map_err(E::Embedded)?;
+        let timestamp = self.timestamp.map(|t| crate::to_u32(t, "timestamp")).transpose()?;
+        let hd_key_path = self
+            .hd_key_path
+            .map(|s| s.parse::<DerivationPath>())
+            .transpose()
+            .map_err(E::HdKeyPath)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+        let hd_master_fingerprint = self
+            .hd_master_fingerprint
+            .map(|s| s.parse::<Fingerprint>())
+            .transpose()
+            .map_err(E::HdMasterFingerprint)?;
+
+        Ok(model::GetAddressInfo {
+            address,
+            script_pubkey,
+            is_mine: self.is_mine,
+            is_watch_only: self.is_watch_only,
+            solvable: Some(self.solvable),
+            descriptor: self.desc,
+            is_script: self.is_script,
+            is_change: Some(self.is_change),
+            is_witness: self.is_witness,
+            witness_program,
+            script: self.script,
+            hex,
+            pubkeys,
+            sigs_required,
+            pubkey,
+            embedded,
+            is_compressed: self.is_compressed,
+            timestamp,
+            hd_key_path,
+            hd_seed_id,
+            hd_master_fingerprint,
+            labels: self.labels,
+        })
+    }
+}
+
+/// Result of the JSON-RPC method `listsinceblock`.
+///
+/// > listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )
+/// >
+/// > Get all transactions in blocks since block [blockhash], or all transactions if omitted.
+/// > If "blockhash" is no longer a part of the main chain, transactions from the fork point onward are included.
+/// > Additionally, if include_removed is set, transactions affecting the wallet which were removed are returned in the "removed" array.
+/// >
+/// > Arguments:
+/// > 1. blockhash               (string, optional) If set, the block hash to list transactions since, otherwise list all transactions.
+/// > 2. target_confirmations    (numeric, optional, default=1) Return the nth block hash from the main chain. e.g. 1 would mean the best block hash. Note: this is not used as a filter, but only affects [lastblock] in the return value
+/// > 3. include_watchonly       (boolean, optional, default=true for watch-only wallets, otherwise false) Include transactions to watch-only addresses (see 'importaddress')
+/// > 4. include_removed         (boolean, optional, default=true) Show transactions that were removed due to a reorg in the "removed" array
+/// >                            (not guaranteed to work on pruned nodes)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListSinceBlock {
+    /// All the transactions.
+    pub transactions: Vec<TransactionItem>,
+    /// Transactions that were removed due to a reorg, only present if `include_removed` is set.
+    #[serde(default)]
+    pub removed: Vec<TransactionItem>,
+    /// The hash of the block (target_confirmations-1) from the best block on the main chain.
+    #[serde(rename = "lastblock")]
+    pub last_block: String,
+}
+
+impl ListSinceBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListSinceBlock, ListSinceBlockError> {
+        use ListSinceBlockError as E;
+
+        let transactions = self
+            .transactions
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Transactions)?;
+        let removed = self
+            .removed
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Removed)?;
+        let last_block = self.last_block.parse::<BlockHash>().map_err(E::LastBlock)?;
+
+        Ok(model::ListSinceBlock { transactions, removed, last_block })
+    }
+}
+
+/// Result of the JSON-RPC method `listtransactions`.
+///
+/// > listtransactions ( "label" count skip include_watchonly )
+/// >
+/// > If a label name is provided, this will return only incoming transactions paying to addresses with the specified label.
+/// >
+/// > Returns up to 'count' most recent transactions skipping the first 'from' transactions.
+/// >
+/// > Arguments:
+/// > 1. label                (string, optional) If set, should be a valid label name to return only incoming transactions
+/// >                         with the specified label, or "*" to disable filtering and return all transactions.
+/// > 2. count                (numeric, optional, default=10) The number of transactions to return
+/// > 3. skip                 (numeric, optional, default=0) The number of transactions to skip
+/// > 4. include_watchonly    (boolean, optional, default=true for watch-only wallets, otherwise false) Include transactions to watch-only addresses (see 'importaddress')
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListTransactions(pub Vec<TransactionItem>);
+
+impl ListTransactions {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListTransactions, TransactionItemError> {
+        let transactions =
+            self.0.into_iter().map(|tx| tx.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListTransactions(transactions))
+    }
+}
+
+/// A wallet transaction, an item returned by `listtransactions` and `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TransactionItem {
+    /// Only returns true if imported addresses were involved in transaction.
+    #[serde(rename = "involvesWatchonly")]
+    pub involves_watch_only: Option<bool>,
+    /// The bitcoin address of the transaction.
+    pub address: Option<String>,
+    /// The transaction category.
+    pub category: GetTransactionDetailCategory,
+    /// The amount in BTC, negative for the `send` category.
+    pub amount: f64,
+    /// A comment for the address/transaction, if any.
+    pub label: Option<String>,
+    /// The vout value.
+    pub vout: u32,
+    /// The amount of the fee in BTC, negative and only available for the `send` category.
+    pub fee: Option<f64>,
+    /// The number of confirmations for the transaction.
+    ///
+    /// Negative confirmations indicate the transaction conflicts with the block chain.
+    pub confirmations: i64,
+    /// Only present if transaction only input is a coinbase one.
+    pub generated: Option<bool>,
+    /// Only present if we consider the transaction to be trusted and safe to spend from.
+    pub trusted: Option<bool>,
+    /// The block hash containing the transaction.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The block height containing the transaction.
+    #[serde(rename = "blockheight")]
+    pub block_height: Option<i64>,
+    /// The index of the transaction in the block that includes it.
+    #[serde(rename = "blockindex")]
+    pub block_index: Option<i64>,
+    /// The block time in seconds since epoch (1 Jan 1970 GMT).
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<u64>,
+    /// The transaction id.
+    pub txid: String,
+    /// Conflicting transaction ids.
+    #[serde(rename = "walletconflicts")]
+    pub wallet_conflicts: Vec<String>,
+    /// The transaction time in seconds since epoch (midnight Jan 1 1970 GMT).
+    pub time: u64,
+    /// The time received in seconds since epoch (midnight Jan 1 1970 GMT).
+    #[serde(rename = "timereceived")]
+    pub time_received: u64,
+    /// If a comment is associated with the transaction.
+    pub comment: Option<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee).
+    ///
+    /// May be unknown for unconfirmed transactions not in the mempool.
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: String,
+    /// Only available for the `send` category of transactions.
+    ///
+    /// True if the transaction has been abandoned (inputs are respendable).
+    pub abandoned: Option<bool>,
+}
+
+impl TransactionItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TransactionItem, TransactionItemError> {
+        use TransactionItemError as E;
+
+        let address = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Address)?;
+        let amount = SignedAmount::from_btc(self.amount).map_err(E::Amount)?;
+        let fee = self.fee.map(SignedAmount::from_btc).transpose().map_err(E::Fee)?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+        let block_height =
+            self.block_height.map(|h| crate::to_u32(h, "blockheight")).transpose()?;
+        let block_index = self.block_index.map(|i| crate::to_u32(i, "blockindex")).transpose()?;
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wallet_conflicts = self
+            .wallet_conflicts
+            .iter()
+            .map(|s| s.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::WalletConflicts)?;
+
+        Ok(model::TransactionItem {
+            involves_watch_only: self.involves_watch_only,
+            address,
+            category: self.category.into_model(),
+            amount,
+            label: self.label,
+            vout: self.vout,
+            fee,
+            confirmations: self.confirmations,
+            generated: self.generated,
+            trusted: self.trusted,
+            block_hash,
+            block_height,
+            block_index,
+            block_time: self.block_time,
+            txid,
+            wtxid: None,
+            wallet_conflicts,
+            time: self.time,
+            time_received: self.time_received,
+            comment: self.comment,
+            bip125_replaceable: self.bip125_replaceable,
+            parent_descriptors: None,
+            abandoned: self.abandoned,
+        })
+    }
+}
@@ -0,0 +1,314 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use bitcoin::{hex, Amount, BlockHash, Txid, Weight, Wtxid};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{
+    mempool_entries, GetMempoolInfoError, GetTxOutSetInfoError, MapMempoolEntryError,
+    MempoolEntryError, MempoolEntryFees,
+};
+
+/// Result of JSON-RPC method `getmempoolancestors` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry` i.e., an ancestor.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolAncestorsVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetMempoolAncestorsVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolAncestorsVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetMempoolAncestorsVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempooldescendants` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry` i.e., a descendant.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolDescendantsVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetMempoolDescendantsVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolDescendantsVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetMempoolDescendantsVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolentry`.
+///
+/// > getmempoolentry "txid"
+/// >
+/// > Returns mempool data for given transaction
+/// >
+/// > Arguments:
+/// > 1. txid    (string, required) The transaction id (must be in mempool)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+impl GetMempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolEntry, MempoolEntryError> {
+        Ok(model::GetMempoolEntry(self.0.into_model()?))
+    }
+}
+
+/// A relative (ancestor or descendant) transaction of a transaction in the mempool.
+///
+/// Part of `getmempoolancestors`, `getmempooldescendants`, `getmempoolentry` and `getrawmempool`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    ///
+    /// This is different from actual serialized size for witness transactions as witness data is discounted.
+    pub vsize: i64,
+    /// Transaction weight as defined in BIP 141.
+    pub weight: i64,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: i64,
+    /// Block height when transaction entered pool.
+    pub height: i64,
+    /// Number of in-mempool descendant transactions (including this one).
+    #[serde(rename = "descendantcount")]
+    pub descendant_count: i64,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    #[serde(rename = "descendantsize")]
+    pub descendant_size: i64,
+    /// Number of in-mempool ancestor transactions (including this one).
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: i64,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: i64,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: String,
+    /// Fee object which contains the base fee, modified fee (with fee deltas), and
+    /// ancestor/descendant fee totals all in BTC.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction (parent transaction id).
+    pub depends: Vec<String>,
+    /// Unconfirmed transactions spending outputs from this transaction (child transaction id).
+    #[serde(rename = "spentby")]
+    pub spent_by: Vec<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee)
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: bool,
+    /// Whether this transaction is currently unbroadcast (initial broadcast not yet acknowledged by any peers).
+    pub unbroadcast: bool,
+}
+
+impl MempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntry, MempoolEntryError> {
+        use MempoolEntryError as E;
+
+        let vsize = crate::to_u32(self.vsize, "vsize")?;
+        let weight = crate::to_u32(self.weight, "weight")?;
+        let time = crate::to_u32(self.time, "time")?;
+        let height = crate::to_u32(self.height, "height")?;
+        let descendant_count = crate::to_u32(self.descendant_count, "descendant_count")?;
+        let descendant_size = crate::to_u32(self.descendant_size, "descendant_size")?;
+        let ancestor_count = crate::to_u32(self.ancestor_count, "ancestor_count")?;
+        let ancestor_size = crate::to_u32(self.ancestor_size, "ancestor_size")?;
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let fees = self.fees.into_model().map_err(E::Fees)?;
+        let depends = self
+            .depends
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::Depends)?;
+        let spent_by = self
+            .spent_by
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::SpentBy)?;
+
+        Ok(model::MempoolEntry {
+            vsize,
+            weight: Some(Weight::from_wu(u64::from(weight))),
+            time,
+            height,
+            descendant_count,
+            descendant_size,
+            ancestor_count,
+            ancestor_size,
+            wtxid,
+            fees,
+            depends,
+            spent_by,
+            bip125_replaceable: self.bip125_replaceable,
+            unbroadcast: Some(self.unbroadcast),
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to true.
+///
+/// Map of txid to `MempoolEntry`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetRawMempoolVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempoolVerbose, MapMempoolEntryError> {
+        let map = mempool_entries(self.0, MempoolEntry::into_model)?;
+        Ok(model::GetRawMempoolVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolinfo`.
+///
+/// > getmempoolinfo
+/// >
+/// > Returns details on the active state of the TX memory pool.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolInfo {
+    /// True if the mempool is fully loaded.
+    pub loaded: bool,
+    /// Current tx count.
+    pub size: i64,
+    /// Sum of all virtual transaction sizes as defined in BIP 141.
+    ///
+    /// Differs from actual serialized size because witness data is discounted.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Maximum memory usage for the mempool.
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate in BTC/kB for tx to be accepted.
+    ///
+    /// Is the maximum of minrelaytxfee and minimum mempool fee.
+    #[serde(rename = "mempoolminfee")]
+    pub mempool_min_fee: f64,
+    /// Current minimum relay fee for transactions.
+    #[serde(rename = "minrelaytxfee")]
+    pub min_relay_tx_fee: f64,
+    /// Current number of transactions that haven't passed initial broadcast yet.
+    #[serde(rename = "unbroadcastcount")]
+    pub unbroadcast_count: i64,
+}
+
+impl GetMempoolInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolInfo, GetMempoolInfoError> {
+        use GetMempoolInfoError as E;
+
+        let size = crate::to_u32(self.size, "size")?;
+        let mempool_min_fee = crate::btc_per_kb(self.mempool_min_fee).map_err(E::MempoolMinFee)?;
+        let min_relay_tx_fee =
+            crate::btc_per_kb(self.min_relay_tx_fee).map_err(E::MinRelayTxFee)?;
+        let unbroadcast_count = crate::to_u32(self.unbroadcast_count, "unbroadcast_count")?;
+
+        Ok(model::GetMempoolInfo {
+            loaded: Some(self.loaded),
+            size,
+            bytes: self.bytes,
+            usage: self.usage,
+            total_fee: None,
+            max_mempool: self.max_mempool,
+            mempool_min_fee,
+            min_relay_tx_fee,
+            incremental_relay_fee: None,
+            unbroadcast_count: Some(unbroadcast_count),
+            full_rbf: None,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to false and `mempool_sequence`
+/// set to true.
+///
+/// > getrawmempool ( verbose mempool_sequence )
+/// >
+/// > Returns all transaction ids in memory pool as a json array of string transaction ids.
+/// >
+/// > Hint: use getmempoolentry to fetch a specific transaction from the mempool.
+/// >
+/// > Arguments:
+/// > 1. verbose             (boolean, optional, default=false) True for a json object, false for array of transaction ids
+/// > 2. mempool_sequence    (boolean, optional, default=false) If verbose=false, returns a json object with transaction list and mempool sequence number attached.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawMempoolSequence {
+    /// The transaction ids in the mempool.
+    pub txids: Vec<String>,
+    /// The mempool sequence value.
+    pub mempool_sequence: u64,
+}
+
+impl GetRawMempoolSequence {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempoolSequence, hex::HexToArrayError> {
+        let txids = self.txids.iter().map(|t| t.parse::<Txid>()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::GetRawMempoolSequence { txids, mempool_sequence: self.mempool_sequence })
+    }
+}
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time.
+/// >
+/// > Arguments:
+/// > 1. hash_type    (string, optional, default=hash_serialized_2) Which UTXO set hash should be calculated. Options: 'hash_serialized_2' (the legacy algorithm), 'muhash', 'none'.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The current block height (index).
+    pub height: i64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of transactions with unspent outputs.
+    pub transactions: u64,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// A meaningless metric for UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash (only present if 'hash_serialized_2' hash_type is chosen).
+    pub hash_serialized_2: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The estimated size of the chainstate on disk.
+    pub disk_size: u64,
+    /// The total amount.
+    pub total_amount: f64,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height,
+            best_block,
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: self.hash_serialized_2,
+            muhash: self.muhash,
+            transactions: Some(self.transactions),
+            disk_size: Some(self.disk_size),
+            total_amount,
+            total_unspendable_amount: None,
+            block_info: None,
+        })
+    }
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - network.
+//!
+//! Types for methods found under the `== Network ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v19::PeerInfoError;
+
+/// Result of JSON-RPC method `getpeerinfo`.
+///
+/// > getpeerinfo
+/// >
+/// > Returns data about each connected network node as a json array of objects.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+/// An item from the list returned by the JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u32,
+    /// The IP address and port of the peer ("host:port").
+    #[serde(rename = "addr")]
+    pub address: String,
+    /// Bind address of the connection to the peer ("ip:port").
+    #[serde(rename = "addrbind")]
+    pub address_bind: Option<String>,
+    /// Local address as reported by the peer.
+    #[serde(rename = "addrlocal")]
+    pub address_local: Option<String>,
+    /// Network (ipv4, ipv6, onion, i2p, cjdns, not_publicly_routable).
+    pub network: String,
+    /// The AS in the BGP route to the peer used for diversifying peer selection (only available
+    /// if the asmap config flag is set).
+    pub mapped_as: Option<u32>,
+    /// The services offered.
+    pub services: String,
+    /// The services offered, in human-readable form.
+    #[serde(rename = "servicesnames")]
+    pub services_names: Vec<String>,
+    /// Whether peer has asked us to relay transactions to it.
+    #[serde(rename = "relaytxes")]
+    pub relay_transactions: Option<bool>,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send.
+    #[serde(rename = "lastsend")]
+    pub last_send: u32,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last receive.
+    #[serde(rename = "lastrecv")]
+    pub last_received: u32,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last valid transaction received
+    /// from this peer.
+    pub last_transaction: u32,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last block received from this
+    /// peer.
+    pub last_block: u32,
+    /// The total bytes sent.
+    #[serde(rename = "bytessent")]
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    #[serde(rename = "bytesrecv")]
+    pub bytes_received: u64,
+    /// The connection time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "conntime")]
+    pub connection_time: u32,
+    /// The time offset in seconds.
+    #[serde(rename = "timeoffset")]
+    pub time_offset: i64,
+    /// Ping time in seconds (if available).
+    #[serde(rename = "pingtime")]
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time in seconds (if any at all).
+    #[serde(rename = "minping")]
+    pub minimum_ping: Option<f64>,
+    /// Ping wait in seconds (if non-zero).
+    #[serde(rename = "pingwait")]
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version (e.g. "/Satoshi:0.8.5/").
+    #[serde(rename = "subver")]
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// Whether we selected peer as (compact blocks) high-bandwidth peer.
+    pub bip152_hb_to: Option<bool>,
+    /// Whether peer selected us as (compact blocks) high-bandwidth peer.
+    pub bip152_hb_from: Option<bool>,
+    /// The starting height (block) of the peer.
+    #[serde(rename = "startingheight")]
+    pub starting_height: Option<i64>,
+    /// The ban score (deprecated, removed in v22).
+    #[serde(rename = "banscore")]
+    pub ban_score: Option<u64>,
+    /// The last header we have in common with this peer.
+    pub synced_headers: i64,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: i64,
+    /// The heights of blocks we're currently asking from this peer.
+    pub inflight: Vec<u32>,
+    /// Whether we participate in address relay with this peer.
+    pub addr_relay_enabled: Option<bool>,
+    /// The total number of addresses processed, excluding those dropped due to rate limiting.
+    pub addr_processed: Option<u64>,
+    /// The total number of addresses dropped due to rate limiting.
+    pub addr_rate_limited: Option<u64>,
+    /// Any special permissions that have been granted to this peer.
+    pub permissions: Vec<String>,
+    /// The minimum fee rate for transactions this peer accepts in BTC/kB.
+    #[serde(rename = "minfeefilter")]
+    pub minimum_fee_filter: f64,
+    /// The total bytes sent aggregated by message type.
+    #[serde(rename = "bytessent_per_msg")]
+    pub bytes_sent_per_message: BTreeMap<String, u64>,
+    /// The total bytes received aggregated by message type.
+    #[serde(rename = "bytesrecv_per_msg")]
+    pub bytes_received_per_message: BTreeMap<String, u64>,
+    /// Type of connection e.g., "outbound-full-relay", "inbound", "manual".
+    pub connection_type: String,
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, PeerInfoError> {
+        let peers = self.0.into_iter().map(|p| p.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetPeerInfo(peers))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, PeerInfoError> {
+        use PeerInfoError as E;
+
+        let minimum_fee_filter =
+            crate::btc_per_kb(self.minimum_fee_filter).map_err(E::MinimumFeeFilter)?;
+
+        Ok(model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            network: Some(self.network),
+            mapped_as: self.mapped_as,
+            services: self.services,
+            services_names: Some(self.services_names),
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            last_transaction: Some(self.last_transaction),
+            last_block: Some(self.last_block),
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            bip152_hb_to: self.bip152_hb_to,
+            bip152_hb_from: self.bip152_hb_from,
+            add_node: None,
+            starting_height: self.starting_height,
+            ban_score: self.ban_score,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            inflight: self.inflight,
+            addr_relay_enabled: self.addr_relay_enabled,
+            addr_processed: self.addr_processed,
+            addr_rate_limited: self.addr_rate_limited,
+            permissions: self.permissions,
+            whitelisted: None,
+            minimum_fee_filter,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: Some(self.connection_type),
+            transport_protocol_type: None,
+            session_id: None,
+        })
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::{amount, hex, Amount, Txid};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::reject_reason;
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `testmempoolaccept`.
+///
+/// > testmempoolaccept ["rawtx",...] ( maxfeerate )
+/// >
+/// > Returns result of mempool acceptance tests indicating if raw transaction (serialized, hex-encoded) would be accepted by mempool.
+/// >
+/// > This checks if the transaction violates the consensus or policy rules.
+/// >
+/// > See sendrawtransaction call.
+/// >
+/// > Arguments:
+/// > 1. rawtxs       (json array, required) An array of hex strings of raw transactions.
+/// >                 Length must be one for now.
+/// > 2. maxfeerate    (numeric or string, optional, default=0.10) Reject transactions whose fee rate is higher than the specified value, expressed in BTC/kB
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
+
+impl TestMempoolAccept {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TestMempoolAccept, TestMempoolAcceptError> {
+        let results = self.0.into_iter().map(|r| r.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::TestMempoolAccept { results })
+    }
+}
+
+/// A single mempool acceptance test result. Part of `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptance {
+    /// The transaction hash in hex.
+    pub txid: String,
+    /// If the mempool allows this tx to be inserted.
+    pub allowed: bool,
+    /// Virtual transaction size as defined in BIP 141 (only present when 'allowed' is true).
+    pub vsize: Option<i64>,
+    /// Transaction fees (only present if 'allowed' is true).
+    pub fees: Option<MempoolAcceptanceFees>,
+    /// Rejection string (only present when 'allowed' is false).
+    #[serde(rename = "reject-reason")]
+    pub reject_reason: Option<String>,
+}
+
+impl MempoolAcceptance {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolAcceptance, TestMempoolAcceptError> {
+        use TestMempoolAcceptError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let vsize = self.vsize.map(|v| crate::to_u32(v, "vsize")).transpose()?;
+        let fees = self.fees.map(|f| f.into_model()).transpose().map_err(E::Base)?;
+
+        Ok(model::MempoolAcceptance {
+            txid,
+            wtxid: None,
+            package_error: None,
+            allowed: self.allowed,
+            vsize,
+            fees,
+            reject_reason: self.reject_reason.as_deref().map(reject_reason),
+            reject_message: self.reject_reason,
+        })
+    }
+}
+
+/// Transaction fees. Part of `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptanceFees {
+    /// Transaction fee in BTC.
+    pub base: f64,
+}
+
+impl MempoolAcceptanceFees {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolAcceptanceFees, amount::ParseAmountError> {
+        let base = Amount::from_btc(self.base)?;
+        Ok(model::MempoolAcceptanceFees {
+            base,
+            effective_fee_rate: None,
+            effective_includes: None,
+        })
+    }
+}
+
+/// Error when converting a `TestMempoolAccept` type into the model type.
+#[derive(Debug)]
+pub enum TestMempoolAcceptError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `base` fee field failed.
+    Base(amount::ParseAmountError),
+}
+
+impl fmt::Display for TestMempoolAcceptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TestMempoolAcceptError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Base(ref e) => write_err!(f, "conversion of the `base` fee field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TestMempoolAcceptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TestMempoolAcceptError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::Base(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for TestMempoolAcceptError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
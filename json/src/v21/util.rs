@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - util.
+//!
+//! Types for methods found under the `== Util ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `getindexinfo`.
+///
+/// > getindexinfo ( "index_name" )
+/// >
+/// > Returns the status of one or all available indices currently running in the node.
+/// >
+/// > Arguments:
+/// > 1. index_name    (string, optional) Filter results for an index with a specific name.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetIndexInfo(pub BTreeMap<String, IndexInfo>);
+
+impl GetIndexInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetIndexInfo, NumericError> {
+        let map = self
+            .0
+            .into_iter()
+            .map(|(name, info)| Ok((name, info.into_model()?)))
+            .collect::<Result<_, NumericError>>()?;
+        Ok(model::GetIndexInfo(map))
+    }
+}
+
+/// The status of a single index, part of `getindexinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IndexInfo {
+    /// Whether the index is synced or not.
+    pub synced: bool,
+    /// The block height to which the index is synced.
+    pub best_block_height: i64,
+}
+
+impl IndexInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::IndexInfo, NumericError> {
+        let best_block_height = crate::to_u32(self.best_block_height, "best_block_height")?;
+        Ok(model::IndexInfo { synced: self.synced, best_block_height })
+    }
+}
@@ -0,0 +1,368 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::amount::ParseAmountError;
+use bitcoin::consensus::encode;
+use bitcoin::hashes::hash160;
+use bitcoin::psbt::{Psbt, PsbtParseError};
+use bitcoin::{hex, Amount, Transaction, Txid};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{GetWalletInfoError, JsonRpcError};
+use crate::v19::GetWalletInfoScanning;
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: i64,
+    /// The database format (bdb or sqlite).
+    pub format: String,
+    /// DEPRECATED. The total confirmed balance of the wallet in BTC.
+    pub balance: Option<f64>,
+    /// DEPRECATED. The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: Option<f64>,
+    /// DEPRECATED. The total immature balance of the wallet in BTC.
+    pub immature_balance: Option<f64>,
+    /// The total number of transactions in the wallet.
+    #[serde(rename = "txcount")]
+    pub tx_count: i64,
+    /// The UNIX epoch time of the oldest pre-generated key in the key pool (legacy wallet only).
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: Option<i64>,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: i64,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    pub keypool_size_hd_internal: Option<i64>,
+    /// The UNIX epoch time until which the wallet is unlocked for transfers, or 0 if the wallet
+    /// is locked (only present for passphrase-encrypted wallets).
+    pub unlocked_until: Option<i64>,
+    /// The transaction fee configuration, set in BTC/kvB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse.
+    pub avoid_reuse: bool,
+    /// Current scanning details, or false if no scan is in progress.
+    pub scanning: GetWalletInfoScanning,
+    /// Whether this wallet uses descriptors for scriptPubKey management.
+    pub descriptors: bool,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let wallet_version = crate::to_u32(self.wallet_version, "walletversion")?;
+        let balance = self.balance.map(Amount::from_btc).transpose().map_err(E::Balance)?;
+        let unconfirmed_balance = self
+            .unconfirmed_balance
+            .map(Amount::from_btc)
+            .transpose()
+            .map_err(E::UnconfirmedBalance)?;
+        let immature_balance =
+            self.immature_balance.map(Amount::from_btc).transpose().map_err(E::ImmatureBalance)?;
+        let keypool_oldest =
+            self.keypool_oldest.map(|t| crate::to_u32(t, "keypoololdest")).transpose()?;
+        let tx_count = crate::to_u32(self.tx_count, "txcount")?;
+        let keypool_size = crate::to_u32(self.keypool_size, "keypoolsize")?;
+        let keypool_size_hd_internal = self
+            .keypool_size_hd_internal
+            .map(|n| crate::to_u32(n, "keypoolsize_hd_internal"))
+            .transpose()?;
+        let unlocked_until =
+            self.unlocked_until.map(|t| crate::to_u32(t, "unlocked_until")).transpose()?;
+        let pay_tx_fee = crate::btc_per_kb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version,
+            format: Some(self.format),
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count,
+            keypool_oldest,
+            keypool_size,
+            keypool_size_hd_internal,
+            unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            avoid_reuse: Some(self.avoid_reuse),
+            scanning: Some(self.scanning.into_model()),
+            descriptors: Some(self.descriptors),
+            external_signer: None,
+            blank: None,
+            birthtime: None,
+            last_processed_block: None,
+        })
+    }
+}
+
+/// Result of the JSON-RPC method `importdescriptors`.
+///
+/// > importdescriptors "requests"
+/// >
+/// > Import descriptors. This will trigger a rescan of the blockchain based on the earliest timestamp of all descriptors being imported. Requires a new wallet backup.
+/// >
+/// > Note: This call can take over an hour to complete if using an early timestamp; during that time, other rpc calls
+/// > may report that the imported keys, addresses or scripts exist but related transactions are still missing.
+/// >
+/// > Arguments:
+/// > 1. requests    (json array, required) Data to be imported
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptors(pub Vec<ImportDescriptorsResult>);
+
+impl ImportDescriptors {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptors {
+        model::ImportDescriptors(self.0.into_iter().map(|r| r.into_model()).collect())
+    }
+}
+
+/// The result of importing a single descriptor. Part of `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptorsResult {
+    /// Whether the descriptor was imported.
+    pub success: bool,
+    /// Warnings (if there are any).
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// The error if the import failed.
+    pub error: Option<JsonRpcError>,
+}
+
+impl ImportDescriptorsResult {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptorsResult {
+        model::ImportDescriptorsResult {
+            success: self.success,
+            warnings: self.warnings,
+            error: self.error.map(|e| e.into_model()),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `psbtbumpfee`.
+///
+/// > psbtbumpfee "txid" ( options )
+/// >
+/// > Bumps the fee of an opt-in-RBF transaction T, replacing it with a new transaction B.
+/// > Returns a PSBT instead of creating and signing a new transaction.
+/// > An opt-in RBF transaction with the given txid must be in the wallet.
+/// > The command will pay the additional fee by reducing change outputs or adding inputs when necessary. It may add a new change output if one does not already exist.
+/// > All inputs in the original transaction will be included in the replacement transaction.
+/// > The command will fail if the wallet or mempool contains a transaction that spends one of T's outputs.
+/// > By default, the new fee will be calculated automatically using the estimatesmartfee RPC.
+/// > The user can specify a confirmation target for estimatesmartfee.
+/// > Alternatively, the user can specify a fee rate in sat/vB for the new transaction.
+/// > At a minimum, the new fee rate must be high enough to pay an additional new relay fee (incrementalfee
+/// > returned by getnetworkinfo) to enter the node's mempool.
+/// >
+/// > Arguments:
+/// > 1. txid                           (string, required) The txid to be bumped
+/// > 2. options                        (json object, optional)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtBumpFee {
+    /// The base64-encoded unsigned PSBT of the new transaction.
+    pub psbt: String,
+    /// The fee of the replaced transaction.
+    #[serde(rename = "origfee")]
+    pub original_fee: f64,
+    /// The fee of the new transaction.
+    pub fee: f64,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+impl PsbtBumpFee {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PsbtBumpFee, PsbtBumpFeeError> {
+        use PsbtBumpFeeError as E;
+
+        let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
+        let original_fee = Amount::from_btc(self.original_fee).map_err(E::OriginalFee)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+
+        Ok(model::PsbtBumpFee { psbt, original_fee, fee, errors: self.errors })
+    }
+}
+
+/// Error when converting a `PsbtBumpFee` type into the model type.
+#[derive(Debug)]
+pub enum PsbtBumpFeeError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the `original_fee` field failed.
+    OriginalFee(ParseAmountError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+}
+
+impl fmt::Display for PsbtBumpFeeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtBumpFeeError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::OriginalFee(ref e) =>
+                write_err!(f, "conversion of the `original_fee` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtBumpFeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtBumpFeeError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::OriginalFee(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `send`.
+///
+/// > send [{"address":amount},{"data":"hex"},...] ( conf_target "estimate_mode" fee_rate options )
+/// >
+/// > EXPERIMENTAL warning: this call may be changed in future releases.
+/// >
+/// > Send a transaction.
+/// >
+/// > Arguments:
+/// > 1. outputs                               (json array, required) The outputs (key-value pairs), where none of the keys are duplicated.
+/// >                                          That is, each address can only appear once and there can only be one 'data' object.
+/// >                                          For convenience, a dictionary, which holds the key-value pairs directly, is also accepted.
+/// > 2. conf_target                           (numeric, optional, default=wallet -txconfirmtarget) Confirmation target in blocks
+/// > 3. estimate_mode                         (string, optional, default=unset) The fee estimate mode, must be one of (case insensitive):
+/// >                                          "unset"
+/// >                                          "economical"
+/// >                                          "conservative"
+/// > 4. fee_rate                              (numeric or string, optional, default=not set, falls back to wallet fee estimation) Specify a fee rate in sat/vB.
+/// > 5. options                               (json object, optional)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Send {
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction id for the send, only returned if the transaction was complete.
+    pub txid: Option<String>,
+    /// If add_to_wallet is false, the hex-encoded raw transaction with signature(s).
+    pub hex: Option<String>,
+    /// If more signatures are needed, or if add_to_wallet is false, the base64-encoded
+    /// (partially) signed transaction.
+    pub psbt: Option<String>,
+}
+
+impl Send {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Send, SendError> {
+        use SendError as E;
+
+        let txid = self.txid.map(|s| s.parse::<Txid>()).transpose().map_err(E::Txid)?;
+        let tx = self
+            .hex
+            .map(|s| encode::deserialize_hex::<Transaction>(&s))
+            .transpose()
+            .map_err(E::Tx)?;
+        let psbt = self.psbt.map(|s| s.parse::<Psbt>()).transpose().map_err(E::Psbt)?;
+
+        Ok(model::Send { complete: self.complete, txid, tx, psbt })
+    }
+}
+
+/// Error when converting a `Send` type into the model type.
+#[derive(Debug)]
+pub enum SendError {
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the transaction `hex` field failed.
+    Tx(encode::FromHexError),
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SendError as E;
+
+        match *self {
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for SendError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SendError as E;
+
+        match *self {
+            E::Txid(ref e) => Some(e),
+            E::Tx(ref e) => Some(e),
+            E::Psbt(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `sendmany` with verbose set to `true`.
+///
+/// > sendmany "" {"address":amount} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode" fee_rate verbose )
+/// >
+/// > Send multiple times. Amounts are double-precision floating point numbers.
+/// > Requires wallet passphrase to be set with walletpassphrase call if wallet is encrypted.
+/// >
+/// > Arguments:
+/// > 1. dummy                     (string, required) Must be set to "" for backwards compatibility.
+/// > 2. amounts                   (json object, required) The addresses and amounts
+/// > 3. minconf                   (numeric, optional) Ignored dummy value
+/// > 4. comment                   (string, optional) A comment
+/// > 5. subtractfeefrom           (json array, optional) The addresses.
+/// > 6. replaceable               (boolean, optional, default=wallet default) Signal that this transaction can be replaced by a transaction (BIP 125)
+/// > 7. conf_target               (numeric, optional, default=wallet -txconfirmtarget) Confirmation target in blocks
+/// > 8. estimate_mode             (string, optional, default=unset) The fee estimate mode
+/// > 9. fee_rate                  (numeric or string, optional, default=not set, falls back to wallet fee estimation) Specify a fee rate in sat/vB.
+/// > 10. verbose                  (boolean, optional, default=false) If true, return extra infomration about the transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SendManyVerbose {
+    /// The transaction id for the send.
+    pub txid: String,
+    /// The transaction fee reason.
+    pub fee_reason: String,
+}
+
+impl SendManyVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SendManyVerbose, hex::HexToArrayError> {
+        let txid = self.txid.parse::<Txid>()?;
+        Ok(model::SendManyVerbose { txid, fee_reason: self.fee_reason })
+    }
+}
@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v22.1 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use bitcoin::consensus::encode;
+use bitcoin::{amount, block, Amount, BlockHash, CompactTarget, Transaction, Weight, Work};
+use serde::{Deserialize, Serialize};
+
+use super::RawTransactionOutput;
+use crate::model;
+use crate::v17::{GetBlockVerbosityTwoError, GetTxOutSetInfoError, RawTransactionInput};
+
+/// Result of JSON-RPC method `getblock` with verbosity set to 2.
+///
+/// > getblock "blockhash" ( verbosity )
+/// >
+/// > If verbosity is 2, returns an Object with information about block <hash> and information about each transaction.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockVerbosityTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: String,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: i64,
+    /// The block size excluding witness data.
+    #[serde(rename = "strippedsize")]
+    pub stripped_size: Option<i64>,
+    /// The block weight as defined in BIP-141.
+    pub weight: u64,
+    /// The block height or index.
+    pub height: i64,
+    /// The block version.
+    pub version: i32,
+    /// The block version formatted in hexadecimal.
+    #[serde(rename = "versionHex")]
+    pub version_hex: String,
+    /// The merkle root
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The transactions in the block.
+    pub tx: Vec<GetBlockVerbosityTwoTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: i64,
+    /// The median block time expressed in UNIX epoch time.
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<i64>,
+    /// The nonce (this should be only 4 bytes).
+    pub nonce: i64,
+    /// The bits.
+    pub bits: String,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The number of transactions in the block.
+    #[serde(rename = "nTx")]
+    pub n_tx: i64,
+    /// The hash of the previous block (if available).
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// The hash of the next block (if available).
+    #[serde(rename = "nextblockhash")]
+    pub next_block_hash: Option<String>,
+}
+
+impl GetBlockVerbosityTwo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockVerbosityTwo, GetBlockVerbosityTwoError> {
+        use GetBlockVerbosityTwoError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let stripped_size =
+            self.stripped_size.map(|size| crate::to_u32(size, "stripped_size")).transpose()?;
+        let weight = Weight::from_wu(self.weight);
+        let version = block::Version::from_consensus(self.version);
+        let tx = self.tx.into_iter().map(|tx| tx.into_model()).collect::<Result<Vec<_>, _>>()?;
+        let median_time = self.median_time.map(|t| crate::to_u32(t, "median_time")).transpose()?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
+
+        Ok(model::GetBlockVerbosityTwo {
+            hash,
+            confirmations: self.confirmations,
+            size: crate::to_u32(self.size, "size")?,
+            stripped_size,
+            weight,
+            height: crate::to_u32(self.height, "height")?,
+            version,
+            merkle_root: self.merkle_root,
+            tx,
+            time: crate::to_u32(self.time, "time")?,
+            median_time,
+            nonce: crate::to_u32(self.nonce, "nonce")?,
+            bits,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: crate::to_u32(self.n_tx, "n_tx")?,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+}
+
+/// A transaction in the result of `getblock` with verbosity set to 2.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockVerbosityTwoTransaction {
+    /// The transaction id.
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The version.
+    pub version: i32,
+    /// The serialized transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4-3 and vsize*4).
+    pub weight: i64,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInput>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+    /// The serialized, hex-encoded data for the transaction.
+    pub hex: String,
+    /// Transaction fee in BTC, omitted for the coinbase or if block undo data is not available.
+    pub fee: Option<f64>,
+}
+
+impl GetBlockVerbosityTwoTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::BlockTransaction, GetBlockVerbosityTwoError> {
+        use GetBlockVerbosityTwoError as E;
+
+        let transaction =
+            encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Transaction)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        Ok(model::BlockTransaction { transaction, fee })
+    }
+}
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" hash_or_height use_index )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time if you are not using coinstatsindex.
+/// >
+/// > Arguments:
+/// > 1. hash_type         (string, optional, default=hash_serialized_2) Which UTXO set hash should be calculated. Options: 'hash_serialized_2' (the legacy algorithm), 'muhash', 'none'.
+/// > 2. hash_or_height    (string or numeric, optional) The block hash or height of the target height (only available with coinstatsindex).
+/// > 3. use_index         (boolean, optional, default=true) Use coinstatsindex, if available.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The block height (index) of the returned statistics.
+    pub height: i64,
+    /// The hash of the block at which these statistics are calculated.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// Database-independent, meaningless metric indicating the UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash (only present if 'hash_serialized_2' hash_type is chosen).
+    pub hash_serialized_2: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The number of transactions with unspent outputs (not available when coinstatsindex is used).
+    pub transactions: Option<u64>,
+    /// The estimated size of the chainstate on disk (not available when coinstatsindex is used).
+    pub disk_size: Option<u64>,
+    /// The total amount of coins in the UTXO set.
+    pub total_amount: f64,
+    /// The total amount of coins permanently excluded from the UTXO set (only available if
+    /// coinstatsindex is used).
+    pub total_unspendable_amount: Option<f64>,
+    /// Info on amounts in the block at this block height (only available if coinstatsindex is
+    /// used).
+    pub block_info: Option<TxOutSetBlockInfo>,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+        let total_unspendable_amount = self
+            .total_unspendable_amount
+            .map(Amount::from_btc)
+            .transpose()
+            .map_err(E::TotalUnspendableAmount)?;
+        let block_info =
+            self.block_info.map(|info| info.into_model()).transpose().map_err(E::BlockInfo)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height,
+            best_block,
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: self.hash_serialized_2,
+            muhash: self.muhash,
+            transactions: self.transactions,
+            disk_size: self.disk_size,
+            total_amount,
+            total_unspendable_amount,
+            block_info,
+        })
+    }
+}
+
+/// Info on amounts in the block at a given height. Part of `gettxoutsetinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TxOutSetBlockInfo {
+    /// Total amount of all prevouts spent in this block.
+    pub prevout_spent: f64,
+    /// Coinbase subsidy amount of this block.
+    pub coinbase: f64,
+    /// Total amount of new outputs created by this block.
+    pub new_outputs_ex_coinbase: f64,
+    /// Total amount of unspendable outputs created in this block.
+    pub unspendable: f64,
+    /// Detailed view of the unspendable categories.
+    pub unspendables: TxOutSetUnspendables,
+}
+
+impl TxOutSetBlockInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TxOutSetBlockInfo, amount::ParseAmountError> {
+        Ok(model::TxOutSetBlockInfo {
+            prevout_spent: Amount::from_btc(self.prevout_spent)?,
+            coinbase: Amount::from_btc(self.coinbase)?,
+            new_outputs_ex_coinbase: Amount::from_btc(self.new_outputs_ex_coinbase)?,
+            unspendable: Amount::from_btc(self.unspendable)?,
+            unspendables: self.unspendables.into_model()?,
+        })
+    }
+}
+
+/// Detailed view of the unspendable categories. Part of `gettxoutsetinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TxOutSetUnspendables {
+    /// The unspendable amount of the Genesis block subsidy.
+    pub genesis_block: f64,
+    /// Transactions overridden by duplicates (no longer possible with BIP30).
+    pub bip30: f64,
+    /// Amounts sent to scripts that are unspendable (for example OP_RETURN outputs).
+    pub scripts: f64,
+    /// Fee rewards that miners did not claim in their coinbase transaction.
+    pub unclaimed_rewards: f64,
+}
+
+impl TxOutSetUnspendables {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TxOutSetUnspendables, amount::ParseAmountError> {
+        Ok(model::TxOutSetUnspendables {
+            genesis_block: Amount::from_btc(self.genesis_block)?,
+            bip30: Amount::from_btc(self.bip30)?,
+            scripts: Amount::from_btc(self.scripts)?,
+            unclaimed_rewards: Amount::from_btc(self.unclaimed_rewards)?,
+        })
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v22.1 - network.
+//!
+//! Types for methods found under the `== Network ==` section of the API docs.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `getnodeaddresses`.
+///
+/// > getnodeaddresses ( count "network" )
+/// >
+/// > Return known addresses, which can potentially be used to find new nodes in the network.
+/// >
+/// > Arguments:
+/// > 1. count      (numeric, optional, default=1) The maximum number of addresses to return. Specify 0 to return all known addresses.
+/// > 2. network    (string, optional, default=all networks) Return only addresses of the specified network. Can be one of: ipv4, ipv6, onion, i2p.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetNodeAddresses(pub Vec<NodeAddress>);
+
+/// An item from the list returned by the JSON-RPC method `getnodeaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NodeAddress {
+    /// The UNIX epoch time when the node was last seen.
+    pub time: u64,
+    /// The services offered by the node.
+    pub services: u64,
+    /// The address of the node.
+    pub address: String,
+    /// The port number of the node.
+    pub port: u16,
+    /// The network (ipv4, ipv6, onion, i2p) the node connected through.
+    pub network: String,
+}
+
+impl GetNodeAddresses {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetNodeAddresses {
+        model::GetNodeAddresses(self.0.into_iter().map(|a| a.into_model()).collect())
+    }
+}
+
+impl NodeAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::NodeAddress {
+        model::NodeAddress {
+            time: self.time,
+            services: self.services,
+            address: self.address,
+            port: self.port,
+            network: Some(self.network),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `listbanned`.
+///
+/// > listbanned
+/// >
+/// > List all manually banned IPs/Subnets.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListBanned(pub Vec<Banned>);
+
+/// An item from the list returned by the JSON-RPC method `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Banned {
+    /// The banned IP address or subnet.
+    pub address: String,
+    /// The UNIX epoch time the ban was created.
+    pub ban_created: i64,
+    /// The UNIX epoch time the ban expires.
+    pub banned_until: i64,
+    /// The ban duration, in seconds.
+    pub ban_duration: i64,
+    /// The time remaining until the ban expires, in seconds.
+    pub time_remaining: i64,
+}
+
+impl ListBanned {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListBanned, NumericError> {
+        let banned = self.0.into_iter().map(|b| b.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::ListBanned(banned))
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Banned, NumericError> {
+        Ok(model::Banned {
+            address: self.address,
+            ban_created: crate::to_u32(self.ban_created, "ban_created")?,
+            banned_until: crate::to_u32(self.banned_until, "banned_until")?,
+            ban_duration: Some(crate::to_u32(self.ban_duration, "ban_duration")?),
+            time_remaining: Some(crate::to_u32(self.time_remaining, "time_remaining")?),
+        })
+    }
+}
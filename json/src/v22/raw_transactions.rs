@@ -0,0 +1,602 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v22 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+//!
+//! From v22 script pubkeys have a single optional `address` instead of the `reqSigs` and
+//! `addresses` fields, this includes the transactions embedded in `decodepsbt`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::address::{Address, NetworkUnchecked};
+use bitcoin::consensus::encode;
+use bitcoin::hex::FromHex as _;
+use bitcoin::psbt::{self, Psbt};
+use bitcoin::{
+    absolute, amount, ecdsa, hex, transaction, Amount, BlockHash, PublicKey, ScriptBuf,
+    Transaction, TxOut, Txid, Witness, Wtxid,
+};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::{
+    psbt_sighash_type, psbt_unknown, reject_reason, DecodePsbtError, DecodeScriptError,
+    GetRawTransactionVerboseError, PsbtInputError, PsbtScript, RawTransactionError,
+    RawTransactionInput, RawTransactionScriptSig, WitnessUtxo,
+};
+use crate::v18::{Bip32Deriv, PsbtOutput};
+use crate::v21::MempoolAcceptanceFees;
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `decodepsbt`.
+///
+/// > decodepsbt "psbt"
+/// >
+/// > Return a JSON object representing the serialized, base64-encoded partially signed Bitcoin transaction.
+/// >
+/// > Arguments:
+/// > 1. psbt    (string, required) The PSBT base64 string
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The decoded network-serialized unsigned transaction.
+    pub tx: DecodeRawTransaction,
+    /// The unknown global fields.
+    pub unknown: BTreeMap<String, String>,
+    /// The PSBT inputs.
+    pub inputs: Vec<PsbtInput>,
+    /// The PSBT outputs.
+    pub outputs: Vec<PsbtOutput>,
+    /// The transaction fee paid if all UTXOs slots in the PSBT have been filled.
+    pub fee: Option<f64>,
+}
+
+impl DecodePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodePsbt, DecodePsbtError> {
+        use DecodePsbtError as E;
+
+        let unsigned_tx = self.tx.transaction().map_err(E::Tx)?;
+        let unknown = psbt_unknown(self.unknown).map_err(E::Unknown)?;
+        let inputs = self
+            .inputs
+            .into_iter()
+            .map(|input| input.into_input())
+            .collect::<Result<_, _>>()
+            .map_err(E::Inputs)?;
+        let outputs = self
+            .outputs
+            .into_iter()
+            .map(|output| output.into_output())
+            .collect::<Result<_, _>>()
+            .map_err(E::Outputs)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        let psbt = Psbt {
+            unsigned_tx,
+            version: 0,
+            xpub: BTreeMap::new(),
+            proprietary: BTreeMap::new(),
+            unknown,
+            inputs,
+            outputs,
+        };
+        Ok(model::DecodePsbt { psbt, fee })
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, DecodePsbtError> { Ok(self.into_model()?.psbt) }
+}
+
+/// An input of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtInput {
+    /// Decoded network transaction for non-witness UTXOs.
+    pub non_witness_utxo: Option<DecodeRawTransaction>,
+    /// Transaction output for witness UTXOs.
+    pub witness_utxo: Option<WitnessUtxo>,
+    /// The public keys with their signatures.
+    pub partial_signatures: Option<BTreeMap<String, String>>,
+    /// The sighash type to be used.
+    pub sighash: Option<String>,
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<Vec<Bip32Deriv>>,
+    /// The final scriptsig.
+    #[serde(rename = "final_scriptsig")]
+    pub final_script_sig: Option<RawTransactionScriptSig>,
+    /// The final script witness, hex-encoded witness data items.
+    #[serde(rename = "final_scriptwitness")]
+    pub final_script_witness: Option<Vec<String>>,
+    /// The unknown input fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtInput {
+    /// Converts the input to a `bitcoin::psbt::Input`.
+    pub fn into_input(self) -> Result<psbt::Input, PsbtInputError> {
+        use PsbtInputError as E;
+
+        let non_witness_utxo = self
+            .non_witness_utxo
+            .map(|tx| tx.transaction())
+            .transpose()
+            .map_err(E::NonWitnessUtxo)?;
+        let witness_utxo = match self.witness_utxo {
+            Some(utxo) => Some(TxOut {
+                value: Amount::from_btc(utxo.amount).map_err(E::WitnessUtxoAmount)?,
+                script_pubkey: ScriptBuf::from_hex(&utxo.script_pubkey.hex)
+                    .map_err(E::WitnessUtxoScriptPubkey)?,
+            }),
+            None => None,
+        };
+        let partial_sigs = self
+            .partial_signatures
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, sig)| {
+                let pubkey = pubkey.parse::<PublicKey>().map_err(E::PartialSignaturePubkey)?;
+                let sig = sig.parse::<ecdsa::Signature>().map_err(E::PartialSignature)?;
+                Ok((pubkey, sig))
+            })
+            .collect::<Result<_, _>>()?;
+        let sighash_type =
+            self.sighash.map(|s| psbt_sighash_type(&s)).transpose().map_err(E::Sighash)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_bip32_derivation())
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let final_script_sig = self
+            .final_script_sig
+            .map(|script_sig| ScriptBuf::from_hex(&script_sig.hex))
+            .transpose()
+            .map_err(E::FinalScriptSig)?;
+        let final_script_witness = self
+            .final_script_witness
+            .map(|witness| {
+                witness
+                    .iter()
+                    .map(|item| Vec::<u8>::from_hex(item))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|items| Witness::from_slice(&items))
+            })
+            .transpose()
+            .map_err(E::FinalScriptWitness)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Input {
+            non_witness_utxo,
+            witness_utxo,
+            partial_sigs,
+            sighash_type,
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            final_script_sig,
+            final_script_witness,
+            unknown,
+            ..Default::default()
+        })
+    }
+}
+
+/// Result of JSON-RPC method `decoderawtransaction`.
+///
+/// > decoderawtransaction "hexstring" ( iswitness )
+/// >
+/// > Return a JSON object representing the serialized, hex-encoded transaction.
+/// >
+/// > Arguments:
+/// > 1. hexstring    (string, required) The transaction hex string
+/// > 2. iswitness    (boolean, optional, default=depends on heuristic tests) Whether the transaction hex is a serialized witness transaction.
+/// >                 If iswitness is not present, heuristic tests will be used in decoding.
+/// >                 If true, only witness deserialization will be tried.
+/// >                 If false, only non-witness deserialization will be tried.
+/// >                 This boolean should reflect whether the transaction has inputs
+/// >                 (e.g. fully valid, or on-chain transactions), if known by the caller.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeRawTransaction {
+    /// The transaction id.
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4 - 3 and vsize*4).
+    pub weight: i64,
+    /// The version.
+    pub version: i32,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInput>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+}
+
+impl DecodeRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeRawTransaction, RawTransactionError> {
+        let input =
+            self.vin.into_iter().map(|input| input.into_tx_in()).collect::<Result<_, _>>()?;
+        let output =
+            self.vout.into_iter().map(|output| output.into_tx_out()).collect::<Result<_, _>>()?;
+
+        let tx = Transaction {
+            version: transaction::Version(self.version),
+            lock_time: absolute::LockTime::from_consensus(crate::to_u32(
+                self.lock_time,
+                "lock_time",
+            )?),
+            input,
+            output,
+        };
+        Ok(model::DecodeRawTransaction(tx))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, RawTransactionError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// A transaction output. Part of `decoderawtransaction` and `getrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RawTransactionOutput {
+    /// The value in BTC.
+    pub value: f64,
+    /// Index.
+    pub n: i64,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: RawTransactionScriptPubkey,
+}
+
+impl RawTransactionOutput {
+    /// Converts the output to a `bitcoin::TxOut`.
+    pub fn into_tx_out(self) -> Result<TxOut, RawTransactionError> {
+        use RawTransactionError as E;
+
+        let value = Amount::from_btc(self.value).map_err(E::Value)?;
+        let script_pubkey =
+            ScriptBuf::from_hex(&self.script_pubkey.hex).map_err(E::ScriptPubkey)?;
+        Ok(TxOut { value, script_pubkey })
+    }
+}
+
+/// A script pubkey. Part of `decoderawtransaction` and `getrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RawTransactionScriptPubkey {
+    /// Script assembly.
+    pub asm: String,
+    /// Script hex.
+    pub hex: String,
+    /// The type, eg 'pubkeyhash'.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The Bitcoin address (only if a well-defined address exists).
+    pub address: Option<String>,
+}
+
+/// Result of JSON-RPC method `decodescript`.
+///
+/// > decodescript "hexstring"
+/// >
+/// > Decode a hex-encoded script.
+/// >
+/// > Arguments:
+/// > 1. hexstring    (string, required) the hex-encoded script
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeScript {
+    /// Script public key.
+    pub asm: String,
+    /// The output type (e.g. nonstandard, pubkey, pubkeyhash, scripthash, multisig, nulldata,
+    /// witness_v0_scripthash, witness_v0_keyhash, witness_v1_taproot, witness_unknown).
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The Bitcoin address (only if a well-defined address exists).
+    pub address: Option<String>,
+    /// Address of P2SH script wrapping this redeem script (not returned if the script is already a P2SH).
+    pub p2sh: Option<String>,
+    /// Result of a witness script public key wrapping this redeem script (not returned if the
+    /// script is a P2SH or witness).
+    pub segwit: Option<DecodeScriptSegwit>,
+}
+
+impl DecodeScript {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeScript, DecodeScriptError> {
+        use DecodeScriptError as E;
+
+        let addresses = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Addresses)?
+            .into_iter()
+            .collect();
+        let p2sh = self
+            .p2sh
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::P2sh)?;
+        let segwit = self.segwit.map(|s| s.into_model()).transpose()?;
+
+        Ok(model::DecodeScript {
+            type_: self.type_,
+            required_signatures: None,
+            addresses,
+            p2sh,
+            segwit,
+        })
+    }
+}
+
+/// The segwit part of the result of JSON-RPC method `decodescript`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeScriptSegwit {
+    /// Disassembly of the script.
+    pub asm: String,
+    /// The raw output script bytes, hex-encoded.
+    pub hex: String,
+    /// The type of the output script (e.g. witness_v0_keyhash or witness_v0_scripthash).
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The Bitcoin address (only if a well-defined address exists).
+    pub address: Option<String>,
+    /// Address of the P2SH script wrapping this witness redeem script.
+    #[serde(rename = "p2sh-segwit")]
+    pub p2sh_segwit: Option<String>,
+}
+
+impl DecodeScriptSegwit {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeScriptSegwit, DecodeScriptError> {
+        use DecodeScriptError as E;
+
+        let script_pubkey = ScriptBuf::from_hex(&self.hex).map_err(E::Hex)?;
+        let addresses = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Addresses)?
+            .into_iter()
+            .collect();
+        let p2sh_segwit = self
+            .p2sh_segwit
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::P2shSegwit)?;
+
+        Ok(model::DecodeScriptSegwit {
+            script_pubkey,
+            type_: self.type_,
+            required_signatures: None,
+            addresses,
+            p2sh_segwit,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getrawtransaction` with verbose set to `true`.
+///
+/// > getrawtransaction "txid" ( verbose "blockhash" )
+/// >
+/// > Return the raw transaction data.
+/// >
+/// > By default, this call only returns a transaction if it is in the mempool. If -txindex is enabled
+/// > and no blockhash argument is passed, it will return the transaction if it is in the mempool or any block.
+/// > If a blockhash argument is passed, it will return the transaction if
+/// > the specified block is available and the transaction is in that block.
+/// >
+/// > If verbose is 'true', returns an Object with information about 'txid'.
+/// > If verbose is 'false' or omitted, returns a string that is serialized, hex-encoded data for 'txid'.
+/// >
+/// > Arguments:
+/// > 1. txid         (string, required) The transaction id
+/// > 2. verbose      (boolean, optional, default=false) If false, return a string, otherwise return a json object
+/// > 3. blockhash    (string, optional) The block in which to look for the transaction
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransactionVerbose {
+    /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
+    pub in_active_chain: Option<bool>,
+    /// The serialized, hex-encoded data for 'txid'.
+    pub hex: String,
+    /// The transaction id (same as provided).
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The serialized transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4-3 and vsize*4).
+    pub weight: i64,
+    /// The version.
+    pub version: i32,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInput>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+    /// The block hash.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The confirmations.
+    pub confirmations: Option<i64>,
+    /// Same as "blocktime".
+    #[serde(rename = "time")]
+    pub transaction_time: Option<i64>,
+    /// The block time expressed in UNIX epoch time.
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<i64>,
+}
+
+impl GetRawTransactionVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::GetRawTransactionVerbose, GetRawTransactionVerboseError> {
+        use GetRawTransactionVerboseError as E;
+
+        let transaction =
+            encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Transaction)?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+        let confirmations =
+            self.confirmations.map(|n| crate::to_u32(n, "confirmations")).transpose()?;
+        let transaction_time =
+            self.transaction_time.map(|t| crate::to_u32(t, "transaction_time")).transpose()?;
+        let block_time = self.block_time.map(|t| crate::to_u32(t, "block_time")).transpose()?;
+
+        Ok(model::GetRawTransactionVerbose {
+            in_active_chain: self.in_active_chain,
+            transaction,
+            block_hash,
+            confirmations,
+            transaction_time,
+            block_time,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `testmempoolaccept`.
+///
+/// > testmempoolaccept ["rawtx",...] ( maxfeerate )
+/// >
+/// > Returns result of mempool acceptance tests indicating if raw transaction(s) (serialized, hex-encoded) would be accepted by mempool.
+/// >
+/// > If multiple transactions are passed in, parents must come before children and package policies apply: the transactions cannot conflict with any mempool transactions or each other.
+/// >
+/// > If one transaction fails, other transactions may not be fully validated (the 'allowed' key will be blank).
+/// >
+/// > The maximum number of transactions allowed is 25.
+/// >
+/// > This checks if transactions violate the consensus or policy rules.
+/// >
+/// > See sendrawtransaction call.
+/// >
+/// > Arguments:
+/// > 1. rawtxs       (json array, required) An array of hex strings of raw transactions.
+/// > 2. maxfeerate    (numeric or string, optional, default=0.10) Reject transactions whose fee rate is higher than the specified value, expressed in BTC/kvB
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
+
+impl TestMempoolAccept {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TestMempoolAccept, TestMempoolAcceptError> {
+        let results = self.0.into_iter().map(|r| r.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::TestMempoolAccept { results })
+    }
+}
+
+/// A single mempool acceptance test result. Part of `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptance {
+    /// The transaction hash in hex.
+    pub txid: String,
+    /// The transaction witness hash in hex.
+    pub wtxid: String,
+    /// Package validation error, if any (only possible if rawtxs had more than 1 transaction).
+    #[serde(rename = "package-error")]
+    pub package_error: Option<String>,
+    /// Whether this tx would be accepted to the mempool and pass client-specified maxfeerate.
+    ///
+    /// If not present, the tx was not fully validated due to a failure in another tx in the list.
+    pub allowed: Option<bool>,
+    /// Virtual transaction size as defined in BIP 141 (only present when 'allowed' is true).
+    pub vsize: Option<i64>,
+    /// Transaction fees (only present if 'allowed' is true).
+    pub fees: Option<MempoolAcceptanceFees>,
+    /// Rejection string (only present when 'allowed' is false).
+    #[serde(rename = "reject-reason")]
+    pub reject_reason: Option<String>,
+}
+
+impl MempoolAcceptance {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolAcceptance, TestMempoolAcceptError> {
+        use TestMempoolAcceptError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let vsize = self.vsize.map(|v| crate::to_u32(v, "vsize")).transpose()?;
+        let fees = self.fees.map(|f| f.into_model()).transpose().map_err(E::Base)?;
+
+        Ok(model::MempoolAcceptance {
+            txid,
+            wtxid: Some(wtxid),
+            package_error: self.package_error,
+            allowed: self.allowed.unwrap_or(false),
+            vsize,
+            fees,
+            reject_reason: self.reject_reason.as_deref().map(reject_reason),
+            reject_message: self.reject_reason,
+        })
+    }
+}
+
+/// Error when converting a `TestMempoolAccept` type into the model type.
+#[derive(Debug)]
+pub enum TestMempoolAcceptError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `wtxid` field failed.
+    Wtxid(hex::HexToArrayError),
+    /// Conversion of the `base` fee field failed.
+    Base(amount::ParseAmountError),
+}
+
+impl fmt::Display for TestMempoolAcceptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TestMempoolAcceptError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Wtxid(ref e) => write_err!(f, "conversion of the `wtxid` field failed"; e),
+            E::Base(ref e) => write_err!(f, "conversion of the `base` fee field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TestMempoolAcceptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TestMempoolAcceptError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::Wtxid(ref e) => Some(e),
+            E::Base(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for TestMempoolAcceptError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
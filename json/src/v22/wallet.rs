@@ -4,9 +4,12 @@
 //!
 //! Types for methods found under the `== Wallet ==` section of the API docs.
 
+use std::fmt;
+
+use internals::write_err;
 use serde::{Deserialize, Serialize};
 
-use crate::model;
+use crate::{model, NumericError};
 
 /// Result of the JSON-RPC method `unloadwallet`.
 ///
@@ -30,3 +33,156 @@ impl UnloadWallet {
         model::UnloadWallet { warnings: vec![self.warning] }
     }
 }
+
+/// Result of the JSON-RPC method `listdescriptors`.
+///
+/// > listdescriptors
+/// >
+/// > List descriptors imported into a descriptor-enabled wallet.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptors {
+    /// Name of wallet this operation was performed on.
+    pub wallet_name: String,
+    /// Array of descriptor objects.
+    pub descriptors: Vec<ListDescriptorsItem>,
+}
+
+impl ListDescriptors {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListDescriptors, ListDescriptorsError> {
+        let descriptors =
+            self.descriptors.into_iter().map(|d| d.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::ListDescriptors { wallet_name: self.wallet_name, descriptors })
+    }
+}
+
+/// A descriptor object. Part of `listdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsItem {
+    /// Descriptor string representation.
+    #[serde(rename = "desc")]
+    pub descriptor: String,
+    /// The creation time of the descriptor.
+    pub timestamp: i64,
+    /// Activeness flag.
+    pub active: bool,
+    /// Whether this is an internal or external descriptor; defined only for active descriptors.
+    pub internal: Option<bool>,
+    /// Defined only for ranged descriptors, the range start and end.
+    pub range: Option<[i64; 2]>,
+    /// The next index to generate addresses from; defined only for ranged descriptors.
+    pub next: Option<i64>,
+}
+
+impl ListDescriptorsItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListDescriptorsItem, ListDescriptorsError> {
+        #[cfg(feature = "miniscript")]
+        let parsed_descriptor =
+            crate::descriptor(&self.descriptor).map_err(ListDescriptorsError::Descriptor)?;
+        let timestamp = crate::to_u32(self.timestamp, "timestamp")?;
+        let range = self
+            .range
+            .map(|[start, end]| {
+                Ok::<_, NumericError>((
+                    crate::to_u32(start, "range")?,
+                    crate::to_u32(end, "range")?,
+                ))
+            })
+            .transpose()?;
+        let next = self.next.map(|next| crate::to_u32(next, "next")).transpose()?;
+
+        Ok(model::ListDescriptorsItem {
+            descriptor: self.descriptor,
+            #[cfg(feature = "miniscript")]
+            parsed_descriptor,
+            timestamp,
+            active: self.active,
+            internal: self.internal,
+            range,
+            next,
+        })
+    }
+}
+
+/// Error when converting a `ListDescriptors` type into the model type.
+#[derive(Debug)]
+pub enum ListDescriptorsError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `desc` field failed.
+    #[cfg(feature = "miniscript")]
+    Descriptor(miniscript::Error),
+}
+
+impl fmt::Display for ListDescriptorsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ListDescriptorsError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            #[cfg(feature = "miniscript")]
+            E::Descriptor(ref e) => write_err!(f, "conversion of the `desc` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ListDescriptorsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ListDescriptorsError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            #[cfg(feature = "miniscript")]
+            E::Descriptor(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for ListDescriptorsError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+#[cfg(all(test, feature = "miniscript"))]
+mod tests {
+    use std::str::FromStr;
+
+    use miniscript::{Descriptor, DescriptorPublicKey};
+
+    use super::*;
+
+    // A regtest BIP-84 descriptor, as returned by `listdescriptors` with and without `private`.
+    const PUBLIC: &str = "wpkh([5159c173/84h/1h/0h]tpubDD3WNAnESEbJ7RrrzgsTbLaViceBrnHr9pni1pfweCVuC1HrS6ffrs3BKvDDJZr6ANcUugKBYWzkwELD4wBSjN7V3wkyDZq98425NzaruAy/0/*)#npaq63fs";
+    const PRIVATE: &str = "wpkh([5159c173/84h/1h/0h]tprv8gMUDkjzHrudDxq573CsBvvP9b8FhT6waXBvjJdeDvhWMX35ohr5gNRK9nAR24WL8Q6ExXiNce8sYGXDxCE3nethqMPbcNdqJwbQyFh3gcM/0/*)#3c3uk6na";
+
+    fn list_descriptors(desc: &str) -> ListDescriptors {
+        let json = format!(
+            r#"{{"wallet_name": "default", "descriptors": [{{"desc": "{}", "timestamp": 1729296000, "active": true, "internal": false, "range": [0, 999], "next": 0}}]}}"#,
+            desc
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn list_descriptors_parses_descriptor() {
+        let want = Descriptor::<DescriptorPublicKey>::from_str(PUBLIC).unwrap();
+
+        let model = list_descriptors(PUBLIC).into_model().unwrap();
+        assert_eq!(model.descriptors[0].descriptor, PUBLIC);
+        assert_eq!(model.descriptors[0].parsed_descriptor, want);
+
+        // Private keys are replaced by their public keys.
+        let model = list_descriptors(PRIVATE).into_model().unwrap();
+        assert_eq!(model.descriptors[0].descriptor, PRIVATE);
+        assert_eq!(model.descriptors[0].parsed_descriptor, want);
+    }
+
+    #[test]
+    fn list_descriptors_invalid_checksum() {
+        let desc = PUBLIC.replace("#npaq63fs", "#npaq63ft");
+        assert!(matches!(
+            list_descriptors(&desc).into_model(),
+            Err(ListDescriptorsError::Descriptor(_))
+        ));
+    }
+}
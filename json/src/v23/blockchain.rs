@@ -0,0 +1,388 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v23 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::consensus::encode;
+use bitcoin::error::UnprefixedHexError;
+use bitcoin::{amount, block, hex, Amount, BlockHash, CompactTarget, Transaction, Weight, Work};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use super::{RawTransactionInputWithPrevout, RawTransactionPrevoutError};
+use crate::v17::Bip9SoftforkStatistics;
+use crate::v19::{Bip9SoftforkStatus, SoftforkType};
+use crate::v22::RawTransactionOutput;
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `getblock` with verbosity set to 3.
+///
+/// > getblock "blockhash" ( verbosity )
+/// >
+/// > If verbosity is 3, returns an Object with information about block <hash> and information about each transaction, including prevout information for inputs (only for unpruned blocks in the current best chain).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockVerbosityThree {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: String,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: i64,
+    /// The block size excluding witness data.
+    #[serde(rename = "strippedsize")]
+    pub stripped_size: Option<i64>,
+    /// The block weight as defined in BIP-141.
+    pub weight: u64,
+    /// The block height or index.
+    pub height: i64,
+    /// The block version.
+    pub version: i32,
+    /// The block version formatted in hexadecimal.
+    #[serde(rename = "versionHex")]
+    pub version_hex: String,
+    /// The merkle root
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The transactions in the block.
+    pub tx: Vec<GetBlockVerbosityThreeTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: i64,
+    /// The median block time expressed in UNIX epoch time.
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<i64>,
+    /// The nonce (this should be only 4 bytes).
+    pub nonce: i64,
+    /// The bits.
+    pub bits: String,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The number of transactions in the block.
+    #[serde(rename = "nTx")]
+    pub n_tx: i64,
+    /// The hash of the previous block (if available).
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// The hash of the next block (if available).
+    #[serde(rename = "nextblockhash")]
+    pub next_block_hash: Option<String>,
+}
+
+impl GetBlockVerbosityThree {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockVerbosityThree, GetBlockVerbosityThreeError> {
+        use GetBlockVerbosityThreeError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let stripped_size =
+            self.stripped_size.map(|size| crate::to_u32(size, "stripped_size")).transpose()?;
+        let weight = Weight::from_wu(self.weight);
+        let version = block::Version::from_consensus(self.version);
+        let tx = self.tx.into_iter().map(|tx| tx.into_model()).collect::<Result<Vec<_>, _>>()?;
+        let median_time = self.median_time.map(|t| crate::to_u32(t, "median_time")).transpose()?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
+
+        Ok(model::GetBlockVerbosityThree {
+            hash,
+            confirmations: self.confirmations,
+            size: crate::to_u32(self.size, "size")?,
+            stripped_size,
+            weight,
+            height: crate::to_u32(self.height, "height")?,
+            version,
+            merkle_root: self.merkle_root,
+            tx,
+            time: crate::to_u32(self.time, "time")?,
+            median_time,
+            nonce: crate::to_u32(self.nonce, "nonce")?,
+            bits,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: crate::to_u32(self.n_tx, "n_tx")?,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+}
+
+/// A transaction in the result of `getblock` with verbosity set to 3.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockVerbosityThreeTransaction {
+    /// The transaction id.
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The version.
+    pub version: i32,
+    /// The serialized transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4-3 and vsize*4).
+    pub weight: i64,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs, including the outputs they spend.
+    pub vin: Vec<RawTransactionInputWithPrevout>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+    /// The serialized, hex-encoded data for the transaction.
+    pub hex: String,
+    /// Transaction fee in BTC, omitted for the coinbase or if block undo data is not available.
+    pub fee: Option<f64>,
+}
+
+impl GetBlockVerbosityThreeTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::BlockTransactionWithPrevouts, GetBlockVerbosityThreeError> {
+        use GetBlockVerbosityThreeError as E;
+
+        let transaction =
+            encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Transaction)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+        let prevouts = self
+            .vin
+            .into_iter()
+            .map(|input| input.prevout.map(|prevout| prevout.into_model()).transpose())
+            .collect::<Result<_, _>>()
+            .map_err(E::Prevout)?;
+
+        Ok(model::BlockTransactionWithPrevouts { transaction, fee, prevouts })
+    }
+}
+
+/// Error when converting a `GetBlockVerbosityThree` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockVerbosityThreeError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of a transaction `hex` field failed.
+    Transaction(encode::FromHexError),
+    /// Conversion of a transaction `fee` field failed.
+    Fee(amount::ParseAmountError),
+    /// Conversion of an input `prevout` field failed.
+    Prevout(RawTransactionPrevoutError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `chain_work` field failed.
+    ChainWork(UnprefixedHexError),
+    /// Conversion of the `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the `next_block_hash` field failed.
+    NextBlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetBlockVerbosityThreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBlockVerbosityThreeError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+            E::Transaction(ref e) =>
+                write_err!(f, "conversion of a transaction `hex` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of a transaction `fee` field failed"; e),
+            E::Prevout(ref e) => write_err!(f, "conversion of an input `prevout` field failed"; e),
+            E::Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            E::ChainWork(ref e) => write_err!(f, "conversion of the `chain_work` field failed"; e),
+            E::PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            E::NextBlockHash(ref e) =>
+                write_err!(f, "conversion of the `next_block_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetBlockVerbosityThreeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBlockVerbosityThreeError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Hash(ref e) => Some(e),
+            E::Transaction(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+            E::Prevout(ref e) => Some(e),
+            E::Bits(ref e) => Some(e),
+            E::ChainWork(ref e) => Some(e),
+            E::PreviousBlockHash(ref e) => Some(e),
+            E::NextBlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetBlockVerbosityThreeError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `getdeploymentinfo`.
+///
+/// > getdeploymentinfo ( "blockhash" )
+/// >
+/// > Returns an object containing various state info regarding deployments of consensus changes.
+/// >
+/// > Arguments:
+/// > 1. blockhash    (string, optional, default="hash of current chain tip") The block hash at which to query deployment state
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetDeploymentInfo {
+    /// Requested block hash (or tip).
+    pub hash: String,
+    /// Requested block height (or tip).
+    pub height: i64,
+    /// Deployments, maps deployment name -> [`DeploymentInfo`].
+    pub deployments: BTreeMap<String, DeploymentInfo>,
+}
+
+/// A deployment. Part of `getdeploymentinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeploymentInfo {
+    /// The [`SoftforkType`]: one of "buried", "bip9".
+    #[serde(rename = "type")]
+    pub type_: SoftforkType,
+    /// Height of the first block which the rules are or will be enforced (only for "buried" type, or "bip9" type with "active" status).
+    pub height: Option<i64>,
+    /// `true` if the rules are enforced for the mempool and the next block.
+    pub active: bool,
+    /// Status of bip9 softforks (only for "bip9" type).
+    pub bip9: Option<Bip9DeploymentInfo>,
+}
+
+/// Status of a BIP-9 deployment. Part of `getdeploymentinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Bip9DeploymentInfo {
+    /// The bit (0-28) in the block version field used to signal this softfork (only for "started" and "locked_in" status).
+    pub bit: Option<u8>,
+    /// The minimum median time past of a block at which the bit gains its meaning.
+    pub start_time: i64,
+    /// The median time past of a block at which the deployment is considered failed if not yet locked in.
+    pub timeout: i64,
+    /// Minimum height of blocks for which the rules may be enforced.
+    pub min_activation_height: i64,
+    /// Status of deployment at specified block (one of "defined", "started", "locked_in", "active", "failed").
+    pub status: Bip9SoftforkStatus,
+    /// Height of the first block to which the status applies.
+    pub since: i64,
+    /// Status of deployment at the next block.
+    pub status_next: Bip9SoftforkStatus,
+    /// Numeric statistics about signalling for a softfork (only for "started" and "locked_in" status).
+    pub statistics: Option<Bip9SoftforkStatistics>,
+    /// Indicates blocks that signalled with a # and blocks that did not with a - (only for "started" and "locked_in" status).
+    pub signalling: Option<String>,
+}
+
+impl GetDeploymentInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetDeploymentInfo, GetDeploymentInfoError> {
+        use GetDeploymentInfoError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let deployments = self
+            .deployments
+            .into_iter()
+            .map(|(name, deployment)| Ok((name, deployment.into_model()?)))
+            .collect::<Result<BTreeMap<_, _>, NumericError>>()?;
+
+        Ok(model::GetDeploymentInfo {
+            hash,
+            height: crate::to_u32(self.height, "height")?,
+            deployments,
+        })
+    }
+}
+
+impl DeploymentInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DeploymentInfo, NumericError> {
+        let height = self.height.map(|h| crate::to_u32(h, "height")).transpose()?;
+        let bip9 = self.bip9.map(|info| info.into_model()).transpose()?;
+
+        Ok(model::DeploymentInfo {
+            type_: self.type_.into_model(),
+            height,
+            active: self.active,
+            bip9,
+        })
+    }
+}
+
+impl Bip9DeploymentInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    ///
+    /// The `signalling` string is converted to one `bool` per block, `true` if the block signalled.
+    pub fn into_model(self) -> Result<model::Bip9DeploymentInfo, NumericError> {
+        let statistics = self.statistics.map(|s| s.into_model()).transpose()?;
+        let signalling = self.signalling.map(|s| s.chars().map(|c| c == '#').collect());
+
+        Ok(model::Bip9DeploymentInfo {
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            min_activation_height: crate::to_u32(
+                self.min_activation_height,
+                "min_activation_height",
+            )?,
+            status: self.status.into_model(),
+            since: crate::to_u32(self.since, "since")?,
+            status_next: self.status_next.into_model(),
+            statistics,
+            signalling,
+        })
+    }
+}
+
+/// Error when converting a `GetDeploymentInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetDeploymentInfoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetDeploymentInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetDeploymentInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetDeploymentInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetDeploymentInfoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Hash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetDeploymentInfoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
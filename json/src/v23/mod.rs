@@ -9,106 +9,106 @@
 //! - [x] `getbestblockhash`
 //! - [x] `getblock "blockhash" ( verbosity )`
 //! - [x] `getblockchaininfo`
-//! - [ ] `getblockcount`
-//! - [ ] `getblockfilter "blockhash" ( "filtertype" )`
+//! - [x] `getblockcount`
+//! - [x] `getblockfilter "blockhash" ( "filtertype" )`
 //! - [ ] `getblockfrompeer "blockhash" peer_id`
-//! - [ ] `getblockhash height`
+//! - [x] `getblockhash height`
 //! - [ ] `getblockheader "blockhash" ( verbose )`
 //! - [ ] `getblockstats hash_or_height ( stats )`
 //! - [ ] `getchaintips`
 //! - [ ] `getchaintxstats ( nblocks "blockhash" )`
-//! - [ ] `getdeploymentinfo ( "blockhash" )`
+//! - [x] `getdeploymentinfo ( "blockhash" )`
 //! - [ ] `getdifficulty`
-//! - [ ] `getmempoolancestors "txid" ( verbose )`
-//! - [ ] `getmempooldescendants "txid" ( verbose )`
-//! - [ ] `getmempoolentry "txid"`
-//! - [ ] `getmempoolinfo`
-//! - [ ] `getrawmempool ( verbose mempool_sequence )`
+//! - [x] `getmempoolancestors "txid" ( verbose )`
+//! - [x] `getmempooldescendants "txid" ( verbose )`
+//! - [x] `getmempoolentry "txid"`
+//! - [x] `getmempoolinfo`
+//! - [x] `getrawmempool ( verbose mempool_sequence )`
 //! - [ ] `gettxout "txid" n ( include_mempool )`
 //! - [ ] `gettxoutproof ["txid",...] ( "blockhash" )`
-//! - [ ] `gettxoutsetinfo ( "hash_type" hash_or_height use_index )`
+//! - [x] `gettxoutsetinfo ( "hash_type" hash_or_height use_index )`
 //! - [ ] `preciousblock "blockhash"`
 //! - [ ] `pruneblockchain height`
 //! - [ ] `savemempool`
-//! - [ ] `scantxoutset "action" ( [scanobjects,...] )`
+//! - [x] `scantxoutset "action" ( [scanobjects,...] )`
 //! - [ ] `verifychain ( checklevel nblocks )`
 //! - [ ] `verifytxoutproof "proof"`
 //!
 //! **== Control ==**
-//! - [ ] `getmemoryinfo ( "mode" )`
-//! - [ ] `getrpcinfo`
-//! - [ ] `help ( "command" )`
-//! - [ ] `logging ( ["include_category",...] ["exclude_category",...] )`
+//! - [x] `getmemoryinfo ( "mode" )`
+//! - [x] `getrpcinfo`
+//! - [-] `help ( "command" )`
+//! - [x] `logging ( ["include_category",...] ["exclude_category",...] )`
 //! - [x] `stop`
-//! - [ ] `uptime`
+//! - [x] `uptime`
 //!
 //! **== Mining ==**
-//! - [ ] `getblocktemplate ( "template_request" )`
-//! - [ ] `getmininginfo`
-//! - [ ] `getnetworkhashps ( nblocks height )`
-//! - [ ] `prioritisetransaction "txid" ( dummy ) fee_delta`
-//! - [ ] `submitblock "hexdata" ( "dummy" )`
-//! - [ ] `submitheader "hexdata"`
+//! - [x] `getblocktemplate ( "template_request" )`
+//! - [x] `getmininginfo`
+//! - [x] `getnetworkhashps ( nblocks height )`
+//! - [x] `prioritisetransaction "txid" ( dummy ) fee_delta`
+//! - [x] `submitblock "hexdata" ( "dummy" )`
+//! - [x] `submitheader "hexdata"`
 //!
 //! **== Network ==**
-//! - [ ] `addnode "node" "command"`
-//! - [ ] `clearbanned`
-//! - [ ] `disconnectnode ( "address" nodeid )`
-//! - [ ] `getaddednodeinfo ( "node" )`
-//! - [ ] `getconnectioncount`
-//! - [ ] `getnettotals`
-//! - [ ] `getnetworkinfo`
-//! - [ ] `getnodeaddresses ( count "network" )`
-//! - [ ] `getpeerinfo`
-//! - [ ] `listbanned`
-//! - [ ] `ping`
-//! - [ ] `setban "subnet" "command" ( bantime absolute )`
-//! - [ ] `setnetworkactive state`
+//! - [-] `addnode "node" "command"`
+//! - [-] `clearbanned`
+//! - [-] `disconnectnode ( "address" nodeid )`
+//! - [x] `getaddednodeinfo ( "node" )`
+//! - [x] `getconnectioncount`
+//! - [x] `getnettotals`
+//! - [x] `getnetworkinfo`
+//! - [x] `getnodeaddresses ( count "network" )`
+//! - [x] `getpeerinfo`
+//! - [x] `listbanned`
+//! - [-] `ping`
+//! - [-] `setban "subnet" "command" ( bantime absolute )`
+//! - [-] `setnetworkactive state`
 //!
 //! **== Rawtransactions ==**
-//! - [ ] `analyzepsbt "psbt"`
-//! - [ ] `combinepsbt ["psbt",...]`
-//! - [ ] `combinerawtransaction ["hexstring",...]`
-//! - [ ] `converttopsbt "hexstring" ( permitsigdata iswitness )`
-//! - [ ] `createpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount,...},{"data":"hex"},...] ( locktime replaceable )`
-//! - [ ] `createrawtransaction [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount,...},{"data":"hex"},...] ( locktime replaceable )`
-//! - [ ] `decodepsbt "psbt"`
-//! - [ ] `decoderawtransaction "hexstring" ( iswitness )`
-//! - [ ] `decodescript "hexstring"`
-//! - [ ] `finalizepsbt "psbt" ( extract )`
-//! - [ ] `fundrawtransaction "hexstring" ( options iswitness )`
-//! - [ ] `getrawtransaction "txid" ( verbose "blockhash" )`
-//! - [ ] `joinpsbts ["psbt",...]`
+//! - [x] `analyzepsbt "psbt"`
+//! - [x] `combinepsbt ["psbt",...]`
+//! - [x] `combinerawtransaction ["hexstring",...]`
+//! - [x] `converttopsbt "hexstring" ( permitsigdata iswitness )`
+//! - [x] `createpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount,...},{"data":"hex"},...] ( locktime replaceable )`
+//! - [x] `createrawtransaction [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount,...},{"data":"hex"},...] ( locktime replaceable )`
+//! - [x] `decodepsbt "psbt"`
+//! - [x] `decoderawtransaction "hexstring" ( iswitness )`
+//! - [x] `decodescript "hexstring"`
+//! - [x] `finalizepsbt "psbt" ( extract )`
+//! - [x] `fundrawtransaction "hexstring" ( options iswitness )`
+//! - [x] `getrawtransaction "txid" ( verbose "blockhash" )`
+//! - [x] `joinpsbts ["psbt",...]`
 //! - [ ] `sendrawtransaction "hexstring" ( maxfeerate )`
-//! - [ ] `signrawtransactionwithkey "hexstring" ["privatekey",...] ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
-//! - [ ] `testmempoolaccept ["rawtx",...] ( maxfeerate )`
-//! - [ ] `utxoupdatepsbt "psbt" ( ["",{"desc":"str","range":n or [n,n]},...] )`
+//! - [x] `signrawtransactionwithkey "hexstring" ["privatekey",...] ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
+//! - [x] `testmempoolaccept ["rawtx",...] ( maxfeerate )`
+//! - [x] `utxoupdatepsbt "psbt" ( ["",{"desc":"str","range":n or [n,n]},...] )`
 //!
 //! **== Signer ==**
 //! - [ ] `enumeratesigners`
 //!
 //! **== Util ==**
-//! - [ ] `createmultisig nrequired ["key",...] ( "address_type" )`
-//! - [ ] `deriveaddresses "descriptor" ( range )`
-//! - [ ] `estimatesmartfee conf_target ( "estimate_mode" )`
-//! - [ ] `getdescriptorinfo "descriptor"`
-//! - [ ] `getindexinfo ( "index_name" )`
-//! - [ ] `signmessagewithprivkey "privkey" "message"`
-//! - [ ] `validateaddress "address"`
-//! - [ ] `verifymessage "address" "signature" "message"`
+//! - [x] `createmultisig nrequired ["key",...] ( "address_type" )`
+//! - [x] `deriveaddresses "descriptor" ( range )`
+//! - [x] `estimatesmartfee conf_target ( "estimate_mode" )`
+//! - [x] `getdescriptorinfo "descriptor"`
+//! - [x] `getindexinfo ( "index_name" )`
+//! - [x] `signmessagewithprivkey "privkey" "message"`
+//! - [x] `validateaddress "address"`
+//! - [x] `verifymessage "address" "signature" "message"`
 //!
 //! **== Wallet ==**
-//! - [ ] `abandontransaction "txid"`
+//! - [-] `abandontransaction "txid"`
 //! - [ ] `abortrescan`
 //! - [ ] `addmultisigaddress nrequired ["key",...] ( "label" "address_type" )`
 //! - [ ] `backupwallet "destination"`
-//! - [ ] `bumpfee "txid" ( options )`
+//! - [x] `bumpfee "txid" ( options )`
 //! - [x] `createwallet "wallet_name" ( disable_private_keys blank "passphrase" avoid_reuse descriptors load_on_startup external_signer )`
 //! - [ ] `dumpprivkey "address"`
 //! - [ ] `dumpwallet "filename"`
 //! - [ ] `encryptwallet "passphrase"`
-//! - [ ] `getaddressesbylabel "label"`
-//! - [ ] `getaddressinfo "address"`
+//! - [x] `getaddressesbylabel "label"`
+//! - [x] `getaddressinfo "address"`
 //! - [x] `getbalance ( "dummy" minconf include_watchonly avoid_reuse )`
 //! - [x] `getbalances`
 //! - [x] `getnewaddress ( "label" "address_type" )`
@@ -117,65 +117,132 @@
 //! - [ ] `getreceivedbylabel "label" ( minconf include_immature_coinbase )`
 //! - [x] `gettransaction "txid" ( include_watchonly verbose )`
 //! - [ ] `getunconfirmedbalance`
-//! - [ ] `getwalletinfo`
+//! - [x] `getwalletinfo`
 //! - [ ] `importaddress "address" ( "label" rescan p2sh )`
-//! - [ ] `importdescriptors "requests"`
-//! - [ ] `importmulti "requests" ( "options" )`
+//! - [x] `importdescriptors "requests"`
+//! - [x] `importmulti "requests" ( "options" )`
 //! - [ ] `importprivkey "privkey" ( "label" rescan )`
 //! - [ ] `importprunedfunds "rawtransaction" "txoutproof"`
 //! - [ ] `importpubkey "pubkey" ( "label" rescan )`
 //! - [ ] `importwallet "filename"`
 //! - [ ] `keypoolrefill ( newsize )`
 //! - [ ] `listaddressgroupings`
-//! - [ ] `listdescriptors ( private )`
-//! - [ ] `listlabels ( "purpose" )`
+//! - [x] `listdescriptors ( private )`
+//! - [x] `listlabels ( "purpose" )`
 //! - [ ] `listlockunspent`
 //! - [ ] `listreceivedbyaddress ( minconf include_empty include_watchonly "address_filter" include_immature_coinbase )`
 //! - [ ] `listreceivedbylabel ( minconf include_empty include_watchonly include_immature_coinbase )`
-//! - [ ] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
-//! - [ ] `listtransactions ( "label" count skip include_watchonly )`
-//! - [ ] `listunspent ( minconf maxconf ["address",...] include_unsafe query_options )`
-//! - [ ] `listwalletdir`
-//! - [ ] `listwallets`
+//! - [x] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
+//! - [x] `listtransactions ( "label" count skip include_watchonly )`
+//! - [x] `listunspent ( minconf maxconf ["address",...] include_unsafe query_options )`
+//! - [x] `listwalletdir`
+//! - [x] `listwallets`
 //! - [x] `loadwallet "filename" ( load_on_startup )`
 //! - [ ] `lockunspent unlock ( [{"txid":"hex","vout":n},...] persistent )`
 //! - [ ] `newkeypool`
-//! - [ ] `psbtbumpfee "txid" ( options )`
+//! - [x] `psbtbumpfee "txid" ( options )`
 //! - [ ] `removeprunedfunds "txid"`
 //! - [ ] `rescanblockchain ( start_height stop_height )`
 //! - [ ] `restorewallet "wallet_name" "backup_file" ( load_on_startup )`
-//! - [ ] `send [{"address":amount,...},{"data":"hex"},...] ( conf_target "estimate_mode" fee_rate options )`
-//! - [ ] `sendmany "" {"address":amount,...} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode" fee_rate verbose )`
+//! - [x] `send [{"address":amount,...},{"data":"hex"},...] ( conf_target "estimate_mode" fee_rate options )`
+//! - [x] `sendmany "" {"address":amount,...} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode" fee_rate verbose )`
 //! - [x] `sendtoaddress "address" amount ( "comment" "comment_to" subtractfeefromamount replaceable conf_target "estimate_mode" avoid_reuse fee_rate verbose )`
 //! - [ ] `sethdseed ( newkeypool "seed" )`
-//! - [ ] `setlabel "address" "label"`
+//! - [-] `setlabel "address" "label"`
 //! - [ ] `settxfee amount`
 //! - [ ] `setwalletflag "flag" ( value )`
-//! - [ ] `signmessage "address" "message"`
-//! - [ ] `signrawtransactionwithwallet "hexstring" ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
+//! - [x] `signmessage "address" "message"`
+//! - [x] `signrawtransactionwithwallet "hexstring" ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
 //! - [ ] `unloadwallet ( "wallet_name" load_on_startup )`
 //! - [ ] `upgradewallet ( version )`
-//! - [ ] `walletcreatefundedpsbt ( [{"txid":"hex","vout":n,"sequence":n,"weight":n},...] ) [{"address":amount,...},{"data":"hex"},...] ( locktime options bip32derivs )`
+//! - [x] `walletcreatefundedpsbt ( [{"txid":"hex","vout":n,"sequence":n,"weight":n},...] ) [{"address":amount,...},{"data":"hex"},...] ( locktime options bip32derivs )`
 //! - [ ] `walletdisplayaddress "address"`
 //! - [ ] `walletlock`
 //! - [ ] `walletpassphrase "passphrase" timeout`
 //! - [ ] `walletpassphrasechange "oldpassphrase" "newpassphrase"`
-//! - [ ] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs finalize )`
+//! - [x] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs finalize )`
 //!
 //! **== Zmq ==**
 //! - [ ] `getzmqnotifications`
+//!
+//! **== Hidden ==**
+//! - [x] `waitforblock <blockhash> (timeout)`
+//! - [x] `waitforblockheight <height> (timeout)`
+//! - [x] `waitfornewblock (timeout)`
+
+mod blockchain;
+mod raw_transactions;
+mod util;
+mod wallet;
 
+#[doc(inline)]
+pub use self::blockchain::{
+    Bip9DeploymentInfo, DeploymentInfo, GetBlockVerbosityThree, GetBlockVerbosityThreeError,
+    GetBlockVerbosityThreeTransaction, GetDeploymentInfo, GetDeploymentInfoError,
+};
+pub(crate) use self::raw_transactions::psbt_preimages;
+#[doc(inline)]
+pub use self::raw_transactions::{
+    DecodePsbt, DecodePsbtError, GlobalXpub, GlobalXpubError, PsbtInput, PsbtInputError,
+    PsbtOutput, PsbtOutputError, PsbtPreimageError, PsbtProprietary, PsbtProprietaryError,
+    RawTransactionInputWithPrevout, RawTransactionPrevout, RawTransactionPrevoutError,
+};
+#[doc(inline)]
+pub use self::util::{CreateMultisig, ValidateAddress};
+#[doc(inline)]
+pub use self::wallet::GetWalletInfo;
 #[doc(inline)]
 pub use crate::{
     v17::{
-        CreateWallet, GenerateToAddress, GetBalance, GetBestBlockHash, GetBlockVerbosityOne,
-        GetBlockVerbosityZero, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork,
-        GetNewAddress, GetTransaction, GetTransactionDetail, GetTransactionDetailCategory,
-        GetTxOut, LoadWallet, SendRawTransaction,
+        AddedNode, AddedNodeAddress, AddressInformation, AddressPurpose, Bip32DerivError,
+        Bip9SoftforkStatistics, BlockTemplateTransaction, BlockTemplateTransactionError, BumpFee,
+        BumpFeeError, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisigError,
+        CreatePsbt, CreateRawTransaction, CreateWallet, DecodeScriptError, EstimateSmartFee,
+        EstimateSmartFeeError, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, GenerateToAddress, GetAddedNodeInfo, GetAddressInfoEmbedded,
+        GetAddressInfoEmbeddedError, GetAddressInfoError, GetAddressesByLabel, GetBalance,
+        GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockTemplate, GetBlockTemplateError,
+        GetBlockVerbosityOne, GetBlockVerbosityTwoError, GetBlockVerbosityZero, GetConnectionCount,
+        GetMemoryInfoMallocInfo, GetMemoryInfoStats, GetMempoolAncestors, GetMempoolDescendants,
+        GetMempoolInfoError, GetMiningInfo, GetMiningInfoError, GetNetTotals, GetNetworkHashPs,
+        GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, GetNewAddress, GetRawMempool,
+        GetRawTransaction, GetRawTransactionVerboseError, GetTransaction, GetTransactionDetail,
+        GetTransactionDetailCategory, GetTxOut, GetTxOutSetInfoError, GetWalletInfoError,
+        ImportMulti, ImportMultiResult, JsonRpcError, ListLabels, ListSinceBlockError,
+        ListUnspentItemError, ListWallets, LoadWallet, Locked, MapMempoolEntryError,
+        MempoolEntryError, MempoolEntryFees, MempoolEntryFeesError, PrioritiseTransaction,
+        PsbtScript, PsbtUnknownError, RawTransactionError, RawTransactionInput,
+        RawTransactionScriptSig, ScanTxOutSetAbort, ScanTxOutSetError, ScanTxOutSetStatus,
+        SendMany, SendRawTransaction, SignFail, SignFailError, SignMessage, SignMessageWithPrivKey,
+        SignRawTransaction, SignRawTransactionError, SubmitBlock, TransactionItemError,
+        UploadTarget, Uptime, ValidateAddressError, VerifyMessage, WaitForBlock,
+        WaitForBlockHeight, WaitForNewBlock, WalletCreateFundedPsbt, WalletCreateFundedPsbtError,
+        WalletProcessPsbt, WitnessUtxo,
+    },
+    v18::{
+        ActiveCommand, AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing,
+        Bip32Deriv, DeriveAddresses, GetDescriptorInfo, GetRpcInfo, JoinPsbts, ListUnspent,
+        ListUnspentItem, ListWalletDir, ListWalletDirWallet, PsbtRole, ScanTxOutSetUnspent,
+        UtxoUpdatePsbt,
     },
     v19::{
-        Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalances, GetBalancesMine,
-        GetBalancesWatchOnly, GetBlockchainInfo, Softfork, SoftforkType,
+        Bip9SoftforkInfo, Bip9SoftforkStatus, GetBalances, GetBalancesMine, GetBalancesWatchOnly,
+        GetBlockFilter, GetBlockFilterError, GetBlockchainInfo, GetWalletInfoScanning,
+        PeerInfoError, ScanTxOutSetStart, Softfork, SoftforkType,
+    },
+    v20::{GetAddressInfo, ListSinceBlock, ListTransactions, TransactionItem},
+    v21::{
+        GetIndexInfo, GetMempoolAncestorsVerbose, GetMempoolDescendantsVerbose, GetMempoolEntry,
+        GetMempoolInfo, GetPeerInfo, GetRawMempoolSequence, GetRawMempoolVerbose,
+        ImportDescriptors, ImportDescriptorsResult, IndexInfo, MempoolAcceptanceFees, MempoolEntry,
+        PeerInfo, PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendManyVerbose,
+    },
+    v22::{
+        Banned, DecodeRawTransaction, DecodeScript, DecodeScriptSegwit, GetBlockVerbosityTwo,
+        GetBlockVerbosityTwoTransaction, GetNodeAddresses, GetRawTransactionVerbose,
+        GetTxOutSetInfo, ListBanned, ListDescriptors, ListDescriptorsError, ListDescriptorsItem,
+        Logging, MempoolAcceptance, NodeAddress, RawTransactionOutput, RawTransactionScriptPubkey,
+        SendToAddress, TestMempoolAccept, TestMempoolAcceptError, TxOutSetBlockInfo,
+        TxOutSetUnspendables, UnloadWallet,
     },
-    v22::{SendToAddress, UnloadWallet},
 };
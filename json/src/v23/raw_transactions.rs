@@ -0,0 +1,795 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v23 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+//!
+//! From v23 `decodepsbt` returns the global xpubs, the PSBT version, the proprietary fields and
+//! the hash preimages of the inputs.
+
+use std::collections::BTreeMap;
+use std::{fmt, num};
+
+use bitcoin::address::{self, Address, NetworkUnchecked};
+use bitcoin::bip32::{self, DerivationPath, Fingerprint, KeySource, Xpub};
+use bitcoin::hashes::{hash160, ripemd160, sha256, sha256d, Hash};
+use bitcoin::hex::{self, FromHex};
+use bitcoin::psbt::{self, raw, Psbt};
+use bitcoin::sighash::SighashTypeParseError;
+use bitcoin::{amount, ecdsa, key, Amount, PublicKey, ScriptBuf, TxOut, Witness};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::{
+    psbt_sighash_type, psbt_unknown, Bip32DerivError, PsbtScript, PsbtUnknownError,
+    RawTransactionError, RawTransactionScriptSig, WitnessUtxo,
+};
+use crate::v18::Bip32Deriv;
+use crate::v22::{DecodeRawTransaction, RawTransactionScriptPubkey};
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `decodepsbt`.
+///
+/// > decodepsbt "psbt"
+/// >
+/// > Return a JSON object representing the serialized, base64-encoded partially signed Bitcoin transaction.
+/// >
+/// > Arguments:
+/// > 1. psbt    (string, required) The PSBT base64 string
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The decoded network-serialized unsigned transaction.
+    pub tx: DecodeRawTransaction,
+    /// The global xpubs.
+    pub global_xpubs: Vec<GlobalXpub>,
+    /// The PSBT version number. Not to be confused with the unsigned transaction version.
+    pub psbt_version: i64,
+    /// The global proprietary map.
+    pub proprietary: Vec<PsbtProprietary>,
+    /// The unknown global fields.
+    pub unknown: BTreeMap<String, String>,
+    /// The PSBT inputs.
+    pub inputs: Vec<PsbtInput>,
+    /// The PSBT outputs.
+    pub outputs: Vec<PsbtOutput>,
+    /// The transaction fee paid if all UTXOs slots in the PSBT have been filled.
+    pub fee: Option<f64>,
+}
+
+impl DecodePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodePsbt, DecodePsbtError> {
+        use DecodePsbtError as E;
+
+        let unsigned_tx = self.tx.transaction().map_err(E::Tx)?;
+        let version = crate::to_u32(self.psbt_version, "psbt_version")?;
+        let xpub = self
+            .global_xpubs
+            .into_iter()
+            .map(|xpub| xpub.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::GlobalXpubs)?;
+        let proprietary = self
+            .proprietary
+            .into_iter()
+            .map(|proprietary| proprietary.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::Proprietary)?;
+        let unknown = psbt_unknown(self.unknown).map_err(E::Unknown)?;
+        let inputs = self
+            .inputs
+            .into_iter()
+            .map(|input| input.into_input())
+            .collect::<Result<_, _>>()
+            .map_err(E::Inputs)?;
+        let outputs = self
+            .outputs
+            .into_iter()
+            .map(|output| output.into_output())
+            .collect::<Result<_, _>>()
+            .map_err(E::Outputs)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        let psbt = Psbt { unsigned_tx, version, xpub, proprietary, unknown, inputs, outputs };
+        Ok(model::DecodePsbt { psbt, fee })
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, DecodePsbtError> { Ok(self.into_model()?.psbt) }
+}
+
+/// An extended public key with its BIP-32 key origin. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GlobalXpub {
+    /// The extended public key this path corresponds to.
+    pub xpub: String,
+    /// The fingerprint of the master key.
+    pub master_fingerprint: String,
+    /// The path.
+    pub path: String,
+}
+
+impl GlobalXpub {
+    /// Converts the xpub to a global xpub map entry.
+    pub fn into_model(self) -> Result<(Xpub, KeySource), GlobalXpubError> {
+        use GlobalXpubError as E;
+
+        let xpub = self.xpub.parse::<Xpub>().map_err(E::Xpub)?;
+        let fingerprint =
+            self.master_fingerprint.parse::<Fingerprint>().map_err(E::MasterFingerprint)?;
+        let path = self.path.parse::<DerivationPath>().map_err(E::Path)?;
+        Ok((xpub, (fingerprint, path)))
+    }
+}
+
+/// A proprietary key-value pair. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PsbtProprietary {
+    /// The hex string for the proprietary identifier.
+    pub identifier: String,
+    /// The number for the subtype.
+    pub subtype: i64,
+    /// The hex for the key.
+    pub key: String,
+    /// The hex for the value.
+    pub value: String,
+}
+
+impl PsbtProprietary {
+    /// Converts the key-value pair to a proprietary map entry.
+    pub fn into_model(self) -> Result<(raw::ProprietaryKey, Vec<u8>), PsbtProprietaryError> {
+        use PsbtProprietaryError as E;
+
+        let prefix = Vec::<u8>::from_hex(&self.identifier).map_err(E::Identifier)?;
+        let subtype = u8::try_from(self.subtype).map_err(E::Subtype)?;
+        let key = Vec::<u8>::from_hex(&self.key).map_err(E::Key)?;
+        let value = Vec::<u8>::from_hex(&self.value).map_err(E::Value)?;
+        Ok((raw::ProprietaryKey { prefix, subtype, key }, value))
+    }
+}
+
+/// An input of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtInput {
+    /// Decoded network transaction for non-witness UTXOs.
+    pub non_witness_utxo: Option<DecodeRawTransaction>,
+    /// Transaction output for witness UTXOs.
+    pub witness_utxo: Option<WitnessUtxo>,
+    /// The public keys with their signatures.
+    pub partial_signatures: Option<BTreeMap<String, String>>,
+    /// The sighash type to be used.
+    pub sighash: Option<String>,
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<Vec<Bip32Deriv>>,
+    /// The final scriptsig.
+    #[serde(rename = "final_scriptsig")]
+    pub final_script_sig: Option<RawTransactionScriptSig>,
+    /// The final script witness, hex-encoded witness data items.
+    #[serde(rename = "final_scriptwitness")]
+    pub final_script_witness: Option<Vec<String>>,
+    /// The RIPEMD160 hashes with their preimages.
+    pub ripemd160_preimages: Option<BTreeMap<String, String>>,
+    /// The SHA256 hashes with their preimages.
+    pub sha256_preimages: Option<BTreeMap<String, String>>,
+    /// The HASH160 hashes with their preimages.
+    pub hash160_preimages: Option<BTreeMap<String, String>>,
+    /// The HASH256 hashes with their preimages.
+    pub hash256_preimages: Option<BTreeMap<String, String>>,
+    /// The input proprietary map.
+    pub proprietary: Option<Vec<PsbtProprietary>>,
+    /// The unknown input fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtInput {
+    /// Converts the input to a `bitcoin::psbt::Input`.
+    pub fn into_input(self) -> Result<psbt::Input, PsbtInputError> {
+        use PsbtInputError as E;
+
+        let non_witness_utxo = self
+            .non_witness_utxo
+            .map(|tx| tx.transaction())
+            .transpose()
+            .map_err(E::NonWitnessUtxo)?;
+        let witness_utxo = match self.witness_utxo {
+            Some(utxo) => Some(TxOut {
+                value: Amount::from_btc(utxo.amount).map_err(E::WitnessUtxoAmount)?,
+                script_pubkey: ScriptBuf::from_hex(&utxo.script_pubkey.hex)
+                    .map_err(E::WitnessUtxoScriptPubkey)?,
+            }),
+            None => None,
+        };
+        let partial_sigs = self
+            .partial_signatures
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, sig)| {
+                let pubkey = pubkey.parse::<PublicKey>().map_err(E::PartialSignaturePubkey)?;
+                let sig = sig.parse::<ecdsa::Signature>().map_err(E::PartialSignature)?;
+                Ok((pubkey, sig))
+            })
+            .collect::<Result<_, _>>()?;
+        let sighash_type =
+            self.sighash.map(|s| psbt_sighash_type(&s)).transpose().map_err(E::Sighash)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_bip32_derivation())
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let final_script_sig = self
+            .final_script_sig
+            .map(|script_sig| ScriptBuf::from_hex(&script_sig.hex))
+            .transpose()
+            .map_err(E::FinalScriptSig)?;
+        let final_script_witness = self
+            .final_script_witness
+            .map(|witness| {
+                witness
+                    .iter()
+                    .map(|item| Vec::<u8>::from_hex(item))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|items| Witness::from_slice(&items))
+            })
+            .transpose()
+            .map_err(E::FinalScriptWitness)?;
+        let ripemd160_preimages =
+            psbt_preimages::<ripemd160::Hash>(self.ripemd160_preimages.unwrap_or_default())
+                .map_err(E::Ripemd160Preimages)?;
+        let sha256_preimages =
+            psbt_preimages::<sha256::Hash>(self.sha256_preimages.unwrap_or_default())
+                .map_err(E::Sha256Preimages)?;
+        let hash160_preimages =
+            psbt_preimages::<hash160::Hash>(self.hash160_preimages.unwrap_or_default())
+                .map_err(E::Hash160Preimages)?;
+        let hash256_preimages =
+            psbt_preimages::<sha256d::Hash>(self.hash256_preimages.unwrap_or_default())
+                .map_err(E::Hash256Preimages)?;
+        let proprietary = self
+            .proprietary
+            .unwrap_or_default()
+            .into_iter()
+            .map(|proprietary| proprietary.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::Proprietary)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Input {
+            non_witness_utxo,
+            witness_utxo,
+            partial_sigs,
+            sighash_type,
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            final_script_sig,
+            final_script_witness,
+            ripemd160_preimages,
+            sha256_preimages,
+            hash160_preimages,
+            hash256_preimages,
+            proprietary,
+            unknown,
+            ..Default::default()
+        })
+    }
+}
+
+/// An output of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtOutput {
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<Vec<Bip32Deriv>>,
+    /// The output proprietary map.
+    pub proprietary: Option<Vec<PsbtProprietary>>,
+    /// The unknown output fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtOutput {
+    /// Converts the output to a `bitcoin::psbt::Output`.
+    pub fn into_output(self) -> Result<psbt::Output, PsbtOutputError> {
+        use PsbtOutputError as E;
+
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_bip32_derivation())
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let proprietary = self
+            .proprietary
+            .unwrap_or_default()
+            .into_iter()
+            .map(|proprietary| proprietary.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::Proprietary)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Output {
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            proprietary,
+            unknown,
+            ..Default::default()
+        })
+    }
+}
+
+/// Error when converting a `DecodePsbt` type into the model type.
+#[derive(Debug)]
+pub enum DecodePsbtError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the unsigned transaction `tx` field failed.
+    Tx(RawTransactionError),
+    /// Conversion of the `global_xpubs` field failed.
+    GlobalXpubs(GlobalXpubError),
+    /// Conversion of the global `proprietary` field failed.
+    Proprietary(PsbtProprietaryError),
+    /// Conversion of the global `unknown` field failed.
+    Unknown(PsbtUnknownError),
+    /// Conversion of one of the PSBT inputs failed.
+    Inputs(PsbtInputError),
+    /// Conversion of one of the PSBT outputs failed.
+    Outputs(PsbtOutputError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+}
+
+impl fmt::Display for DecodePsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodePsbtError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Tx(ref e) => write_err!(f, "conversion of the `tx` field failed"; e),
+            E::GlobalXpubs(ref e) =>
+                write_err!(f, "conversion of the `global_xpubs` field failed"; e),
+            E::Proprietary(ref e) =>
+                write_err!(f, "conversion of the global `proprietary` field failed"; e),
+            E::Unknown(ref e) =>
+                write_err!(f, "conversion of the global `unknown` field failed"; e),
+            E::Inputs(ref e) => write_err!(f, "conversion of one of the PSBT inputs failed"; e),
+            E::Outputs(ref e) => write_err!(f, "conversion of one of the PSBT outputs failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DecodePsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodePsbtError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Tx(ref e) => Some(e),
+            E::GlobalXpubs(ref e) => Some(e),
+            E::Proprietary(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+            E::Inputs(ref e) => Some(e),
+            E::Outputs(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for DecodePsbtError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Error when converting a `PsbtInput` type into a `bitcoin::psbt::Input`.
+#[derive(Debug)]
+pub enum PsbtInputError {
+    /// Conversion of the `non_witness_utxo` field failed.
+    NonWitnessUtxo(RawTransactionError),
+    /// Conversion of the `witness_utxo` amount failed.
+    WitnessUtxoAmount(amount::ParseAmountError),
+    /// Conversion of the `witness_utxo` script pubkey failed.
+    WitnessUtxoScriptPubkey(hex::HexToBytesError),
+    /// Conversion of one of the `partial_signatures` public keys failed.
+    PartialSignaturePubkey(key::ParsePublicKeyError),
+    /// Conversion of one of the `partial_signatures` signatures failed.
+    PartialSignature(ecdsa::Error),
+    /// Conversion of the `sighash` field failed.
+    Sighash(SighashTypeParseError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivError),
+    /// Conversion of the `final_scriptsig` field failed.
+    FinalScriptSig(hex::HexToBytesError),
+    /// Conversion of the `final_scriptwitness` field failed.
+    FinalScriptWitness(hex::HexToBytesError),
+    /// Conversion of the `ripemd160_preimages` field failed.
+    Ripemd160Preimages(PsbtPreimageError),
+    /// Conversion of the `sha256_preimages` field failed.
+    Sha256Preimages(PsbtPreimageError),
+    /// Conversion of the `hash160_preimages` field failed.
+    Hash160Preimages(PsbtPreimageError),
+    /// Conversion of the `hash256_preimages` field failed.
+    Hash256Preimages(PsbtPreimageError),
+    /// Conversion of the `proprietary` field failed.
+    Proprietary(PsbtProprietaryError),
+    /// Conversion of the `unknown` field failed.
+    Unknown(PsbtUnknownError),
+}
+
+impl fmt::Display for PsbtInputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtInputError as E;
+
+        match *self {
+            E::NonWitnessUtxo(ref e) =>
+                write_err!(f, "conversion of the `non_witness_utxo` field failed"; e),
+            E::WitnessUtxoAmount(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` amount failed"; e),
+            E::WitnessUtxoScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` script pubkey failed"; e),
+            E::PartialSignaturePubkey(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` public key failed"; e),
+            E::PartialSignature(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` signature failed"; e),
+            E::Sighash(ref e) => write_err!(f, "conversion of the `sighash` field failed"; e),
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            E::Bip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+            E::FinalScriptSig(ref e) =>
+                write_err!(f, "conversion of the `final_scriptsig` field failed"; e),
+            E::FinalScriptWitness(ref e) =>
+                write_err!(f, "conversion of the `final_scriptwitness` field failed"; e),
+            E::Ripemd160Preimages(ref e) =>
+                write_err!(f, "conversion of the `ripemd160_preimages` field failed"; e),
+            E::Sha256Preimages(ref e) =>
+                write_err!(f, "conversion of the `sha256_preimages` field failed"; e),
+            E::Hash160Preimages(ref e) =>
+                write_err!(f, "conversion of the `hash160_preimages` field failed"; e),
+            E::Hash256Preimages(ref e) =>
+                write_err!(f, "conversion of the `hash256_preimages` field failed"; e),
+            E::Proprietary(ref e) =>
+                write_err!(f, "conversion of the `proprietary` field failed"; e),
+            E::Unknown(ref e) => write_err!(f, "conversion of the `unknown` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtInputError as E;
+
+        match *self {
+            E::NonWitnessUtxo(ref e) => Some(e),
+            E::WitnessUtxoAmount(ref e) => Some(e),
+            E::WitnessUtxoScriptPubkey(ref e) => Some(e),
+            E::PartialSignaturePubkey(ref e) => Some(e),
+            E::PartialSignature(ref e) => Some(e),
+            E::Sighash(ref e) => Some(e),
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+            E::Bip32Derivs(ref e) => Some(e),
+            E::FinalScriptSig(ref e) => Some(e),
+            E::FinalScriptWitness(ref e) => Some(e),
+            E::Ripemd160Preimages(ref e) => Some(e),
+            E::Sha256Preimages(ref e) => Some(e),
+            E::Hash160Preimages(ref e) => Some(e),
+            E::Hash256Preimages(ref e) => Some(e),
+            E::Proprietary(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `PsbtOutput` type into a `bitcoin::psbt::Output`.
+#[derive(Debug)]
+pub enum PsbtOutputError {
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivError),
+    /// Conversion of the `proprietary` field failed.
+    Proprietary(PsbtProprietaryError),
+    /// Conversion of the `unknown` field failed.
+    Unknown(PsbtUnknownError),
+}
+
+impl fmt::Display for PsbtOutputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtOutputError as E;
+
+        match *self {
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            E::Bip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+            E::Proprietary(ref e) =>
+                write_err!(f, "conversion of the `proprietary` field failed"; e),
+            E::Unknown(ref e) => write_err!(f, "conversion of the `unknown` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtOutputError as E;
+
+        match *self {
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+            E::Bip32Derivs(ref e) => Some(e),
+            E::Proprietary(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `GlobalXpub` type into a global xpub map entry.
+#[derive(Debug)]
+pub enum GlobalXpubError {
+    /// Conversion of the `xpub` field failed.
+    Xpub(bip32::Error),
+    /// Conversion of the `master_fingerprint` field failed.
+    MasterFingerprint(hex::HexToArrayError),
+    /// Conversion of the `path` field failed.
+    Path(bip32::Error),
+}
+
+impl fmt::Display for GlobalXpubError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GlobalXpubError as E;
+
+        match *self {
+            E::Xpub(ref e) => write_err!(f, "conversion of the `xpub` field failed"; e),
+            E::MasterFingerprint(ref e) =>
+                write_err!(f, "conversion of the `master_fingerprint` field failed"; e),
+            E::Path(ref e) => write_err!(f, "conversion of the `path` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GlobalXpubError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GlobalXpubError as E;
+
+        match *self {
+            E::Xpub(ref e) => Some(e),
+            E::MasterFingerprint(ref e) => Some(e),
+            E::Path(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `PsbtProprietary` type into a proprietary map entry.
+#[derive(Debug)]
+pub enum PsbtProprietaryError {
+    /// Conversion of the `identifier` field failed.
+    Identifier(hex::HexToBytesError),
+    /// The `subtype` field does not fit in a single byte.
+    Subtype(num::TryFromIntError),
+    /// Conversion of the `key` field failed.
+    Key(hex::HexToBytesError),
+    /// Conversion of the `value` field failed.
+    Value(hex::HexToBytesError),
+}
+
+impl fmt::Display for PsbtProprietaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtProprietaryError as E;
+
+        match *self {
+            E::Identifier(ref e) => write_err!(f, "conversion of the `identifier` field failed"; e),
+            E::Subtype(ref e) => write_err!(f, "conversion of the `subtype` field failed"; e),
+            E::Key(ref e) => write_err!(f, "conversion of the `key` field failed"; e),
+            E::Value(ref e) => write_err!(f, "conversion of the `value` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtProprietaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtProprietaryError as E;
+
+        match *self {
+            E::Identifier(ref e) => Some(e),
+            E::Subtype(ref e) => Some(e),
+            E::Key(ref e) => Some(e),
+            E::Value(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a map of hash preimages of a `decodepsbt` input.
+#[derive(Debug)]
+pub enum PsbtPreimageError {
+    /// Conversion of a hash failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of a preimage failed.
+    Preimage(hex::HexToBytesError),
+}
+
+impl fmt::Display for PsbtPreimageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtPreimageError as E;
+
+        match *self {
+            E::Hash(ref e) => write_err!(f, "conversion of a hash failed"; e),
+            E::Preimage(ref e) => write_err!(f, "conversion of a preimage failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtPreimageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtPreimageError as E;
+
+        match *self {
+            E::Hash(ref e) => Some(e),
+            E::Preimage(ref e) => Some(e),
+        }
+    }
+}
+
+/// A transaction input including the output it spends. Part of `getrawtransaction` and `getblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RawTransactionInputWithPrevout {
+    /// The coinbase script, hex-encoded (only present for coinbase transactions).
+    pub coinbase: Option<String>,
+    /// The transaction id (not present for coinbase transactions).
+    pub txid: Option<String>,
+    /// The output number (not present for coinbase transactions).
+    pub vout: Option<i64>,
+    /// The script (not present for coinbase transactions).
+    #[serde(rename = "scriptSig")]
+    pub script_sig: Option<RawTransactionScriptSig>,
+    /// Hex-encoded witness data (if any).
+    #[serde(rename = "txinwitness")]
+    pub witness: Option<Vec<String>>,
+    /// The script sequence number.
+    pub sequence: i64,
+    /// The output spent by this input, only if undo information is available.
+    pub prevout: Option<RawTransactionPrevout>,
+}
+
+/// The output spent by a transaction input. Part of `getrawtransaction` and `getblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RawTransactionPrevout {
+    /// Coinbase or not.
+    pub generated: bool,
+    /// The height of the prevout.
+    pub height: i64,
+    /// The value in BTC.
+    pub value: f64,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: RawTransactionScriptPubkey,
+}
+
+impl RawTransactionPrevout {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::RawTransactionPrevout, RawTransactionPrevoutError> {
+        use RawTransactionPrevoutError as E;
+
+        let tx_out = TxOut {
+            value: Amount::from_btc(self.value).map_err(E::Value)?,
+            script_pubkey: ScriptBuf::from_hex(&self.script_pubkey.hex).map_err(E::ScriptPubkey)?,
+        };
+        let address = self
+            .script_pubkey
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Address)?;
+
+        Ok(model::RawTransactionPrevout {
+            generated: self.generated,
+            height: crate::to_u32(self.height, "height")?,
+            tx_out,
+            address,
+        })
+    }
+}
+
+/// Error when converting a `RawTransactionPrevout` type into the model type.
+#[derive(Debug)]
+pub enum RawTransactionPrevoutError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `value` field failed.
+    Value(amount::ParseAmountError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+}
+
+impl fmt::Display for RawTransactionPrevoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use RawTransactionPrevoutError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Value(ref e) => write_err!(f, "conversion of the `value` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for RawTransactionPrevoutError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use RawTransactionPrevoutError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Value(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::Address(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for RawTransactionPrevoutError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Converts a `decodepsbt` map of hex-encoded hashes to their hex-encoded preimages.
+///
+/// Core encodes the hashes in byte order, not in the reversed order some hash types display in.
+pub(crate) fn psbt_preimages<H>(
+    preimages: BTreeMap<String, String>,
+) -> Result<BTreeMap<H, Vec<u8>>, PsbtPreimageError>
+where
+    H: Hash + Ord,
+    H::Bytes: FromHex<Error = hex::HexToArrayError>,
+{
+    use PsbtPreimageError as E;
+
+    preimages
+        .into_iter()
+        .map(|(hash, preimage)| {
+            let hash = H::from_byte_array(H::Bytes::from_hex(&hash).map_err(E::Hash)?);
+            let preimage = Vec::<u8>::from_hex(&preimage).map_err(E::Preimage)?;
+            Ok((hash, preimage))
+        })
+        .collect()
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v23 - util.
+//!
+//! Types for methods found under the `== Util ==` section of the API docs.
+//!
+//! From v23 `createmultisig` returns warnings and `validateaddress` locates errors in invalid
+//! addresses.
+
+use bitcoin::{Address, ScriptBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{witness_program, CreateMultisigError, ValidateAddressError};
+
+/// Result of JSON-RPC method `createmultisig`.
+///
+/// > createmultisig nrequired ["key",...] ( "address_type" )
+/// >
+/// > Creates a multi-signature address with n signature of m keys required.
+/// > It returns a json object with the address and redeemScript.
+/// >
+/// > Arguments:
+/// > 1. nrequired       (numeric, required) The number of required signatures out of the n keys.
+/// > 2. keys            (json array, required) The hex-encoded public keys.
+/// > 3. address_type    (string, optional, default="legacy") The address type to use. Options are "legacy", "p2sh-segwit", and "bech32".
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateMultisig {
+    /// The value of the new multisig address.
+    pub address: String,
+    /// The string value of the hex-encoded redemption script.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: String,
+    /// The descriptor for this multisig.
+    pub descriptor: String,
+    /// Any warnings resulting from the creation of this multisig.
+    pub warnings: Option<Vec<String>>,
+}
+
+impl CreateMultisig {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CreateMultisig, CreateMultisigError> {
+        use CreateMultisigError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let redeem_script = ScriptBuf::from_hex(&self.redeem_script).map_err(E::RedeemScript)?;
+
+        Ok(model::CreateMultisig {
+            address,
+            redeem_script,
+            descriptor: Some(self.descriptor),
+            warnings: self.warnings.unwrap_or_default(),
+        })
+    }
+}
+
+/// Result of JSON-RPC method `validateaddress`.
+///
+/// > validateaddress "address"
+/// >
+/// > Return information about the given bitcoin address.
+/// >
+/// > Arguments:
+/// > 1. address    (string, required) The bitcoin address to validate
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValidateAddress {
+    /// If the address is valid or not.
+    #[serde(rename = "isvalid")]
+    pub is_valid: bool,
+    /// The bitcoin address validated.
+    pub address: Option<String>,
+    /// The hex-encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: Option<String>,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: Option<bool>,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: Option<bool>,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+    /// Error message, if any.
+    pub error: Option<String>,
+    /// Indices of likely error locations in address, if known (e.g. Bech32 errors).
+    pub error_locations: Option<Vec<i64>>,
+}
+
+impl ValidateAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ValidateAddress, ValidateAddressError> {
+        use ValidateAddressError as E;
+
+        let address =
+            self.address.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::Address)?;
+        let script_pubkey = self
+            .script_pubkey
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::ScriptPubkey)?;
+        let witness_program = witness_program(self.witness_version, self.witness_program)?;
+        let error_locations = self
+            .error_locations
+            .map(|locations| {
+                locations
+                    .into_iter()
+                    .map(|i| crate::to_u32(i, "error_locations"))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        Ok(model::ValidateAddress {
+            is_valid: self.is_valid,
+            address,
+            script_pubkey,
+            is_script: self.is_script,
+            is_witness: self.is_witness,
+            witness_program,
+            error: self.error,
+            error_locations,
+        })
+    }
+}
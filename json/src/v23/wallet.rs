@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v23 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use bitcoin::hashes::hash160;
+use bitcoin::Amount;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::GetWalletInfoError;
+use crate::v19::GetWalletInfoScanning;
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: i64,
+    /// The database format (bdb or sqlite).
+    pub format: String,
+    /// DEPRECATED. The total confirmed balance of the wallet in BTC.
+    pub balance: Option<f64>,
+    /// DEPRECATED. The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: Option<f64>,
+    /// DEPRECATED. The total immature balance of the wallet in BTC.
+    pub immature_balance: Option<f64>,
+    /// The total number of transactions in the wallet.
+    #[serde(rename = "txcount")]
+    pub tx_count: i64,
+    /// The UNIX epoch time of the oldest pre-generated key in the key pool (legacy wallet only).
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: Option<i64>,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: i64,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    pub keypool_size_hd_internal: Option<i64>,
+    /// The UNIX epoch time until which the wallet is unlocked for transfers, or 0 if the wallet
+    /// is locked (only present for passphrase-encrypted wallets).
+    pub unlocked_until: Option<i64>,
+    /// The transaction fee configuration, set in BTC/kvB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse.
+    pub avoid_reuse: bool,
+    /// Current scanning details, or false if no scan is in progress.
+    pub scanning: GetWalletInfoScanning,
+    /// Whether this wallet uses descriptors for scriptPubKey management.
+    pub descriptors: bool,
+    /// Whether this wallet is configured to use an external signer such as a hardware wallet.
+    pub external_signer: bool,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let wallet_version = crate::to_u32(self.wallet_version, "walletversion")?;
+        let balance = self.balance.map(Amount::from_btc).transpose().map_err(E::Balance)?;
+        let unconfirmed_balance = self
+            .unconfirmed_balance
+            .map(Amount::from_btc)
+            .transpose()
+            .map_err(E::UnconfirmedBalance)?;
+        let immature_balance =
+            self.immature_balance.map(Amount::from_btc).transpose().map_err(E::ImmatureBalance)?;
+        let keypool_oldest =
+            self.keypool_oldest.map(|t| crate::to_u32(t, "keypoololdest")).transpose()?;
+        let tx_count = crate::to_u32(self.tx_count, "txcount")?;
+        let keypool_size = crate::to_u32(self.keypool_size, "keypoolsize")?;
+        let keypool_size_hd_internal = self
+            .keypool_size_hd_internal
+            .map(|n| crate::to_u32(n, "keypoolsize_hd_internal"))
+            .transpose()?;
+        let unlocked_until =
+            self.unlocked_until.map(|t| crate::to_u32(t, "unlocked_until")).transpose()?;
+        let pay_tx_fee = crate::btc_per_kb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version,
+            format: Some(self.format),
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count,
+            keypool_oldest,
+            keypool_size,
+            keypool_size_hd_internal,
+            unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            avoid_reuse: Some(self.avoid_reuse),
+            scanning: Some(self.scanning.into_model()),
+            descriptors: Some(self.descriptors),
+            external_signer: Some(self.external_signer),
+            blank: None,
+            birthtime: None,
+            last_processed_block: None,
+        })
+    }
+}
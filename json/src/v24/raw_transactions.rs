@@ -0,0 +1,948 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v24 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+//!
+//! From v24 `decodepsbt` returns the BIP-371 taproot fields of the inputs and outputs.
+
+use std::collections::BTreeMap;
+use std::{fmt, num};
+
+use bitcoin::bip32::{self, DerivationPath, Fingerprint, KeySource};
+use bitcoin::hashes::{hash160, ripemd160, sha256, sha256d};
+use bitcoin::hex::{self, FromHex as _};
+use bitcoin::key::XOnlyPublicKey;
+use bitcoin::psbt::{self, Psbt};
+use bitcoin::sighash::SighashTypeParseError;
+use bitcoin::taproot::{
+    self, ControlBlock, IncompleteBuilderError, LeafVersion, TapLeafHash, TapNodeHash, TapTree,
+    TaprootBuilder, TaprootBuilderError, TaprootError,
+};
+use bitcoin::{amount, ecdsa, key, secp256k1, Amount, PublicKey, ScriptBuf, TxOut, Witness};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::{
+    psbt_sighash_type, psbt_unknown, Bip32DerivError, PsbtScript, PsbtUnknownError,
+    RawTransactionError, RawTransactionScriptSig, WitnessUtxo,
+};
+use crate::v18::Bip32Deriv;
+use crate::v22::DecodeRawTransaction;
+use crate::v23::{
+    psbt_preimages, GlobalXpub, GlobalXpubError, PsbtPreimageError, PsbtProprietary,
+    PsbtProprietaryError,
+};
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `decodepsbt`.
+///
+/// > decodepsbt "psbt"
+/// >
+/// > Return a JSON object representing the serialized, base64-encoded partially signed Bitcoin transaction.
+/// >
+/// > Arguments:
+/// > 1. psbt    (string, required) The PSBT base64 string
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The decoded network-serialized unsigned transaction.
+    pub tx: DecodeRawTransaction,
+    /// The global xpubs.
+    pub global_xpubs: Vec<GlobalXpub>,
+    /// The PSBT version number. Not to be confused with the unsigned transaction version.
+    pub psbt_version: i64,
+    /// The global proprietary map.
+    pub proprietary: Vec<PsbtProprietary>,
+    /// The unknown global fields.
+    pub unknown: BTreeMap<String, String>,
+    /// The PSBT inputs.
+    pub inputs: Vec<PsbtInput>,
+    /// The PSBT outputs.
+    pub outputs: Vec<PsbtOutput>,
+    /// The transaction fee paid if all UTXOs slots in the PSBT have been filled.
+    pub fee: Option<f64>,
+}
+
+impl DecodePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodePsbt, DecodePsbtError> {
+        use DecodePsbtError as E;
+
+        let unsigned_tx = self.tx.transaction().map_err(E::Tx)?;
+        let version = crate::to_u32(self.psbt_version, "psbt_version")?;
+        let xpub = self
+            .global_xpubs
+            .into_iter()
+            .map(|xpub| xpub.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::GlobalXpubs)?;
+        let proprietary = self
+            .proprietary
+            .into_iter()
+            .map(|proprietary| proprietary.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::Proprietary)?;
+        let unknown = psbt_unknown(self.unknown).map_err(E::Unknown)?;
+        let inputs = self
+            .inputs
+            .into_iter()
+            .map(|input| input.into_input())
+            .collect::<Result<_, _>>()
+            .map_err(E::Inputs)?;
+        let outputs = self
+            .outputs
+            .into_iter()
+            .map(|output| output.into_output())
+            .collect::<Result<_, _>>()
+            .map_err(E::Outputs)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        let psbt = Psbt { unsigned_tx, version, xpub, proprietary, unknown, inputs, outputs };
+        Ok(model::DecodePsbt { psbt, fee })
+    }
+
+    /// Converts json straight to a `bitcoin::Psbt`.
+    pub fn psbt(self) -> Result<Psbt, DecodePsbtError> { Ok(self.into_model()?.psbt) }
+}
+
+/// An input of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtInput {
+    /// Decoded network transaction for non-witness UTXOs.
+    pub non_witness_utxo: Option<DecodeRawTransaction>,
+    /// Transaction output for witness UTXOs.
+    pub witness_utxo: Option<WitnessUtxo>,
+    /// The public keys with their signatures.
+    pub partial_signatures: Option<BTreeMap<String, String>>,
+    /// The sighash type to be used.
+    pub sighash: Option<String>,
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<Vec<Bip32Deriv>>,
+    /// The final scriptsig.
+    #[serde(rename = "final_scriptsig")]
+    pub final_script_sig: Option<RawTransactionScriptSig>,
+    /// The final script witness, hex-encoded witness data items.
+    #[serde(rename = "final_scriptwitness")]
+    pub final_script_witness: Option<Vec<String>>,
+    /// The RIPEMD160 hashes with their preimages.
+    pub ripemd160_preimages: Option<BTreeMap<String, String>>,
+    /// The SHA256 hashes with their preimages.
+    pub sha256_preimages: Option<BTreeMap<String, String>>,
+    /// The HASH160 hashes with their preimages.
+    pub hash160_preimages: Option<BTreeMap<String, String>>,
+    /// The HASH256 hashes with their preimages.
+    pub hash256_preimages: Option<BTreeMap<String, String>>,
+    /// Hex-encoded signature for the taproot key path spend.
+    pub taproot_key_path_sig: Option<String>,
+    /// The signatures for the taproot script path spends.
+    pub taproot_script_path_sigs: Option<Vec<TaprootScriptPathSig>>,
+    /// The taproot leaf scripts with their control blocks.
+    pub taproot_scripts: Option<Vec<TaprootScript>>,
+    /// The x-only public keys with their BIP-32 derivation paths and leaf hashes.
+    pub taproot_bip32_derivs: Option<Vec<TaprootBip32Deriv>>,
+    /// The hex-encoded taproot x-only internal key.
+    pub taproot_internal_key: Option<String>,
+    /// The hex-encoded taproot merkle root.
+    pub taproot_merkle_root: Option<String>,
+    /// The input proprietary map.
+    pub proprietary: Option<Vec<PsbtProprietary>>,
+    /// The unknown input fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtInput {
+    /// Converts the input to a `bitcoin::psbt::Input`.
+    pub fn into_input(self) -> Result<psbt::Input, PsbtInputError> {
+        use PsbtInputError as E;
+
+        let non_witness_utxo = self
+            .non_witness_utxo
+            .map(|tx| tx.transaction())
+            .transpose()
+            .map_err(E::NonWitnessUtxo)?;
+        let witness_utxo = match self.witness_utxo {
+            Some(utxo) => Some(TxOut {
+                value: Amount::from_btc(utxo.amount).map_err(E::WitnessUtxoAmount)?,
+                script_pubkey: ScriptBuf::from_hex(&utxo.script_pubkey.hex)
+                    .map_err(E::WitnessUtxoScriptPubkey)?,
+            }),
+            None => None,
+        };
+        let partial_sigs = self
+            .partial_signatures
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, sig)| {
+                let pubkey = pubkey.parse::<PublicKey>().map_err(E::PartialSignaturePubkey)?;
+                let sig = sig.parse::<ecdsa::Signature>().map_err(E::PartialSignature)?;
+                Ok((pubkey, sig))
+            })
+            .collect::<Result<_, _>>()?;
+        let sighash_type =
+            self.sighash.map(|s| psbt_sighash_type(&s)).transpose().map_err(E::Sighash)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_bip32_derivation())
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let final_script_sig = self
+            .final_script_sig
+            .map(|script_sig| ScriptBuf::from_hex(&script_sig.hex))
+            .transpose()
+            .map_err(E::FinalScriptSig)?;
+        let final_script_witness = self
+            .final_script_witness
+            .map(|witness| {
+                witness
+                    .iter()
+                    .map(|item| Vec::<u8>::from_hex(item))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|items| Witness::from_slice(&items))
+            })
+            .transpose()
+            .map_err(E::FinalScriptWitness)?;
+        let ripemd160_preimages =
+            psbt_preimages::<ripemd160::Hash>(self.ripemd160_preimages.unwrap_or_default())
+                .map_err(E::Ripemd160Preimages)?;
+        let sha256_preimages =
+            psbt_preimages::<sha256::Hash>(self.sha256_preimages.unwrap_or_default())
+                .map_err(E::Sha256Preimages)?;
+        let hash160_preimages =
+            psbt_preimages::<hash160::Hash>(self.hash160_preimages.unwrap_or_default())
+                .map_err(E::Hash160Preimages)?;
+        let hash256_preimages =
+            psbt_preimages::<sha256d::Hash>(self.hash256_preimages.unwrap_or_default())
+                .map_err(E::Hash256Preimages)?;
+        let tap_key_sig = self
+            .taproot_key_path_sig
+            .map(|sig| {
+                let sig = Vec::<u8>::from_hex(&sig).map_err(E::TaprootKeyPathSigHex)?;
+                taproot::Signature::from_slice(&sig).map_err(E::TaprootKeyPathSig)
+            })
+            .transpose()?;
+        let tap_script_sigs = self
+            .taproot_script_path_sigs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|sig| sig.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::TaprootScriptPathSigs)?;
+        let mut tap_scripts = BTreeMap::new();
+        for script in self.taproot_scripts.unwrap_or_default() {
+            tap_scripts.extend(script.into_model().map_err(E::TaprootScripts)?);
+        }
+        let tap_key_origins = self
+            .taproot_bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::TaprootBip32Derivs)?;
+        let tap_internal_key = self
+            .taproot_internal_key
+            .map(|key| key.parse::<XOnlyPublicKey>())
+            .transpose()
+            .map_err(E::TaprootInternalKey)?;
+        let tap_merkle_root = self
+            .taproot_merkle_root
+            .map(|root| root.parse::<TapNodeHash>())
+            .transpose()
+            .map_err(E::TaprootMerkleRoot)?;
+        let proprietary = self
+            .proprietary
+            .unwrap_or_default()
+            .into_iter()
+            .map(|proprietary| proprietary.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::Proprietary)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Input {
+            non_witness_utxo,
+            witness_utxo,
+            partial_sigs,
+            sighash_type,
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            final_script_sig,
+            final_script_witness,
+            ripemd160_preimages,
+            sha256_preimages,
+            hash160_preimages,
+            hash256_preimages,
+            tap_key_sig,
+            tap_script_sigs,
+            tap_scripts,
+            tap_key_origins,
+            tap_internal_key,
+            tap_merkle_root,
+            proprietary,
+            unknown,
+        })
+    }
+}
+
+/// An output of a PSBT. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtOutput {
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The public keys with their BIP-32 derivation paths.
+    pub bip32_derivs: Option<Vec<Bip32Deriv>>,
+    /// The hex-encoded taproot x-only internal key.
+    pub taproot_internal_key: Option<String>,
+    /// The leaves of the taproot tree, in depth-first search order.
+    pub taproot_tree: Option<Vec<TaprootLeaf>>,
+    /// The x-only public keys with their BIP-32 derivation paths and leaf hashes.
+    pub taproot_bip32_derivs: Option<Vec<TaprootBip32Deriv>>,
+    /// The output proprietary map.
+    pub proprietary: Option<Vec<PsbtProprietary>>,
+    /// The unknown output fields.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+impl PsbtOutput {
+    /// Converts the output to a `bitcoin::psbt::Output`.
+    pub fn into_output(self) -> Result<psbt::Output, PsbtOutputError> {
+        use PsbtOutputError as E;
+
+        let redeem_script = self
+            .redeem_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|script| ScriptBuf::from_hex(&script.hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+        let bip32_derivation = self
+            .bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_bip32_derivation())
+            .collect::<Result<_, _>>()
+            .map_err(E::Bip32Derivs)?;
+        let tap_internal_key = self
+            .taproot_internal_key
+            .map(|key| key.parse::<XOnlyPublicKey>())
+            .transpose()
+            .map_err(E::TaprootInternalKey)?;
+        let tap_tree = self
+            .taproot_tree
+            .map(|leaves| {
+                let mut builder = TaprootBuilder::new();
+                for leaf in leaves {
+                    let (depth, script, leaf_version) = leaf.into_model()?;
+                    builder = builder
+                        .add_leaf_with_ver(depth, script, leaf_version)
+                        .map_err(TaprootTreeError::Builder)?;
+                }
+                TapTree::try_from(builder).map_err(TaprootTreeError::IncompleteBuilder)
+            })
+            .transpose()
+            .map_err(E::TaprootTree)?;
+        let tap_key_origins = self
+            .taproot_bip32_derivs
+            .unwrap_or_default()
+            .into_iter()
+            .map(|deriv| deriv.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::TaprootBip32Derivs)?;
+        let proprietary = self
+            .proprietary
+            .unwrap_or_default()
+            .into_iter()
+            .map(|proprietary| proprietary.into_model())
+            .collect::<Result<_, _>>()
+            .map_err(E::Proprietary)?;
+        let unknown = psbt_unknown(self.unknown.unwrap_or_default()).map_err(E::Unknown)?;
+
+        Ok(psbt::Output {
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            tap_internal_key,
+            tap_tree,
+            tap_key_origins,
+            proprietary,
+            unknown,
+        })
+    }
+}
+
+/// A signature for a taproot script path spend. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TaprootScriptPathSig {
+    /// The x-only pubkey for this signature.
+    pub pubkey: String,
+    /// The leaf hash for this signature.
+    pub leaf_hash: String,
+    /// The signature itself.
+    pub sig: String,
+}
+
+impl TaprootScriptPathSig {
+    /// Converts the signature to a taproot script signature map entry.
+    pub fn into_model(
+        self,
+    ) -> Result<((XOnlyPublicKey, TapLeafHash), taproot::Signature), TaprootScriptPathSigError>
+    {
+        use TaprootScriptPathSigError as E;
+
+        let pubkey = self.pubkey.parse::<XOnlyPublicKey>().map_err(E::Pubkey)?;
+        let leaf_hash = self.leaf_hash.parse::<TapLeafHash>().map_err(E::LeafHash)?;
+        let sig = Vec::<u8>::from_hex(&self.sig).map_err(E::SigHex)?;
+        let sig = taproot::Signature::from_slice(&sig).map_err(E::Sig)?;
+        Ok(((pubkey, leaf_hash), sig))
+    }
+}
+
+/// A taproot leaf script with its control blocks. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TaprootScript {
+    /// A leaf script.
+    pub script: String,
+    /// The version number for the leaf script.
+    pub leaf_ver: i64,
+    /// The control blocks for this script.
+    pub control_blocks: Vec<String>,
+}
+
+impl TaprootScript {
+    /// Converts the script to a taproot script map with one entry per control block.
+    pub fn into_model(
+        self,
+    ) -> Result<BTreeMap<ControlBlock, (ScriptBuf, LeafVersion)>, TaprootScriptError> {
+        use TaprootScriptError as E;
+
+        let script = ScriptBuf::from_hex(&self.script).map_err(E::Script)?;
+        let leaf_ver = u8::try_from(self.leaf_ver).map_err(E::LeafVer)?;
+        let leaf_version = LeafVersion::from_consensus(leaf_ver).map_err(E::LeafVersion)?;
+        self.control_blocks
+            .iter()
+            .map(|control_block| {
+                let control_block =
+                    Vec::<u8>::from_hex(control_block).map_err(E::ControlBlockHex)?;
+                let control_block =
+                    ControlBlock::decode(&control_block).map_err(E::ControlBlock)?;
+                Ok((control_block, (script.clone(), leaf_version)))
+            })
+            .collect()
+    }
+}
+
+/// An x-only public key with its BIP-32 key origin and leaf hashes. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TaprootBip32Deriv {
+    /// The x-only public key this path corresponds to.
+    pub pubkey: String,
+    /// The fingerprint of the master key.
+    pub master_fingerprint: String,
+    /// The path.
+    pub path: String,
+    /// The hashes of the leaves this pubkey appears in.
+    pub leaf_hashes: Vec<String>,
+}
+
+impl TaprootBip32Deriv {
+    /// Converts the derivation to a taproot key origin map entry.
+    pub fn into_model(
+        self,
+    ) -> Result<(XOnlyPublicKey, (Vec<TapLeafHash>, KeySource)), TaprootBip32DerivError> {
+        use TaprootBip32DerivError as E;
+
+        let pubkey = self.pubkey.parse::<XOnlyPublicKey>().map_err(E::Pubkey)?;
+        let fingerprint =
+            self.master_fingerprint.parse::<Fingerprint>().map_err(E::MasterFingerprint)?;
+        let path = self.path.parse::<DerivationPath>().map_err(E::Path)?;
+        let leaf_hashes = self
+            .leaf_hashes
+            .iter()
+            .map(|hash| hash.parse::<TapLeafHash>())
+            .collect::<Result<_, _>>()
+            .map_err(E::LeafHashes)?;
+        Ok((pubkey, (leaf_hashes, (fingerprint, path))))
+    }
+}
+
+/// A leaf of a taproot tree. Part of `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TaprootLeaf {
+    /// The depth of this element in the tree.
+    pub depth: i64,
+    /// The version of this leaf.
+    pub leaf_ver: i64,
+    /// The hex-encoded script itself.
+    pub script: String,
+}
+
+impl TaprootLeaf {
+    /// Converts the leaf to its depth, script and leaf version.
+    pub fn into_model(self) -> Result<(u8, ScriptBuf, LeafVersion), TaprootTreeError> {
+        use TaprootTreeError as E;
+
+        let depth = u8::try_from(self.depth).map_err(E::Depth)?;
+        let leaf_ver = u8::try_from(self.leaf_ver).map_err(E::LeafVer)?;
+        let leaf_version = LeafVersion::from_consensus(leaf_ver).map_err(E::LeafVersion)?;
+        let script = ScriptBuf::from_hex(&self.script).map_err(E::Script)?;
+        Ok((depth, script, leaf_version))
+    }
+}
+
+/// Error when converting a `DecodePsbt` type into the model type.
+#[derive(Debug)]
+pub enum DecodePsbtError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the unsigned transaction `tx` field failed.
+    Tx(RawTransactionError),
+    /// Conversion of the `global_xpubs` field failed.
+    GlobalXpubs(GlobalXpubError),
+    /// Conversion of the global `proprietary` field failed.
+    Proprietary(PsbtProprietaryError),
+    /// Conversion of the global `unknown` field failed.
+    Unknown(PsbtUnknownError),
+    /// Conversion of one of the PSBT inputs failed.
+    Inputs(PsbtInputError),
+    /// Conversion of one of the PSBT outputs failed.
+    Outputs(PsbtOutputError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+}
+
+impl fmt::Display for DecodePsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodePsbtError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Tx(ref e) => write_err!(f, "conversion of the `tx` field failed"; e),
+            E::GlobalXpubs(ref e) =>
+                write_err!(f, "conversion of the `global_xpubs` field failed"; e),
+            E::Proprietary(ref e) =>
+                write_err!(f, "conversion of the global `proprietary` field failed"; e),
+            E::Unknown(ref e) =>
+                write_err!(f, "conversion of the global `unknown` field failed"; e),
+            E::Inputs(ref e) => write_err!(f, "conversion of one of the PSBT inputs failed"; e),
+            E::Outputs(ref e) => write_err!(f, "conversion of one of the PSBT outputs failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DecodePsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodePsbtError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Tx(ref e) => Some(e),
+            E::GlobalXpubs(ref e) => Some(e),
+            E::Proprietary(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+            E::Inputs(ref e) => Some(e),
+            E::Outputs(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for DecodePsbtError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Error when converting a `PsbtInput` type into a `bitcoin::psbt::Input`.
+#[derive(Debug)]
+pub enum PsbtInputError {
+    /// Conversion of the `non_witness_utxo` field failed.
+    NonWitnessUtxo(RawTransactionError),
+    /// Conversion of the `witness_utxo` amount failed.
+    WitnessUtxoAmount(amount::ParseAmountError),
+    /// Conversion of the `witness_utxo` script pubkey failed.
+    WitnessUtxoScriptPubkey(hex::HexToBytesError),
+    /// Conversion of one of the `partial_signatures` public keys failed.
+    PartialSignaturePubkey(key::ParsePublicKeyError),
+    /// Conversion of one of the `partial_signatures` signatures failed.
+    PartialSignature(ecdsa::Error),
+    /// Conversion of the `sighash` field failed.
+    Sighash(SighashTypeParseError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivError),
+    /// Conversion of the `final_scriptsig` field failed.
+    FinalScriptSig(hex::HexToBytesError),
+    /// Conversion of the `final_scriptwitness` field failed.
+    FinalScriptWitness(hex::HexToBytesError),
+    /// Conversion of the `ripemd160_preimages` field failed.
+    Ripemd160Preimages(PsbtPreimageError),
+    /// Conversion of the `sha256_preimages` field failed.
+    Sha256Preimages(PsbtPreimageError),
+    /// Conversion of the `hash160_preimages` field failed.
+    Hash160Preimages(PsbtPreimageError),
+    /// Conversion of the `hash256_preimages` field failed.
+    Hash256Preimages(PsbtPreimageError),
+    /// Conversion of the `taproot_key_path_sig` hex failed.
+    TaprootKeyPathSigHex(hex::HexToBytesError),
+    /// Conversion of the `taproot_key_path_sig` field failed.
+    TaprootKeyPathSig(taproot::SigFromSliceError),
+    /// Conversion of the `taproot_script_path_sigs` field failed.
+    TaprootScriptPathSigs(TaprootScriptPathSigError),
+    /// Conversion of the `taproot_scripts` field failed.
+    TaprootScripts(TaprootScriptError),
+    /// Conversion of the `taproot_bip32_derivs` field failed.
+    TaprootBip32Derivs(TaprootBip32DerivError),
+    /// Conversion of the `taproot_internal_key` field failed.
+    TaprootInternalKey(secp256k1::Error),
+    /// Conversion of the `taproot_merkle_root` field failed.
+    TaprootMerkleRoot(hex::HexToArrayError),
+    /// Conversion of the `proprietary` field failed.
+    Proprietary(PsbtProprietaryError),
+    /// Conversion of the `unknown` field failed.
+    Unknown(PsbtUnknownError),
+}
+
+impl fmt::Display for PsbtInputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtInputError as E;
+
+        match *self {
+            E::NonWitnessUtxo(ref e) =>
+                write_err!(f, "conversion of the `non_witness_utxo` field failed"; e),
+            E::WitnessUtxoAmount(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` amount failed"; e),
+            E::WitnessUtxoScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` script pubkey failed"; e),
+            E::PartialSignaturePubkey(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` public key failed"; e),
+            E::PartialSignature(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` signature failed"; e),
+            E::Sighash(ref e) => write_err!(f, "conversion of the `sighash` field failed"; e),
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            E::Bip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+            E::FinalScriptSig(ref e) =>
+                write_err!(f, "conversion of the `final_scriptsig` field failed"; e),
+            E::FinalScriptWitness(ref e) =>
+                write_err!(f, "conversion of the `final_scriptwitness` field failed"; e),
+            E::Ripemd160Preimages(ref e) =>
+                write_err!(f, "conversion of the `ripemd160_preimages` field failed"; e),
+            E::Sha256Preimages(ref e) =>
+                write_err!(f, "conversion of the `sha256_preimages` field failed"; e),
+            E::Hash160Preimages(ref e) =>
+                write_err!(f, "conversion of the `hash160_preimages` field failed"; e),
+            E::Hash256Preimages(ref e) =>
+                write_err!(f, "conversion of the `hash256_preimages` field failed"; e),
+            E::TaprootKeyPathSigHex(ref e) =>
+                write_err!(f, "conversion of the `taproot_key_path_sig` hex failed"; e),
+            E::TaprootKeyPathSig(ref e) =>
+                write_err!(f, "conversion of the `taproot_key_path_sig` field failed"; e),
+            E::TaprootScriptPathSigs(ref e) =>
+                write_err!(f, "conversion of the `taproot_script_path_sigs` field failed"; e),
+            E::TaprootScripts(ref e) =>
+                write_err!(f, "conversion of the `taproot_scripts` field failed"; e),
+            E::TaprootBip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `taproot_bip32_derivs` field failed"; e),
+            E::TaprootInternalKey(ref e) =>
+                write_err!(f, "conversion of the `taproot_internal_key` field failed"; e),
+            E::TaprootMerkleRoot(ref e) =>
+                write_err!(f, "conversion of the `taproot_merkle_root` field failed"; e),
+            E::Proprietary(ref e) =>
+                write_err!(f, "conversion of the `proprietary` field failed"; e),
+            E::Unknown(ref e) => write_err!(f, "conversion of the `unknown` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtInputError as E;
+
+        match *self {
+            E::NonWitnessUtxo(ref e) => Some(e),
+            E::WitnessUtxoAmount(ref e) => Some(e),
+            E::WitnessUtxoScriptPubkey(ref e) => Some(e),
+            E::PartialSignaturePubkey(ref e) => Some(e),
+            E::PartialSignature(ref e) => Some(e),
+            E::Sighash(ref e) => Some(e),
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+            E::Bip32Derivs(ref e) => Some(e),
+            E::FinalScriptSig(ref e) => Some(e),
+            E::FinalScriptWitness(ref e) => Some(e),
+            E::Ripemd160Preimages(ref e) => Some(e),
+            E::Sha256Preimages(ref e) => Some(e),
+            E::Hash160Preimages(ref e) => Some(e),
+            E::Hash256Preimages(ref e) => Some(e),
+            E::TaprootKeyPathSigHex(ref e) => Some(e),
+            E::TaprootKeyPathSig(ref e) => Some(e),
+            E::TaprootScriptPathSigs(ref e) => Some(e),
+            E::TaprootScripts(ref e) => Some(e),
+            E::TaprootBip32Derivs(ref e) => Some(e),
+            E::TaprootInternalKey(ref e) => Some(e),
+            E::TaprootMerkleRoot(ref e) => Some(e),
+            E::Proprietary(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `PsbtOutput` type into a `bitcoin::psbt::Output`.
+#[derive(Debug)]
+pub enum PsbtOutputError {
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivError),
+    /// Conversion of the `taproot_internal_key` field failed.
+    TaprootInternalKey(secp256k1::Error),
+    /// Conversion of the `taproot_tree` field failed.
+    TaprootTree(TaprootTreeError),
+    /// Conversion of the `taproot_bip32_derivs` field failed.
+    TaprootBip32Derivs(TaprootBip32DerivError),
+    /// Conversion of the `proprietary` field failed.
+    Proprietary(PsbtProprietaryError),
+    /// Conversion of the `unknown` field failed.
+    Unknown(PsbtUnknownError),
+}
+
+impl fmt::Display for PsbtOutputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtOutputError as E;
+
+        match *self {
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            E::Bip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+            E::TaprootInternalKey(ref e) =>
+                write_err!(f, "conversion of the `taproot_internal_key` field failed"; e),
+            E::TaprootTree(ref e) =>
+                write_err!(f, "conversion of the `taproot_tree` field failed"; e),
+            E::TaprootBip32Derivs(ref e) =>
+                write_err!(f, "conversion of the `taproot_bip32_derivs` field failed"; e),
+            E::Proprietary(ref e) =>
+                write_err!(f, "conversion of the `proprietary` field failed"; e),
+            E::Unknown(ref e) => write_err!(f, "conversion of the `unknown` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtOutputError as E;
+
+        match *self {
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+            E::Bip32Derivs(ref e) => Some(e),
+            E::TaprootInternalKey(ref e) => Some(e),
+            E::TaprootTree(ref e) => Some(e),
+            E::TaprootBip32Derivs(ref e) => Some(e),
+            E::Proprietary(ref e) => Some(e),
+            E::Unknown(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `TaprootScriptPathSig` type into a taproot script signature map entry.
+#[derive(Debug)]
+pub enum TaprootScriptPathSigError {
+    /// Conversion of the `pubkey` field failed.
+    Pubkey(secp256k1::Error),
+    /// Conversion of the `leaf_hash` field failed.
+    LeafHash(hex::HexToArrayError),
+    /// Conversion of the `sig` hex failed.
+    SigHex(hex::HexToBytesError),
+    /// Conversion of the `sig` field failed.
+    Sig(taproot::SigFromSliceError),
+}
+
+impl fmt::Display for TaprootScriptPathSigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TaprootScriptPathSigError as E;
+
+        match *self {
+            E::Pubkey(ref e) => write_err!(f, "conversion of the `pubkey` field failed"; e),
+            E::LeafHash(ref e) => write_err!(f, "conversion of the `leaf_hash` field failed"; e),
+            E::SigHex(ref e) => write_err!(f, "conversion of the `sig` hex failed"; e),
+            E::Sig(ref e) => write_err!(f, "conversion of the `sig` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TaprootScriptPathSigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TaprootScriptPathSigError as E;
+
+        match *self {
+            E::Pubkey(ref e) => Some(e),
+            E::LeafHash(ref e) => Some(e),
+            E::SigHex(ref e) => Some(e),
+            E::Sig(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `TaprootScript` type into taproot script map entries.
+#[derive(Debug)]
+pub enum TaprootScriptError {
+    /// Conversion of the `script` field failed.
+    Script(hex::HexToBytesError),
+    /// The `leaf_ver` field does not fit in a single byte.
+    LeafVer(num::TryFromIntError),
+    /// The `leaf_ver` field is not a valid leaf version.
+    LeafVersion(TaprootError),
+    /// Conversion of one of the `control_blocks` hex failed.
+    ControlBlockHex(hex::HexToBytesError),
+    /// Conversion of one of the `control_blocks` failed.
+    ControlBlock(TaprootError),
+}
+
+impl fmt::Display for TaprootScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TaprootScriptError as E;
+
+        match *self {
+            E::Script(ref e) => write_err!(f, "conversion of the `script` field failed"; e),
+            E::LeafVer(ref e) => write_err!(f, "conversion of the `leaf_ver` field failed"; e),
+            E::LeafVersion(ref e) => write_err!(f, "invalid `leaf_ver` field"; e),
+            E::ControlBlockHex(ref e) =>
+                write_err!(f, "conversion of a `control_blocks` hex failed"; e),
+            E::ControlBlock(ref e) =>
+                write_err!(f, "conversion of a `control_blocks` item failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TaprootScriptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TaprootScriptError as E;
+
+        match *self {
+            E::Script(ref e) => Some(e),
+            E::LeafVer(ref e) => Some(e),
+            E::LeafVersion(ref e) => Some(e),
+            E::ControlBlockHex(ref e) => Some(e),
+            E::ControlBlock(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `TaprootBip32Deriv` type into a taproot key origin map entry.
+#[derive(Debug)]
+pub enum TaprootBip32DerivError {
+    /// Conversion of the `pubkey` field failed.
+    Pubkey(secp256k1::Error),
+    /// Conversion of the `master_fingerprint` field failed.
+    MasterFingerprint(hex::HexToArrayError),
+    /// Conversion of the `path` field failed.
+    Path(bip32::Error),
+    /// Conversion of one of the `leaf_hashes` failed.
+    LeafHashes(hex::HexToArrayError),
+}
+
+impl fmt::Display for TaprootBip32DerivError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TaprootBip32DerivError as E;
+
+        match *self {
+            E::Pubkey(ref e) => write_err!(f, "conversion of the `pubkey` field failed"; e),
+            E::MasterFingerprint(ref e) =>
+                write_err!(f, "conversion of the `master_fingerprint` field failed"; e),
+            E::Path(ref e) => write_err!(f, "conversion of the `path` field failed"; e),
+            E::LeafHashes(ref e) =>
+                write_err!(f, "conversion of the `leaf_hashes` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TaprootBip32DerivError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TaprootBip32DerivError as E;
+
+        match *self {
+            E::Pubkey(ref e) => Some(e),
+            E::MasterFingerprint(ref e) => Some(e),
+            E::Path(ref e) => Some(e),
+            E::LeafHashes(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting the `taproot_tree` field into a `bitcoin::taproot::TapTree`.
+#[derive(Debug)]
+pub enum TaprootTreeError {
+    /// The `depth` field of a leaf does not fit in a single byte.
+    Depth(num::TryFromIntError),
+    /// The `leaf_ver` field of a leaf does not fit in a single byte.
+    LeafVer(num::TryFromIntError),
+    /// The `leaf_ver` field of a leaf is not a valid leaf version.
+    LeafVersion(TaprootError),
+    /// Conversion of the `script` field of a leaf failed.
+    Script(hex::HexToBytesError),
+    /// The leaves are not in depth-first search order.
+    Builder(TaprootBuilderError),
+    /// The leaves do not form a complete tree.
+    IncompleteBuilder(IncompleteBuilderError),
+}
+
+impl fmt::Display for TaprootTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TaprootTreeError as E;
+
+        match *self {
+            E::Depth(ref e) => write_err!(f, "conversion of the leaf `depth` field failed"; e),
+            E::LeafVer(ref e) => write_err!(f, "conversion of the leaf `leaf_ver` field failed"; e),
+            E::LeafVersion(ref e) => write_err!(f, "invalid leaf `leaf_ver` field"; e),
+            E::Script(ref e) => write_err!(f, "conversion of the leaf `script` field failed"; e),
+            E::Builder(ref e) => write_err!(f, "failed to add a leaf to the tree"; e),
+            E::IncompleteBuilder(ref e) =>
+                write_err!(f, "the leaves do not form a complete tree"; e),
+        }
+    }
+}
+
+impl std::error::Error for TaprootTreeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TaprootTreeError as E;
+
+        match *self {
+            E::Depth(ref e) => Some(e),
+            E::LeafVer(ref e) => Some(e),
+            E::LeafVersion(ref e) => Some(e),
+            E::Script(ref e) => Some(e),
+            E::Builder(ref e) => Some(e),
+            E::IncompleteBuilder(ref e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v24.2 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::consensus::encode;
+use bitcoin::{
+    Address, Amount, BlockHash, OutPoint, Psbt, ScriptBuf, SignedAmount, Transaction, Txid, Wtxid,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{
+    GetTransactionDetailCategory, ListSinceBlockError, ListUnspentItemError, TransactionItemError,
+};
+use crate::v21::SendError;
+
+/// Result of the JSON-RPC method `listsinceblock`.
+///
+/// > listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed include_change )
+/// >
+/// > Get all transactions in blocks since block [blockhash], or all transactions if omitted.
+/// > If "blockhash" is no longer a part of the main chain, transactions from the fork point onward are included.
+/// > Additionally, if include_removed is set, transactions affecting the wallet which were removed are returned in the "removed" array.
+/// >
+/// > Arguments:
+/// > 1. blockhash               (string, optional) If set, the block hash to list transactions since, otherwise list all transactions.
+/// > 2. target_confirmations    (numeric, optional, default=1) Return the nth block hash from the main chain. e.g. 1 would mean the best block hash. Note: this is not used as a filter, but only affects [lastblock] in the return value
+/// > 3. include_watchonly       (boolean, optional, default=true for watch-only wallets, otherwise false) Include transactions to watch-only addresses (see 'importaddress')
+/// > 4. include_removed         (boolean, optional, default=true) Show transactions that were removed due to a reorg in the "removed" array
+/// >                            (not guaranteed to work on pruned nodes)
+/// > 5. include_change          (boolean, optional, default=false) Also add entries for change outputs.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListSinceBlock {
+    /// All the transactions.
+    pub transactions: Vec<TransactionItem>,
+    /// Transactions that were removed due to a reorg, only present if `include_removed` is set.
+    #[serde(default)]
+    pub removed: Vec<TransactionItem>,
+    /// The hash of the block (target_confirmations-1) from the best block on the main chain.
+    #[serde(rename = "lastblock")]
+    pub last_block: String,
+}
+
+impl ListSinceBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListSinceBlock, ListSinceBlockError> {
+        use ListSinceBlockError as E;
+
+        let transactions = self
+            .transactions
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Transactions)?;
+        let removed = self
+            .removed
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Removed)?;
+        let last_block = self.last_block.parse::<BlockHash>().map_err(E::LastBlock)?;
+
+        Ok(model::ListSinceBlock { transactions, removed, last_block })
+    }
+}
+
+/// Result of the JSON-RPC method `listtransactions`.
+///
+/// > listtransactions ( "label" count skip include_watchonly )
+/// >
+/// > If a label name is provided, this will return only incoming transactions paying to addresses with the specified label.
+/// >
+/// > Returns up to 'count' most recent transactions skipping the first 'from' transactions.
+/// >
+/// > Arguments:
+/// > 1. label                (string, optional) If set, should be a valid label name to return only incoming transactions
+/// >                         with the specified label, or "*" to disable filtering and return all transactions.
+/// > 2. count                (numeric, optional, default=10) The number of transactions to return
+/// > 3. skip                 (numeric, optional, default=0) The number of transactions to skip
+/// > 4. include_watchonly    (boolean, optional, default=true for watch-only wallets, otherwise false) Include transactions to watch-only addresses (see 'importaddress')
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListTransactions(pub Vec<TransactionItem>);
+
+impl ListTransactions {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListTransactions, TransactionItemError> {
+        let transactions =
+            self.0.into_iter().map(|tx| tx.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListTransactions(transactions))
+    }
+}
+
+/// A wallet transaction, an item returned by `listtransactions` and `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TransactionItem {
+    /// Only returns true if imported addresses were involved in transaction.
+    #[serde(rename = "involvesWatchonly")]
+    pub involves_watch_only: Option<bool>,
+    /// The bitcoin address of the transaction.
+    pub address: Option<String>,
+    /// The transaction category.
+    pub category: GetTransactionDetailCategory,
+    /// The amount in BTC, negative for the `send` category.
+    pub amount: f64,
+    /// A comment for the address/transaction, if any.
+    pub label: Option<String>,
+    /// The vout value.
+    pub vout: u32,
+    /// The amount of the fee in BTC, negative and only available for the `send` category.
+    pub fee: Option<f64>,
+    /// The number of confirmations for the transaction.
+    ///
+    /// Negative confirmations indicate the transaction conflicts with the block chain.
+    pub confirmations: i64,
+    /// Only present if transaction only input is a coinbase one.
+    pub generated: Option<bool>,
+    /// Only present if we consider the transaction to be trusted and safe to spend from.
+    pub trusted: Option<bool>,
+    /// The block hash containing the transaction.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The block height containing the transaction.
+    #[serde(rename = "blockheight")]
+    pub block_height: Option<i64>,
+    /// The index of the transaction in the block that includes it.
+    #[serde(rename = "blockindex")]
+    pub block_index: Option<i64>,
+    /// The block time in seconds since epoch (1 Jan 1970 GMT).
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<u64>,
+    /// The transaction id.
+    pub txid: String,
+    /// The hash of serialized transaction, including witness data.
+    pub wtxid: String,
+    /// Conflicting transaction ids.
+    #[serde(rename = "walletconflicts")]
+    pub wallet_conflicts: Vec<String>,
+    /// The transaction time in seconds since epoch (midnight Jan 1 1970 GMT).
+    pub time: u64,
+    /// The time received in seconds since epoch (midnight Jan 1 1970 GMT).
+    #[serde(rename = "timereceived")]
+    pub time_received: u64,
+    /// If a comment is associated with the transaction.
+    pub comment: Option<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee).
+    ///
+    /// May be unknown for unconfirmed transactions not in the mempool.
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: String,
+    /// Only if `category` is `received`. List of parent descriptors for the scriptPubKey of this
+    /// coin.
+    pub parent_descs: Option<Vec<String>>,
+    /// Only available for the `send` category of transactions.
+    ///
+    /// True if the transaction has been abandoned (inputs are respendable).
+    pub abandoned: Option<bool>,
+}
+
+impl TransactionItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TransactionItem, TransactionItemError> {
+        use TransactionItemError as E;
+
+        let address = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Address)?;
+        let amount = SignedAmount::from_btc(self.amount).map_err(E::Amount)?;
+        let fee = self.fee.map(SignedAmount::from_btc).transpose().map_err(E::Fee)?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+        let block_height =
+            self.block_height.map(|h| crate::to_u32(h, "blockheight")).transpose()?;
+        let block_index = self.block_index.map(|i| crate::to_u32(i, "blockindex")).transpose()?;
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let wallet_conflicts = self
+            .wallet_conflicts
+            .iter()
+            .map(|s| s.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::WalletConflicts)?;
+
+        Ok(model::TransactionItem {
+            involves_watch_only: self.involves_watch_only,
+            address,
+            category: self.category.into_model(),
+            amount,
+            label: self.label,
+            vout: self.vout,
+            fee,
+            confirmations: self.confirmations,
+            generated: self.generated,
+            trusted: self.trusted,
+            block_hash,
+            block_height,
+            block_index,
+            block_time: self.block_time,
+            txid,
+            wtxid: Some(wtxid),
+            wallet_conflicts,
+            time: self.time,
+            time_received: self.time_received,
+            comment: self.comment,
+            bip125_replaceable: self.bip125_replaceable,
+            parent_descriptors: self.parent_descs,
+            abandoned: self.abandoned,
+        })
+    }
+}
+
+/// Result of the JSON-RPC method `listunspent`.
+///
+/// > listunspent ( minconf maxconf ["address",...] include_unsafe query_options )
+/// >
+/// > Returns array of unspent transaction outputs
+/// > with between minconf and maxconf (inclusive) confirmations.
+/// > Optionally filter to only include txouts paid to specified addresses.
+/// >
+/// > Arguments:
+/// > 1. minconf                            (numeric, optional, default=1) The minimum confirmations to filter
+/// > 2. maxconf                            (numeric, optional, default=9999999) The maximum confirmations to filter
+/// > 3. addresses                          (json array, optional, default=[]) The bitcoin addresses to filter
+/// > 4. include_unsafe                     (boolean, optional, default=true) Include outputs that are not safe to spend
+/// >                                       See description of "safe" attribute below.
+/// > 5. query_options                      (json object, optional) JSON with query options
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspent(pub Vec<ListUnspentItem>);
+
+impl ListUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspent, ListUnspentItemError> {
+        let unspent =
+            self.0.into_iter().map(|utxo| utxo.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListUnspent(unspent))
+    }
+}
+
+/// An unspent transaction output, an item returned by `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspentItem {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    pub vout: u32,
+    /// The bitcoin address.
+    pub address: Option<String>,
+    /// The associated label, or "" for the default label.
+    pub label: Option<String>,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// The transaction output amount in BTC.
+    pub amount: f64,
+    /// The number of confirmations.
+    pub confirmations: i64,
+    /// The redeemScript if scriptPubKey is P2SH.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: Option<String>,
+    /// The witnessScript if the scriptPubKey is P2WSH or P2SH-P2WSH.
+    #[serde(rename = "witnessScript")]
+    pub witness_script: Option<String>,
+    /// Whether we have the private keys to spend this output.
+    pub spendable: bool,
+    /// Whether we know how to spend this output, ignoring the lack of keys.
+    pub solvable: bool,
+    /// (only present if avoid_reuse is set) Whether this output is reused/dirty (sent to an
+    /// address that was previously spent from).
+    pub reused: Option<bool>,
+    /// A descriptor for spending this output (only when solvable).
+    pub desc: Option<String>,
+    /// List of parent descriptors for the scriptPubKey of this coin.
+    pub parent_descs: Vec<String>,
+    /// Whether this output is considered safe to spend.
+    ///
+    /// Unconfirmed transactions from outside keys and unconfirmed replacement transactions are
+    /// considered unsafe and are not eligible for spending by fundrawtransaction and
+    /// sendtoaddress.
+    pub safe: bool,
+}
+
+impl ListUnspentItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspentItem, ListUnspentItemError> {
+        use ListUnspentItemError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let address = self
+            .address
+            .map(|s| s.parse::<Address<NetworkUnchecked>>())
+            .transpose()
+            .map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let confirmations = crate::to_u32(self.confirmations, "confirmations")?;
+        let redeem_script = self
+            .redeem_script
+            .map(|hex| ScriptBuf::from_hex(&hex))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|hex| ScriptBuf::from_hex(&hex))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+
+        Ok(model::ListUnspentItem {
+            outpoint: OutPoint { txid, vout: self.vout },
+            address,
+            label: self.label,
+            script_pubkey,
+            amount,
+            confirmations,
+            redeem_script,
+            witness_script,
+            spendable: self.spendable,
+            solvable: self.solvable,
+            reused: self.reused,
+            descriptor: self.desc,
+            parent_descriptors: Some(self.parent_descs),
+            safe: self.safe,
+        })
+    }
+}
+
+/// Result of the JSON-RPC method `sendall`.
+///
+/// > sendall ["address",{"address":amount,...},...] ( conf_target "estimate_mode" fee_rate options )
+/// >
+/// > EXPERIMENTAL warning: this call may be changed in future releases.
+/// >
+/// > Spend the value of all (or specific) confirmed UTXOs in the wallet to one or more recipients.
+/// > Unconfirmed inbound UTXOs and locked UTXOs will not be spent. Sendall will respect the avoid_reuse wallet flag.
+/// > If your wallet contains many small inputs, either because it received tiny payments or as a result of accumulating change, consider using `send_max` to exclude inputs that are worth less than the fees needed to spend them.
+/// >
+/// > Arguments:
+/// > 1. recipients                        (json array, required) The sendall destinations. Each address may only appear once.
+/// >                                      Optionally some recipients can be specified with an amount to perform payments, but at least one address must appear without a specified amount.
+/// > 2. conf_target                       (numeric, optional, default=wallet -txconfirmtarget) Confirmation target in blocks
+/// > 3. estimate_mode                     (string, optional, default="unset") The fee estimate mode
+/// > 4. fee_rate                          (numeric or string, optional, default=not set, falls back to wallet fee estimation) Specify a fee rate in sat/vB.
+/// > 5. options                           (json object, optional)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SendAll {
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction id for the send, only returned if the transaction was complete.
+    pub txid: Option<String>,
+    /// If add_to_wallet is false, the hex-encoded raw transaction with signature(s).
+    pub hex: Option<String>,
+    /// If more signatures are needed, or if add_to_wallet is false, the base64-encoded
+    /// (partially) signed transaction.
+    pub psbt: Option<String>,
+}
+
+impl SendAll {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SendAll, SendError> {
+        use SendError as E;
+
+        let txid = self.txid.map(|s| s.parse::<Txid>()).transpose().map_err(E::Txid)?;
+        let tx = self
+            .hex
+            .map(|s| encode::deserialize_hex::<Transaction>(&s))
+            .transpose()
+            .map_err(E::Tx)?;
+        let psbt = self.psbt.map(|s| s.parse::<Psbt>()).transpose().map_err(E::Psbt)?;
+
+        Ok(model::SendAll { complete: self.complete, txid, tx, psbt })
+    }
+}
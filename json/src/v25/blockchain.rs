@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v25.2 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use core::fmt;
+
+use bitcoin::{hex, Amount, BlockHash, ScriptBuf, Txid};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::ScanTxOutSetError;
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `scanblocks` with action `abort`.
+///
+/// > scanblocks "action" ( [scanobjects,...] start_height stop_height "filtertype" "options" )
+/// >
+/// > Return relevant blockhashes for given descriptors (requires blockfilterindex).
+/// > This call may take several minutes. Make sure to use no RPC timeout (bitcoin-cli -rpcclienttimeout=0)
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksAbort(pub bool);
+
+impl ScanBlocksAbort {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ScanBlocksAbort { model::ScanBlocksAbort(self.0) }
+}
+
+/// Result of JSON-RPC method `scanblocks` with action `start`.
+///
+/// > scanblocks "action" ( [scanobjects,...] start_height stop_height "filtertype" "options" )
+/// >
+/// > Return relevant blockhashes for given descriptors (requires blockfilterindex).
+/// > This call may take several minutes. Make sure to use no RPC timeout (bitcoin-cli -rpcclienttimeout=0)
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksStart {
+    /// The height we started the scan from.
+    pub from_height: i64,
+    /// The height we ended the scan at.
+    pub to_height: i64,
+    /// Blocks that may have matched a scan object.
+    pub relevant_blocks: Vec<String>,
+}
+
+impl ScanBlocksStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanBlocksStart, ScanBlocksStartError> {
+        use ScanBlocksStartError as E;
+
+        let from_height = crate::to_u32(self.from_height, "from_height")?;
+        let to_height = crate::to_u32(self.to_height, "to_height")?;
+        let relevant_blocks = self
+            .relevant_blocks
+            .iter()
+            .map(|h| h.parse::<BlockHash>())
+            .collect::<Result<_, _>>()
+            .map_err(E::RelevantBlocks)?;
+
+        Ok(model::ScanBlocksStart { from_height, to_height, relevant_blocks, completed: None })
+    }
+}
+
+/// Error when converting a `ScanBlocksStart` type into the model type.
+#[derive(Debug)]
+pub enum ScanBlocksStartError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `relevant_blocks` field failed.
+    RelevantBlocks(hex::HexToArrayError),
+}
+
+impl fmt::Display for ScanBlocksStartError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ScanBlocksStartError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::RelevantBlocks(ref e) =>
+                write_err!(f, "conversion of the `relevant_blocks` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ScanBlocksStartError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ScanBlocksStartError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::RelevantBlocks(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for ScanBlocksStartError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `scanblocks` with action `status`.
+///
+/// Only returned if a scan is currently in progress, `null` otherwise.
+///
+/// > scanblocks "action" ( [scanobjects,...] start_height stop_height "filtertype" "options" )
+/// >
+/// > Return relevant blockhashes for given descriptors (requires blockfilterindex).
+/// > This call may take several minutes. Make sure to use no RPC timeout (bitcoin-cli -rpcclienttimeout=0)
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanBlocksStatus {
+    /// Approximate percent complete.
+    pub progress: f64,
+    /// Height of the block currently being scanned.
+    pub current_height: i64,
+}
+
+impl ScanBlocksStatus {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanBlocksStatus, NumericError> {
+        let current_height = crate::to_u32(self.current_height, "current_height")?;
+        Ok(model::ScanBlocksStatus { progress: self.progress, current_height })
+    }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action `start`.
+///
+/// > scantxoutset "action" ( [scanobjects,...] )
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+/// > 2. scanobjects                   (json array) Array of scan objects. Required for "start" action
+/// >                                  Every scan object is either a string descriptor or an object:
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// Whether the scan was completed.
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// The current block height (index).
+    pub height: i64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The unspent outputs found.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs in BTC.
+    pub total_amount: f64,
+}
+
+impl ScanTxOutSetStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetStart, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let unspents =
+            self.unspents.into_iter().map(|u| u.into_model()).collect::<Result<_, _>>()?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::ScanTxOutSetStart {
+            success: Some(self.success),
+            tx_outs: Some(self.tx_outs),
+            height: Some(height),
+            best_block: Some(best_block),
+            unspents,
+            total_amount,
+        })
+    }
+}
+
+/// An unspent output found by the scan. Part of `scantxoutset`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetUnspent {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    pub vout: i64,
+    /// The output script.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// A specialized descriptor for the matched output script.
+    #[serde(rename = "desc")]
+    pub descriptor: String,
+    /// The total amount in BTC of the unspent output.
+    pub amount: f64,
+    /// Whether this is a coinbase output.
+    pub coinbase: bool,
+    /// Height of the unspent transaction output.
+    pub height: i64,
+    /// Blockhash of the unspent transaction output.
+    #[serde(rename = "blockhash")]
+    pub block_hash: String,
+}
+
+impl ScanTxOutSetUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetUnspent, ScanTxOutSetError> {
+        use ScanTxOutSetError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let vout = crate::to_u32(self.vout, "vout")?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let height = crate::to_u32(self.height, "height")?;
+        let block_hash = self.block_hash.parse::<BlockHash>().map_err(E::BlockHash)?;
+
+        Ok(model::ScanTxOutSetUnspent {
+            txid,
+            vout,
+            script_pubkey,
+            descriptor: Some(self.descriptor),
+            amount,
+            coinbase: Some(self.coinbase),
+            height,
+            block_hash: Some(block_hash),
+        })
+    }
+}
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v25.2 - control.
+//!
+//! Types for methods found under the `== Control ==` section of the API docs.
+
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::model::LogCategory;
+
+/// Result of JSON-RPC method `logging`.
+///
+/// > logging ( ["include_category",...] ["exclude_category",...] )
+/// >
+/// > Gets and sets the logging configuration.
+/// > When called without an argument, returns the list of categories with status that are currently being debug logged or not.
+/// > When called with arguments, adds or removes categories from debug logging and return the lists above.
+/// > The arguments are evaluated in order "include", "exclude".
+/// > If an item is both included and excluded, it will thus end up being excluded.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Logging {
+    pub net: bool,
+    pub tor: bool,
+    pub mempool: bool,
+    pub http: bool,
+    pub bench: bool,
+    pub zmq: bool,
+    pub walletdb: bool,
+    pub rpc: bool,
+    pub estimatefee: bool,
+    pub addrman: bool,
+    pub selectcoins: bool,
+    pub reindex: bool,
+    pub cmpctblock: bool,
+    pub rand: bool,
+    pub prune: bool,
+    pub proxy: bool,
+    pub mempoolrej: bool,
+    pub libevent: bool,
+    pub coindb: bool,
+    pub qt: bool,
+    pub leveldb: bool,
+    pub validation: bool,
+    pub i2p: bool,
+    pub ipc: bool,
+    pub lock: bool,
+    pub blockstorage: bool,
+    pub txreconciliation: bool,
+    pub scan: bool,
+    /// Utilities, not returned by all builds of Core.
+    pub util: Option<bool>,
+}
+
+impl Logging {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        use LogCategory as C;
+
+        let categories = [
+            (C::Net, Some(self.net)),
+            (C::Tor, Some(self.tor)),
+            (C::Mempool, Some(self.mempool)),
+            (C::Http, Some(self.http)),
+            (C::Bench, Some(self.bench)),
+            (C::Zmq, Some(self.zmq)),
+            (C::WalletDb, Some(self.walletdb)),
+            (C::Rpc, Some(self.rpc)),
+            (C::EstimateFee, Some(self.estimatefee)),
+            (C::AddrMan, Some(self.addrman)),
+            (C::SelectCoins, Some(self.selectcoins)),
+            (C::Reindex, Some(self.reindex)),
+            (C::CmpctBlock, Some(self.cmpctblock)),
+            (C::Rand, Some(self.rand)),
+            (C::Prune, Some(self.prune)),
+            (C::Proxy, Some(self.proxy)),
+            (C::MempoolRej, Some(self.mempoolrej)),
+            (C::Libevent, Some(self.libevent)),
+            (C::CoinDb, Some(self.coindb)),
+            (C::Qt, Some(self.qt)),
+            (C::LevelDb, Some(self.leveldb)),
+            (C::Validation, Some(self.validation)),
+            (C::I2p, Some(self.i2p)),
+            (C::Ipc, Some(self.ipc)),
+            (C::Lock, Some(self.lock)),
+            (C::BlockStorage, Some(self.blockstorage)),
+            (C::TxReconciliation, Some(self.txreconciliation)),
+            (C::Scan, Some(self.scan)),
+            (C::Util, self.util),
+        ];
+        model::Logging(categories.into_iter().filter_map(|(c, v)| v.map(|v| (c, v))).collect())
+    }
+}
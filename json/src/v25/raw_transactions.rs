@@ -0,0 +1,352 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v25 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::consensus::encode;
+use bitcoin::{amount, hex, Amount, BlockHash, Transaction, Txid, Wtxid};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::reject_reason;
+use crate::v22::RawTransactionOutput;
+use crate::v23::{RawTransactionInputWithPrevout, RawTransactionPrevoutError};
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `getrawtransaction` with verbosity set to 2.
+///
+/// > getrawtransaction "txid" ( verbosity "blockhash" )
+/// >
+/// > By default, this call only returns a transaction if it is in the mempool. If -txindex is enabled
+/// > and no blockhash argument is passed, it will return the transaction if it is in the mempool or any block.
+/// > If a blockhash argument is passed, it will return the transaction if
+/// > the specified block is available and the transaction is in that block.
+/// >
+/// > Hint: Use gettransaction for wallet transactions.
+/// >
+/// > If verbosity is 0 or omitted, returns the serialized transaction as a hex-encoded string.
+/// > If verbosity is 1, returns a JSON Object with information about transaction.
+/// > If verbosity is 2, returns a JSON Object with information about transaction, including fee and prevout information.
+/// >
+/// > Arguments:
+/// > 1. txid         (string, required) The transaction id
+/// > 2. verbosity    (numeric, optional, default=0) 0 for hex-encoded data, 1 for a JSON object, and 2 for JSON object with fee and prevout
+/// > 3. blockhash    (string, optional) The block in which to look for the transaction
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransactionVerbosityTwo {
+    /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
+    pub in_active_chain: Option<bool>,
+    /// The serialized, hex-encoded data for 'txid'.
+    pub hex: String,
+    /// The transaction id (same as provided).
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The serialized transaction size.
+    pub size: i64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: i64,
+    /// The transaction's weight (between vsize*4-3 and vsize*4).
+    pub weight: i64,
+    /// The version.
+    pub version: i32,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: i64,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInputWithPrevout>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+    /// Transaction fee in BTC, omitted if block undo data is not available.
+    pub fee: Option<f64>,
+    /// The block hash.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The confirmations.
+    pub confirmations: Option<i64>,
+    /// Same as "blocktime".
+    #[serde(rename = "time")]
+    pub transaction_time: Option<i64>,
+    /// The block time expressed in UNIX epoch time.
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<i64>,
+}
+
+impl GetRawTransactionVerbosityTwo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::GetRawTransactionVerbosityTwo, GetRawTransactionVerbosityTwoError> {
+        use GetRawTransactionVerbosityTwoError as E;
+
+        let transaction =
+            encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Transaction)?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+        let prevouts = self
+            .vin
+            .into_iter()
+            .map(|input| input.prevout.map(|prevout| prevout.into_model()).transpose())
+            .collect::<Result<_, _>>()
+            .map_err(E::Prevout)?;
+        let block_hash =
+            self.block_hash.map(|s| s.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+        let confirmations =
+            self.confirmations.map(|n| crate::to_u32(n, "confirmations")).transpose()?;
+        let transaction_time =
+            self.transaction_time.map(|t| crate::to_u32(t, "transaction_time")).transpose()?;
+        let block_time = self.block_time.map(|t| crate::to_u32(t, "block_time")).transpose()?;
+
+        Ok(model::GetRawTransactionVerbosityTwo {
+            in_active_chain: self.in_active_chain,
+            transaction,
+            fee,
+            prevouts,
+            block_hash,
+            confirmations,
+            transaction_time,
+            block_time,
+        })
+    }
+}
+
+/// Error when converting a `GetRawTransactionVerbosityTwo` type into the model type.
+#[derive(Debug)]
+pub enum GetRawTransactionVerbosityTwoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the transaction `hex` field failed.
+    Transaction(encode::FromHexError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+    /// Conversion of an input `prevout` field failed.
+    Prevout(RawTransactionPrevoutError),
+    /// Conversion of the `block_hash` field failed.
+    BlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetRawTransactionVerbosityTwoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetRawTransactionVerbosityTwoError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Transaction(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+            E::Prevout(ref e) => write_err!(f, "conversion of an input `prevout` field failed"; e),
+            E::BlockHash(ref e) => write_err!(f, "conversion of the `block_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetRawTransactionVerbosityTwoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetRawTransactionVerbosityTwoError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Transaction(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+            E::Prevout(ref e) => Some(e),
+            E::BlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetRawTransactionVerbosityTwoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `testmempoolaccept`.
+///
+/// > testmempoolaccept ["rawtx",...] ( maxfeerate )
+/// >
+/// > Returns result of mempool acceptance tests indicating if raw transaction(s) (serialized, hex-encoded) would be accepted by mempool.
+/// >
+/// > If multiple transactions are passed in, parents must come before children and package policies apply: the transactions cannot conflict with any mempool transactions or each other.
+/// >
+/// > If one transaction fails, other transactions may not be fully validated (the 'allowed' key will be blank).
+/// >
+/// > The maximum number of transactions allowed is 25.
+/// >
+/// > This checks if transactions violate the consensus or policy rules.
+/// >
+/// > See sendrawtransaction call.
+/// >
+/// > Arguments:
+/// > 1. rawtxs       (json array, required) An array of hex strings of raw transactions.
+/// > 2. maxfeerate    (numeric or string, optional, default="0.10") Reject transactions whose fee rate is higher than the specified value, expressed in BTC/kvB
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
+
+impl TestMempoolAccept {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TestMempoolAccept, TestMempoolAcceptError> {
+        let results = self.0.into_iter().map(|r| r.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::TestMempoolAccept { results })
+    }
+}
+
+/// A single mempool acceptance test result. Part of `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptance {
+    /// The transaction hash in hex.
+    pub txid: String,
+    /// The transaction witness hash in hex.
+    pub wtxid: String,
+    /// Package validation error, if any (only possible if rawtxs had more than 1 transaction).
+    #[serde(rename = "package-error")]
+    pub package_error: Option<String>,
+    /// Whether this tx would be accepted to the mempool and pass client-specified maxfeerate.
+    ///
+    /// If not present, the tx was not fully validated due to a failure in another tx in the list.
+    pub allowed: Option<bool>,
+    /// Virtual transaction size as defined in BIP 141 (only present when 'allowed' is true).
+    pub vsize: Option<i64>,
+    /// Transaction fees (only present if 'allowed' is true).
+    pub fees: Option<MempoolAcceptanceFees>,
+    /// Rejection string (only present when 'allowed' is false).
+    #[serde(rename = "reject-reason")]
+    pub reject_reason: Option<String>,
+}
+
+impl MempoolAcceptance {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolAcceptance, TestMempoolAcceptError> {
+        use TestMempoolAcceptError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let vsize = self.vsize.map(|v| crate::to_u32(v, "vsize")).transpose()?;
+        let fees = self.fees.map(|f| f.into_model()).transpose().map_err(E::Fees)?;
+
+        Ok(model::MempoolAcceptance {
+            txid,
+            wtxid: Some(wtxid),
+            package_error: self.package_error,
+            allowed: self.allowed.unwrap_or(false),
+            vsize,
+            fees,
+            reject_reason: self.reject_reason.as_deref().map(reject_reason),
+            reject_message: self.reject_reason,
+        })
+    }
+}
+
+/// Transaction fees. Part of `testmempoolaccept` and `submitpackage`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolAcceptanceFees {
+    /// Transaction fee in BTC.
+    pub base: f64,
+    /// The effective feerate in BTC per KvB.
+    ///
+    /// May differ from the base feerate if, for example, there are modified fees from
+    /// prioritisetransaction or a package feerate was used.
+    #[serde(rename = "effective-feerate")]
+    pub effective_fee_rate: Option<f64>,
+    /// Transactions whose fees and vsizes are included in effective-feerate.
+    #[serde(rename = "effective-includes")]
+    pub effective_includes: Option<Vec<String>>,
+}
+
+impl MempoolAcceptanceFees {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolAcceptanceFees, MempoolAcceptanceFeesError> {
+        use MempoolAcceptanceFeesError as E;
+
+        let base = Amount::from_btc(self.base).map_err(E::Base)?;
+        let effective_fee_rate = match self.effective_fee_rate {
+            Some(rate) => crate::btc_per_kb(rate).map_err(E::EffectiveFeeRate)?,
+            None => None,
+        };
+        let effective_includes = self
+            .effective_includes
+            .map(|wtxids| wtxids.iter().map(|wtxid| wtxid.parse::<Wtxid>()).collect())
+            .transpose()
+            .map_err(E::EffectiveIncludes)?;
+
+        Ok(model::MempoolAcceptanceFees { base, effective_fee_rate, effective_includes })
+    }
+}
+
+/// Error when converting a `TestMempoolAccept` type into the model type.
+#[derive(Debug)]
+pub enum TestMempoolAcceptError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `wtxid` field failed.
+    Wtxid(hex::HexToArrayError),
+    /// Conversion of the `fees` field failed.
+    Fees(MempoolAcceptanceFeesError),
+}
+
+impl fmt::Display for TestMempoolAcceptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TestMempoolAcceptError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Wtxid(ref e) => write_err!(f, "conversion of the `wtxid` field failed"; e),
+            E::Fees(ref e) => write_err!(f, "conversion of the `fees` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TestMempoolAcceptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TestMempoolAcceptError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::Wtxid(ref e) => Some(e),
+            E::Fees(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for TestMempoolAcceptError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Error when converting a `MempoolAcceptanceFees` type into the model type.
+#[derive(Debug)]
+pub enum MempoolAcceptanceFeesError {
+    /// Conversion of the `base` field failed.
+    Base(amount::ParseAmountError),
+    /// Conversion of the `effective_fee_rate` field failed.
+    EffectiveFeeRate(amount::ParseAmountError),
+    /// Conversion of one of the `effective_includes` wtxids failed.
+    EffectiveIncludes(hex::HexToArrayError),
+}
+
+impl fmt::Display for MempoolAcceptanceFeesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use MempoolAcceptanceFeesError as E;
+
+        match *self {
+            E::Base(ref e) => write_err!(f, "conversion of the `base` field failed"; e),
+            E::EffectiveFeeRate(ref e) =>
+                write_err!(f, "conversion of the `effective_fee_rate` field failed"; e),
+            E::EffectiveIncludes(ref e) =>
+                write_err!(f, "conversion of one of the `effective_includes` wtxids failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for MempoolAcceptanceFeesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use MempoolAcceptanceFeesError as E;
+
+        match *self {
+            E::Base(ref e) => Some(e),
+            E::EffectiveFeeRate(ref e) => Some(e),
+            E::EffectiveIncludes(ref e) => Some(e),
+        }
+    }
+}
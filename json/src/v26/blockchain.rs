@@ -0,0 +1,409 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26.2 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use core::fmt;
+
+use bitcoin::{hex, Amount, BlockHash};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v17::GetTxOutSetInfoError;
+use crate::v22::TxOutSetBlockInfo;
+use crate::v25::ScanBlocksStartError;
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `dumptxoutset`.
+///
+/// > dumptxoutset "path"
+/// >
+/// > Write the serialized UTXO set to disk.
+/// >
+/// > Arguments:
+/// > 1. path    (string, required) Path to the output file. If relative, will be prefixed by datadir.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DumpTxOutSet {
+    /// The number of coins written in the snapshot.
+    pub coins_written: u64,
+    /// The hash of the base of the snapshot.
+    pub base_hash: String,
+    /// The height of the base of the snapshot.
+    pub base_height: i64,
+    /// The absolute path that the snapshot was written to.
+    pub path: String,
+    /// The hash of the UTXO set contents.
+    pub txoutset_hash: String,
+    /// The number of transactions in the chain up to and including the base block.
+    #[serde(rename = "nchaintx")]
+    pub n_chain_tx: u64,
+}
+
+impl DumpTxOutSet {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DumpTxOutSet, DumpTxOutSetError> {
+        use DumpTxOutSetError as E;
+
+        let base_hash = self.base_hash.parse::<BlockHash>().map_err(E::BaseHash)?;
+        let base_height = crate::to_u32(self.base_height, "base_height")?;
+
+        Ok(model::DumpTxOutSet {
+            coins_written: self.coins_written,
+            base_hash,
+            base_height,
+            path: self.path,
+            txoutset_hash: self.txoutset_hash,
+            n_chain_tx: self.n_chain_tx,
+        })
+    }
+}
+
+/// Error when converting a `DumpTxOutSet` type into the model type.
+#[derive(Debug)]
+pub enum DumpTxOutSetError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `base_hash` field failed.
+    BaseHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for DumpTxOutSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DumpTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::BaseHash(ref e) => write_err!(f, "conversion of the `base_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DumpTxOutSetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DumpTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::BaseHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for DumpTxOutSetError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `scanblocks` with action `start`.
+///
+/// > scanblocks "action" ( [scanobjects,...] start_height stop_height "filtertype" options )
+/// >
+/// > Return relevant blockhashes for given descriptors (requires blockfilterindex).
+/// > This call may take several minutes. Make sure to use no RPC timeout (bitcoin-cli -rpcclienttimeout=0)
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksStart {
+    /// The height we started the scan from.
+    pub from_height: i64,
+    /// The height we ended the scan at.
+    pub to_height: i64,
+    /// Blocks that may have matched a scan object.
+    pub relevant_blocks: Vec<String>,
+    /// True if the scan process was not aborted.
+    pub completed: bool,
+}
+
+impl ScanBlocksStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanBlocksStart, ScanBlocksStartError> {
+        use ScanBlocksStartError as E;
+
+        let from_height = crate::to_u32(self.from_height, "from_height")?;
+        let to_height = crate::to_u32(self.to_height, "to_height")?;
+        let relevant_blocks = self
+            .relevant_blocks
+            .iter()
+            .map(|h| h.parse::<BlockHash>())
+            .collect::<Result<_, _>>()
+            .map_err(E::RelevantBlocks)?;
+
+        Ok(model::ScanBlocksStart {
+            from_height,
+            to_height,
+            relevant_blocks,
+            completed: Some(self.completed),
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getchainstates`.
+///
+/// > getchainstates
+/// >
+/// > Return information about chainstates.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetChainStates {
+    /// The number of headers seen so far.
+    pub headers: i64,
+    /// List of the chainstates ordered by work, with the most-work (active) chainstate last.
+    #[serde(rename = "chainstates")]
+    pub chain_states: Vec<ChainState>,
+}
+
+impl GetChainStates {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetChainStates, GetChainStatesError> {
+        let headers = crate::to_u32(self.headers, "headers")?;
+        let chain_states =
+            self.chain_states.into_iter().map(|c| c.into_model()).collect::<Result<_, _>>()?;
+
+        Ok(model::GetChainStates { headers, chain_states })
+    }
+}
+
+/// A single chainstate. Part of `getchainstates`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChainState {
+    /// Number of blocks in this chainstate.
+    pub blocks: i64,
+    /// Blockhash of the tip.
+    #[serde(rename = "bestblockhash")]
+    pub best_block_hash: String,
+    /// Difficulty of the tip.
+    pub difficulty: f64,
+    /// Progress towards the network tip.
+    #[serde(rename = "verificationprogress")]
+    pub verification_progress: f64,
+    /// The base block of the snapshot this chainstate is based on, if any.
+    #[serde(rename = "snapshot_blockhash")]
+    pub snapshot_block_hash: Option<String>,
+    /// Size of the coinsdb cache.
+    pub coins_db_cache_bytes: u64,
+    /// Size of the coinstip cache.
+    pub coins_tip_cache_bytes: u64,
+    /// Whether the chainstate is fully validated.
+    ///
+    /// True if all blocks in the chainstate were validated, false if the chain is based on a
+    /// snapshot and the snapshot has not yet been validated.
+    pub validated: bool,
+}
+
+impl ChainState {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ChainState, GetChainStatesError> {
+        use GetChainStatesError as E;
+
+        let blocks = crate::to_u32(self.blocks, "blocks")?;
+        let best_block_hash =
+            self.best_block_hash.parse::<BlockHash>().map_err(E::BestBlockHash)?;
+        let snapshot_block_hash = self
+            .snapshot_block_hash
+            .map(|hash| hash.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::SnapshotBlockHash)?;
+
+        Ok(model::ChainState {
+            blocks,
+            best_block_hash,
+            difficulty: self.difficulty,
+            verification_progress: self.verification_progress,
+            snapshot_block_hash,
+            coins_db_cache_bytes: self.coins_db_cache_bytes,
+            coins_tip_cache_bytes: self.coins_tip_cache_bytes,
+            validated: self.validated,
+        })
+    }
+}
+
+/// Error when converting a `GetChainStates` type into the model type.
+#[derive(Debug)]
+pub enum GetChainStatesError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `best_block_hash` field failed.
+    BestBlockHash(hex::HexToArrayError),
+    /// Conversion of the `snapshot_block_hash` field failed.
+    SnapshotBlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetChainStatesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetChainStatesError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::BestBlockHash(ref e) =>
+                write_err!(f, "conversion of the `best_block_hash` field failed"; e),
+            E::SnapshotBlockHash(ref e) =>
+                write_err!(f, "conversion of the `snapshot_block_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetChainStatesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetChainStatesError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::BestBlockHash(ref e) => Some(e),
+            E::SnapshotBlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetChainStatesError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" hash_or_height use_index )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time if you are not using coinstatsindex.
+/// >
+/// > Arguments:
+/// > 1. hash_type         (string, optional, default="hash_serialized_3") Which UTXO set hash should be calculated. Options: 'hash_serialized_3' (the legacy algorithm), 'muhash', 'none'.
+/// > 2. hash_or_height    (string or numeric, optional, default=the current best block) The block hash or height of the target height (only available with coinstatsindex).
+/// > 3. use_index         (boolean, optional, default=true) Use coinstatsindex, if available.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The block height (index) of the returned statistics.
+    pub height: i64,
+    /// The hash of the block at which these statistics are calculated.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// Database-independent, meaningless metric indicating the UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash (only present if 'hash_serialized_3' hash_type is chosen).
+    pub hash_serialized_3: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The number of transactions with unspent outputs (not available when coinstatsindex is used).
+    pub transactions: Option<u64>,
+    /// The estimated size of the chainstate on disk (not available when coinstatsindex is used).
+    pub disk_size: Option<u64>,
+    /// The total amount of coins in the UTXO set.
+    pub total_amount: f64,
+    /// The total amount of coins permanently excluded from the UTXO set (only available if
+    /// coinstatsindex is used).
+    pub total_unspendable_amount: Option<f64>,
+    /// Info on amounts in the block at this block height (only available if coinstatsindex is
+    /// used).
+    pub block_info: Option<TxOutSetBlockInfo>,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+        let total_unspendable_amount = self
+            .total_unspendable_amount
+            .map(Amount::from_btc)
+            .transpose()
+            .map_err(E::TotalUnspendableAmount)?;
+        let block_info =
+            self.block_info.map(|info| info.into_model()).transpose().map_err(E::BlockInfo)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height,
+            best_block,
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: self.hash_serialized_3,
+            muhash: self.muhash,
+            transactions: self.transactions,
+            disk_size: self.disk_size,
+            total_amount,
+            total_unspendable_amount,
+            block_info,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `loadtxoutset`.
+///
+/// > loadtxoutset "path"
+/// >
+/// > Load the serialized UTXO set from a file.
+/// > Once this snapshot is loaded, its contents will be deserialized into a second chainstate data structure, which is then used to sync to the network's tip. Meanwhile, the original chainstate will complete the initial block download process in the background, eventually validating up to the block that the snapshot is based upon.
+/// >
+/// > Arguments:
+/// > 1. path    (string, required) path to the snapshot file. If relative, will be prefixed by datadir.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct LoadTxOutSet {
+    /// The number of coins loaded from the snapshot.
+    pub coins_loaded: u64,
+    /// The hash of the base of the snapshot.
+    pub tip_hash: String,
+    /// The height of the base of the snapshot.
+    pub base_height: i64,
+    /// The absolute path that the snapshot was loaded from.
+    pub path: String,
+}
+
+impl LoadTxOutSet {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::LoadTxOutSet, LoadTxOutSetError> {
+        use LoadTxOutSetError as E;
+
+        let tip_hash = self.tip_hash.parse::<BlockHash>().map_err(E::TipHash)?;
+        let base_height = crate::to_u32(self.base_height, "base_height")?;
+
+        Ok(model::LoadTxOutSet {
+            coins_loaded: self.coins_loaded,
+            tip_hash,
+            base_height,
+            path: self.path,
+        })
+    }
+}
+
+/// Error when converting a `LoadTxOutSet` type into the model type.
+#[derive(Debug)]
+pub enum LoadTxOutSetError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the `tip_hash` field failed.
+    TipHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for LoadTxOutSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use LoadTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::TipHash(ref e) => write_err!(f, "conversion of the `tip_hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for LoadTxOutSetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use LoadTxOutSetError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::TipHash(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for LoadTxOutSetError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
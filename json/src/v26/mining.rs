@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26 - mining.
+//!
+//! Types for methods found under the `== Mining ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use bitcoin::{hex, Amount, SignedAmount, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of the JSON-RPC method `getprioritisedtransactions`.
+///
+/// > getprioritisedtransactions
+/// >
+/// > Returns a map of all user-created (see prioritisetransaction) fee deltas by txid, and whether the tx is present in mempool.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPrioritisedTransactions(
+    /// Prioritisation keyed by txid.
+    pub BTreeMap<String, PrioritisedTransaction>,
+);
+
+/// A prioritised transaction. Part of `getprioritisedtransactions`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PrioritisedTransaction {
+    /// Transaction fee delta in satoshis.
+    pub fee_delta: i64,
+    /// Whether this transaction is currently in mempool.
+    pub in_mempool: bool,
+    /// Modified fee in satoshis. Only returned if in_mempool=true.
+    pub modified_fee: Option<u64>,
+}
+
+impl GetPrioritisedTransactions {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPrioritisedTransactions, hex::HexToArrayError> {
+        let map = self
+            .0
+            .into_iter()
+            .map(|(txid, tx)| txid.parse::<Txid>().map(|txid| (txid, tx.into_model())))
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+        Ok(model::GetPrioritisedTransactions(map))
+    }
+}
+
+impl PrioritisedTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::PrioritisedTransaction {
+        model::PrioritisedTransaction {
+            fee_delta: SignedAmount::from_sat(self.fee_delta),
+            in_mempool: self.in_mempool,
+            modified_fee: self.modified_fee.map(Amount::from_sat),
+        }
+    }
+}
@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::consensus::encode;
+use bitcoin::psbt::{Psbt, PsbtParseError};
+use bitcoin::{hex, Transaction, Txid, Wtxid};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::v25::{MempoolAcceptanceFees, MempoolAcceptanceFeesError};
+use crate::{model, NumericError};
+
+/// Result of JSON-RPC method `descriptorprocesspsbt`.
+///
+/// > descriptorprocesspsbt "psbt" ["",{"desc":"str","range":n or [n,n]},...] ( "sighashtype" bip32derivs finalize )
+/// >
+/// > Update all segwit inputs in a PSBT with information from output descriptors, the UTXO set or the mempool.
+/// > Then, sign the inputs we are able to with information from the output descriptors.
+/// >
+/// > Arguments:
+/// > 1. psbt                          (string, required) The transaction base64 string
+/// > 2. descriptors                   (json array, required) An array of either strings or objects
+/// > 3. sighashtype                   (string, optional, default="DEFAULT for Taproot, ALL otherwise") The signature hash type to sign with if not specified by the PSBT.
+/// > 4. bip32derivs                   (boolean, optional, default=true) Include BIP 32 derivation paths for public keys if we know them
+/// > 5. finalize                      (boolean, optional, default=true) Also finalize inputs if possible
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DescriptorProcessPsbt {
+    /// The base64-encoded partially signed transaction.
+    pub psbt: String,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The hex-encoded network transaction if complete.
+    pub hex: Option<String>,
+}
+
+impl DescriptorProcessPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DescriptorProcessPsbt, DescriptorProcessPsbtError> {
+        use DescriptorProcessPsbtError as E;
+
+        let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
+        let tx = self
+            .hex
+            .map(|s| encode::deserialize_hex::<Transaction>(&s))
+            .transpose()
+            .map_err(E::Tx)?;
+
+        Ok(model::DescriptorProcessPsbt { psbt, complete: self.complete, tx })
+    }
+}
+
+/// Error when converting a `DescriptorProcessPsbt` type into the model type.
+#[derive(Debug)]
+pub enum DescriptorProcessPsbtError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the transaction `hex` field failed.
+    Tx(encode::FromHexError),
+}
+
+impl fmt::Display for DescriptorProcessPsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DescriptorProcessPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DescriptorProcessPsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DescriptorProcessPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::Tx(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `submitpackage`.
+///
+/// > submitpackage ["rawtx",...]
+/// >
+/// > Submit a package of raw transactions (serialized, hex-encoded) to local node.
+/// > The package must consist of a child with its parents, and none of the parents may depend on one another.
+/// > The package will be validated according to consensus and mempool policy rules. If all transactions pass, they will be accepted to mempool.
+/// > This RPC is experimental and the interface may be unstable. Refer to doc/policy/packages.md for documentation on package policies.
+/// > Warning: successful submission does not mean the transactions will propagate throughout the network.
+/// >
+/// > Arguments:
+/// > 1. package          (json array, required) An array of raw transactions.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmitPackage {
+    /// Transaction results keyed by wtxid.
+    #[serde(rename = "tx-results")]
+    pub tx_results: BTreeMap<String, SubmitPackageTxResult>,
+    /// List of txids of replaced transactions.
+    #[serde(rename = "replaced-transactions")]
+    pub replaced_transactions: Option<Vec<String>>,
+}
+
+impl SubmitPackage {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SubmitPackage, SubmitPackageError> {
+        use SubmitPackageError as E;
+
+        let tx_results = self
+            .tx_results
+            .into_iter()
+            .map(|(wtxid, result)| {
+                let wtxid = wtxid.parse::<Wtxid>().map_err(E::TxResultWtxid)?;
+                Ok((wtxid, result.into_model()?))
+            })
+            .collect::<Result<_, E>>()?;
+        let replaced_transactions = self
+            .replaced_transactions
+            .unwrap_or_default()
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::ReplacedTransactions)?;
+
+        Ok(model::SubmitPackage { package_msg: None, tx_results, replaced_transactions })
+    }
+}
+
+/// The result of submitting a single package transaction. Part of `submitpackage`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmitPackageTxResult {
+    /// The transaction hash in hex.
+    pub txid: String,
+    /// The wtxid of a different transaction with the same txid but different witness found in the mempool.
+    ///
+    /// If set, this means the submitted transaction was ignored.
+    #[serde(rename = "other-wtxid")]
+    pub other_wtxid: Option<String>,
+    /// Virtual transaction size as defined in BIP 141.
+    pub vsize: Option<i64>,
+    /// Transaction fees.
+    pub fees: Option<MempoolAcceptanceFees>,
+}
+
+impl SubmitPackageTxResult {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SubmitPackageTxResult, SubmitPackageError> {
+        use SubmitPackageError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::TxResultTxid)?;
+        let other_wtxid = self
+            .other_wtxid
+            .map(|wtxid| wtxid.parse::<Wtxid>())
+            .transpose()
+            .map_err(E::TxResultOtherWtxid)?;
+        let vsize = self.vsize.map(|v| crate::to_u32(v, "vsize")).transpose()?;
+        let fees = self.fees.map(|f| f.into_model()).transpose().map_err(E::TxResultFees)?;
+
+        Ok(model::SubmitPackageTxResult {
+            txid,
+            other_wtxid,
+            vsize,
+            fees,
+            error: None,
+            error_message: None,
+        })
+    }
+}
+
+/// Error when converting a `SubmitPackage` type into the model type.
+#[derive(Debug)]
+pub enum SubmitPackageError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of a `tx_results` map key failed.
+    TxResultWtxid(hex::HexToArrayError),
+    /// Conversion of a transaction result `txid` field failed.
+    TxResultTxid(hex::HexToArrayError),
+    /// Conversion of a transaction result `other_wtxid` field failed.
+    TxResultOtherWtxid(hex::HexToArrayError),
+    /// Conversion of a transaction result `fees` field failed.
+    TxResultFees(MempoolAcceptanceFeesError),
+    /// Conversion of one of the `replaced_transactions` txids failed.
+    ReplacedTransactions(hex::HexToArrayError),
+}
+
+impl fmt::Display for SubmitPackageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SubmitPackageError as E;
+
+        match *self {
+            E::Numeric(ref e) => write_err!(f, "numeric"; e),
+            E::TxResultWtxid(ref e) =>
+                write_err!(f, "conversion of a `tx_results` map key failed"; e),
+            E::TxResultTxid(ref e) =>
+                write_err!(f, "conversion of a transaction result `txid` field failed"; e),
+            E::TxResultOtherWtxid(ref e) =>
+                write_err!(f, "conversion of a transaction result `other_wtxid` field failed"; e),
+            E::TxResultFees(ref e) =>
+                write_err!(f, "conversion of a transaction result `fees` field failed"; e),
+            E::ReplacedTransactions(ref e) =>
+                write_err!(f, "conversion of one of the `replaced_transactions` txids failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for SubmitPackageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SubmitPackageError as E;
+
+        match *self {
+            E::Numeric(ref e) => Some(e),
+            E::TxResultWtxid(ref e) => Some(e),
+            E::TxResultTxid(ref e) => Some(e),
+            E::TxResultOtherWtxid(ref e) => Some(e),
+            E::TxResultFees(ref e) => Some(e),
+            E::ReplacedTransactions(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for SubmitPackageError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
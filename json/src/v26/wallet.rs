@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26.2 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::consensus::encode;
+use bitcoin::hashes::hash160;
+use bitcoin::psbt::{Psbt, PsbtParseError};
+use bitcoin::{Amount, BlockHash, Transaction};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::GetWalletInfoError;
+use crate::v19::GetWalletInfoScanning;
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: i64,
+    /// The database format (bdb or sqlite).
+    pub format: String,
+    /// DEPRECATED. The total confirmed balance of the wallet in BTC.
+    pub balance: Option<f64>,
+    /// DEPRECATED. The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: Option<f64>,
+    /// DEPRECATED. The total immature balance of the wallet in BTC.
+    pub immature_balance: Option<f64>,
+    /// The total number of transactions in the wallet.
+    #[serde(rename = "txcount")]
+    pub tx_count: i64,
+    /// The UNIX epoch time of the oldest pre-generated key in the key pool (legacy wallet only).
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: Option<i64>,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: i64,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    pub keypool_size_hd_internal: Option<i64>,
+    /// The UNIX epoch time until which the wallet is unlocked for transfers, or 0 if the wallet
+    /// is locked (only present for passphrase-encrypted wallets).
+    pub unlocked_until: Option<i64>,
+    /// The transaction fee configuration, set in BTC/kvB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse.
+    pub avoid_reuse: bool,
+    /// Current scanning details, or false if no scan is in progress.
+    pub scanning: GetWalletInfoScanning,
+    /// Whether this wallet uses descriptors for scriptPubKey management.
+    pub descriptors: bool,
+    /// Whether this wallet is configured to use an external signer such as a hardware wallet.
+    pub external_signer: bool,
+    /// Whether this wallet intentionally does not contain any keys, scripts, or descriptors.
+    pub blank: bool,
+    /// The start time for blocks scanning. It could be modified by (re)importing any descriptor
+    /// with an earlier timestamp.
+    pub birthtime: Option<i64>,
+    /// Hash and height of the block this information was generated on.
+    #[serde(rename = "lastprocessedblock")]
+    pub last_processed_block: LastProcessedBlock,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let wallet_version = crate::to_u32(self.wallet_version, "walletversion")?;
+        let balance = self.balance.map(Amount::from_btc).transpose().map_err(E::Balance)?;
+        let unconfirmed_balance = self
+            .unconfirmed_balance
+            .map(Amount::from_btc)
+            .transpose()
+            .map_err(E::UnconfirmedBalance)?;
+        let immature_balance =
+            self.immature_balance.map(Amount::from_btc).transpose().map_err(E::ImmatureBalance)?;
+        let keypool_oldest =
+            self.keypool_oldest.map(|t| crate::to_u32(t, "keypoololdest")).transpose()?;
+        let tx_count = crate::to_u32(self.tx_count, "txcount")?;
+        let keypool_size = crate::to_u32(self.keypool_size, "keypoolsize")?;
+        let keypool_size_hd_internal = self
+            .keypool_size_hd_internal
+            .map(|n| crate::to_u32(n, "keypoolsize_hd_internal"))
+            .transpose()?;
+        let unlocked_until =
+            self.unlocked_until.map(|t| crate::to_u32(t, "unlocked_until")).transpose()?;
+        let pay_tx_fee = crate::btc_per_kb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+        let birthtime = self.birthtime.map(|t| crate::to_u32(t, "birthtime")).transpose()?;
+        let last_processed_block = self.last_processed_block.into_model()?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version,
+            format: Some(self.format),
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count,
+            keypool_oldest,
+            keypool_size,
+            keypool_size_hd_internal,
+            unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            avoid_reuse: Some(self.avoid_reuse),
+            scanning: Some(self.scanning.into_model()),
+            descriptors: Some(self.descriptors),
+            external_signer: Some(self.external_signer),
+            blank: Some(self.blank),
+            birthtime,
+            last_processed_block: Some(last_processed_block),
+        })
+    }
+}
+
+/// Hash and height of the block this information was generated on, part of `getwalletinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LastProcessedBlock {
+    /// Hash of the block this information was generated on.
+    pub hash: String,
+    /// Height of the block this information was generated on.
+    pub height: i64,
+}
+
+impl LastProcessedBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::LastProcessedBlock, GetWalletInfoError> {
+        let hash =
+            self.hash.parse::<BlockHash>().map_err(GetWalletInfoError::LastProcessedBlock)?;
+        let height = crate::to_u32(self.height, "height")?;
+        Ok(model::LastProcessedBlock { hash, height })
+    }
+}
+
+/// Result of the JSON-RPC method `walletprocesspsbt`.
+///
+/// > walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs finalize )
+/// >
+/// > Update a PSBT with input information from our wallet and then sign inputs
+/// > that we can sign for.
+/// > Requires wallet passphrase to be set with walletpassphrase call if wallet is encrypted.
+/// >
+/// > Arguments:
+/// > 1. psbt           (string, required) The transaction base64 string
+/// > 2. sign           (boolean, optional, default=true) Also sign the transaction when updating (requires wallet to be unlocked)
+/// > 3. sighashtype    (string, optional, default="DEFAULT for Taproot, ALL otherwise") The signature hash type to sign with if not specified by the PSBT. Must be one of
+/// > 4. bip32derivs    (boolean, optional, default=true) Include BIP 32 derivation paths for public keys if we know them
+/// > 5. finalize       (boolean, optional, default=true) Also finalize inputs if possible
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletProcessPsbt {
+    /// The base64-encoded partially signed transaction.
+    pub psbt: String,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The hex-encoded network transaction if complete.
+    pub hex: Option<String>,
+}
+
+impl WalletProcessPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WalletProcessPsbt, WalletProcessPsbtError> {
+        use WalletProcessPsbtError as E;
+
+        let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
+        let tx = self
+            .hex
+            .map(|s| encode::deserialize_hex::<Transaction>(&s))
+            .transpose()
+            .map_err(E::Tx)?;
+
+        Ok(model::WalletProcessPsbt { psbt, complete: self.complete, tx })
+    }
+}
+
+/// Error when converting a `WalletProcessPsbt` type into the model type.
+#[derive(Debug)]
+pub enum WalletProcessPsbtError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the transaction `hex` field failed.
+    Tx(encode::FromHexError),
+}
+
+impl fmt::Display for WalletProcessPsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use WalletProcessPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for WalletProcessPsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use WalletProcessPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::Tx(ref e) => Some(e),
+        }
+    }
+}
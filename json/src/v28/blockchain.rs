@@ -10,7 +10,7 @@ use bitcoin::{BlockHash, Network, Work};
 use serde::{Deserialize, Serialize};
 
 use super::{GetBlockchainInfoError, Softfork};
-use crate::model;
+use crate::{model, NumericError};
 
 #[rustfmt::skip]                // Keep public re-exports separate.
 
@@ -75,7 +75,11 @@ impl GetBlockchainInfo {
             self.prune_height.map(|h| crate::to_u32(h, "prune_height")).transpose()?;
         let prune_target_size =
             self.prune_target_size.map(|h| crate::to_u32(h, "prune_target_size")).transpose()?;
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let softforks = self
+            .softforks
+            .into_iter()
+            .map(|(name, softfork)| Ok((name, softfork.into_model()?)))
+            .collect::<Result<BTreeMap<_, _>, NumericError>>()?;
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v28.0 - mining.
+//!
+//! Types for methods found under the `== Mining ==` section of the API docs.
+
+use bitcoin::{Network, Weight};
+use serde::{Deserialize, Serialize};
+
+use super::GetMiningInfoError;
+use crate::model;
+
+/// Result of the JSON-RPC method `getmininginfo`.
+///
+/// > getmininginfo
+/// >
+/// > Returns a json object containing mining-related information.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMiningInfo {
+    /// The current block.
+    pub blocks: i64,
+    /// The block weight of the last assembled block (only present if a block was ever assembled).
+    #[serde(rename = "currentblockweight")]
+    pub current_block_weight: Option<u64>,
+    /// The number of block transactions of the last assembled block (only present if a block was
+    /// ever assembled).
+    #[serde(rename = "currentblocktx")]
+    pub current_block_tx: Option<i64>,
+    /// The current difficulty.
+    pub difficulty: f64,
+    /// The network hashes per second.
+    #[serde(rename = "networkhashps")]
+    pub network_hash_ps: f64,
+    /// The size of the mempool.
+    #[serde(rename = "pooledtx")]
+    pub pooled_tx: i64,
+    /// Current network name (main, test, testnet4, signet, regtest).
+    pub chain: String,
+    /// Any network and blockchain warnings.
+    pub warnings: Vec<String>,
+}
+
+impl GetMiningInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMiningInfo, GetMiningInfoError> {
+        use GetMiningInfoError as E;
+
+        let current_block_tx =
+            self.current_block_tx.map(|t| crate::to_u32(t, "current_block_tx")).transpose()?;
+        let chain = Network::from_core_arg(&self.chain).map_err(E::Chain)?;
+
+        Ok(model::GetMiningInfo {
+            blocks: crate::to_u32(self.blocks, "blocks")?,
+            current_block_weight: self.current_block_weight.map(Weight::from_wu),
+            current_block_tx,
+            difficulty: self.difficulty,
+            network_hash_ps: self.network_hash_ps,
+            pooled_tx: crate::to_u32(self.pooled_tx, "pooled_tx")?,
+            chain,
+            warnings: self.warnings,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v28.0 - raw transactions.
+//!
+//! Types for methods found under the `== Rawtransactions ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use bitcoin::{Txid, Wtxid};
+use serde::{Deserialize, Serialize};
+
+use super::{MempoolAcceptanceFees, SubmitPackageError};
+use crate::model;
+use crate::v17::reject_reason;
+
+/// Result of JSON-RPC method `submitpackage`.
+///
+/// > submitpackage ["rawtx",...] ( maxfeerate maxburnamount )
+/// >
+/// > Submit a package of raw transactions (serialized, hex-encoded) to local node.
+/// > The package will be validated according to consensus and mempool policy rules. If any transaction passes, it will be accepted to mempool.
+/// > This RPC is experimental and the interface may be unstable. Refer to doc/policy/packages.md for documentation on package policies.
+/// > Warning: successful submission does not mean the transactions will propagate throughout the network.
+/// >
+/// > Arguments:
+/// > 1. package          (json array, required) An array of raw transactions.
+/// >                     The package must solely consist of a child and its parents. None of the parents may depend on each other.
+/// >                     The package must be topologically sorted, with the child being the last element in the array.
+/// > 2. maxfeerate       (numeric or string, optional, default="0.10") Reject transactions whose fee rate is higher than the specified value, expressed in BTC/kvB.
+/// > 3. maxburnamount    (numeric or string, optional, default="0.00") Reject transactions with provably unspendable outputs (e.g. 'datacarrier' outputs that use the OP_RETURN opcode) greater than the specified value, expressed in BTC.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmitPackage {
+    /// The transaction package result message.
+    ///
+    /// "success" indicates all transactions were accepted into or are already in the mempool.
+    pub package_msg: String,
+    /// Transaction results keyed by wtxid.
+    #[serde(rename = "tx-results")]
+    pub tx_results: BTreeMap<String, SubmitPackageTxResult>,
+    /// List of txids of replaced transactions.
+    #[serde(rename = "replaced-transactions")]
+    pub replaced_transactions: Option<Vec<String>>,
+}
+
+impl SubmitPackage {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SubmitPackage, SubmitPackageError> {
+        use SubmitPackageError as E;
+
+        let tx_results = self
+            .tx_results
+            .into_iter()
+            .map(|(wtxid, result)| {
+                let wtxid = wtxid.parse::<Wtxid>().map_err(E::TxResultWtxid)?;
+                Ok((wtxid, result.into_model()?))
+            })
+            .collect::<Result<_, E>>()?;
+        let replaced_transactions = self
+            .replaced_transactions
+            .unwrap_or_default()
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<_, _>>()
+            .map_err(E::ReplacedTransactions)?;
+
+        Ok(model::SubmitPackage {
+            package_msg: Some(self.package_msg),
+            tx_results,
+            replaced_transactions,
+        })
+    }
+}
+
+/// The result of submitting a single package transaction. Part of `submitpackage`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmitPackageTxResult {
+    /// The transaction hash in hex.
+    pub txid: String,
+    /// The wtxid of a different transaction with the same txid but different witness found in the mempool.
+    ///
+    /// If set, this means the submitted transaction was ignored.
+    #[serde(rename = "other-wtxid")]
+    pub other_wtxid: Option<String>,
+    /// Sigops-adjusted virtual transaction size.
+    pub vsize: Option<i64>,
+    /// Transaction fees.
+    pub fees: Option<MempoolAcceptanceFees>,
+    /// The transaction error string, if it was rejected by the mempool.
+    pub error: Option<String>,
+}
+
+impl SubmitPackageTxResult {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SubmitPackageTxResult, SubmitPackageError> {
+        use SubmitPackageError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::TxResultTxid)?;
+        let other_wtxid = self
+            .other_wtxid
+            .map(|wtxid| wtxid.parse::<Wtxid>())
+            .transpose()
+            .map_err(E::TxResultOtherWtxid)?;
+        let vsize = self.vsize.map(|v| crate::to_u32(v, "vsize")).transpose()?;
+        let fees = self.fees.map(|f| f.into_model()).transpose().map_err(E::TxResultFees)?;
+
+        Ok(model::SubmitPackageTxResult {
+            txid,
+            other_wtxid,
+            vsize,
+            fees,
+            error: self.error.as_deref().map(reject_reason),
+            error_message: self.error,
+        })
+    }
+}
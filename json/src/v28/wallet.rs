@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v28.0 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::bip32::{self, Xpriv, Xpub};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of the JSON-RPC method `createwalletdescriptor`.
+///
+/// > createwalletdescriptor "type" ( {"internal":bool,"hdkey":"str",...} )
+/// >
+/// > Creates the wallet's descriptor for the given address type. The address type must be one that the wallet does not already have a descriptor for.
+/// > Requires wallet passphrase to be set with walletpassphrase call if wallet is encrypted.
+/// >
+/// > Arguments:
+/// > 1. type                 (string, required) The address type the descriptor will produce. Options are "legacy", "p2sh-segwit", "bech32", and "bech32m".
+/// > 2. options              (json object, optional) Options object that can be used to pass named arguments, listed below.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateWalletDescriptor {
+    /// The public descriptors that were added to the wallet.
+    #[serde(rename = "descs")]
+    pub descriptors: Vec<String>,
+}
+
+impl CreateWalletDescriptor {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CreateWalletDescriptor, CreateWalletDescriptorError> {
+        #[cfg(feature = "miniscript")]
+        let parsed_descriptors = self
+            .descriptors
+            .iter()
+            .map(|s| crate::descriptor(s))
+            .collect::<Result<_, _>>()
+            .map_err(CreateWalletDescriptorError::Descriptor)?;
+
+        Ok(model::CreateWalletDescriptor {
+            descriptors: self.descriptors,
+            #[cfg(feature = "miniscript")]
+            parsed_descriptors,
+        })
+    }
+}
+
+/// Error when converting a `CreateWalletDescriptor` type into the model type.
+///
+/// Conversion can only fail if descriptors are parsed i.e., with the `miniscript` feature.
+#[derive(Debug)]
+pub enum CreateWalletDescriptorError {
+    /// Conversion of one of the `descs` failed.
+    #[cfg(feature = "miniscript")]
+    Descriptor(miniscript::Error),
+}
+
+impl fmt::Display for CreateWalletDescriptorError {
+    #[cfg_attr(not(feature = "miniscript"), allow(unused_variables))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            #[cfg(feature = "miniscript")]
+            Self::Descriptor(ref e) => write_err!(f, "conversion of the `descs` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for CreateWalletDescriptorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            #[cfg(feature = "miniscript")]
+            Self::Descriptor(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `gethdkeys`.
+///
+/// > gethdkeys ( {"active_only":bool,"private":bool,...} )
+/// >
+/// > List all BIP 32 HD keys in the wallet and which descriptors use them.
+/// >
+/// > Arguments:
+/// > 1. options                  (json object, optional)
+/// >      {
+/// >        "active_only": bool,    (boolean, optional, default=false) Show the keys for only active descriptors
+/// >        "private": bool,        (boolean, optional, default=false) Show private keys
+/// >      }
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetHdKeys(pub Vec<HdKey>);
+
+impl GetHdKeys {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetHdKeys, HdKeyError> {
+        let keys = self.0.into_iter().map(|key| key.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetHdKeys(keys))
+    }
+}
+
+/// An HD key used by the wallet. Part of `gethdkeys`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HdKey {
+    /// The extended public key.
+    pub xpub: String,
+    /// Whether the wallet has the private key for this xpub.
+    pub has_private: bool,
+    /// The extended private key if "private" is true.
+    pub xprv: Option<String>,
+    /// Array of descriptor objects that use this HD key.
+    pub descriptors: Vec<HdKeyDescriptor>,
+}
+
+impl HdKey {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::HdKey, HdKeyError> {
+        use HdKeyError as E;
+
+        let xpub = self.xpub.parse::<Xpub>().map_err(E::Xpub)?;
+        let xprv = self.xprv.map(|s| s.parse::<Xpriv>()).transpose().map_err(E::Xprv)?;
+        let descriptors = self.descriptors.into_iter().map(|d| d.into_model()).collect();
+
+        Ok(model::HdKey { xpub, has_private: self.has_private, xprv, descriptors })
+    }
+}
+
+/// A descriptor using an HD key. Part of `gethdkeys`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HdKeyDescriptor {
+    /// Descriptor string representation.
+    #[serde(rename = "desc")]
+    pub descriptor: String,
+    /// Whether this descriptor is currently used to generate new addresses.
+    pub active: bool,
+}
+
+impl HdKeyDescriptor {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::HdKeyDescriptor {
+        model::HdKeyDescriptor { descriptor: self.descriptor, active: self.active }
+    }
+}
+
+/// Error when converting a `HdKey` type into the model type.
+#[derive(Debug)]
+pub enum HdKeyError {
+    /// Conversion of the `xpub` field failed.
+    Xpub(bip32::Error),
+    /// Conversion of the `xprv` field failed.
+    Xprv(bip32::Error),
+}
+
+impl fmt::Display for HdKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use HdKeyError as E;
+
+        match *self {
+            E::Xpub(ref e) => write_err!(f, "conversion of the `xpub` field failed"; e),
+            E::Xprv(ref e) => write_err!(f, "conversion of the `xprv` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for HdKeyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use HdKeyError as E;
+
+        match *self {
+            E::Xpub(ref e) => Some(e),
+            E::Xprv(ref e) => Some(e),
+        }
+    }
+}
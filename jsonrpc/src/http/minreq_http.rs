@@ -11,7 +11,7 @@ use std::time::Duration;
 use std::{error, fmt};
 
 use crate::client::Transport;
-use crate::{Request, Response};
+use crate::{Request, Response, Secret};
 
 const DEFAULT_URL: &str = "http://localhost";
 const DEFAULT_PORT: u16 = 8332; // the default RPC port for bitcoind.
@@ -28,7 +28,7 @@ pub struct MinreqHttpTransport {
     /// timeout only supports second granularity.
     timeout: Duration,
     /// The value of the `Authorization` HTTP header, i.e., a base64 encoding of 'user:password'.
-    basic_auth: Option<String>,
+    basic_auth: Option<Secret>,
 }
 
 impl Default for MinreqHttpTransport {
@@ -55,7 +55,7 @@ impl MinreqHttpTransport {
         let req = match &self.basic_auth {
             Some(auth) => minreq::Request::new(minreq::Method::Post, &self.url)
                 .with_timeout(self.timeout.as_secs())
-                .with_header("Authorization", auth)
+                .with_header("Authorization", auth.expose_secret())
                 .with_json(&req)?,
             None => minreq::Request::new(minreq::Method::Post, &self.url)
                 .with_timeout(self.timeout.as_secs())
@@ -117,13 +117,9 @@ impl Builder {
     }
 
     /// Adds authentication information to the transport.
-    pub fn basic_auth(mut self, user: String, pass: Option<String>) -> Self {
-        let mut s = user;
-        s.push(':');
-        if let Some(ref pass) = pass {
-            s.push_str(pass.as_ref());
-        }
-        self.tp.basic_auth = Some(format!("Basic {}", &base64::encode(s.as_bytes())));
+    pub fn basic_auth<S: AsRef<str>>(mut self, user: S, pass: Option<S>) -> Self {
+        self.tp.basic_auth =
+            Some(crate::http::basic_auth(user.as_ref(), pass.as_ref().map(AsRef::as_ref)));
         self
     }
 
@@ -144,7 +140,7 @@ impl Builder {
     /// let client = MinreqHttpTransport::builder().cookie_auth(cookie);
     /// ```
     pub fn cookie_auth<S: AsRef<str>>(mut self, cookie: S) -> Self {
-        self.tp.basic_auth = Some(format!("Basic {}", &base64::encode(cookie.as_ref().as_bytes())));
+        self.tp.basic_auth = Some(crate::http::cookie_auth(cookie.as_ref()));
         self
     }
 
@@ -268,4 +264,23 @@ mod tests {
             .build();
         let _ = Client::with_transport(tp);
     }
+
+    #[test]
+    fn debug_redacts_credentials() {
+        let encoded = base64::encode("user:hunter2");
+        let builder = Builder::new().basic_auth("user".to_string(), Some("hunter2".to_string()));
+        let header = builder.tp.basic_auth.as_ref().unwrap();
+        assert_eq!(header.expose_secret(), format!("Basic {}", encoded));
+
+        let tp = Builder::new().cookie_auth("user:hunter2").build();
+        let debug = [
+            format!("{:?}", builder),
+            format!("{:?}", tp),
+            format!("{:?}", Client::with_transport(tp)),
+        ];
+        for debug in &debug {
+            assert!(!debug.contains("hunter2"), "{}", debug);
+            assert!(!debug.contains(&encoded), "{}", debug);
+        }
+    }
 }
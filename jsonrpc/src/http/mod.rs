@@ -6,6 +6,9 @@ pub mod simple_http;
 #[cfg(feature = "minreq_http")]
 pub mod minreq_http;
 
+#[cfg(any(feature = "simple_http", feature = "minreq_http"))]
+use crate::Secret;
+
 /// The default TCP port to use for connections.
 /// Set to 8332, the default RPC port for bitcoind.
 pub const DEFAULT_PORT: u16 = 8332;
@@ -15,3 +18,25 @@ pub const DEFAULT_PORT: u16 = 8332;
 // Currently only used by `simple_http` module, here for consistency.
 #[cfg(feature = "proxy")]
 pub const DEFAULT_PROXY_PORT: u16 = 9050;
+
+/// Returns the value of the `Authorization` HTTP header for `user` and `pass`.
+#[cfg(any(feature = "simple_http", feature = "minreq_http"))]
+pub(crate) fn basic_auth(user: &str, pass: Option<&str>) -> Secret {
+    let pass = pass.unwrap_or_default();
+    // Allocate once up front, a reallocation would leave a copy of the password behind.
+    let mut cookie = String::with_capacity(user.len() + 1 + pass.len());
+    cookie.push_str(user);
+    cookie.push(':');
+    cookie.push_str(pass);
+    cookie_auth(Secret::from(cookie).expose_secret())
+}
+
+/// Returns the value of the `Authorization` HTTP header for a cookie string ('user:pass').
+#[cfg(any(feature = "simple_http", feature = "minreq_http"))]
+pub(crate) fn cookie_auth(cookie: &str) -> Secret {
+    let encoded = Secret::from(base64::encode(cookie.as_bytes()));
+    let mut header = String::with_capacity("Basic ".len() + encoded.expose_secret().len());
+    header.push_str("Basic ");
+    header.push_str(encoded.expose_secret());
+    Secret::from(header)
+}
@@ -21,7 +21,7 @@ use crate::client::Transport;
 use crate::http::DEFAULT_PORT;
 #[cfg(feature = "proxy")]
 use crate::http::DEFAULT_PROXY_PORT;
-use crate::{Request, Response};
+use crate::{Request, Response, Secret};
 
 /// Absolute maximum content length allowed before cutting off the response.
 const FINAL_RESP_ALLOC: u64 = 1024 * 1024 * 1024;
@@ -40,11 +40,11 @@ pub struct SimpleHttpTransport {
     path: String,
     timeout: Duration,
     /// The value of the `Authorization` HTTP header.
-    basic_auth: Option<String>,
+    basic_auth: Option<Secret>,
     #[cfg(feature = "proxy")]
     proxy_addr: net::SocketAddr,
     #[cfg(feature = "proxy")]
-    proxy_auth: Option<(String, String)>,
+    proxy_auth: Option<(String, Secret)>,
     sock: Arc<Mutex<Option<BufReader<TcpStream>>>>,
 }
 
@@ -109,7 +109,7 @@ impl SimpleHttpTransport {
                 self.proxy_addr,
                 self.addr,
                 username.as_str(),
-                password.as_str(),
+                password.expose_secret(),
             )?
         } else {
             Socks5Stream::connect(self.proxy_addr, self.addr)?
@@ -156,7 +156,7 @@ impl SimpleHttpTransport {
         request_bytes.write_all(b"\r\n")?;
         if let Some(ref auth) = self.basic_auth {
             request_bytes.write_all(b"Authorization: ")?;
-            request_bytes.write_all(auth.as_ref())?;
+            request_bytes.write_all(auth.expose_secret().as_bytes())?;
             request_bytes.write_all(b"\r\n")?;
         }
         // Write body
@@ -372,18 +372,14 @@ impl Builder {
 
     /// Adds authentication information to the transport.
     pub fn auth<S: AsRef<str>>(mut self, user: S, pass: Option<S>) -> Self {
-        let mut auth = user.as_ref().to_owned();
-        auth.push(':');
-        if let Some(ref pass) = pass {
-            auth.push_str(pass.as_ref());
-        }
-        self.tp.basic_auth = Some(format!("Basic {}", &base64::encode(auth.as_bytes())));
+        self.tp.basic_auth =
+            Some(crate::http::basic_auth(user.as_ref(), pass.as_ref().map(AsRef::as_ref)));
         self
     }
 
     /// Adds authentication information to the transport using a cookie string ('user:pass').
     pub fn cookie_auth<S: AsRef<str>>(mut self, cookie: S) -> Self {
-        self.tp.basic_auth = Some(format!("Basic {}", &base64::encode(cookie.as_ref().as_bytes())));
+        self.tp.basic_auth = Some(crate::http::cookie_auth(cookie.as_ref()));
         self
     }
 
@@ -398,8 +394,7 @@ impl Builder {
     /// Adds optional proxy authentication as ('username', 'password').
     #[cfg(feature = "proxy")]
     pub fn proxy_auth<S: AsRef<str>>(mut self, user: S, pass: S) -> Self {
-        self.tp.proxy_auth =
-            Some((user, pass)).map(|(u, p)| (u.as_ref().to_string(), p.as_ref().to_string()));
+        self.tp.proxy_auth = Some((user.as_ref().to_string(), Secret::from(pass.as_ref())));
         self
     }
 
@@ -724,6 +719,33 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn debug_redacts_credentials() {
+        let encoded = base64::encode("user:hunter2");
+        let builder = Builder::new().url("localhost:22").unwrap().auth("user", Some("hunter2"));
+        let header = builder.tp.basic_auth.as_ref().unwrap();
+        assert_eq!(header.expose_secret(), format!("Basic {}", encoded));
+
+        let tp = Builder::new().url("localhost:22").unwrap().cookie_auth("user:hunter2").build();
+        let debug = [
+            format!("{:?}", builder),
+            format!("{:?}", tp),
+            format!("{:?}", Client::with_transport(tp)),
+        ];
+        for debug in &debug {
+            assert!(!debug.contains("hunter2"), "{}", debug);
+            assert!(!debug.contains(&encoded), "{}", debug);
+        }
+    }
+
+    #[cfg(feature = "proxy")]
+    #[test]
+    fn debug_redacts_proxy_credentials() {
+        let tp = Builder::new().proxy_auth("user", "hunter2").build();
+        let debug = format!("{:?}", tp);
+        assert!(!debug.contains("hunter2"), "{}", debug);
+    }
+
     /// Test that the client will detect that a socket is closed and open a fresh one before sending
     /// the request
     #[cfg(all(not(feature = "proxy"), not(jsonrpc_fuzz)))]
@@ -24,6 +24,7 @@ pub extern crate minreq;
 pub mod client;
 pub mod error;
 pub mod http;
+pub mod secret;
 
 #[cfg(feature = "minreq_http")]
 pub use http::minreq_http;
@@ -41,6 +42,7 @@ use serde_json::value::RawValue;
 
 pub use crate::client::{Client, Transport};
 pub use crate::error::Error;
+pub use crate::secret::Secret;
 
 /// Shorthand method to convert an argument into a boxed [`serde_json::value::RawValue`].
 ///
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A wrapper for secret strings e.g., passwords and `Authorization` header values.
+
+use std::sync::atomic::{self, Ordering};
+use std::{fmt, ptr};
+
+/// Replaces the secret in formatted output.
+const REDACTED: &str = "<redacted>";
+
+/// A secret string that is never formatted and is zeroed when dropped.
+///
+/// Both [`fmt::Debug`] and [`fmt::Display`] print `<redacted>` so a secret can not end up in
+/// logs by accident, use [`Secret::expose_secret`] to get at the value.
+#[derive(Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Wraps `secret`.
+    pub fn new<S: Into<String>>(secret: S) -> Self { Secret(secret.into()) }
+
+    /// Returns the secret value.
+    pub fn expose_secret(&self) -> &str { &self.0 }
+
+    /// Returns `true` if the secret is the empty string.
+    pub fn is_empty(&self) -> bool { self.0.is_empty() }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self { Secret(secret) }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self { Secret(secret.to_owned()) }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "Secret({})", REDACTED) }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(REDACTED) }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) { wipe(&mut self.0) }
+}
+
+/// Overwrites the whole allocation of `s`, including any spare capacity, with zeros.
+///
+/// Uses volatile writes so the compiler can not optimize them away as dead stores.
+fn wipe(s: &mut String) {
+    // SAFETY: Zero bytes are valid UTF-8, and we only write within the allocated capacity.
+    unsafe {
+        let bytes = s.as_mut_vec();
+        let ptr = bytes.as_mut_ptr();
+        for i in 0..bytes.capacity() {
+            ptr::write_volatile(ptr.add(i), 0);
+        }
+    }
+    atomic::compiler_fence(Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatting_is_redacted() {
+        let secret = Secret::new("hunter2");
+        assert_eq!(format!("{:?}", secret), "Secret(<redacted>)");
+        assert_eq!(format!("{}", secret), "<redacted>");
+        assert_eq!(
+            format!("{:?}", Some((String::from("user"), secret))),
+            "Some((\"user\", Secret(<redacted>)))"
+        );
+    }
+
+    #[test]
+    fn expose_secret() {
+        let secret = Secret::from("hunter2");
+        assert_eq!(secret.expose_secret(), "hunter2");
+        assert_eq!(secret.clone(), secret);
+        assert!(Secret::default().is_empty());
+    }
+
+    #[test]
+    fn wipe_zeroes_allocation() {
+        let mut s = String::with_capacity(16);
+        s.push_str("hunter2");
+        wipe(&mut s);
+        assert_eq!(s.as_bytes(), &[0; 7]);
+    }
+}
@@ -16,6 +16,7 @@ use std::{env, fmt, fs, thread};
 
 use anyhow::Context;
 use bitcoind_json_rpc_client::client_sync::{self, Auth};
+use bitcoind_json_rpc_client::rpcauth::Credentials;
 use log::{debug, error, warn};
 use tempfile::TempDir;
 pub use {anyhow, tempfile, which};
@@ -134,6 +135,10 @@ pub enum Error {
     SkipDownload,
     /// It appears that bitcoind is not reachable.
     NoBitcoindInstance,
+    /// The block filter index did not catch up with the chain tip in time.
+    BlockFilterIndexNotSynced,
+    /// Returned when an [`RpcUser`] has a whitelist but the node is older than v0.20.
+    RpcWhitelistUnsupported,
 }
 
 impl fmt::Debug for Error {
@@ -151,6 +156,8 @@ impl fmt::Debug for Error {
             RpcUserAndPasswordUsed => write!(f, "`-rpcuser` and `-rpcpassword` cannot be used, it will be deprecated soon and it's recommended to use `-rpcauth` instead which works alongside with the default cookie authentication"),
             SkipDownload => write!(f, "expecting an auto-downloaded executable but `BITCOIND_SKIP_DOWNLOAD` env var is set"),
             NoBitcoindInstance => write!(f, "it appears that bitcoind is not reachable"),
+            BlockFilterIndexNotSynced => write!(f, "the block filter index did not catch up with the chain tip"),
+            RpcWhitelistUnsupported => write!(f, "`-rpcwhitelist` requires bitcoind v0.20 or later"),
         }
     }
 }
@@ -173,7 +180,9 @@ impl std::error::Error for Error {
             | BothDirsSpecified
             | RpcUserAndPasswordUsed
             | SkipDownload
-            | NoBitcoindInstance => None,
+            | NoBitcoindInstance
+            | BlockFilterIndexNotSynced
+            | RpcWhitelistUnsupported => None,
         }
     }
 }
@@ -199,6 +208,7 @@ const INVALID_ARGS: [&str; 2] = ["-rpcuser", "-rpcpassword"];
 /// conf.tmpdir = None;
 /// conf.staticdir = None;
 /// conf.attempts = 3;
+/// conf.rpc_users = vec![];
 /// assert_eq!(conf, bitcoind::Conf::default());
 /// ```
 ///
@@ -250,6 +260,48 @@ pub struct Conf<'a> {
 
     /// Load `wallet` after initialization.
     pub wallet: Option<String>,
+
+    /// Additional JSON-RPC users, passed to the node as `-rpcauth` (and `-rpcwhitelist`) args.
+    ///
+    /// The node is always accessible using the cookie file, use [`BitcoinD::client_for`] to
+    /// connect as one of these users.
+    pub rpc_users: Vec<RpcUser>,
+}
+
+/// A JSON-RPC user added to the node using `-rpcauth`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RpcUser {
+    /// The user name and password, the node only gets the salted HMAC of the password.
+    pub credentials: Credentials,
+    /// The RPC methods this user is allowed to call, `None` allows all methods.
+    ///
+    /// Requires Bitcoin Core v0.20 or later, starting an older node with a whitelist set errors
+    /// with [`Error::RpcWhitelistUnsupported`].
+    pub whitelist: Option<Vec<String>>,
+}
+
+impl RpcUser {
+    /// Creates a user called `user`, with a random password, that can call all methods.
+    pub fn new(user: &str) -> Self {
+        RpcUser { credentials: Credentials::generate(user), whitelist: None }
+    }
+
+    /// Creates a user called `user`, with a random password, that can only call `methods`.
+    pub fn with_whitelist(user: &str, methods: &[&str]) -> Self {
+        RpcUser {
+            credentials: Credentials::generate(user),
+            whitelist: Some(methods.iter().map(|m| m.to_string()).collect()),
+        }
+    }
+
+    /// Returns the `bitcoind` args for this user.
+    fn args(&self) -> Vec<String> {
+        let mut args = vec![self.credentials.rpcauth().to_arg()];
+        if let Some(ref whitelist) = self.whitelist {
+            args.push(format!("-rpcwhitelist={}:{}", self.credentials.user, whitelist.join(",")));
+        }
+        args
+    }
 }
 
 impl Default for Conf<'_> {
@@ -264,6 +316,7 @@ impl Default for Conf<'_> {
             attempts: 3,
             enable_zmq: false,
             wallet: Some("default".to_string()),
+            rpc_users: vec![],
         }
     }
 }
@@ -348,6 +401,16 @@ impl BitcoinD {
         let rpc_arg = format!("-rpcport={}", rpc_port);
         let default_args = [&datadir_arg, &rpc_arg];
         let conf_args = validate_args(conf.args.clone())?;
+        let mut rpc_user_args: Vec<String> =
+            conf.rpc_users.iter().flat_map(RpcUser::args).collect();
+        if conf.rpc_users.iter().any(|user| user.whitelist.is_some()) {
+            // `-rpcwhitelist` and `-rpcwhitelistdefault` were both added in v0.20.
+            if !cfg!(any(feature = "0_20_2", not(feature = "download"))) {
+                return Err(Error::RpcWhitelistUnsupported.into());
+            }
+            // Otherwise any whitelist implies an empty whitelist for the cookie user.
+            rpc_user_args.push("-rpcwhitelistdefault=0".to_string());
+        }
 
         debug!(
             "launching {:?} with args: {:?} {:?} AND custom args: {:?}",
@@ -362,6 +425,7 @@ impl BitcoinD {
             .args(&p2p_args)
             .args(&conf_args)
             .args(&zmq_args)
+            .args(&rpc_user_args)
             .stdout(stdout)
             .spawn()
             .with_context(|| format!("Error while executing {:?}", exe.as_ref()))?;
@@ -439,6 +503,37 @@ impl BitcoinD {
         })
     }
 
+    #[cfg(any(feature = "0_19_1", not(feature = "download")))]
+    /// Launch the bitcoind process with the given [Conf] param plus `-blockfilterindex`, and wait
+    /// for the block filter index to sync before returning.
+    pub fn with_block_filter_index<S: AsRef<OsStr>>(
+        exe: S,
+        conf: &Conf,
+    ) -> anyhow::Result<BitcoinD> {
+        let mut conf = conf.clone();
+        conf.args.push("-blockfilterindex=1");
+        let bitcoind = BitcoinD::with_conf(exe, &conf)?;
+        bitcoind.wait_for_block_filter_index()?;
+        Ok(bitcoind)
+    }
+
+    #[cfg(any(feature = "0_19_1", not(feature = "download")))]
+    /// Wait for the block filter index to catch up with the chain tip, e.g. after mining blocks.
+    ///
+    /// Requires the node to be running with `-blockfilterindex`, see
+    /// [`BitcoinD::with_block_filter_index`].
+    pub fn wait_for_block_filter_index(&self) -> anyhow::Result<()> {
+        for _ in 0..100 {
+            // The filter for the tip is only available once the index has caught up.
+            let tip = self.client.best_block_hash()?;
+            if self.client.get_block_filter(&tip).is_ok() {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        Err(Error::BlockFilterIndexNotSynced.into())
+    }
+
     /// Returns the rpc URL including the schema eg. http://127.0.0.1:44842
     pub fn rpc_url(&self) -> String { format!("http://{}", self.params.rpc_socket) }
 
@@ -449,6 +544,11 @@ impl BitcoinD {
         format!("http://{}/wallet/{}", self.params.rpc_socket, wallet_name.as_ref())
     }
 
+    /// Returns a client connected to this node as `user`, one of the [`Conf::rpc_users`].
+    pub fn client_for(&self, user: &RpcUser) -> anyhow::Result<Client> {
+        Ok(Client::new_with_auth(&self.rpc_url(), user.credentials.auth())?)
+    }
+
     /// Return the current workdir path of the running node
     pub fn workdir(&self) -> PathBuf { self.work_dir.path() }
 
@@ -622,6 +722,19 @@ mod test {
         assert!(bitcoind.client.server_version().unwrap() >= 210_000);
     }
 
+    #[test]
+    #[cfg(any(feature = "0_19_1", not(feature = "download")))]
+    fn test_block_filter_index() {
+        let exe = init();
+        let bitcoind = BitcoinD::with_block_filter_index(&exe, &Conf::default()).unwrap();
+        let address = bitcoind.client.new_address().unwrap();
+        let _ = bitcoind.client.generate_to_address(10, &address).unwrap();
+        bitcoind.wait_for_block_filter_index().unwrap();
+
+        let filters = bitcoind.client.verify_block_filter_headers(0, 10).unwrap();
+        assert_eq!(filters.len(), 11);
+    }
+
     #[test]
     fn test_p2p() {
         let exe = init();
@@ -783,7 +896,7 @@ mod test {
 
         let bitcoind = BitcoinD::with_conf(exe, &conf).unwrap();
 
-        let auth = Auth::UserPass("bitcoind".to_string(), "bitcoind".to_string());
+        let auth = Auth::UserPass("bitcoind".to_string(), "bitcoind".into());
         let client = Client::new_with_auth(
             format!("{}/wallet/default", bitcoind.rpc_url().as_str()).as_str(),
             auth,
@@ -798,6 +911,29 @@ mod test {
         assert_eq!(1, info.blocks);
     }
 
+    #[test]
+    #[cfg(any(feature = "0_20_2", not(feature = "download")))]
+    fn test_bitcoind_rpc_users() {
+        let exe = init();
+
+        let alice = RpcUser::new("alice");
+        let bob = RpcUser::with_whitelist("bob", &["getblockchaininfo"]);
+
+        let conf = Conf::<'_> { rpc_users: vec![alice.clone(), bob.clone()], ..Default::default() };
+        let bitcoind = BitcoinD::with_conf(exe, &conf).unwrap();
+
+        let alice = bitcoind.client_for(&alice).unwrap();
+        assert_eq!(0, alice.get_blockchain_info().unwrap().blocks);
+        assert!(alice.get_network_info().is_ok());
+
+        let bob = bitcoind.client_for(&bob).unwrap();
+        assert_eq!(0, bob.get_blockchain_info().unwrap().blocks);
+        assert!(bob.get_network_info().is_err());
+
+        // The cookie user is not affected by the whitelist.
+        assert!(bitcoind.client.get_network_info().is_ok());
+    }
+
     #[test]
     fn test_get_cookie_user_and_pass() {
         let exe = init();